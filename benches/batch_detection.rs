@@ -1,6 +1,6 @@
 use chrono::{Duration, Local};
 use overitall::log::{LogLine, LogSource};
-use overitall::ui::detect_batches_from_logs;
+use overitall::ui::{BatchStrategy, detect_batches_from_logs};
 use std::time::Instant;
 
 fn create_test_logs(count: usize) -> Vec<LogLine> {
@@ -28,7 +28,7 @@ fn main() {
         let frames = 60;
         let start = Instant::now();
         for _ in 0..frames {
-            let _ = detect_batches_from_logs(&refs, 100);
+            let _ = detect_batches_from_logs(&refs, 100, BatchStrategy::TimeWindow);
         }
         let elapsed = start.elapsed();
 
@@ -32,22 +32,68 @@ pub struct Cli {
     #[arg(short, long, default_value = ".overitall.toml")]
     pub config: String,
 
-    /// Path to Procfile (overrides config file setting)
-    #[arg(short = 'f', long = "file")]
-    pub procfile: Option<String>,
+    /// Path to Procfile (overrides config file setting). Use `-` to read from
+    /// stdin; `.yml`/`.yaml` files are parsed as YAML. Repeat to load several
+    /// Procfiles; their processes are named `<dir>/<name>`
+    #[arg(short = 'f', long = "file", visible_alias = "procfile")]
+    pub procfile: Vec<String>,
 
     /// Initialize a new .overitall.toml config file from Procfile
     #[arg(long)]
     pub init: bool,
 
+    /// With --init: print the generated config instead of writing it
+    #[arg(long, requires = "init")]
+    pub dry_run: bool,
+
+    /// With --init: overwrite an existing config file. Otherwise: start every
+    /// process even when there are more than `max_processes`
+    #[arg(long)]
+    pub force: bool,
+
     /// Skip auto-update check on startup
     #[arg(long)]
     pub no_update: bool,
 
+    /// Check for updates after the TUI starts and only report a newer release,
+    /// instead of updating before launch
+    #[arg(long)]
+    pub background_update_check: bool,
+
+    /// Start no processes; start them later with :s or `oit start`
+    #[arg(long)]
+    pub no_autostart: bool,
+
     /// Check for updates and exit (doesn't start processes)
     #[arg(long)]
     pub update: bool,
 
+    /// How to render ANSI escape codes in process output (overrides config)
+    #[arg(long, value_name = "MODE", value_parser = config::ANSI_MODES.to_vec())]
+    pub ansi_mode: Option<String>,
+
+    /// UI color preset (overrides config)
+    #[arg(long, value_name = "THEME", value_parser = crate::ui::theme::THEMES.to_vec())]
+    pub theme: Option<String>,
+
+    /// Quit once every process has exited and no logs arrived for this long (overrides config)
+    #[arg(long, value_name = "MS", value_parser = clap::value_parser!(u64).range(1..))]
+    pub idle_quit_ms: Option<u64>,
+
+    /// Directory to run processes in and resolve log files against, instead of
+    /// the Procfile's directory (overrides config)
+    #[arg(long, value_name = "PATH")]
+    pub working_dir: Option<String>,
+
+    /// Write the exit summary (each process's final status, exit code and line
+    /// count) to this file instead of stderr
+    #[arg(long, value_name = "PATH")]
+    pub summary_file: Option<String>,
+
+    /// Print the exit summary as JSON
+    #[arg(long)]
+    pub json: bool,
+
     /// Subcommand for IPC client operations
     #[command(subcommand)]
     pub command: Option<Commands>,
@@ -65,7 +111,27 @@ pub enum Commands {
     /// Get status from running TUI (version, running state)
     Status,
     /// List all processes and their current status
-    Processes,
+    Processes {
+        /// Only processes in this status (running, stopped, failed, done, restarting, terminating, unhealthy)
+        #[arg(long)]
+        status: Option<String>,
+        /// Only failed processes (same as --status failed)
+        #[arg(long, conflicts_with = "status")]
+        failed: bool,
+    },
+    /// Show full detail for one process (command, cwd, env, exit code, last error)
+    Describe {
+        /// Process name
+        name: String,
+    },
+    /// Show the last lines a process emitted before its most recent failure
+    LastOutput {
+        /// Process name
+        name: String,
+        /// Strip ANSI color codes from the output
+        #[arg(long)]
+        strip_ansi: bool,
+    },
     /// Get recent log lines from the TUI
     Logs {
         /// Maximum number of log lines to return (default: 100)
@@ -74,6 +140,64 @@ pub enum Commands {
         /// Number of log lines to skip (default: 0)
         #[arg(long, default_value = "0")]
         offset: u64,
+        /// Return content without ANSI escape codes
+        #[arg(long)]
+        strip_ansi: bool,
+        /// Only return lines from this output stream
+        #[arg(long, default_value = "both", value_parser = ["stdout", "stderr", "both"])]
+        stream: String,
+        /// Detect batches among the returned lines and fill in batch_id
+        #[arg(long)]
+        with_batches: bool,
+    },
+    /// Get the most recent log lines from one process, oldest first
+    ProcessLogs {
+        /// Process or log file name
+        name: String,
+        /// Maximum number of log lines to return (default: 100)
+        #[arg(long, default_value = "100")]
+        limit: u64,
+        /// Return content without ANSI escape codes
+        #[arg(long)]
+        strip_ansi: bool,
+    },
+    /// Get lines received in the last N seconds and scroll the TUI to them
+    Recent {
+        /// Window length in seconds
+        secs: u64,
+        /// Maximum number of log lines to return, newest kept (default: 100)
+        #[arg(long, default_value = "100")]
+        limit: u64,
+        /// Return content without ANSI escape codes
+        #[arg(long)]
+        strip_ansi: bool,
+    },
+    /// Count recent lines per time bucket (by arrival time)
+    Histogram {
+        /// Bucket width in seconds (default: 10)
+        #[arg(long, default_value = "10")]
+        bucket_secs: u64,
+        /// Number of buckets ending now, at most 360 (default: 30)
+        #[arg(long, default_value = "30")]
+        buckets: u64,
+        /// Also break the counts down by process
+        #[arg(long)]
+        per_process: bool,
+    },
+    /// Get every line in the TUI's current view (filters, search and focus applied)
+    CopyVisible {
+        /// Also place the text on the TUI machine's clipboard
+        #[arg(long)]
+        clipboard: bool,
+    },
+    /// Place text, or a log line's content, on the TUI machine's clipboard
+    ClipboardCopy {
+        /// Text to copy
+        #[arg(required_unless_present = "id", conflicts_with = "id")]
+        text: Option<String>,
+        /// Copy this log line's content instead
+        #[arg(long)]
+        id: Option<u64>,
     },
     /// Search log lines for a pattern
     Search {
@@ -85,7 +209,14 @@ pub enum Commands {
         /// Enable case-sensitive matching (default: case-insensitive)
         #[arg(long)]
         case_sensitive: bool,
+        /// Match and return content without ANSI escape codes
+        #[arg(long)]
+        strip_ansi: bool,
     },
+    /// Jump to the next line matching the active search (wraps around)
+    SearchNext,
+    /// Jump to the previous line matching the active search (wraps around)
+    SearchPrev,
     /// Select a log line by ID and open expanded view in TUI
     Select {
         /// The log line ID to select (from search results)
@@ -107,9 +238,52 @@ pub enum Commands {
     IpcHelp,
     /// Get trace recording status and active trace info
     Trace,
+    /// Start a manual trace recording (prints its trace_id)
+    TraceStart {
+        /// Label shown in the TUI while recording
+        #[arg(long)]
+        label: Option<String>,
+    },
+    /// Stop the manual trace recording and show the captured lines
+    TraceStop,
+    /// Add a note line to the log during a trace recording
+    TraceAnnotate {
+        /// Note text
+        #[arg(required = true, trailing_var_arg = true)]
+        note: Vec<String>,
+    },
+    /// Show a message in the TUI status bar for the person watching it
+    SetStatus {
+        /// Message style: info, success or error
+        #[arg(long = "type", default_value = "info", value_parser = ["info", "success", "error"])]
+        status_type: String,
+        /// Message text
+        #[arg(required = true, trailing_var_arg = true)]
+        message: Vec<String>,
+    },
+    /// Clear the TUI status bar message
+    ClearStatus,
     /// Jump to a specific log line by ID (scrolls view without expanding)
     Goto {
         /// The log line ID to scroll to (from search or logs output)
+        #[arg(required_unless_present = "reference", conflicts_with = "reference")]
+        id: Option<u64>,
+        /// A shared line reference like web#4821 (from 'oit reference' or the Y key)
+        #[arg(long = "ref")]
+        reference: Option<String>,
+    },
+    /// Select the nearest error line before or after the current one (wraps)
+    GotoError {
+        /// next or prev
+        #[arg(default_value = "next", value_parser = ["next", "prev"])]
+        direction: String,
+        /// Search from this line ID instead of the TUI selection
+        #[arg(long)]
+        from_id: Option<u64>,
+    },
+    /// Get a shareable <process>#<id> reference for a log line
+    Reference {
+        /// The log line ID
         id: u64,
     },
     /// Scroll the log view up, down, to top, or to bottom
@@ -120,12 +294,35 @@ pub enum Commands {
         #[arg(long, default_value = "20")]
         lines: u64,
     },
+    /// Scroll the log view to a position through the filtered log (0 = oldest, 100 = newest)
+    ScrollPercent {
+        /// Position from 0 to 100 (larger values are clamped to 100)
+        percent: u64,
+    },
     /// Freeze or unfreeze the TUI display (pauses auto-scroll)
     Freeze {
         /// Mode: on, off, or toggle (default: toggle)
         #[arg(default_value = "toggle")]
         mode: String,
     },
+    /// Show only error and warning lines in the TUI
+    ErrorsOnly {
+        /// Mode: on, off, or toggle (default: toggle)
+        #[arg(default_value = "toggle")]
+        mode: String,
+    },
+    /// Set the TUI display mode, wrapping and auto-scroll
+    SetView {
+        /// Display mode: compact, full, or wrap
+        #[arg(long)]
+        display_mode: Option<String>,
+        /// Wrap long lines (true/false)
+        #[arg(long)]
+        wrap: Option<bool>,
+        /// Follow new logs (true/false)
+        #[arg(long)]
+        auto_scroll: Option<bool>,
+    },
     /// List current filters
     Filters,
     /// Add a new filter (persists to config file)
@@ -141,8 +338,20 @@ pub enum Commands {
         /// The filter pattern to remove
         pattern: String,
     },
+    /// Switch a filter off or back on without removing it (persists to config file)
+    FilterToggle {
+        /// The filter pattern to toggle
+        pattern: String,
+    },
     /// Clear all filters (persists to config file)
     FilterClear,
+    /// List saved filter presets
+    Presets,
+    /// Replace all filters with a saved preset (persists to config file)
+    PresetApply {
+        /// The preset name
+        name: String,
+    },
     /// List visibility status for all processes (which are shown/hidden)
     Visibility,
     /// Hide a process from log view (runtime only, does not persist)
@@ -160,12 +369,24 @@ pub enum Commands {
     Restart {
         /// Process name to restart (restarts all if omitted)
         name: Option<String>,
+        /// Show what would be restarted without doing it
+        #[arg(long)]
+        dry_run: bool,
+        /// Wait until the restarted processes are running (and ready, if configured)
+        #[arg(long)]
+        wait_ready: bool,
+        /// How long --wait-ready waits before failing, in milliseconds
+        #[arg(long, default_value_t = 30000, requires = "wait_ready")]
+        timeout_ms: u64,
     },
     /// Kill a running process
     #[command(visible_alias = "k")]
     Kill {
         /// Process name to kill
         name: String,
+        /// Show what would be killed without doing it
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Start a stopped process
     #[command(visible_alias = "s")]
@@ -173,6 +394,36 @@ pub enum Commands {
         /// Process name to start
         name: String,
     },
+    /// Kill every running process without quitting oit
+    KillAll,
+    /// Start every stopped or failed process
+    StartAll,
+    /// Add and start an ad-hoc process (runtime only, not saved to the Procfile)
+    ProcessAdd {
+        /// Name for the new process
+        name: String,
+        /// Shell command to run
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+        /// Working directory (relative to the Procfile directory)
+        #[arg(long)]
+        cwd: Option<String>,
+        /// Environment variable as KEY=VALUE (repeatable)
+        #[arg(long = "env", value_name = "KEY=VALUE")]
+        env: Vec<String>,
+    },
+    /// Start tailing a log file at runtime (it may not exist yet)
+    AddLogFile {
+        /// Name to show the file's lines under
+        name: String,
+        /// File path (relative to the Procfile directory)
+        path: String,
+    },
+    /// Change the log buffer memory limit for the running session
+    SetBuffer {
+        /// New limit in megabytes (shrinking evicts the oldest lines)
+        mb: usize,
+    },
     /// Get recent log lines containing error or warning patterns
     Errors {
         /// Maximum number of lines to return (default: 50)
@@ -185,8 +436,24 @@ pub enum Commands {
         #[arg(long)]
         process: Option<String>,
     },
+    /// Stay connected and print each new error/warning line as a JSON frame
+    TailErrors {
+        /// Level filter: error, warning, or error_or_warning (default: error)
+        #[arg(long, default_value = "error")]
+        level: String,
+    },
     /// Get comprehensive AI-friendly summary of current state
     Summary,
+    /// Get the full state snapshot as JSON (for building external tooling)
+    Snapshot {
+        /// Maximum recent log lines to include
+        #[arg(long, default_value = "20")]
+        recent_logs: usize,
+    },
+    /// Get the display color assigned to each process and log file
+    Colors,
+    /// Show where the running instance lives: working directory, config, Procfiles, socket
+    Info,
     /// Get all log lines from a specific batch
     Batch {
         /// Batch ID to retrieve
@@ -195,6 +462,19 @@ pub enum Commands {
         #[arg(long)]
         scroll: bool,
     },
+    /// Run several commands in one round-trip, e.g. `oit pipeline status processes errors`
+    Pipeline {
+        /// Command names, or JSON objects like '{"command": "logs", "args": {"limit": 10}}'
+        #[arg(required = true)]
+        commands: Vec<String>,
+    },
+    /// Open an exported log file (from `oit logs`, `oit search` or a copy) in a read-only TUI
+    Replay {
+        /// Path to the exported logs (JSON, JSONL or copied text)
+        file: String,
+    },
+    /// Check the config and Procfile (patterns, commands on PATH, log files) without starting anything
+    Doctor,
     /// VS Code extension management
     Vscode {
         #[command(subcommand)]
@@ -390,107 +670,169 @@ pub fn install_vscode_extension() -> anyhow::Result<()> {
     Ok(())
 }
 
-/// Initialize a new config file from an existing Procfile
-pub fn init_config(config_path: &str, procfile_override: Option<&str>) -> anyhow::Result<()> {
-    let config_exists = Path::new(config_path).exists();
+/// Options for `oit --init`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InitOptions {
+    /// Print the generated config instead of writing it
+    pub dry_run: bool,
+    /// Overwrite an existing config file
+    pub force: bool,
+}
 
-    // Only create config if it doesn't exist
-    if !config_exists {
-        // Use override or default Procfile location
-        let procfile_path = procfile_override.unwrap_or("Procfile");
+/// Build the `--init` config TOML for a Procfile. Returns the TOML (with a
+/// header listing the Procfile's processes and a commented theme hint) and the
+/// process names.
+pub fn generate_init_config(procfile_path: &str) -> anyhow::Result<(String, Vec<String>)> {
+    // Check if Procfile exists and provide helpful error if not
+    if !Path::new(procfile_path).exists() {
+        return Err(anyhow!(
+            "Procfile not found at '{}'.\n\n\
+            To use --init, first create a Procfile with your processes.\n\
+            Example Procfile:\n\
+            \n\
+              web: rails server -p 3000\n\
+              worker: bundle exec sidekiq\n\
+            \n\
+            See: https://devcenter.heroku.com/articles/procfile\n\
+            \n\
+            Then run 'oit --init' again to generate the config file.\n\
+            Or specify a Procfile with: oit --init -f <path>",
+            procfile_path
+        ));
+    }
 
-        // Check if Procfile exists and provide helpful error if not
-        if !Path::new(procfile_path).exists() {
-            return Err(anyhow!(
-                "Procfile not found at '{}'.\n\n\
-                To use --init, first create a Procfile with your processes.\n\
-                Example Procfile:\n\
-                \n\
-                  web: rails server -p 3000\n\
-                  worker: bundle exec sidekiq\n\
-                \n\
-                See: https://devcenter.heroku.com/articles/procfile\n\
-                \n\
-                Then run 'oit --init' again to generate the config file.\n\
-                Or specify a Procfile with: oit --init -f <path>",
-                procfile_path
-            ));
-        }
+    // Try to parse the Procfile
+    let procfile = Procfile::from_file(procfile_path)
+        .with_context(|| format!("Failed to parse Procfile at '{}'", procfile_path))?;
+
+    // Get sorted list of process names
+    let process_names: Vec<String> = procfile.process_names().into_iter().map(String::from).collect();
 
-        // Try to parse the Procfile
-        let procfile = Procfile::from_file(procfile_path)
-            .with_context(|| format!("Failed to parse Procfile at '{}'", procfile_path))?;
-
-        // Get sorted list of process names
-        let process_names = procfile.process_names();
-
-        // Check if log/development.log exists for standalone log
-        let log_files = if Path::new("log/development.log").exists() {
-            vec![config::LogFileConfig {
-                name: "log".to_string(),
-                path: std::path::PathBuf::from("log/development.log"),
-            }]
-        } else {
-            Vec::new()
-        };
-
-        let config = Config {
-            procfile: std::path::PathBuf::from(procfile_path),
-            processes: HashMap::new(),
-            log_files,
-            filters: config::FilterConfig {
-                include: vec![],
-                exclude: vec![],
-            },
-            batch_window_ms: Some(100),
-            max_log_buffer_mb: Some(50),
-            hidden_processes: Vec::new(),
-            ignored_processes: Vec::new(),
-            start_processes: Vec::new(),
-            disable_auto_update: None,
-            compact_mode: None,
-            colors: std::collections::HashMap::new(),
-            process_coloring: Some(true),
-            context_copy_seconds: None,
-            groups: std::collections::HashMap::new(),
-            theme: None,
-            config_path: None,
-        };
-
-        // Save the config
-        config.save(config_path)
-            .with_context(|| format!("Failed to write config to '{}'", config_path))?;
-
-        let theme_hint = "# theme = \"dark\"  # or \"light\" for light terminals\n";
-        let content = std::fs::read_to_string(config_path)
-            .with_context(|| format!("Failed to read '{}' to insert theme hint", config_path))?;
-        let insert_at = content
-            .find("\n[")
-            .map(|idx| idx + 1)
-            .unwrap_or(content.len());
-        let mut content_with_hint = String::with_capacity(content.len() + theme_hint.len() + 1);
-        content_with_hint.push_str(&content[..insert_at]);
-        if !content_with_hint.ends_with('\n') {
-            content_with_hint.push('\n');
-        }
-        content_with_hint.push_str(theme_hint);
-        content_with_hint.push_str(&content[insert_at..]);
-        std::fs::write(config_path, content_with_hint)
-            .with_context(|| format!("Failed to insert theme hint into '{}'", config_path))?;
-
-        // Print success message
-        println!("Created {} with {} processes:", config_path, process_names.len());
-        for name in &process_names {
-            println!("  - {}", name);
-        }
-
-        println!("\nNext steps:");
-        println!("  1. Edit {} to configure log file paths", config_path);
-        println!("  2. Run 'oit' to start the TUI");
+    // Check if log/development.log exists for standalone log
+    let log_files = if Path::new("log/development.log").exists() {
+        vec![config::LogFileConfig {
+            name: "log".to_string(),
+            path: std::path::PathBuf::from("log/development.log"),
+        }]
     } else {
-        println!("Config file '{}' already exists.", config_path);
+        Vec::new()
+    };
+
+    let config = Config {
+        procfile: std::path::PathBuf::from(procfile_path),
+        processes: HashMap::new(),
+        log_files,
+        filters: config::FilterConfig {
+            include: vec![],
+            exclude: vec![],
+            disabled: vec![],
+        },
+        batch_window_ms: Some(100),
+        max_log_buffer_mb: Some(50),
+        hidden_processes: Vec::new(),
+        ignored_processes: Vec::new(),
+        start_processes: Vec::new(),
+        pinned_processes: Vec::new(),
+        disable_auto_update: None,
+        background_update_check: None,
+        compact_mode: None,
+        condensed_display: None,
+        colors: std::collections::HashMap::new(),
+        process_coloring: Some(true),
+        context_copy_seconds: None,
+        groups: std::collections::HashMap::new(),
+        presets: std::collections::HashMap::new(),
+        aliases: std::collections::HashMap::new(),
+        theme: None,
+        confirm_destructive: None,
+        confirm_quit: None,
+        json_logs: None,
+        timestamp_format: None,
+        max_ingest_per_tick: None,
+        ansi_mode: None,
+        severity: None,
+        idle_quit_ms: None,
+        working_dir: None,
+        notify_on_failure: Vec::new(),
+        mark_stderr: None,
+        tail_preview: None,
+        stable_process_layout: None,
+        persist_search_history: None,
+        scroll_stick_lines: None,
+        batch_strategy: None,
+        show_date_dividers: None,
+        max_processes: None,
+        max_fps: None,
+        startup_banner: None,
+        align_messages: None,
+        show_last_line: None,
+        sticky_header: None,
+        highlight_syntax: None,
+        volume_histogram: None,
+        watch_config: None,
+        show_commands: None,
+        truncation_suffix: None,
+        line_margin: None,
+        base_port: None,
+        port_step: None,
+        process_list_position: None,
+        config_path: None,
+        namespace_groups: std::collections::HashMap::new(),
+    };
+
+    let content = toml::to_string_pretty(&config).context("Failed to serialize config")?;
+
+    let header = format!("# Procfile processes: {}\n", process_names.join(", "));
+    let theme_hint = "# theme = \"dark\"  # or \"light\" for light terminals\n";
+    let insert_at = content
+        .find("\n[")
+        .map(|idx| idx + 1)
+        .unwrap_or(content.len());
+    let mut generated = String::with_capacity(header.len() + content.len() + theme_hint.len() + 1);
+    generated.push_str(&header);
+    generated.push_str(&content[..insert_at]);
+    if !generated.ends_with('\n') {
+        generated.push('\n');
+    }
+    generated.push_str(theme_hint);
+    generated.push_str(&content[insert_at..]);
+
+    Ok((generated, process_names))
+}
+
+/// Initialize a new config file from an existing Procfile. Refuses to replace
+/// an existing config unless `options.force`; `options.dry_run` prints the
+/// config to stdout and touches nothing.
+pub fn init_config(config_path: &str, procfile_override: Option<&str>, options: InitOptions) -> anyhow::Result<()> {
+    if !options.dry_run && !options.force && Path::new(config_path).exists() {
+        return Err(anyhow!(
+            "Config file '{}' already exists; use --force to overwrite it (or --dry-run to preview)",
+            config_path
+        ));
+    }
+
+    // Use override or default Procfile location
+    let procfile_path = procfile_override.unwrap_or("Procfile");
+    let (content, process_names) = generate_init_config(procfile_path)?;
+
+    if options.dry_run {
+        print!("{}", content);
+        return Ok(());
+    }
+
+    std::fs::write(config_path, content)
+        .with_context(|| format!("Failed to write config to '{}'", config_path))?;
+
+    // Print success message
+    println!("Created {} with {} processes:", config_path, process_names.len());
+    for name in &process_names {
+        println!("  - {}", name);
     }
 
+    println!("\nNext steps:");
+    println!("  1. Edit {} to configure log file paths", config_path);
+    println!("  2. Run 'oit' to start the TUI");
+
     Ok(())
 }
 
@@ -510,54 +852,48 @@ pub fn get_socket_path() -> std::path::PathBuf {
 /// Returns Ok(true) if running, Ok(false) if not running (stale socket removed),
 /// or Err if there was an unexpected error.
 pub async fn check_already_running() -> anyhow::Result<bool> {
-    use crate::ipc::{IpcClient, IpcRequest};
-    use std::time::Duration;
+    check_socket_in_use(&get_socket_path()).await
+}
 
-    let socket_path = get_socket_path();
+/// Check whether a live instance answers on `socket_path`. A socket file left
+/// behind by a crashed instance (refused, timed out, or no valid pong) is
+/// removed so a fresh server can bind in its place.
+pub async fn check_socket_in_use(socket_path: &std::path::Path) -> anyhow::Result<bool> {
+    use crate::ipc::IpcClient;
+    use std::time::Duration;
 
     // If socket file doesn't exist, nothing is running
     if !socket_path.exists() {
         return Ok(false);
     }
 
-    // Try to connect and send a ping with a short timeout
-    let connect_result = tokio::time::timeout(
-        Duration::from_millis(500),
-        IpcClient::connect(&socket_path),
-    )
-    .await;
-
-    match connect_result {
-        Ok(Ok(mut client)) => {
-            // Connected, try to ping
-            let request = IpcRequest::new("ping");
-            let ping_result = tokio::time::timeout(
-                Duration::from_millis(500),
-                client.call(&request),
-            )
-            .await;
-
-            match ping_result {
-                Ok(Ok(response)) if response.success => {
-                    // Server responded to ping - it's running
-                    Ok(true)
-                }
-                _ => {
-                    // Connected but no valid response - stale socket
-                    let _ = std::fs::remove_file(&socket_path);
-                    Ok(false)
-                }
-            }
-        }
-        Ok(Err(_)) | Err(_) => {
-            // Connection failed or timed out - stale socket
-            let _ = std::fs::remove_file(&socket_path);
-            Ok(false)
-        }
+    if IpcClient::ping(socket_path, Duration::from_millis(500)).await {
+        return Ok(true);
+    }
+
+    match std::fs::remove_file(socket_path) {
+        Ok(()) => Ok(false),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e).with_context(|| format!("Could not remove stale socket {:?}", socket_path)),
     }
 }
 
 /// Run an IPC command and print the result
+/// Turn `oit pipeline` arguments into `{command, args}` objects.
+/// Bare names use the IPC spelling, so `errors-only` becomes `errors_only`.
+pub fn pipeline_commands(commands: &[String]) -> anyhow::Result<Vec<serde_json::Value>> {
+    commands
+        .iter()
+        .map(|c| {
+            if c.trim_start().starts_with('{') {
+                serde_json::from_str(c).map_err(|e| anyhow!("Invalid pipeline command '{}': {}", c, e))
+            } else {
+                Ok(serde_json::json!({"command": c.replace('-', "_")}))
+            }
+        })
+        .collect()
+}
+
 pub async fn run_ipc_command(command: &Commands) -> anyhow::Result<()> {
     use crate::ipc::{IpcClient, IpcRequest};
 
@@ -575,22 +911,64 @@ pub async fn run_ipc_command(command: &Commands) -> anyhow::Result<()> {
     let request = match command {
         Commands::Ping => IpcRequest::new("ping"),
         Commands::Status => IpcRequest::new("status"),
-        Commands::Processes => IpcRequest::new("processes"),
-        Commands::Logs { limit, offset } => {
-            IpcRequest::with_args("logs", serde_json::json!({"limit": limit, "offset": offset}))
+        Commands::Processes { status, failed } => {
+            match status.as_deref().or(failed.then_some("failed")) {
+                Some(status) => IpcRequest::with_args("processes", serde_json::json!({"status": status})),
+                None => IpcRequest::new("processes"),
+            }
+        }
+        Commands::Describe { name } => IpcRequest::with_args("describe", serde_json::json!({"name": name})),
+        Commands::LastOutput { name, strip_ansi } => {
+            IpcRequest::with_args("last_output", serde_json::json!({"name": name, "strip_ansi": strip_ansi}))
+        }
+        Commands::Logs { limit, offset, strip_ansi, stream, with_batches } => IpcRequest::with_args(
+            "logs",
+            serde_json::json!({
+                "limit": limit,
+                "offset": offset,
+                "strip_ansi": strip_ansi,
+                "stream": stream,
+                "with_batches": with_batches
+            }),
+        ),
+        Commands::ProcessLogs { name, limit, strip_ansi } => IpcRequest::with_args(
+            "process_logs",
+            serde_json::json!({"name": name, "limit": limit, "strip_ansi": strip_ansi}),
+        ),
+        Commands::Recent { secs, limit, strip_ansi } => IpcRequest::with_args(
+            "recent",
+            serde_json::json!({"secs": secs, "limit": limit, "strip_ansi": strip_ansi}),
+        ),
+        Commands::Histogram { bucket_secs, buckets, per_process } => IpcRequest::with_args(
+            "histogram",
+            serde_json::json!({"bucket_secs": bucket_secs, "buckets": buckets, "per_process": per_process}),
+        ),
+        Commands::CopyVisible { clipboard } => {
+            IpcRequest::with_args("copy_visible", serde_json::json!({"clipboard": clipboard}))
+        }
+        Commands::ClipboardCopy { text, id } => {
+            let args = match (text, id) {
+                (Some(text), _) => serde_json::json!({"text": text}),
+                (None, id) => serde_json::json!({"id": id}),
+            };
+            IpcRequest::with_args("clipboard_copy", args)
         }
         Commands::Search {
             pattern,
             limit,
             case_sensitive,
+            strip_ansi,
         } => IpcRequest::with_args(
             "search",
             serde_json::json!({
                 "pattern": pattern,
                 "limit": limit,
-                "case_sensitive": case_sensitive
+                "case_sensitive": case_sensitive,
+                "strip_ansi": strip_ansi
             }),
         ),
+        Commands::SearchNext => IpcRequest::new("search_next"),
+        Commands::SearchPrev => IpcRequest::new("search_prev"),
         Commands::Select { id } => {
             IpcRequest::with_args("select", serde_json::json!({"id": id}))
         }
@@ -600,14 +978,52 @@ pub async fn run_ipc_command(command: &Commands) -> anyhow::Result<()> {
         ),
         Commands::IpcHelp => IpcRequest::new("help"),
         Commands::Trace => IpcRequest::new("trace"),
-        Commands::Goto { id } => IpcRequest::with_args("goto", serde_json::json!({"id": id})),
+        Commands::TraceStart { label } => {
+            IpcRequest::with_args("trace_start", serde_json::json!({"label": label}))
+        }
+        Commands::TraceStop => IpcRequest::new("trace_stop"),
+        Commands::TraceAnnotate { note } => {
+            IpcRequest::with_args("trace_annotate", serde_json::json!({"note": note.join(" ")}))
+        }
+        Commands::SetStatus { status_type, message } => IpcRequest::with_args(
+            "set_status",
+            serde_json::json!({"message": message.join(" "), "type": status_type}),
+        ),
+        Commands::ClearStatus => IpcRequest::new("clear_status"),
+        Commands::GotoError { direction, from_id } => {
+            IpcRequest::with_args("goto_error", serde_json::json!({"direction": direction, "from_id": from_id}))
+        }
+        Commands::Goto { id, reference } => match reference {
+            Some(reference) => IpcRequest::with_args("goto", serde_json::json!({"ref": reference})),
+            None => IpcRequest::with_args("goto", serde_json::json!({"id": id})),
+        },
+        Commands::Reference { id } => IpcRequest::with_args("reference", serde_json::json!({"id": id})),
         Commands::Scroll { direction, lines } => IpcRequest::with_args(
             "scroll",
             serde_json::json!({"direction": direction, "lines": lines}),
         ),
+        Commands::ScrollPercent { percent } => {
+            IpcRequest::with_args("scroll_percent", serde_json::json!({"percent": percent}))
+        }
         Commands::Freeze { mode } => {
             IpcRequest::with_args("freeze", serde_json::json!({"mode": mode}))
         }
+        Commands::ErrorsOnly { mode } => {
+            IpcRequest::with_args("errors_only", serde_json::json!({"mode": mode}))
+        }
+        Commands::SetView { display_mode, wrap, auto_scroll } => {
+            let mut args = serde_json::Map::new();
+            if let Some(mode) = display_mode {
+                args.insert("display_mode".to_string(), serde_json::json!(mode));
+            }
+            if let Some(wrap) = wrap {
+                args.insert("wrap".to_string(), serde_json::json!(wrap));
+            }
+            if let Some(auto_scroll) = auto_scroll {
+                args.insert("auto_scroll".to_string(), serde_json::json!(auto_scroll));
+            }
+            IpcRequest::with_args("set_view", serde_json::Value::Object(args))
+        }
         Commands::Filters => IpcRequest::new("filters"),
         Commands::FilterAdd { pattern, exclude } => IpcRequest::with_args(
             "filter_add",
@@ -616,7 +1032,14 @@ pub async fn run_ipc_command(command: &Commands) -> anyhow::Result<()> {
         Commands::FilterRemove { pattern } => {
             IpcRequest::with_args("filter_remove", serde_json::json!({"pattern": pattern}))
         }
+        Commands::FilterToggle { pattern } => {
+            IpcRequest::with_args("filter_toggle", serde_json::json!({"pattern": pattern}))
+        }
         Commands::FilterClear => IpcRequest::new("filter_clear"),
+        Commands::Presets => IpcRequest::new("presets"),
+        Commands::PresetApply { name } => {
+            IpcRequest::with_args("preset_apply", serde_json::json!({"name": name}))
+        }
         Commands::Visibility => IpcRequest::new("visibility"),
         Commands::Hide { name } => {
             IpcRequest::with_args("hide", serde_json::json!({"name": name}))
@@ -624,19 +1047,49 @@ pub async fn run_ipc_command(command: &Commands) -> anyhow::Result<()> {
         Commands::Show { name } => {
             IpcRequest::with_args("show", serde_json::json!({"name": name}))
         }
-        Commands::Restart { name } => {
-            let args = match name {
-                Some(n) => serde_json::json!({"name": n}),
-                None => serde_json::json!({}),
-            };
+        Commands::Restart { name, dry_run, wait_ready, timeout_ms } => {
+            let mut args = serde_json::json!({"dry_run": dry_run});
+            if let Some(n) = name {
+                args["name"] = serde_json::json!(n);
+            }
+            if *wait_ready {
+                args["wait_ready"] = serde_json::json!(true);
+                args["timeout_ms"] = serde_json::json!(timeout_ms);
+            }
             IpcRequest::with_args("restart", args)
         }
-        Commands::Kill { name } => {
-            IpcRequest::with_args("kill", serde_json::json!({"name": name}))
+        Commands::Kill { name, dry_run } => {
+            IpcRequest::with_args("kill", serde_json::json!({"name": name, "dry_run": dry_run}))
         }
         Commands::Start { name } => {
             IpcRequest::with_args("start", serde_json::json!({"name": name}))
         }
+        Commands::KillAll => IpcRequest::new("kill_all"),
+        Commands::SetBuffer { mb } => {
+            IpcRequest::with_args("set_buffer", serde_json::json!({"mb": mb}))
+        }
+        Commands::StartAll => IpcRequest::new("start_all"),
+        Commands::AddLogFile { name, path } => {
+            IpcRequest::with_args("add_log_file", serde_json::json!({"name": name, "path": path}))
+        }
+        Commands::ProcessAdd { name, command, cwd, env } => {
+            let mut env_map = serde_json::Map::new();
+            for pair in env {
+                let (key, value) = pair
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("Invalid --env '{}', expected KEY=VALUE", pair))?;
+                env_map.insert(key.to_string(), serde_json::json!(value));
+            }
+            IpcRequest::with_args(
+                "process_add",
+                serde_json::json!({
+                    "name": name,
+                    "command": command.join(" "),
+                    "cwd": cwd,
+                    "env": env_map,
+                }),
+            )
+        }
         Commands::Errors {
             limit,
             level,
@@ -648,13 +1101,24 @@ pub async fn run_ipc_command(command: &Commands) -> anyhow::Result<()> {
             }
             IpcRequest::with_args("errors", args)
         }
+        Commands::TailErrors { level } => {
+            IpcRequest::with_args("tail_errors", serde_json::json!({"level": level}))
+        }
         Commands::Summary => IpcRequest::new("summary"),
+        Commands::Snapshot { recent_logs } => {
+            IpcRequest::with_args("snapshot", serde_json::json!({"recent_logs": recent_logs}))
+        }
+        Commands::Colors => IpcRequest::new("colors"),
+        Commands::Info => IpcRequest::new("info"),
         Commands::Batch { id, scroll } => {
             IpcRequest::with_args("batch", serde_json::json!({"id": id, "scroll": scroll}))
         }
-        Commands::Vscode { .. } | Commands::Cursor { .. } | Commands::Skill { .. } => {
+        Commands::Pipeline { commands } => {
+            IpcRequest::with_args("pipeline", serde_json::json!({"commands": pipeline_commands(commands)?}))
+        }
+        Commands::Vscode { .. } | Commands::Cursor { .. } | Commands::Skill { .. } | Commands::Replay { .. } | Commands::Doctor => {
             // These should be handled separately in main.rs, not via IPC
-            return Err(anyhow!("editor/skill commands don't use IPC"));
+            return Err(anyhow!("editor/skill/replay/doctor commands don't use IPC"));
         }
     };
 
@@ -662,6 +1126,10 @@ pub async fn run_ipc_command(command: &Commands) -> anyhow::Result<()> {
         format!("Failed to communicate with TUI at {:?}", socket_path)
     })?;
 
+    if let Some(warning) = response.protocol_mismatch() {
+        eprintln!("Warning: {}", warning);
+    }
+
     // Print response as JSON
     let json = serde_json::to_string_pretty(&response)
         .with_context(|| "Failed to serialize response")?;
@@ -672,6 +1140,13 @@ pub async fn run_ipc_command(command: &Commands) -> anyhow::Result<()> {
         std::process::exit(1);
     }
 
+    // Streaming commands keep printing pushed frames, one per line, until the TUI exits
+    if matches!(command, Commands::TailErrors { .. }) {
+        while let Ok(frame) = client.recv_response().await {
+            println!("{}", serde_json::to_string(&frame).with_context(|| "Failed to serialize frame")?);
+        }
+    }
+
     Ok(())
 }
 
@@ -28,7 +28,7 @@ fn test_init_config_creates_file() {
     std::env::set_current_dir(temp_path).unwrap();
 
     // Call init_config
-    let result = init_config(config_path.to_str().unwrap(), None);
+    let result = init_config(config_path.to_str().unwrap(), None, InitOptions::default());
 
     // Restore original directory
     std::env::set_current_dir(original_dir).unwrap();
@@ -61,6 +61,7 @@ fn test_init_config_theme_hint_is_root_config() {
     let result = init_config(
         config_path.to_str().unwrap(),
         Some(procfile_path.to_str().unwrap()),
+        InitOptions::default(),
     );
 
     assert!(result.is_ok(), "init_config should succeed: {:?}", result.err());
@@ -81,33 +82,91 @@ fn test_init_config_theme_hint_is_root_config() {
 }
 
 #[test]
-fn test_init_config_skips_config_if_file_exists() {
-    // Lock mutex to prevent parallel directory changes
-    let _guard = CWD_MUTEX.lock().unwrap();
-
-    // Create a temporary directory for the test
+fn test_init_config_refuses_to_overwrite_existing_config() {
     let temp_dir = TempDir::new().unwrap();
     let temp_path = temp_dir.path();
 
-    // Create a config file that already exists with custom content
+    let procfile_path = temp_path.join("Procfile");
+    fs::write(&procfile_path, "web: rails server\n").unwrap();
     let config_path = temp_path.join(".overitall.toml");
     let original_content = "# existing config\n";
     fs::write(&config_path, original_content).unwrap();
 
-    // Change to the temp directory
-    let original_dir = std::env::current_dir().unwrap();
-    std::env::set_current_dir(temp_path).unwrap();
+    let result = init_config(
+        config_path.to_str().unwrap(),
+        Some(procfile_path.to_str().unwrap()),
+        InitOptions::default(),
+    );
 
-    // Call init_config (no Procfile needed since config exists)
-    let result = init_config(config_path.to_str().unwrap(), None);
+    let err_msg = result.unwrap_err().to_string();
+    assert!(err_msg.contains("already exists") && err_msg.contains("--force"), "{}", err_msg);
+    let content = fs::read_to_string(&config_path).unwrap();
+    assert_eq!(content, original_content, "Config file should not be modified");
+}
 
-    // Restore original directory
-    std::env::set_current_dir(original_dir).unwrap();
+#[test]
+fn test_init_config_force_overwrites_existing_config() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+
+    let procfile_path = temp_path.join("Procfile");
+    fs::write(&procfile_path, "web: rails server\n").unwrap();
+    let config_path = temp_path.join(".overitall.toml");
+    fs::write(&config_path, "# existing config\n").unwrap();
+
+    let result = init_config(
+        config_path.to_str().unwrap(),
+        Some(procfile_path.to_str().unwrap()),
+        InitOptions { force: true, ..InitOptions::default() },
+    );
 
-    // Check that init succeeded but didn't overwrite the config
-    assert!(result.is_ok(), "init_config should succeed when config exists");
+    assert!(result.is_ok(), "init_config --force should succeed: {:?}", result.err());
     let content = fs::read_to_string(&config_path).unwrap();
-    assert_eq!(content, original_content, "Config file should not be modified");
+    assert!(content.contains("batch_window_ms = 100"));
+    assert!(!content.contains("# existing config"));
+}
+
+#[test]
+fn test_init_config_dry_run_writes_nothing() {
+    let temp_dir = TempDir::new().unwrap();
+    let temp_path = temp_dir.path();
+
+    let procfile_path = temp_path.join("Procfile");
+    fs::write(&procfile_path, "web: rails server\nworker: sidekiq\n").unwrap();
+    let config_path = temp_path.join(".overitall.toml");
+
+    let result = init_config(
+        config_path.to_str().unwrap(),
+        Some(procfile_path.to_str().unwrap()),
+        InitOptions { dry_run: true, ..InitOptions::default() },
+    );
+
+    assert!(result.is_ok(), "dry run should succeed: {:?}", result.err());
+    assert!(!config_path.exists(), "dry run must not create the config file");
+}
+
+#[test]
+fn test_generate_init_config_lists_each_process() {
+    let temp_dir = TempDir::new().unwrap();
+    let procfile_path = temp_dir.path().join("Procfile");
+    fs::write(&procfile_path, "web: rails server\nworker: sidekiq\nclock: clockwork\n").unwrap();
+
+    let (content, names) = generate_init_config(procfile_path.to_str().unwrap()).unwrap();
+
+    assert_eq!(names.len(), 3);
+    for name in ["web", "worker", "clock"] {
+        assert!(content.contains(name), "generated config should mention {}:\n{}", name, content);
+    }
+    // The generated text is the config that would be written
+    let parsed: crate::config::Config = toml::from_str(&content).unwrap();
+    assert_eq!(parsed.batch_window_ms, Some(100));
+}
+
+#[test]
+fn test_init_dry_run_and_force_require_init() {
+    assert!(Cli::try_parse_from(["oit", "--init", "--dry-run"]).is_ok());
+    assert!(Cli::try_parse_from(["oit", "--init", "--force"]).is_ok());
+    assert!(Cli::try_parse_from(["oit", "--dry-run"]).is_err());
 }
 
 #[test]
@@ -127,7 +186,7 @@ fn test_init_config_fails_if_procfile_missing() {
     std::env::set_current_dir(temp_path).unwrap();
 
     // Call init_config
-    let result = init_config(config_path.to_str().unwrap(), None);
+    let result = init_config(config_path.to_str().unwrap(), None, InitOptions::default());
 
     // Restore original directory
     std::env::set_current_dir(original_dir).unwrap();
@@ -174,26 +233,38 @@ fn test_cli_parses_init_with_custom_config() {
 #[test]
 fn test_cli_parses_procfile_short_flag() {
     let cli = Cli::parse_from(["oit", "-f", "Procfile.dev"]);
-    assert_eq!(cli.procfile, Some("Procfile.dev".to_string()));
+    assert_eq!(cli.procfile, vec!["Procfile.dev".to_string()]);
 }
 
 #[test]
 fn test_cli_parses_procfile_long_flag() {
     let cli = Cli::parse_from(["oit", "--file", "Procfile.test"]);
-    assert_eq!(cli.procfile, Some("Procfile.test".to_string()));
+    assert_eq!(cli.procfile, vec!["Procfile.test".to_string()]);
+}
+
+#[test]
+fn test_cli_parses_procfile_alias_and_stdin() {
+    let cli = Cli::parse_from(["oit", "--procfile", "-"]);
+    assert_eq!(cli.procfile, vec!["-".to_string()]);
 }
 
 #[test]
 fn test_cli_default_procfile_is_none() {
     let cli = Cli::parse_from(["oit"]);
-    assert!(cli.procfile.is_none());
+    assert!(cli.procfile.is_empty());
+}
+
+#[test]
+fn test_cli_parses_repeated_procfile_flag() {
+    let cli = Cli::parse_from(["oit", "-f", "frontend/Procfile", "--procfile", "backend/Procfile"]);
+    assert_eq!(cli.procfile, vec!["frontend/Procfile".to_string(), "backend/Procfile".to_string()]);
 }
 
 #[test]
 fn test_cli_parses_procfile_with_init() {
     let cli = Cli::parse_from(["oit", "--init", "-f", "Procfile.custom"]);
     assert!(cli.init);
-    assert_eq!(cli.procfile, Some("Procfile.custom".to_string()));
+    assert_eq!(cli.procfile, vec!["Procfile.custom".to_string()]);
 }
 
 #[test]
@@ -213,7 +284,7 @@ fn test_init_config_with_custom_procfile() {
     std::env::set_current_dir(temp_path).unwrap();
 
     // Call init_config with custom procfile
-    let result = init_config(config_path.to_str().unwrap(), Some("Procfile.dev"));
+    let result = init_config(config_path.to_str().unwrap(), Some("Procfile.dev"), InitOptions::default());
 
     std::env::set_current_dir(original_dir).unwrap();
 
@@ -247,7 +318,7 @@ fn test_init_config_adds_development_log_when_exists() {
     let original_dir = std::env::current_dir().unwrap();
     std::env::set_current_dir(temp_path).unwrap();
 
-    let result = init_config(config_path.to_str().unwrap(), None);
+    let result = init_config(config_path.to_str().unwrap(), None, InitOptions::default());
 
     std::env::set_current_dir(original_dir).unwrap();
 
@@ -278,7 +349,7 @@ fn test_init_config_no_log_when_development_log_missing() {
     let original_dir = std::env::current_dir().unwrap();
     std::env::set_current_dir(temp_path).unwrap();
 
-    let result = init_config(config_path.to_str().unwrap(), None);
+    let result = init_config(config_path.to_str().unwrap(), None, InitOptions::default());
 
     std::env::set_current_dir(original_dir).unwrap();
 
@@ -296,10 +367,22 @@ fn test_cli_parses_no_update_flag() {
     assert!(!cli.init);
 }
 
+#[test]
+fn test_cli_parses_no_autostart_flag() {
+    assert!(Cli::parse_from(["oit", "--no-autostart"]).no_autostart);
+    assert!(!Cli::parse_from(["oit"]).no_autostart);
+}
+
 #[test]
 fn test_cli_default_no_update_is_false() {
     let cli = Cli::parse_from(["oit"]);
     assert!(!cli.no_update);
+    assert!(!cli.background_update_check);
+}
+
+#[test]
+fn test_cli_parses_background_update_check_flag() {
+    assert!(Cli::parse_from(["oit", "--background-update-check"]).background_update_check);
 }
 
 #[test]
@@ -329,16 +412,27 @@ fn test_cli_parses_status_subcommand() {
 #[test]
 fn test_cli_parses_processes_subcommand() {
     let cli = Cli::parse_from(["oit", "processes"]);
-    assert!(matches!(cli.command, Some(Commands::Processes)));
+    assert!(matches!(cli.command, Some(Commands::Processes { status: None, failed: false })));
+}
+
+#[test]
+fn test_cli_parses_processes_status_filter() {
+    let cli = Cli::parse_from(["oit", "processes", "--status", "unhealthy"]);
+    assert!(matches!(cli.command, Some(Commands::Processes { status: Some(s), .. }) if s == "unhealthy"));
+
+    let cli = Cli::parse_from(["oit", "processes", "--failed"]);
+    assert!(matches!(cli.command, Some(Commands::Processes { failed: true, .. })));
+    assert!(Cli::try_parse_from(["oit", "processes", "--failed", "--status", "running"]).is_err());
 }
 
 #[test]
 fn test_cli_parses_logs_subcommand() {
     let cli = Cli::parse_from(["oit", "logs"]);
     match cli.command {
-        Some(Commands::Logs { limit, offset }) => {
+        Some(Commands::Logs { limit, offset, strip_ansi, .. }) => {
             assert_eq!(limit, 100);
             assert_eq!(offset, 0);
+            assert!(!strip_ansi);
         }
         _ => panic!("Expected Logs command"),
     }
@@ -346,11 +440,12 @@ fn test_cli_parses_logs_subcommand() {
 
 #[test]
 fn test_cli_parses_logs_with_limit_and_offset() {
-    let cli = Cli::parse_from(["oit", "logs", "--limit", "50", "--offset", "10"]);
+    let cli = Cli::parse_from(["oit", "logs", "--limit", "50", "--offset", "10", "--strip-ansi"]);
     match cli.command {
-        Some(Commands::Logs { limit, offset }) => {
+        Some(Commands::Logs { limit, offset, strip_ansi, .. }) => {
             assert_eq!(limit, 50);
             assert_eq!(offset, 10);
+            assert!(strip_ansi);
         }
         _ => panic!("Expected Logs command"),
     }
@@ -379,6 +474,30 @@ fn test_get_socket_path_filename_is_hidden() {
     assert_eq!(filename, ".oit.sock");
 }
 
+#[tokio::test]
+async fn test_check_socket_in_use_removes_stale_socket() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("stale.sock");
+
+    // A bound-then-dropped listener leaves the socket file with nobody listening,
+    // which is what a crashed instance leaves behind
+    drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+    assert!(path.exists());
+
+    assert!(!check_socket_in_use(&path).await.unwrap());
+    assert!(!path.exists(), "stale socket should be removed");
+
+    // A fresh server can now bind in its place
+    let _server = crate::ipc::IpcServer::new(&path).unwrap();
+    assert!(path.exists());
+}
+
+#[tokio::test]
+async fn test_check_socket_in_use_without_socket_file() {
+    let dir = TempDir::new().unwrap();
+    assert!(!check_socket_in_use(&dir.path().join("missing.sock")).await.unwrap());
+}
+
 #[test]
 fn test_cli_parses_search_subcommand() {
     let cli = Cli::parse_from(["oit", "search", "error"]);
@@ -387,6 +506,7 @@ fn test_cli_parses_search_subcommand() {
             pattern,
             limit,
             case_sensitive,
+            ..
         }) => {
             assert_eq!(pattern, "error");
             assert_eq!(limit, 100);
@@ -398,21 +518,72 @@ fn test_cli_parses_search_subcommand() {
 
 #[test]
 fn test_cli_parses_search_with_options() {
-    let cli = Cli::parse_from(["oit", "search", "ERROR", "--limit", "50", "--case-sensitive"]);
+    let cli = Cli::parse_from(["oit", "search", "ERROR", "--limit", "50", "--case-sensitive", "--strip-ansi"]);
     match cli.command {
         Some(Commands::Search {
             pattern,
             limit,
             case_sensitive,
+            strip_ansi,
         }) => {
             assert_eq!(pattern, "ERROR");
             assert_eq!(limit, 50);
             assert!(case_sensitive);
+            assert!(strip_ansi);
         }
         _ => panic!("Expected Search command"),
     }
 }
 
+#[test]
+fn test_cli_parses_idle_quit_ms_flag() {
+    let cli = Cli::parse_from(["oit", "--idle-quit-ms", "5000"]);
+    assert_eq!(cli.idle_quit_ms, Some(5000));
+    assert_eq!(Cli::parse_from(["oit"]).idle_quit_ms, None);
+    assert!(Cli::try_parse_from(["oit", "--idle-quit-ms", "0"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_working_dir_flag() {
+    let cli = Cli::parse_from(["oit", "--working-dir", "..", "web"]);
+    assert_eq!(cli.working_dir.as_deref(), Some(".."));
+    assert_eq!(cli.processes, vec!["web"]);
+    assert!(Cli::parse_from(["oit"]).working_dir.is_none());
+}
+
+#[test]
+fn test_cli_parses_exit_summary_flags() {
+    let cli = Cli::parse_from(["oit", "--summary-file", "oit-summary.json", "--json"]);
+    assert_eq!(cli.summary_file.as_deref(), Some("oit-summary.json"));
+    assert!(cli.json);
+
+    let cli = Cli::parse_from(["oit"]);
+    assert!(cli.summary_file.is_none() && !cli.json);
+}
+
+#[test]
+fn test_cli_parses_theme_flag() {
+    let cli = Cli::parse_from(["oit", "--theme", "high-contrast"]);
+    assert_eq!(cli.theme.as_deref(), Some("high-contrast"));
+    assert!(Cli::parse_from(["oit"]).theme.is_none());
+    assert!(Cli::try_parse_from(["oit", "--theme", "solarized"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_ansi_mode_flag() {
+    let cli = Cli::parse_from(["oit", "--ansi-mode", "strip"]);
+    assert_eq!(cli.ansi_mode.as_deref(), Some("strip"));
+    assert!(Cli::try_parse_from(["oit", "--ansi-mode", "rainbow"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_search_next_and_prev() {
+    let cli = Cli::parse_from(["oit", "search-next"]);
+    assert!(matches!(cli.command, Some(Commands::SearchNext)));
+    let cli = Cli::parse_from(["oit", "search-prev"]);
+    assert!(matches!(cli.command, Some(Commands::SearchPrev)));
+}
+
 #[test]
 fn test_cli_parses_commands_subcommand() {
     let cli = Cli::parse_from(["oit", "commands"]);
@@ -429,11 +600,31 @@ fn test_cli_parses_trace_subcommand() {
 fn test_cli_parses_goto_subcommand() {
     let cli = Cli::parse_from(["oit", "goto", "42"]);
     match cli.command {
-        Some(Commands::Goto { id }) => {
-            assert_eq!(id, 42);
+        Some(Commands::Goto { id, reference }) => {
+            assert_eq!(id, Some(42));
+            assert_eq!(reference, None);
+        }
+        _ => panic!("Expected Goto command"),
+    }
+}
+
+#[test]
+fn test_cli_parses_goto_by_reference() {
+    let cli = Cli::parse_from(["oit", "goto", "--ref", "web#4821"]);
+    match cli.command {
+        Some(Commands::Goto { id, reference }) => {
+            assert_eq!(id, None);
+            assert_eq!(reference.as_deref(), Some("web#4821"));
         }
         _ => panic!("Expected Goto command"),
     }
+    assert!(Cli::try_parse_from(["oit", "goto"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_reference_subcommand() {
+    let cli = Cli::parse_from(["oit", "reference", "4821"]);
+    assert!(matches!(cli.command, Some(Commands::Reference { id: 4821 })));
 }
 
 #[test]
@@ -460,6 +651,12 @@ fn test_cli_parses_scroll_with_lines() {
     }
 }
 
+#[test]
+fn test_cli_parses_scroll_percent_subcommand() {
+    let cli = Cli::parse_from(["oit", "scroll-percent", "50"]);
+    assert!(matches!(cli.command, Some(Commands::ScrollPercent { percent }) if percent == 50));
+}
+
 #[test]
 fn test_cli_parses_scroll_top() {
     let cli = Cli::parse_from(["oit", "scroll", "top"]);
@@ -493,6 +690,28 @@ fn test_cli_parses_freeze_subcommand() {
     }
 }
 
+#[test]
+fn test_cli_parses_errors_only_subcommand() {
+    let cli = Cli::parse_from(["oit", "errors-only", "on"]);
+    match cli.command {
+        Some(Commands::ErrorsOnly { mode }) => assert_eq!(mode, "on"),
+        _ => panic!("Expected ErrorsOnly command"),
+    }
+}
+
+#[test]
+fn test_cli_parses_set_view_subcommand() {
+    let cli = Cli::parse_from(["oit", "set-view", "--display-mode", "full", "--auto-scroll", "false"]);
+    match cli.command {
+        Some(Commands::SetView { display_mode, wrap, auto_scroll }) => {
+            assert_eq!(display_mode.as_deref(), Some("full"));
+            assert_eq!(wrap, None);
+            assert_eq!(auto_scroll, Some(false));
+        }
+        _ => panic!("Expected SetView command"),
+    }
+}
+
 #[test]
 fn test_cli_parses_freeze_on() {
     let cli = Cli::parse_from(["oit", "freeze", "on"]);
@@ -567,12 +786,95 @@ fn test_cli_parses_filter_remove_subcommand() {
     }
 }
 
+#[test]
+fn test_cli_parses_filter_toggle_subcommand() {
+    let cli = Cli::parse_from(["oit", "filter-toggle", "DEBUG"]);
+    assert!(matches!(cli.command, Some(Commands::FilterToggle { pattern }) if pattern == "DEBUG"));
+}
+
 #[test]
 fn test_cli_parses_filter_clear_subcommand() {
     let cli = Cli::parse_from(["oit", "filter-clear"]);
     assert!(matches!(cli.command, Some(Commands::FilterClear)));
 }
 
+#[test]
+fn test_cli_parses_process_logs_subcommand() {
+    let cli = Cli::parse_from(["oit", "process-logs", "worker", "--limit", "20"]);
+    match cli.command {
+        Some(Commands::ProcessLogs { name, limit, strip_ansi }) => {
+            assert_eq!(name, "worker");
+            assert_eq!(limit, 20);
+            assert!(!strip_ansi);
+        }
+        _ => panic!("Expected ProcessLogs command"),
+    }
+}
+
+#[test]
+fn test_cli_parses_recent_subcommand() {
+    let cli = Cli::parse_from(["oit", "recent", "30", "--limit", "5"]);
+    match cli.command {
+        Some(Commands::Recent { secs, limit, strip_ansi }) => {
+            assert_eq!(secs, 30);
+            assert_eq!(limit, 5);
+            assert!(!strip_ansi);
+        }
+        _ => panic!("Expected Recent command"),
+    }
+}
+
+#[test]
+fn test_cli_parses_histogram_subcommand() {
+    let cli = Cli::parse_from(["oit", "histogram", "--bucket-secs", "60", "--per-process"]);
+    match cli.command {
+        Some(Commands::Histogram { bucket_secs, buckets, per_process }) => {
+            assert_eq!(bucket_secs, 60);
+            assert_eq!(buckets, 30);
+            assert!(per_process);
+        }
+        _ => panic!("Expected Histogram command"),
+    }
+}
+
+#[test]
+fn test_cli_parses_add_log_file_subcommand() {
+    let cli = Cli::parse_from(["oit", "add-log-file", "jobs", "log/jobs.log"]);
+    match cli.command {
+        Some(Commands::AddLogFile { name, path }) => {
+            assert_eq!(name, "jobs");
+            assert_eq!(path, "log/jobs.log");
+        }
+        _ => panic!("Expected AddLogFile command"),
+    }
+}
+
+#[test]
+fn test_cli_parses_copy_visible_subcommand() {
+    let cli = Cli::parse_from(["oit", "copy-visible", "--clipboard"]);
+    assert!(matches!(cli.command, Some(Commands::CopyVisible { clipboard: true })));
+}
+
+#[test]
+fn test_cli_parses_clipboard_copy_subcommand() {
+    let cli = Cli::parse_from(["oit", "clipboard-copy", "bin/rails db:migrate"]);
+    assert!(matches!(cli.command, Some(Commands::ClipboardCopy { ref text, id: None }) if text.as_deref() == Some("bin/rails db:migrate")));
+
+    let cli = Cli::parse_from(["oit", "clipboard-copy", "--id", "42"]);
+    assert!(matches!(cli.command, Some(Commands::ClipboardCopy { text: None, id: Some(42) })));
+
+    assert!(Cli::try_parse_from(["oit", "clipboard-copy"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_preset_subcommands() {
+    let cli = Cli::parse_from(["oit", "presets"]);
+    assert!(matches!(cli.command, Some(Commands::Presets)));
+
+    let cli = Cli::parse_from(["oit", "preset-apply", "http_errors"]);
+    assert!(matches!(cli.command, Some(Commands::PresetApply { ref name }) if name == "http_errors"));
+}
+
 #[test]
 fn test_cli_parses_visibility_subcommand() {
     let cli = Cli::parse_from(["oit", "visibility"]);
@@ -605,7 +907,7 @@ fn test_cli_parses_show_subcommand() {
 fn test_cli_parses_restart_subcommand() {
     let cli = Cli::parse_from(["oit", "restart"]);
     match cli.command {
-        Some(Commands::Restart { name }) => {
+        Some(Commands::Restart { name, .. }) => {
             assert!(name.is_none());
         }
         _ => panic!("Expected Restart command"),
@@ -616,7 +918,7 @@ fn test_cli_parses_restart_subcommand() {
 fn test_cli_parses_restart_subcommand_with_name() {
     let cli = Cli::parse_from(["oit", "restart", "web"]);
     match cli.command {
-        Some(Commands::Restart { name }) => {
+        Some(Commands::Restart { name, .. }) => {
             assert_eq!(name, Some("web".to_string()));
         }
         _ => panic!("Expected Restart command"),
@@ -627,11 +929,41 @@ fn test_cli_parses_restart_subcommand_with_name() {
 fn test_cli_parses_kill_subcommand() {
     let cli = Cli::parse_from(["oit", "kill", "web"]);
     match cli.command {
-        Some(Commands::Kill { name }) => {
+        Some(Commands::Kill { name, .. }) => {
+            assert_eq!(name, "web");
+        }
+        _ => panic!("Expected Kill command"),
+    }
+}
+
+#[test]
+fn test_cli_parses_kill_dry_run() {
+    let cli = Cli::parse_from(["oit", "kill", "web", "--dry-run"]);
+    match cli.command {
+        Some(Commands::Kill { name, dry_run }) => {
             assert_eq!(name, "web");
+            assert!(dry_run);
         }
         _ => panic!("Expected Kill command"),
     }
+
+    let cli = Cli::parse_from(["oit", "restart", "--dry-run"]);
+    assert!(matches!(cli.command, Some(Commands::Restart { name: None, dry_run: true, .. })));
+}
+
+#[test]
+fn test_cli_parses_restart_wait_ready() {
+    let cli = Cli::parse_from(["oit", "restart", "web", "--wait-ready", "--timeout-ms", "5000"]);
+    match cli.command {
+        Some(Commands::Restart { name, wait_ready, timeout_ms, .. }) => {
+            assert_eq!(name.as_deref(), Some("web"));
+            assert!(wait_ready);
+            assert_eq!(timeout_ms, 5000);
+        }
+        _ => panic!("Expected Restart command"),
+    }
+
+    assert!(Cli::try_parse_from(["oit", "restart", "--timeout-ms", "5000"]).is_err());
 }
 
 #[test]
@@ -649,7 +981,7 @@ fn test_cli_parses_start_subcommand() {
 fn test_cli_parses_restart_alias_r() {
     let cli = Cli::parse_from(["oit", "r", "web"]);
     match cli.command {
-        Some(Commands::Restart { name }) => {
+        Some(Commands::Restart { name, .. }) => {
             assert_eq!(name, Some("web".to_string()));
         }
         _ => panic!("Expected Restart command via 'r' alias"),
@@ -660,7 +992,7 @@ fn test_cli_parses_restart_alias_r() {
 fn test_cli_parses_kill_alias_k() {
     let cli = Cli::parse_from(["oit", "k", "web"]);
     match cli.command {
-        Some(Commands::Kill { name }) => {
+        Some(Commands::Kill { name, .. }) => {
             assert_eq!(name, "web");
         }
         _ => panic!("Expected Kill command via 'k' alias"),
@@ -678,6 +1010,27 @@ fn test_cli_parses_start_alias_s() {
     }
 }
 
+#[test]
+fn test_cli_parses_process_add_subcommand() {
+    let cli = Cli::parse_from([
+        "oit", "process-add", "--cwd", "tmp", "--env", "DEBUG=1", "tailer", "tail", "-f", "log/dev.log",
+    ]);
+    match cli.command {
+        Some(Commands::ProcessAdd { name, command, cwd, env }) => {
+            assert_eq!(name, "tailer");
+            assert_eq!(command, vec!["tail", "-f", "log/dev.log"]);
+            assert_eq!(cwd, Some("tmp".to_string()));
+            assert_eq!(env, vec!["DEBUG=1"]);
+        }
+        _ => panic!("Expected ProcessAdd command"),
+    }
+}
+
+#[test]
+fn test_cli_process_add_requires_command() {
+    assert!(Cli::try_parse_from(["oit", "process-add", "tailer"]).is_err());
+}
+
 #[test]
 fn test_cli_parses_errors_subcommand() {
     let cli = Cli::parse_from(["oit", "errors"]);
@@ -814,3 +1167,151 @@ fn test_cli_processes_with_flags() {
     assert!(cli.no_update);
     assert_eq!(cli.processes, vec!["web", "worker"]);
 }
+
+#[test]
+fn test_cli_parses_replay_subcommand() {
+    let cli = Cli::parse_from(["oit", "replay", "incident.json"]);
+    match cli.command {
+        Some(Commands::Replay { file }) => assert_eq!(file, "incident.json"),
+        _ => panic!("Expected Replay command"),
+    }
+}
+
+#[test]
+fn test_cli_parses_doctor_subcommand() {
+    let cli = Cli::parse_from(["oit", "-c", "other.toml", "doctor"]);
+    assert!(matches!(cli.command, Some(Commands::Doctor)));
+    assert_eq!(cli.config, "other.toml");
+}
+
+#[test]
+fn test_cli_parses_pipeline_subcommand() {
+    let cli = Cli::parse_from(["oit", "pipeline", "status", "errors-only", r#"{"command": "logs", "args": {"limit": 5}}"#]);
+    let Some(Commands::Pipeline { commands }) = cli.command else {
+        panic!("Expected Pipeline command");
+    };
+    let commands = pipeline_commands(&commands).unwrap();
+    assert_eq!(commands[0], serde_json::json!({"command": "status"}));
+    assert_eq!(commands[1], serde_json::json!({"command": "errors_only"}));
+    assert_eq!(commands[2], serde_json::json!({"command": "logs", "args": {"limit": 5}}));
+
+    assert!(pipeline_commands(&["{not json".to_string()]).is_err());
+    assert!(Cli::try_parse_from(["oit", "pipeline"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_colors_subcommand() {
+    let cli = Cli::parse_from(["oit", "colors"]);
+    assert!(matches!(cli.command, Some(Commands::Colors)));
+}
+
+#[test]
+fn test_cli_parses_info_subcommand() {
+    let cli = Cli::parse_from(["oit", "info"]);
+    assert!(matches!(cli.command, Some(Commands::Info)));
+}
+
+#[test]
+fn test_cli_parses_logs_stream() {
+    let cli = Cli::parse_from(["oit", "logs", "--stream", "stderr"]);
+    match cli.command {
+        Some(Commands::Logs { stream, .. }) => assert_eq!(stream, "stderr"),
+        _ => panic!("Expected Logs command"),
+    }
+
+    let cli = Cli::parse_from(["oit", "logs"]);
+    assert!(matches!(cli.command, Some(Commands::Logs { stream, .. }) if stream == "both"));
+
+    assert!(Cli::try_parse_from(["oit", "logs", "--stream", "stdin"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_logs_with_batches() {
+    let cli = Cli::parse_from(["oit", "logs", "--with-batches"]);
+    assert!(matches!(cli.command, Some(Commands::Logs { with_batches: true, .. })));
+
+    let cli = Cli::parse_from(["oit", "logs"]);
+    assert!(matches!(cli.command, Some(Commands::Logs { with_batches: false, .. })));
+}
+
+#[test]
+fn test_cli_parses_kill_all_and_start_all() {
+    assert!(matches!(Cli::parse_from(["oit", "kill-all"]).command, Some(Commands::KillAll)));
+    assert!(matches!(Cli::parse_from(["oit", "start-all"]).command, Some(Commands::StartAll)));
+}
+
+#[test]
+fn test_cli_parses_describe_subcommand() {
+    let cli = Cli::parse_from(["oit", "describe", "worker"]);
+    assert!(matches!(cli.command, Some(Commands::Describe { ref name }) if name == "worker"));
+    assert!(Cli::try_parse_from(["oit", "describe"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_last_output_subcommand() {
+    let cli = Cli::parse_from(["oit", "last-output", "worker", "--strip-ansi"]);
+    assert!(matches!(cli.command, Some(Commands::LastOutput { ref name, strip_ansi: true }) if name == "worker"));
+    assert!(Cli::try_parse_from(["oit", "last-output"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_set_buffer_subcommand() {
+    let cli = Cli::parse_from(["oit", "set-buffer", "20"]);
+    assert!(matches!(cli.command, Some(Commands::SetBuffer { mb: 20 })));
+    assert!(Cli::try_parse_from(["oit", "set-buffer", "lots"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_snapshot_subcommand() {
+    let cli = Cli::parse_from(["oit", "snapshot"]);
+    assert!(matches!(cli.command, Some(Commands::Snapshot { recent_logs: 20 })));
+
+    let cli = Cli::parse_from(["oit", "snapshot", "--recent-logs", "100"]);
+    assert!(matches!(cli.command, Some(Commands::Snapshot { recent_logs: 100 })));
+}
+
+#[test]
+fn test_cli_parses_trace_recording_subcommands() {
+    let cli = Cli::parse_from(["oit", "trace-start", "--label", "checkout"]);
+    assert!(matches!(cli.command, Some(Commands::TraceStart { label: Some(ref l) }) if l == "checkout"));
+
+    assert!(matches!(Cli::parse_from(["oit", "trace-stop"]).command, Some(Commands::TraceStop)));
+
+    let cli = Cli::parse_from(["oit", "trace-annotate", "clicked", "pay"]);
+    assert!(matches!(cli.command, Some(Commands::TraceAnnotate { ref note }) if note == &["clicked", "pay"]));
+    assert!(Cli::try_parse_from(["oit", "trace-annotate"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_goto_error_subcommand() {
+    let cli = Cli::parse_from(["oit", "goto-error", "prev", "--from-id", "42"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::GotoError { ref direction, from_id: Some(42) }) if direction == "prev"
+    ));
+    let cli = Cli::parse_from(["oit", "goto-error"]);
+    assert!(matches!(cli.command, Some(Commands::GotoError { ref direction, from_id: None }) if direction == "next"));
+    assert!(Cli::try_parse_from(["oit", "goto-error", "up"]).is_err());
+}
+
+#[test]
+fn test_cli_parses_set_and_clear_status_subcommands() {
+    let cli = Cli::parse_from(["oit", "set-status", "--type", "error", "worker", "is", "stuck"]);
+    assert!(matches!(
+        cli.command,
+        Some(Commands::SetStatus { ref status_type, ref message }) if status_type == "error" && message == &["worker", "is", "stuck"]
+    ));
+    let cli = Cli::parse_from(["oit", "set-status", "checking"]);
+    assert!(matches!(cli.command, Some(Commands::SetStatus { ref status_type, .. }) if status_type == "info"));
+    assert!(Cli::try_parse_from(["oit", "set-status", "--type", "warning", "x"]).is_err());
+    assert!(matches!(Cli::parse_from(["oit", "clear-status"]).command, Some(Commands::ClearStatus)));
+}
+
+#[test]
+fn test_cli_parses_tail_errors_subcommand() {
+    let cli = Cli::parse_from(["oit", "tail-errors"]);
+    assert!(matches!(cli.command, Some(Commands::TailErrors { ref level }) if level == "error"));
+
+    let cli = Cli::parse_from(["oit", "tail-errors", "--level", "error_or_warning"]);
+    assert!(matches!(cli.command, Some(Commands::TailErrors { ref level }) if level == "error_or_warning"));
+}
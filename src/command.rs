@@ -1,9 +1,11 @@
 use crate::config::Config;
 use crate::group::GroupResolver;
-use crate::operations::{batch, batch_window, coloring, filter, goto, process, traces, visibility};
-use crate::process::ProcessManager;
-use crate::ui::App;
+use crate::operations::{batch, batch_window, clipboard, coloring, display, filter, goto, logs, process, traces, visibility};
+use crate::process::{ProcessManager, REPLAY_MODE_MESSAGE};
+use crate::ui::{App, BatchStrategy, PendingAction, PendingConfirmation};
+use crate::ui::display_state::ProcessListPosition;
 use anyhow::Result;
+use std::collections::HashMap;
 
 /// Target for goto command - absolute or relative time
 #[derive(Debug, PartialEq, Clone)]
@@ -17,17 +19,30 @@ pub enum GotoTarget {
 pub enum Command {
     Quit,
     Start(String),
+    StartLastKilled,
     Restart(Option<String>),
     Kill(String),
+    AddProcess { name: String, command: String },
+    TailFile { name: String, path: String },
     FilterInclude(String),
     FilterExclude(String),
     FilterClear,
     FilterList,
+    FilterToggle(usize),
+    ApplyPreset(String),
+    SavePreset(String),
+    ListPresets,
+    ErrorsOnly,
+    ToggleQuietStartup,
     NextBatch,
     PrevBatch,
     ShowBatch,
     SetBatchWindow(i64),
     ShowBatchWindow,
+    SetBatchStrategy(BatchStrategy),
+    ShowBatchStrategy,
+    SetBufferLimit(usize),
+    ShowBufferLimit,
     Hide(String),
     Show(String),
     HideAll,
@@ -35,10 +50,111 @@ pub enum Command {
     Only(String),
     Traces,
     ColorToggle,
+    CondenseToggle,
+    Split(String, String),
+    Unsplit,
+    ProcessListPosition(Option<ProcessListPosition>),
+    Pin(String),
+    Unpin(String),
+    PauseIngest(String),
+    ResumeIngest(String),
+    Mute { process: String, pattern: String },
+    Unmute(String),
     Goto(GotoTarget),
+    Recent(u64),
+    Retail(String),
+    LastError(Option<String>),
+    Cmd(String),
+    CopyAll,
     Unknown(String),
 }
 
+impl Command {
+    /// Commands that start, stop or add processes (disabled in replay mode)
+    fn controls_processes(&self) -> bool {
+        matches!(
+            self,
+            Command::Start(_) | Command::StartLastKilled | Command::Restart(_) | Command::Kill(_)
+                | Command::AddProcess { .. }
+                | Command::TailFile { .. }
+        )
+    }
+}
+
+/// Built-in alternate names and the command each stands for. `parse_command`
+/// resolves these before matching; user aliases from `[aliases]` are expanded
+/// first by `parse_command_with_aliases`.
+pub const BUILTIN_ALIASES: &[(&str, &str)] = &[
+    ("q", "quit"),
+    ("exit", "quit"),
+    ("s", "start"),
+    ("k", "kill"),
+    ("f", "filter"),
+    ("r", "restart"),
+    ("g", "goto"),
+    ("colors", "color"),
+];
+
+fn resolve_builtin_alias(word: &str) -> &str {
+    BUILTIN_ALIASES
+        .iter()
+        .find(|(alias, _)| *alias == word)
+        .map_or(word, |(_, name)| name)
+}
+
+/// Parse a command line after expanding a user alias in the command position
+pub fn parse_command_with_aliases(input: &str, aliases: &HashMap<String, String>) -> Command {
+    match expand_aliases(input.trim(), aliases) {
+        Ok(expanded) => parse_command(&expanded),
+        Err(msg) => Command::Unknown(msg),
+    }
+}
+
+/// Replace a leading user alias with its value, following chains. The value
+/// may carry arguments (`rw = "restart web"`); typed arguments are appended.
+pub fn expand_aliases(input: &str, aliases: &HashMap<String, String>) -> Result<String, String> {
+    let mut text = input.to_string();
+    let mut chain: Vec<String> = Vec::new();
+    loop {
+        let (word, rest) = text.split_once(char::is_whitespace).unwrap_or((&text, ""));
+        let Some(target) = aliases.get(word) else {
+            return Ok(text);
+        };
+        if chain.iter().any(|seen| seen == word) {
+            chain.push(word.to_string());
+            return Err(format!("Alias loop: {}", chain.join(" -> ")));
+        }
+        chain.push(word.to_string());
+        text = format!("{} {}", target.trim(), rest).trim_end().to_string();
+    }
+}
+
+/// True if `word` names a built-in command (including built-in aliases)
+fn is_builtin_command(word: &str) -> bool {
+    !matches!(parse_command(word), Command::Unknown(msg) if msg.starts_with("Unknown command") || msg == "Empty command")
+}
+
+/// Check `[aliases]`: names must be single words that don't shadow a built-in
+/// command, and every alias must end at a real command without looping.
+pub fn validate_aliases(aliases: &HashMap<String, String>) -> Result<(), String> {
+    let mut names: Vec<&String> = aliases.keys().collect();
+    names.sort();
+    for name in names {
+        if name.is_empty() || name.contains(char::is_whitespace) {
+            return Err(format!("alias name '{}' must be a single word", name));
+        }
+        if is_builtin_command(name) {
+            return Err(format!("alias '{}' shadows a built-in command", name));
+        }
+        let expanded = expand_aliases(name, aliases)?;
+        let command = expanded.split_whitespace().next().unwrap_or("");
+        if !is_builtin_command(command) {
+            return Err(format!("alias '{}' points to unknown command '{}'", name, command));
+        }
+    }
+    Ok(())
+}
+
 /// Parse a command from user input (without the leading ':')
 pub fn parse_command(input: &str) -> Command {
     let input = input.trim();
@@ -48,30 +164,52 @@ pub fn parse_command(input: &str) -> Command {
         return Command::Unknown("Empty command".to_string());
     }
 
-    match parts[0] {
-        "q" | "quit" | "exit" => Command::Quit,
-        "s" => {
+    match resolve_builtin_alias(parts[0]) {
+        "quit" => Command::Quit,
+        "start" => {
             if parts.len() < 2 {
-                Command::Unknown("Usage: :s <process>".to_string())
+                Command::StartLastKilled
             } else {
                 Command::Start(parts[1].to_string())
             }
         }
-        "r" | "restart" => {
+        "restart" => {
             if parts.len() < 2 {
                 Command::Restart(None)
             } else {
                 Command::Restart(Some(parts[1].to_string()))
             }
         }
-        "k" => {
+        "kill" => {
             if parts.len() < 2 {
                 Command::Unknown("Usage: :k <process>".to_string())
             } else {
                 Command::Kill(parts[1].to_string())
             }
         }
-        "f" => {
+        "add" => {
+            // Keep the command text verbatim (it's passed to the shell)
+            let rest = input["add".len()..].trim_start();
+            match rest.split_once(char::is_whitespace) {
+                Some((name, command)) if !command.trim().is_empty() => Command::AddProcess {
+                    name: name.to_string(),
+                    command: command.trim().to_string(),
+                },
+                _ => Command::Unknown("Usage: :add <name> <command>".to_string()),
+            }
+        }
+        "tailfile" => {
+            // Keep the path verbatim (it may contain spaces)
+            let rest = input["tailfile".len()..].trim_start();
+            match rest.split_once(char::is_whitespace) {
+                Some((name, path)) if !path.trim().is_empty() => Command::TailFile {
+                    name: name.to_string(),
+                    path: path.trim().to_string(),
+                },
+                _ => Command::Unknown("Usage: :tailfile <name> <path>".to_string()),
+            }
+        }
+        "filter" => {
             if parts.len() < 2 {
                 Command::Unknown("Usage: :f <text_or_regex>".to_string())
             } else {
@@ -87,6 +225,18 @@ pub fn parse_command(input: &str) -> Command {
         }
         "fc" => Command::FilterClear,
         "fl" => Command::FilterList,
+        "ft" => match parts.get(1).and_then(|s| s.parse::<usize>().ok()) {
+            Some(n) => Command::FilterToggle(n),
+            None => Command::Unknown("Usage: :ft <n> (see :fl for numbers)".to_string()),
+        },
+        "preset" => match (parts.get(1).copied(), parts.get(2)) {
+            (None, _) => Command::ListPresets,
+            (Some("save"), Some(name)) => Command::SavePreset(name.to_string()),
+            (Some("save"), None) => Command::Unknown("Usage: :preset save <name>".to_string()),
+            (Some(name), _) => Command::ApplyPreset(name.to_string()),
+        },
+        "errors" => Command::ErrorsOnly,
+        "quiet" => Command::ToggleQuietStartup,
         "nb" => Command::NextBatch,
         "pb" => Command::PrevBatch,
         "sb" => Command::ShowBatch,
@@ -111,6 +261,20 @@ pub fn parse_command(input: &str) -> Command {
                 }
             }
         }
+        "buffer" => match parts.get(1) {
+            None => Command::ShowBufferLimit,
+            Some(mb) => match mb.parse::<usize>() {
+                Ok(mb) if mb > 0 => Command::SetBufferLimit(mb),
+                _ => Command::Unknown("Buffer limit must be a positive number of MB".to_string()),
+            },
+        },
+        "bs" => match parts.get(1) {
+            None => Command::ShowBatchStrategy,
+            Some(name) => match BatchStrategy::parse(name) {
+                Some(strategy) => Command::SetBatchStrategy(strategy),
+                None => Command::Unknown("Batch strategy must be time_window or hybrid".to_string()),
+            },
+        },
         "hide" => {
             if parts.len() < 2 {
                 Command::Unknown("Usage: :hide <process> or :hide all".to_string())
@@ -136,15 +300,82 @@ pub fn parse_command(input: &str) -> Command {
                 Command::Only(parts[1].to_string())
             }
         }
+        "retail" => {
+            if parts.len() < 2 {
+                Command::Unknown("Usage: :retail <name>".to_string())
+            } else {
+                Command::Retail(parts[1].to_string())
+            }
+        }
         "traces" => Command::Traces,
-        "color" | "colors" => Command::ColorToggle,
-        "g" | "goto" => {
+        "lasterror" => Command::LastError(parts.get(1).map(|name| name.to_string())),
+        "cmd" => match parts.get(1) {
+            Some(name) => Command::Cmd(name.to_string()),
+            None => Command::Unknown("Usage: :cmd <process>".to_string()),
+        },
+        "copyall" => Command::CopyAll,
+        "color" => Command::ColorToggle,
+        "condense" => Command::CondenseToggle,
+        "split" => {
+            if parts.len() < 3 {
+                Command::Unknown("Usage: :split <process> <process>".to_string())
+            } else {
+                Command::Split(parts[1].to_string(), parts[2].to_string())
+            }
+        }
+        "unsplit" => Command::Unsplit,
+        "pin" => match parts.get(1) {
+            Some(name) => Command::Pin(name.to_string()),
+            None => Command::Unknown("Usage: :pin <process>".to_string()),
+        },
+        "unpin" => match parts.get(1) {
+            Some(name) => Command::Unpin(name.to_string()),
+            None => Command::Unknown("Usage: :unpin <process>".to_string()),
+        },
+        "pause" => match parts.get(1) {
+            Some(name) => Command::PauseIngest(name.to_string()),
+            None => Command::Unknown("Usage: :pause <process>".to_string()),
+        },
+        "resume" => match parts.get(1) {
+            Some(name) => Command::ResumeIngest(name.to_string()),
+            None => Command::Unknown("Usage: :resume <process>".to_string()),
+        },
+        "mute" => {
+            // Keep the pattern verbatim (it may contain spaces)
+            let rest = input["mute".len()..].trim_start();
+            let parsed = rest
+                .split_once(char::is_whitespace)
+                .and_then(|(process, rest)| Some((process, rest.trim_start().strip_prefix("until ")?.trim())));
+            match parsed {
+                Some((process, pattern)) if !pattern.is_empty() => Command::Mute {
+                    process: process.to_string(),
+                    pattern: pattern.to_string(),
+                },
+                _ => Command::Unknown("Usage: :mute <process> until <pattern>".to_string()),
+            }
+        }
+        "unmute" => match parts.get(1) {
+            Some(name) => Command::Unmute(name.to_string()),
+            None => Command::Unknown("Usage: :unmute <process>".to_string()),
+        },
+        "proclist" => match parts.get(1) {
+            None => Command::ProcessListPosition(None),
+            Some(position) => match ProcessListPosition::parse(position) {
+                Some(position) => Command::ProcessListPosition(Some(position)),
+                None => Command::Unknown("Process list position must be top, bottom or hidden".to_string()),
+            },
+        },
+        "goto" => {
             if parts.len() < 2 {
                 Command::Unknown("Usage: :goto HH:MM[:SS] or :goto +/-Ns/m/h".to_string())
             } else {
                 parse_goto_target(parts[1])
             }
         }
+        "recent" => match parts.get(1).and_then(|s| s.parse::<u64>().ok()) {
+            Some(secs) => Command::Recent(secs),
+            None => Command::Unknown("Usage: :recent <secs>".to_string()),
+        },
         _ => Command::Unknown(format!("Unknown command: {}", parts[0])),
     }
 }
@@ -210,12 +441,16 @@ impl<'a> CommandExecutor<'a> {
         Self { app, manager, config }
     }
 
-    fn create_resolver(&self) -> GroupResolver<'_> {
+    fn create_resolver(&self) -> GroupResolver<'static> {
         let process_names: Vec<String> = self.manager.get_processes().keys().cloned().collect();
-        GroupResolver::new(&self.config.groups, process_names)
+        GroupResolver::owned(self.config.all_groups(), process_names)
     }
 
     pub async fn execute(&mut self, command: Command) -> Result<()> {
+        if self.manager.is_replay_mode() && command.controls_processes() {
+            self.app.display.set_status_error(REPLAY_MODE_MESSAGE.to_string());
+            return Ok(());
+        }
         match command {
             Command::Quit => {
                 self.app.quit();
@@ -223,14 +458,35 @@ impl<'a> CommandExecutor<'a> {
             Command::Start(name) => {
                 self.execute_start(&name).await?;
             }
+            Command::StartLastKilled => {
+                self.execute_start_last_killed().await?;
+            }
             Command::Restart(Some(name)) => {
                 self.execute_restart(&name)?;
             }
             Command::Restart(None) => {
-                self.execute_restart_all()?;
+                if self.needs_confirmation() {
+                    self.request_confirmation(PendingAction::RestartAll);
+                } else {
+                    self.execute_restart_all()?;
+                }
             }
             Command::Kill(name) => {
-                self.execute_kill(&name).await?;
+                if self.needs_confirmation() {
+                    self.request_confirmation(PendingAction::Kill(name));
+                } else {
+                    self.execute_kill(&name).await?;
+                }
+            }
+            Command::AddProcess { name, command } => {
+                self.execute_add_process(&name, &command).await;
+            }
+            Command::TailFile { name, path } => {
+                match process::add_log_file(self.manager, &name, &path).await {
+                    Ok(msg) => self.app.display.set_status_success(msg),
+                    Err(msg) => self.app.display.set_status_error(msg),
+                }
+                coloring::refresh_process_colors(self.app, self.manager, self.config);
             }
             Command::FilterInclude(pattern) => {
                 self.execute_filter_include(pattern);
@@ -244,6 +500,29 @@ impl<'a> CommandExecutor<'a> {
             Command::FilterList => {
                 self.execute_filter_list();
             }
+            Command::FilterToggle(n) => match filter::toggle_filter_at(self.app, self.config, n) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::ApplyPreset(name) => match filter::apply_preset(self.app, self.config, &name) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::SavePreset(name) => match filter::save_preset(self.app, self.config, &name) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::ListPresets => {
+                let msg = filter::list_presets(self.config).unwrap_or_else(|| "No saved presets".to_string());
+                self.app.display.set_status_info(msg);
+            }
+            Command::ToggleQuietStartup => {
+                let msg = filter::toggle_quiet_startup(self.app);
+                self.app.display.set_status_info(msg);
+            }
+            Command::ErrorsOnly => {
+                self.execute_errors_only();
+            }
             Command::NextBatch => {
                 self.execute_next_batch();
             }
@@ -259,6 +538,31 @@ impl<'a> CommandExecutor<'a> {
             Command::ShowBatchWindow => {
                 self.execute_show_batch_window();
             }
+            Command::SetBatchStrategy(strategy) => {
+                let batch_count = batch_window::set_batch_strategy(self.app, self.manager, self.config, strategy);
+                self.app.display.set_status_success(format!(
+                    "Batch strategy set to {} ({} batches detected)",
+                    strategy.name(),
+                    batch_count
+                ));
+            }
+            Command::SetBufferLimit(mb) => match logs::set_buffer_limit(self.manager, mb) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::ShowBufferLimit => {
+                let stats = self.manager.get_buffer_stats();
+                self.app.display.set_status_info(format!(
+                    "Buffer limit: {} MB ({:.1} MB used, {} lines)",
+                    stats.limit_mb, stats.memory_mb, stats.line_count
+                ));
+            }
+            Command::ShowBatchStrategy => {
+                self.app.display.set_status_info(format!(
+                    "Current batch strategy: {}",
+                    self.app.batch.batch_strategy.name()
+                ));
+            }
             Command::Hide(process) => {
                 self.execute_hide(process);
             }
@@ -274,15 +578,80 @@ impl<'a> CommandExecutor<'a> {
             Command::Only(process) => {
                 self.execute_only(process);
             }
+            Command::Retail(name) => {
+                match process::retail_log_file(self.manager, &name) {
+                    Ok(msg) => self.app.display.set_status_success(msg),
+                    Err(msg) => self.app.display.set_status_error(msg),
+                }
+            }
             Command::Traces => {
                 self.execute_traces();
             }
+            Command::LastError(name) => match process::show_last_output(self.app, self.manager, name.as_deref()) {
+                Ok(msg) => self.app.display.set_status_info(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::Cmd(name) => match process::launch_command(self.manager, &name) {
+                Ok(msg) => self.app.display.set_status_info(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::CopyAll => match clipboard::copy_visible(self.app, self.manager) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
             Command::ColorToggle => {
                 self.execute_color_toggle();
             }
+            Command::CondenseToggle => {
+                if display::toggle_condensed(self.app, self.config) {
+                    self.app.display.set_status_success("Condensed display on: [key:value] metadata collapsed".to_string());
+                } else if self.app.display.is_compact() {
+                    self.app.display.set_status_info("Condensed display off (compact mode still condenses)".to_string());
+                } else {
+                    self.app.display.set_status_info("Condensed display off: showing raw lines".to_string());
+                }
+            }
+            Command::Split(left, right) => {
+                self.execute_split(&left, &right);
+            }
+            Command::Unsplit => {
+                self.execute_unsplit();
+            }
+            Command::Pin(name) => match display::pin_process(self.app, self.manager, self.config, &name) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::Unpin(name) => match display::unpin_process(self.app, self.config, &name) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::PauseIngest(name) => match process::pause_ingest(self.manager, &name) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::ResumeIngest(name) => match process::resume_ingest(self.manager, &name) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::Mute { process, pattern } => match visibility::mute_process(self.app, self.manager, &process, &pattern) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::Unmute(name) => match visibility::unmute_process(self.app, self.manager, &name) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
+            Command::ProcessListPosition(position) => {
+                let name = display::set_process_list_position(self.app, self.config, position);
+                self.app.display.set_status_success(format!("Process list: {}", name));
+            }
             Command::Goto(target) => {
                 self.execute_goto(target);
             }
+            Command::Recent(secs) => match goto::goto_recent(self.app, self.manager, secs) {
+                Ok(msg) => self.app.display.set_status_success(msg),
+                Err(msg) => self.app.display.set_status_error(msg),
+            },
             Command::Unknown(msg) => {
                 self.app.display.set_status_error(format!("Error: {}", msg));
             }
@@ -290,6 +659,39 @@ impl<'a> CommandExecutor<'a> {
         Ok(())
     }
 
+    /// Run a destructive action the user has confirmed
+    pub async fn execute_confirmed(&mut self, action: PendingAction) -> Result<()> {
+        match action {
+            PendingAction::Kill(name) => self.execute_kill(&name).await,
+            PendingAction::RestartAll => self.execute_restart_all(),
+            PendingAction::Quit(_) => {
+                self.app.start_shutdown();
+                // Set all processes to Terminating status immediately (UI will show this on next draw)
+                self.manager.set_all_terminating();
+                Ok(())
+            }
+        }
+    }
+
+    fn needs_confirmation(&self) -> bool {
+        self.config.confirm_destructive == Some(true)
+    }
+
+    fn request_confirmation(&mut self, action: PendingAction) {
+        self.app.display.set_status_info(format!("Press y to confirm {}", action.describe()));
+        self.app.pending_confirm = Some(PendingConfirmation::new(action));
+    }
+
+    async fn execute_start_last_killed(&mut self) -> Result<()> {
+        match self.manager.last_killed().map(|s| s.to_string()) {
+            Some(name) => self.execute_start(&name).await,
+            None => {
+                self.app.display.set_status_error("Usage: :s <process> (no recently killed process)".to_string());
+                Ok(())
+            }
+        }
+    }
+
     async fn execute_start(&mut self, name: &str) -> Result<()> {
         let resolved = self.create_resolver().resolve(name);
         let is_group = resolved.len() > 1;
@@ -318,6 +720,14 @@ impl<'a> CommandExecutor<'a> {
         Ok(())
     }
 
+    async fn execute_add_process(&mut self, name: &str, command: &str) {
+        match process::add_process(self.manager, name, command, None, Default::default()).await {
+            Ok(msg) => self.app.display.set_status_success(msg),
+            Err(msg) => self.app.display.set_status_error(msg),
+        }
+        coloring::refresh_process_colors(self.app, self.manager, self.config);
+    }
+
     fn execute_restart(&mut self, name: &str) -> Result<()> {
         // Reload Procfile to pick up changes
         match self.manager.reload_procfile(self.config) {
@@ -438,6 +848,11 @@ impl<'a> CommandExecutor<'a> {
         }
     }
 
+    fn execute_errors_only(&mut self) {
+        let msg = filter::toggle_errors_only(self.app);
+        self.app.display.set_status_info(msg);
+    }
+
     fn execute_next_batch(&mut self) {
         batch::next_batch(self.app, self.manager);
         self.app.display.set_status_info("Next batch".to_string());
@@ -558,6 +973,20 @@ impl<'a> CommandExecutor<'a> {
         }
     }
 
+    fn execute_split(&mut self, left: &str, right: &str) {
+        match display::split_view(self.app, self.manager, left, right) {
+            Ok(msg) => self.app.display.set_status_success(msg),
+            Err(msg) => self.app.display.set_status_error(msg),
+        }
+    }
+
+    fn execute_unsplit(&mut self) {
+        match display::unsplit_view(self.app) {
+            Ok(msg) => self.app.display.set_status_success(msg),
+            Err(msg) => self.app.display.set_status_error(msg),
+        }
+    }
+
     fn execute_goto(&mut self, target: GotoTarget) {
         match goto::goto_timestamp(self.app, self.manager, target) {
             Ok(msg) => self.app.display.set_status_success(msg),
@@ -570,6 +999,253 @@ impl<'a> CommandExecutor<'a> {
 mod tests {
     use super::*;
 
+    fn confirm_config(enabled: bool) -> Config {
+        toml::from_str(&format!("procfile = \"Procfile\"\nconfirm_destructive = {}", enabled)).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_process_control_is_disabled_in_replay_mode() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        manager.load_replay(vec![crate::log::LogLine::new(
+            crate::log::LogSource::ProcessStdout("web".to_string()),
+            "hello".to_string(),
+        )]);
+        let mut config = confirm_config(true);
+
+        let mut executor = CommandExecutor::new(&mut app, &mut manager, &mut config);
+        executor.execute(Command::Kill("web".to_string())).await.unwrap();
+        executor.execute(Command::Restart(None)).await.unwrap();
+
+        assert!(app.pending_confirm.is_none());
+        let (msg, _) = app.display.status_message.as_ref().unwrap();
+        assert_eq!(msg, REPLAY_MODE_MESSAGE);
+
+        // Non-process commands still work against the loaded logs
+        let mut executor = CommandExecutor::new(&mut app, &mut manager, &mut config);
+        executor.execute(Command::FilterInclude("hello".to_string())).await.unwrap();
+        assert_eq!(app.filters.filters.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_kill_requires_confirmation_when_enabled() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "sleep 10".to_string(), None, None, None);
+        let mut config = confirm_config(true);
+
+        let mut executor = CommandExecutor::new(&mut app, &mut manager, &mut config);
+        executor.execute(Command::Kill("web".to_string())).await.unwrap();
+
+        let pending = app.pending_confirm.as_ref().expect("kill should wait for confirmation");
+        assert_eq!(pending.action, PendingAction::Kill("web".to_string()));
+        let (msg, _) = app.display.status_message.as_ref().unwrap();
+        assert_eq!(msg, "Press y to confirm kill web");
+        assert_eq!(manager.last_killed(), None);
+    }
+
+    #[tokio::test]
+    async fn test_kill_runs_immediately_when_confirmation_disabled() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "sleep 10".to_string(), None, None, None);
+        let mut config = confirm_config(false);
+
+        let mut executor = CommandExecutor::new(&mut app, &mut manager, &mut config);
+        executor.execute(Command::Kill("web".to_string())).await.unwrap();
+
+        assert!(app.pending_confirm.is_none());
+        assert_eq!(manager.last_killed(), Some("web"));
+    }
+
+    #[tokio::test]
+    async fn test_start_without_name_and_nothing_killed_shows_error() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        let mut config = confirm_config(false);
+
+        let mut executor = CommandExecutor::new(&mut app, &mut manager, &mut config);
+        executor.execute(parse_command("s")).await.unwrap();
+
+        let (msg, _) = app.display.status_message.as_ref().unwrap();
+        assert!(msg.contains("no recently killed process"));
+    }
+
+    #[test]
+    fn test_parse_add_command_keeps_command_verbatim() {
+        assert_eq!(
+            parse_command("add tailer tail -f  log/dev.log"),
+            Command::AddProcess {
+                name: "tailer".to_string(),
+                command: "tail -f  log/dev.log".to_string(),
+            }
+        );
+        assert!(matches!(parse_command("add tailer"), Command::Unknown(_)));
+        assert!(matches!(parse_command("add"), Command::Unknown(_)));
+    }
+
+    #[tokio::test]
+    async fn test_add_process_starts_it_and_rejects_duplicates() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        let mut config = confirm_config(false);
+
+        let mut executor = CommandExecutor::new(&mut app, &mut manager, &mut config);
+        executor.execute(parse_command("add helper sleep 10")).await.unwrap();
+        executor.execute(parse_command("add helper echo again")).await.unwrap();
+
+        let (msg, _) = app.display.status_message.as_ref().unwrap();
+        assert_eq!(msg, "Failed to add helper: Process 'helper' already exists");
+        assert_eq!(manager.get_status("helper"), Some(crate::process::ProcessStatus::Running));
+        assert_eq!(manager.get_processes()["helper"].command, "sleep 10");
+        manager.kill_all().await.unwrap();
+    }
+
+    #[test]
+    fn test_parse_split_commands() {
+        assert_eq!(
+            parse_command("split web worker"),
+            Command::Split("web".to_string(), "worker".to_string())
+        );
+        assert!(matches!(parse_command("split web"), Command::Unknown(_)));
+        assert_eq!(parse_command("unsplit"), Command::Unsplit);
+    }
+
+    #[test]
+    fn test_parse_pin_commands() {
+        assert_eq!(parse_command("pin web"), Command::Pin("web".to_string()));
+        assert_eq!(parse_command("unpin web"), Command::Unpin("web".to_string()));
+        assert!(matches!(parse_command("pin"), Command::Unknown(_)));
+        assert_eq!(parse_command("cmd web"), Command::Cmd("web".to_string()));
+        assert!(matches!(parse_command("cmd"), Command::Unknown(_)));
+        assert!(matches!(parse_command("unpin"), Command::Unknown(_)));
+    }
+
+    #[test]
+    fn test_builtin_aliases_resolve_to_the_same_command() {
+        assert_eq!(parse_command("r web"), parse_command("restart web"));
+        assert_eq!(parse_command("exit"), Command::Quit);
+        assert_eq!(parse_command("colors"), Command::ColorToggle);
+        assert_eq!(parse_command("condense"), Command::CondenseToggle);
+        assert_eq!(parse_command("g +5m"), parse_command("goto +5m"));
+        assert_eq!(parse_command("s web"), parse_command("start web"));
+        assert_eq!(parse_command("start"), Command::StartLastKilled);
+        assert_eq!(parse_command("k web"), parse_command("kill web"));
+        assert_eq!(parse_command("f timeout"), parse_command("filter timeout"));
+    }
+
+    #[test]
+    fn test_user_alias_resolves_to_underlying_command() {
+        let aliases = HashMap::from([
+            ("reboot".to_string(), "restart".to_string()),
+            ("rw".to_string(), "reboot web".to_string()),
+        ]);
+        assert_eq!(parse_command_with_aliases("reboot worker", &aliases), Command::Restart(Some("worker".to_string())));
+        assert_eq!(parse_command_with_aliases("rw", &aliases), Command::Restart(Some("web".to_string())));
+        assert_eq!(parse_command_with_aliases("r", &aliases), Command::Restart(None));
+    }
+
+    #[test]
+    fn test_validate_aliases_rejects_shadowing_loops_and_unknown_targets() {
+        let ok = HashMap::from([("reboot".to_string(), "restart".to_string())]);
+        assert!(validate_aliases(&ok).is_ok());
+
+        let shadow = HashMap::from([("restart".to_string(), "quit".to_string())]);
+        assert_eq!(validate_aliases(&shadow).unwrap_err(), "alias 'restart' shadows a built-in command");
+
+        let looping = HashMap::from([
+            ("a".to_string(), "b".to_string()),
+            ("b".to_string(), "a web".to_string()),
+        ]);
+        assert_eq!(validate_aliases(&looping).unwrap_err(), "Alias loop: a -> b -> a");
+
+        let unknown = HashMap::from([("boom".to_string(), "explode".to_string())]);
+        assert_eq!(validate_aliases(&unknown).unwrap_err(), "alias 'boom' points to unknown command 'explode'");
+    }
+
+    #[test]
+    fn test_parse_filter_toggle_command() {
+        assert_eq!(parse_command("ft 2"), Command::FilterToggle(2));
+        assert!(matches!(parse_command("ft"), Command::Unknown(msg) if msg.starts_with("Usage: :ft <n>")));
+    }
+
+    #[test]
+    fn test_parse_tailfile_command() {
+        assert_eq!(
+            parse_command("tailfile jobs  tmp/new jobs.log"),
+            Command::TailFile { name: "jobs".to_string(), path: "tmp/new jobs.log".to_string() }
+        );
+        assert!(matches!(parse_command("tailfile jobs"), Command::Unknown(msg) if msg == "Usage: :tailfile <name> <path>"));
+    }
+
+    #[test]
+    fn test_parse_recent_command() {
+        assert_eq!(parse_command("recent 30"), Command::Recent(30));
+        assert!(matches!(parse_command("recent"), Command::Unknown(msg) if msg == "Usage: :recent <secs>"));
+        assert!(matches!(parse_command("recent soon"), Command::Unknown(_)));
+    }
+
+    #[test]
+    fn test_parse_pause_and_resume_commands() {
+        assert_eq!(parse_command("pause web"), Command::PauseIngest("web".to_string()));
+        assert_eq!(parse_command("resume web"), Command::ResumeIngest("web".to_string()));
+        assert!(matches!(parse_command("pause"), Command::Unknown(msg) if msg.contains("Usage")));
+        assert!(matches!(parse_command("resume"), Command::Unknown(msg) if msg.contains("Usage")));
+    }
+
+    #[test]
+    fn test_parse_mute_keeps_pattern_verbatim() {
+        assert_eq!(
+            parse_command("mute worker until Listening on  port"),
+            Command::Mute { process: "worker".to_string(), pattern: "Listening on  port".to_string() }
+        );
+        assert_eq!(parse_command("unmute worker"), Command::Unmute("worker".to_string()));
+        assert!(matches!(parse_command("mute worker ready"), Command::Unknown(msg) if msg.contains("Usage")));
+        assert!(matches!(parse_command("mute worker until "), Command::Unknown(msg) if msg.contains("Usage")));
+    }
+
+    #[test]
+    fn test_parse_proclist_command() {
+        assert_eq!(parse_command("proclist"), Command::ProcessListPosition(None));
+        assert_eq!(
+            parse_command("proclist bottom"),
+            Command::ProcessListPosition(Some(ProcessListPosition::Bottom))
+        );
+        assert_eq!(
+            parse_command("proclist hidden"),
+            Command::ProcessListPosition(Some(ProcessListPosition::Hidden))
+        );
+        assert!(matches!(parse_command("proclist left"), Command::Unknown(_)));
+    }
+
+    #[tokio::test]
+    async fn test_split_validates_processes_and_unsplit_restores() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "echo web".to_string(), None, None, None);
+        manager.add_process("worker".to_string(), "echo worker".to_string(), None, None, None);
+        let mut config = confirm_config(false);
+
+        let mut executor = CommandExecutor::new(&mut app, &mut manager, &mut config);
+        executor.execute(parse_command("split web nope")).await.unwrap();
+        assert!(executor.app.display.split_processes.is_none());
+
+        executor.execute(parse_command("split web worker")).await.unwrap();
+        assert_eq!(
+            executor.app.display.split_processes,
+            Some(("web".to_string(), "worker".to_string()))
+        );
+
+        executor.execute(parse_command("unsplit")).await.unwrap();
+        assert!(app.display.split_processes.is_none());
+    }
+
+    #[test]
+    fn test_parse_errors_command() {
+        assert!(matches!(parse_command("errors"), Command::ErrorsOnly));
+        assert!(matches!(parse_command("quiet"), Command::ToggleQuietStartup));
+    }
+
     #[test]
     fn test_parse_bw_command_valid_values() {
         // Test valid batch window values
@@ -594,6 +1270,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_preset_commands() {
+        assert_eq!(parse_command("preset"), Command::ListPresets);
+        assert_eq!(parse_command("preset http_errors"), Command::ApplyPreset("http_errors".to_string()));
+        assert_eq!(parse_command("preset save db_slow"), Command::SavePreset("db_slow".to_string()));
+        assert!(matches!(parse_command("preset save"), Command::Unknown(_)));
+    }
+
+    #[test]
+    fn test_parse_copyall_command() {
+        assert_eq!(parse_command("copyall"), Command::CopyAll);
+    }
+
+    #[test]
+    fn test_parse_buffer_command() {
+        assert!(matches!(parse_command("buffer"), Command::ShowBufferLimit));
+        assert!(matches!(parse_command("buffer 200"), Command::SetBufferLimit(200)));
+        assert!(matches!(parse_command("buffer 0"), Command::Unknown(_)));
+        assert!(matches!(parse_command("buffer lots"), Command::Unknown(_)));
+    }
+
+    #[test]
+    fn test_parse_bs_command() {
+        assert!(matches!(parse_command("bs"), Command::ShowBatchStrategy));
+        assert!(matches!(parse_command("bs time_window"), Command::SetBatchStrategy(BatchStrategy::TimeWindow)));
+        assert!(matches!(parse_command("bs hybrid"), Command::SetBatchStrategy(BatchStrategy::Hybrid)));
+        assert!(matches!(parse_command("bs sometimes"), Command::Unknown(_)));
+    }
+
     #[test]
     fn test_parse_bw_command_negative_value() {
         // Test that negative values are rejected
@@ -698,6 +1403,12 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parse_retail_command() {
+        assert_eq!(parse_command("retail app"), Command::Retail("app".to_string()));
+        assert!(matches!(parse_command("retail"), Command::Unknown(msg) if msg.contains("Usage")));
+    }
+
     #[test]
     fn test_parse_only_command_missing_argument() {
         match parse_command("only") {
@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
@@ -14,17 +14,27 @@ pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub batch_window_ms: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub batch_strategy: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub max_log_buffer_mb: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ingest_per_tick: Option<usize>,
     #[serde(default)]
     pub hidden_processes: Vec<String>,
     #[serde(default)]
     pub ignored_processes: Vec<String>,
     #[serde(default)]
     pub start_processes: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pinned_processes: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub disable_auto_update: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub background_update_check: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub compact_mode: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condensed_display: Option<bool>,
     #[serde(default)]
     pub colors: HashMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,14 +43,96 @@ pub struct Config {
     pub context_copy_seconds: Option<f64>,
     #[serde(default)]
     pub groups: HashMap<String, Vec<String>>,
+    #[serde(default)]
+    pub presets: HashMap<String, FilterConfig>,
+    #[serde(default)]
+    pub aliases: HashMap<String, String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub theme: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm_destructive: Option<bool>,
+    /// Make a bare `q` ask before killing running processes (`:q` and signals don't ask)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confirm_quit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub json_logs: Option<JsonLogsConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ansi_mode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<SeverityConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idle_quit_ms: Option<u64>,
+    /// Directory processes run in and log files resolve against, instead of the Procfile's
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub notify_on_failure: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mark_stderr: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tail_preview: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stable_process_layout: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub persist_search_history: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scroll_stick_lines: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_date_dividers: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_processes: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_fps: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub process_list_position: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub startup_banner: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub align_messages: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_last_line: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sticky_header: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub highlight_syntax: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_histogram: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub watch_config: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub show_commands: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncation_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_margin: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_step: Option<u16>,
 
     // This field is not serialized, just used at runtime
     #[serde(skip)]
     pub config_path: Option<PathBuf>,
+
+    /// One group per Procfile namespace when several Procfiles are loaded (runtime only)
+    #[serde(skip)]
+    pub namespace_groups: HashMap<String, Vec<String>>,
 }
 
+/// Gap between consecutive assigned ports when `port_step` isn't set (as in foreman)
+pub const DEFAULT_PORT_STEP: u16 = 100;
+
+/// `timestamp_format` value that shows elapsed time since session start
+pub const RELATIVE_TIMESTAMP_FORMAT: &str = "relative";
+
+/// Valid `ansi_mode` values: keep process colors, or render the stripped text
+pub const ANSI_MODES: &[&str] = &["preserve", "strip"];
+
+/// Valid `notify_on_failure` entries: ring the terminal bell, or post a desktop notification
+pub const FAILURE_NOTIFICATIONS: &[&str] = &["bell", "desktop"];
+
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ProcessConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -49,6 +141,50 @@ pub struct ProcessConfig {
     pub status: Option<StatusConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stdin: Option<String>,
+    /// Shell command run each time the process starts
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_start: Option<String>,
+    /// Shell command run when the process exits, cleanly or not
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_exit: Option<String>,
+    /// Shell command run when the process fails (nonzero exit or a `marks_failed` match)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_failure: Option<String>,
+    /// Readiness pattern: output before the first match is collapsed in the viewer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quiet_until: Option<String>,
+    /// Leading portion removed from each line (e.g. the process's own timestamp)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub strip_prefix: Option<String>,
+    /// Start with everything else (default true); false leaves it Stopped until `:s`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub autostart: Option<bool>,
+    /// Name color when process coloring is on, instead of the next palette color
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    /// A nonzero exit this soon after starting leaves the process Stopped, not Failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub startup_grace_ms: Option<u64>,
+    /// Whether death by a signal inside the grace window still fails (default true)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub startup_grace_crash_fails: Option<bool>,
+}
+
+impl ProcessConfig {
+    /// Compiled `quiet_until` pattern (already checked by `Config::validate`)
+    pub fn quiet_until_regex(&self) -> Option<regex::Regex> {
+        self.quiet_until.as_deref().and_then(|p| regex::Regex::new(p).ok())
+    }
+
+    /// Compiled `strip_prefix` pattern (already checked by `Config::validate`)
+    pub fn strip_prefix_regex(&self) -> Option<regex::Regex> {
+        self.strip_prefix.as_deref().and_then(|p| regex::Regex::new(p).ok())
+    }
+
+    /// `startup_grace_ms` as a duration (None when unset or zero)
+    pub fn startup_grace(&self) -> Option<std::time::Duration> {
+        self.startup_grace_ms.filter(|ms| *ms > 0).map(std::time::Duration::from_millis)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -63,6 +199,9 @@ pub struct StatusConfig {
     pub default: Option<String>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
+    /// Label that means the process is up, for `restart` with `wait_ready`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ready: Option<String>,
     #[serde(default)]
     pub transitions: Vec<StatusTransition>,
 }
@@ -73,6 +212,48 @@ pub struct StatusTransition {
     pub label: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub color: Option<String>,
+    /// Treat a match as a crash: the process is marked Failed even though it is still running
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub marks_failed: bool,
+}
+
+/// `[json_logs]` section: extract message/level fields from JSON log lines
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JsonLogsConfig {
+    #[serde(default = "default_json_logs_enabled")]
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message_field: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level_field: Option<String>,
+}
+
+fn default_json_logs_enabled() -> bool {
+    true
+}
+
+impl JsonLogsConfig {
+    /// Field names to extract, or None if JSON parsing is disabled
+    pub fn fields(&self) -> Option<crate::log::JsonLogFields> {
+        self.enabled.then(|| {
+            crate::log::JsonLogFields::new(self.message_field.as_deref(), self.level_field.as_deref())
+        })
+    }
+}
+
+/// `[severity]` section: custom regexes deciding which lines count as errors/warnings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SeverityConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub error_patterns: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warning_patterns: Vec<String>,
+}
+
+impl SeverityConfig {
+    pub fn matcher(&self) -> Result<crate::log::SeverityMatcher, regex::Error> {
+        crate::log::SeverityMatcher::new(&self.error_patterns, &self.warning_patterns)
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -81,6 +262,25 @@ pub struct FilterConfig {
     pub include: Vec<String>,
     #[serde(default)]
     pub exclude: Vec<String>,
+    /// Patterns from include/exclude that are switched off (`:ft`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub disabled: Vec<String>,
+}
+
+/// Resolve a configured file path: `~` expands to the home directory, absolute
+/// paths pass through, and relative paths are joined to `base_dir` (the Procfile's directory).
+pub fn resolve_path(path: &Path, base_dir: &Path) -> PathBuf {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    resolve_path_with_home(path, base_dir, home.as_deref())
+}
+
+fn resolve_path_with_home(path: &Path, base_dir: &Path, home: Option<&Path>) -> PathBuf {
+    if let Some(home) = home
+        && let Ok(rest) = path.strip_prefix("~")
+    {
+        return if rest.as_os_str().is_empty() { home.to_path_buf() } else { home.join(rest) };
+    }
+    base_dir.join(path)
 }
 
 impl Config {
@@ -90,6 +290,7 @@ impl Config {
         Ok(config)
     }
 
+    #[allow(dead_code)]
     pub fn save(&self, path: &str) -> anyhow::Result<()> {
         let content = toml::to_string_pretty(self)?;
         std::fs::write(path, content)?;
@@ -105,16 +306,97 @@ impl Config {
     pub fn update_filters(&mut self, app_filters: &[crate::ui::Filter]) {
         let mut include_filters = Vec::new();
         let mut exclude_filters = Vec::new();
+        let mut disabled_filters = Vec::new();
 
         for filter in app_filters {
             match filter.filter_type {
                 crate::ui::FilterType::Include => include_filters.push(filter.pattern.clone()),
                 crate::ui::FilterType::Exclude => exclude_filters.push(filter.pattern.clone()),
             }
+            if !filter.enabled {
+                disabled_filters.push(filter.pattern.clone());
+            }
         }
 
         self.filters.include = include_filters;
         self.filters.exclude = exclude_filters;
+        self.filters.disabled = disabled_filters;
+    }
+
+    /// Fixed name colors: the `[colors]` table plus each process's own `color`
+    /// (which wins when both name the same process)
+    pub fn color_overrides(&self) -> HashMap<String, String> {
+        let mut colors = self.colors.clone();
+        for (name, process) in &self.processes {
+            if let Some(color) = &process.color {
+                colors.insert(name.clone(), color.clone());
+            }
+        }
+        colors
+    }
+
+    /// `PORT` for each process when `base_port` is set: processes are indexed
+    /// in name order, so the nth gets `base_port + n * port_step`. Ports past
+    /// 65535 are left out (`validate` rejects configs that produce them).
+    pub fn assigned_ports(&self, process_names: &[String]) -> HashMap<String, u16> {
+        let Some(base) = self.base_port else {
+            return HashMap::new();
+        };
+        let step = u32::from(self.port_step.unwrap_or(DEFAULT_PORT_STEP));
+        let mut names: Vec<&String> = process_names.iter().collect();
+        names.sort();
+        names
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, name)| {
+                let port = u16::try_from(u32::from(base) + index as u32 * step).ok()?;
+                Some((name.clone(), port))
+            })
+            .collect()
+    }
+
+    /// Custom chrono format for precomputed timestamps, if one is configured.
+    /// Returns None for the default and for `relative`, which is rendered by the viewer.
+    pub fn custom_timestamp_format(&self) -> Option<&str> {
+        self.timestamp_format
+            .as_deref()
+            .filter(|f| *f != RELATIVE_TIMESTAMP_FORMAT)
+    }
+
+    /// Whether timestamps should show elapsed time instead of clock time
+    pub fn relative_timestamps(&self) -> bool {
+        self.timestamp_format.as_deref() == Some(RELATIVE_TIMESTAMP_FORMAT)
+    }
+
+    /// Error/warning classifier, using the `[severity]` patterns when configured.
+    /// Patterns are checked by `validate`, so an invalid one falls back to the defaults.
+    pub fn severity_matcher(&self) -> crate::log::SeverityMatcher {
+        self.severity
+            .as_ref()
+            .and_then(|s| s.matcher().ok())
+            .unwrap_or_default()
+    }
+
+    /// Whether process output should be rendered without its ANSI escape codes
+    pub fn strip_ansi(&self) -> bool {
+        self.ansi_mode.as_deref() == Some("strip")
+    }
+
+    /// Non-fatal problems with configured log files (paths resolved against `base_dir`).
+    /// Missing files are tailed once they appear, so these are warnings rather than `validate` errors.
+    pub fn log_file_warnings(&self, base_dir: &Path) -> Vec<String> {
+        let mut names: Vec<&String> = self.processes.keys().collect();
+        names.sort();
+        let process_files = names.into_iter().filter_map(|name| {
+            self.processes[name].log_file.as_ref().map(|path| (name.as_str(), path))
+        });
+        let standalone_files = self.log_files.iter().map(|f| (f.name.as_str(), &f.path));
+
+        process_files
+            .chain(standalone_files)
+            .filter(|(_, path)| !resolve_path(path, base_dir).exists())
+            .map(|(name, path)| format!("Log file for '{}' not found yet: {}", name, path.display()))
+            .collect()
     }
 
     pub fn validate(&self, process_names: &[String]) -> anyhow::Result<()> {
@@ -132,6 +414,95 @@ impl Config {
                     );
                 }
             }
+            if let Some(pattern) = &process_config.quiet_until
+                && let Err(e) = regex::Regex::new(pattern)
+            {
+                anyhow::bail!("Invalid quiet_until pattern for process '{}': {}", process_name, e);
+            }
+            if let Some(pattern) = &process_config.strip_prefix
+                && let Err(e) = regex::Regex::new(pattern)
+            {
+                anyhow::bail!("Invalid strip_prefix pattern for process '{}': {}", process_name, e);
+            }
+        }
+
+        if self.max_processes == Some(0) {
+            anyhow::bail!("max_processes must be at least 1");
+        }
+
+        if self.max_fps == Some(0) {
+            anyhow::bail!("max_fps must be at least 1");
+        }
+
+        if self.base_port.is_some() && self.assigned_ports(process_names).len() < process_names.len() {
+            anyhow::bail!(
+                "base_port {} with port_step {} runs past port 65535 for {} processes",
+                self.base_port.unwrap_or_default(),
+                self.port_step.unwrap_or(DEFAULT_PORT_STEP),
+                process_names.len()
+            );
+        }
+
+        if let Some(format) = &self.timestamp_format
+            && format != RELATIVE_TIMESTAMP_FORMAT
+        {
+            use std::fmt::Write;
+            let mut formatted = String::new();
+            if write!(formatted, "{}", chrono::Local::now().format(format)).is_err() {
+                anyhow::bail!("Invalid timestamp_format '{}'", format);
+            }
+        }
+
+        if let Some(mode) = &self.ansi_mode
+            && !ANSI_MODES.contains(&mode.as_str())
+        {
+            anyhow::bail!("Invalid ansi_mode '{}'. Must be 'preserve' or 'strip'", mode);
+        }
+
+        let mut overrides: Vec<(String, String)> = self.color_overrides().into_iter().collect();
+        overrides.sort();
+        if let Some((name, color)) = overrides.iter().find(|(_, color)| crate::ui::process_colors::parse_color_name(color).is_none()) {
+            anyhow::bail!(
+                "Invalid color '{}' for '{}'. Use a name like blue, light_green or dark_gray",
+                color,
+                name
+            );
+        }
+
+        if let Some(kind) = self.notify_on_failure.iter().find(|k| !FAILURE_NOTIFICATIONS.contains(&k.as_str())) {
+            anyhow::bail!("Invalid notify_on_failure entry '{}'. Must be 'bell' or 'desktop'", kind);
+        }
+
+        if let Some(severity) = &self.severity
+            && let Err(e) = severity.matcher()
+        {
+            anyhow::bail!("Invalid [severity] pattern: {}", e);
+        }
+
+        if let Some(strategy) = &self.batch_strategy
+            && crate::ui::BatchStrategy::parse(strategy).is_none()
+        {
+            anyhow::bail!(
+                "Invalid batch_strategy '{}'. Must be 'time_window' or 'hybrid'",
+                strategy
+            );
+        }
+
+        if let Some(position) = &self.process_list_position
+            && crate::ui::display_state::ProcessListPosition::parse(position).is_none()
+        {
+            anyhow::bail!(
+                "Invalid process_list_position '{}'. Must be 'top', 'bottom' or 'hidden'",
+                position
+            );
+        }
+
+        if let Err(e) = crate::command::validate_aliases(&self.aliases) {
+            anyhow::bail!("Invalid [aliases]: {}", e);
+        }
+
+        if self.idle_quit_ms == Some(0) {
+            anyhow::bail!("idle_quit_ms must be greater than 0 (omit it to disable)");
         }
 
         for log_file in &self.log_files {
@@ -150,6 +521,12 @@ impl Config {
             }
         }
 
+        for name in &self.pinned_processes {
+            if !process_set.contains(name.as_str()) && !log_file_names.contains(name.as_str()) {
+                anyhow::bail!("pinned_processes contains unknown process '{}'", name);
+            }
+        }
+
         for name in &self.start_processes {
             if !process_set.contains(name.as_str()) {
                 anyhow::bail!("start_processes contains unknown process '{}'", name);
@@ -183,8 +560,24 @@ impl Config {
             }
         }
 
+        for namespace in self.namespace_groups.keys() {
+            if self.groups.contains_key(namespace) || log_file_names.contains(namespace.as_str()) {
+                anyhow::bail!(
+                    "Procfile namespace '{}' conflicts with a group or log file name",
+                    namespace
+                );
+            }
+        }
+
         Ok(())
     }
+
+    /// Configured groups plus the Procfile namespace groups
+    pub fn all_groups(&self) -> HashMap<String, Vec<String>> {
+        let mut groups = self.namespace_groups.clone();
+        groups.extend(self.groups.iter().map(|(k, v)| (k.clone(), v.clone())));
+        groups
+    }
 }
 
 #[cfg(test)]
@@ -204,14 +597,52 @@ mod tests {
             hidden_processes: Vec::new(),
             ignored_processes: Vec::new(),
             start_processes: Vec::new(),
+            pinned_processes: Vec::new(),
             disable_auto_update: None,
+            background_update_check: None,
             compact_mode: None,
+            condensed_display: None,
             colors: HashMap::new(),
             process_coloring: None,
             context_copy_seconds: None,
             groups: HashMap::new(),
+            presets: HashMap::new(),
+            aliases: HashMap::new(),
             theme: None,
+            confirm_destructive: None,
+            confirm_quit: None,
+            json_logs: None,
+            timestamp_format: None,
+            max_ingest_per_tick: None,
+            ansi_mode: None,
+            severity: None,
+            idle_quit_ms: None,
+            working_dir: None,
+            notify_on_failure: Vec::new(),
+            mark_stderr: None,
+            tail_preview: None,
+            stable_process_layout: None,
+            persist_search_history: None,
+            scroll_stick_lines: None,
+            batch_strategy: None,
+            show_date_dividers: None,
+            max_processes: None,
+            max_fps: None,
+            startup_banner: None,
+            align_messages: None,
+            show_last_line: None,
+            sticky_header: None,
+            highlight_syntax: None,
+            volume_histogram: None,
+            watch_config: None,
+            show_commands: None,
+            truncation_suffix: None,
+            line_margin: None,
+            base_port: None,
+            port_step: None,
+            process_list_position: None,
             config_path: None,
+            namespace_groups: HashMap::new(),
         }
     }
 
@@ -695,20 +1126,32 @@ log_file = "logs/worker.log"
                 status: Some(StatusConfig {
                     default: Some("Preparing".to_string()),
                     color: None,
+                    ready: None,
                     transitions: vec![
                         StatusTransition {
                             pattern: "webpack compiled".to_string(),
                             label: "Ready".to_string(),
                             color: Some("green".to_string()),
+                            marks_failed: false,
                         },
                         StatusTransition {
                             pattern: "Compiling".to_string(),
                             label: "Building".to_string(),
                             color: None,
+                            marks_failed: false,
                         },
                     ],
                 }),
                 stdin: None,
+                on_start: None,
+                on_exit: None,
+                on_failure: None,
+                quiet_until: None,
+                strip_prefix: None,
+                autostart: None,
+                color: None,
+                startup_grace_ms: None,
+                startup_grace_crash_fails: None,
             },
         );
 
@@ -766,6 +1209,69 @@ transitions = []
         assert!(status.transitions.is_empty());
     }
 
+    #[test]
+    fn test_resolve_path_joins_relative_to_base_dir() {
+        let resolved = resolve_path_with_home(Path::new("log/dev.log"), Path::new("/app"), Some(Path::new("/home/me")));
+        assert_eq!(resolved, PathBuf::from("/app/log/dev.log"));
+    }
+
+    #[test]
+    fn test_resolve_path_keeps_absolute_paths() {
+        let resolved = resolve_path_with_home(Path::new("/var/log/app.log"), Path::new("/app"), Some(Path::new("/home/me")));
+        assert_eq!(resolved, PathBuf::from("/var/log/app.log"));
+    }
+
+    #[test]
+    fn test_resolve_path_expands_tilde() {
+        let home = Some(Path::new("/home/me"));
+        assert_eq!(
+            resolve_path_with_home(Path::new("~/logs/app.log"), Path::new("/app"), home),
+            PathBuf::from("/home/me/logs/app.log")
+        );
+        assert_eq!(resolve_path_with_home(Path::new("~"), Path::new("/app"), home), PathBuf::from("/home/me"));
+        // `~user` isn't expanded, and without a home directory `~` stays a relative path
+        assert_eq!(
+            resolve_path_with_home(Path::new("~bob/app.log"), Path::new("/app"), home),
+            PathBuf::from("/app/~bob/app.log")
+        );
+        assert_eq!(
+            resolve_path_with_home(Path::new("~/app.log"), Path::new("/app"), None),
+            PathBuf::from("/app/~/app.log")
+        );
+    }
+
+    #[test]
+    fn test_log_file_warnings_lists_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("present.log"), "").unwrap();
+
+        let mut processes = HashMap::new();
+        processes.insert(
+            "web".to_string(),
+            ProcessConfig { log_file: Some(PathBuf::from("present.log")), ..ProcessConfig::default() },
+        );
+        processes.insert(
+            "worker".to_string(),
+            ProcessConfig { log_file: Some(PathBuf::from("logs/worker.log")), ..ProcessConfig::default() },
+        );
+        let config = Config {
+            processes,
+            log_files: vec![LogFileConfig { name: "rails".to_string(), path: PathBuf::from("missing.log") }],
+            ..test_config()
+        };
+
+        let warnings = config.log_file_warnings(dir.path());
+        assert_eq!(
+            warnings,
+            vec![
+                "Log file for 'worker' not found yet: logs/worker.log".to_string(),
+                "Log file for 'rails' not found yet: missing.log".to_string(),
+            ]
+        );
+        // Missing log files don't fail validation
+        assert!(config.validate(&["web".to_string(), "worker".to_string()]).is_ok());
+    }
+
     #[test]
     fn test_log_files_loads_from_config() {
         let mut temp_file = NamedTempFile::new().unwrap();
@@ -1189,6 +1695,101 @@ procfile = "Procfile"
         assert!(result.unwrap_err().to_string().contains("start_processes contains unknown process"));
     }
 
+    #[test]
+    fn test_validate_fails_with_unknown_pinned_process() {
+        let config = Config {
+            pinned_processes: vec!["web".to_string(), "nope".to_string()],
+            ..test_config()
+        };
+
+        let result = config.validate(&["web".to_string()]);
+        assert!(result.unwrap_err().to_string().contains("pinned_processes contains unknown process 'nope'"));
+    }
+
+    #[test]
+    fn test_aliases_load_from_config() {
+        let config: Config = toml::from_str("procfile = \"Procfile\"\n[aliases]\nreboot = \"restart\"\n").unwrap();
+        assert_eq!(config.aliases.get("reboot"), Some(&"restart".to_string()));
+        assert!(config.validate(&[]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_alias_loop() {
+        let config: Config = toml::from_str(
+            "procfile = \"Procfile\"\n[aliases]\nfoo = \"bar\"\nbar = \"foo\"\n",
+        )
+        .unwrap();
+        let err = config.validate(&[]).unwrap_err().to_string();
+        assert!(err.contains("Invalid [aliases]: Alias loop: bar -> foo -> bar"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_strip_prefix() {
+        let config: Config = toml::from_str(
+            "procfile = \"Procfile\"\n[processes.web]\nstrip_prefix = \"^[unclosed\"\n",
+        )
+        .unwrap();
+        let err = config.validate(&["web".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("Invalid strip_prefix pattern for process 'web'"));
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_max_fps() {
+        let config = Config { max_fps: Some(0), ..test_config() };
+        let err = config.validate(&["web".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("max_fps"));
+
+        let config = Config { max_fps: Some(60), ..test_config() };
+        assert!(config.validate(&["web".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn test_assigned_ports_follow_name_order() {
+        let names = vec!["worker".to_string(), "web".to_string(), "clock".to_string()];
+        assert!(test_config().assigned_ports(&names).is_empty());
+
+        let config = Config { base_port: Some(5000), ..test_config() };
+        let ports = config.assigned_ports(&names);
+        assert_eq!(ports["clock"], 5000);
+        assert_eq!(ports["web"], 5100);
+        assert_eq!(ports["worker"], 5200);
+
+        let config = Config { base_port: Some(3000), port_step: Some(1), ..test_config() };
+        let ports = config.assigned_ports(&names);
+        assert_eq!((ports["clock"], ports["web"], ports["worker"]), (3000, 3001, 3002));
+    }
+
+    #[test]
+    fn test_validate_rejects_ports_past_65535() {
+        let names = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let config = Config { base_port: Some(65400), ..test_config() };
+        let err = config.validate(&names).unwrap_err();
+        assert!(err.to_string().contains("runs past port 65535"), "{}", err);
+
+        let config = Config { base_port: Some(65400), port_step: Some(10), ..test_config() };
+        assert!(config.validate(&names).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_process_list_position() {
+        let config = Config { process_list_position: Some("left".to_string()), ..test_config() };
+        let err = config.validate(&["web".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("Invalid process_list_position 'left'"));
+
+        let config = Config { process_list_position: Some("bottom".to_string()), ..test_config() };
+        assert!(config.validate(&["web".to_string()]).is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_zero_max_processes() {
+        let config = Config { max_processes: Some(0), ..test_config() };
+        let err = config.validate(&["web".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("max_processes"));
+
+        let config = Config { max_processes: Some(10), ..test_config() };
+        assert!(config.validate(&["web".to_string()]).is_ok());
+    }
+
     #[test]
     fn test_groups_loads_from_config() {
         let mut temp_file = NamedTempFile::new().unwrap();
@@ -1259,6 +1860,45 @@ procfile = "Procfile"
         assert!(config.validate(&process_names).is_ok());
     }
 
+    #[test]
+    fn test_validate_accepts_namespaced_processes_and_groups() {
+        let mut groups = HashMap::new();
+        groups.insert("web".to_string(), vec!["frontend/web".to_string(), "backend/web".to_string()]);
+        let mut namespace_groups = HashMap::new();
+        namespace_groups.insert("frontend".to_string(), vec!["frontend/web".to_string()]);
+        namespace_groups.insert("backend".to_string(), vec!["backend/web".to_string()]);
+
+        let config = Config {
+            groups,
+            namespace_groups,
+            start_processes: vec!["backend/web".to_string()],
+            ..test_config()
+        };
+
+        let process_names = vec!["frontend/web".to_string(), "backend/web".to_string()];
+        assert!(config.validate(&process_names).is_ok());
+        let all = config.all_groups();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all["backend"], vec!["backend/web"]);
+    }
+
+    #[test]
+    fn test_validate_fails_when_group_shadows_namespace() {
+        let mut groups = HashMap::new();
+        groups.insert("backend".to_string(), vec!["backend/web".to_string()]);
+        let mut namespace_groups = HashMap::new();
+        namespace_groups.insert("backend".to_string(), vec!["backend/web".to_string()]);
+
+        let config = Config {
+            groups,
+            namespace_groups,
+            ..test_config()
+        };
+
+        let err = config.validate(&["backend/web".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("Procfile namespace 'backend' conflicts"));
+    }
+
     #[test]
     fn test_validate_fails_when_group_name_conflicts_with_process() {
         let mut groups = HashMap::new();
@@ -1376,6 +2016,15 @@ log_file = "web.log"
                 log_file: Some(PathBuf::from("web.log")),
                 status: None,
                 stdin: None,
+                on_start: None,
+                on_exit: None,
+                on_failure: None,
+                quiet_until: None,
+                strip_prefix: None,
+                autostart: None,
+                color: None,
+                startup_grace_ms: None,
+                startup_grace_crash_fails: None,
             },
         );
 
@@ -1400,6 +2049,15 @@ log_file = "web.log"
                 log_file: None,
                 status: None,
                 stdin: Some("open".to_string()),
+                on_start: None,
+                on_exit: None,
+                on_failure: None,
+                quiet_until: None,
+                strip_prefix: None,
+                autostart: None,
+                color: None,
+                startup_grace_ms: None,
+                startup_grace_crash_fails: None,
             },
         );
 
@@ -1424,6 +2082,15 @@ log_file = "web.log"
                 log_file: None,
                 status: None,
                 stdin: Some("open".to_string()),
+                on_start: None,
+                on_exit: None,
+                on_failure: None,
+                quiet_until: None,
+                strip_prefix: None,
+                autostart: None,
+                color: None,
+                startup_grace_ms: None,
+                startup_grace_crash_fails: None,
             },
         );
 
@@ -1448,6 +2115,15 @@ log_file = "web.log"
                 log_file: None,
                 status: None,
                 stdin: Some("open".to_string()),
+                on_start: None,
+                on_exit: None,
+                on_failure: None,
+                quiet_until: None,
+                strip_prefix: None,
+                autostart: None,
+                color: None,
+                startup_grace_ms: None,
+                startup_grace_crash_fails: None,
             },
         );
         processes.insert(
@@ -1456,6 +2132,15 @@ log_file = "web.log"
                 log_file: None,
                 status: None,
                 stdin: Some("close".to_string()),
+                on_start: None,
+                on_exit: None,
+                on_failure: None,
+                quiet_until: None,
+                strip_prefix: None,
+                autostart: None,
+                color: None,
+                startup_grace_ms: None,
+                startup_grace_crash_fails: None,
             },
         );
 
@@ -1477,6 +2162,15 @@ log_file = "web.log"
                 log_file: None,
                 status: None,
                 stdin: Some("invalid".to_string()),
+                on_start: None,
+                on_exit: None,
+                on_failure: None,
+                quiet_until: None,
+                strip_prefix: None,
+                autostart: None,
+                color: None,
+                startup_grace_ms: None,
+                startup_grace_crash_fails: None,
             },
         );
 
@@ -1490,4 +2184,167 @@ log_file = "web.log"
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Invalid stdin value"));
     }
+
+    #[test]
+    fn test_json_logs_loads_from_toml() {
+        let toml_str = r#"
+procfile = "Procfile"
+
+[json_logs]
+message_field = "text"
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        let json_logs = config.json_logs.unwrap();
+        assert!(json_logs.enabled);
+        let fields = json_logs.fields().unwrap();
+        assert_eq!(fields.message_fields, vec!["text".to_string()]);
+        assert_eq!(fields.level_fields, vec!["level".to_string(), "severity".to_string()]);
+    }
+
+    #[test]
+    fn test_json_logs_disabled_has_no_fields() {
+        let toml_str = r#"
+procfile = "Procfile"
+
+[json_logs]
+enabled = false
+"#;
+        let config: Config = toml::from_str(toml_str).unwrap();
+        assert!(config.json_logs.unwrap().fields().is_none());
+    }
+
+    #[test]
+    fn test_json_logs_none_not_serialized() {
+        let serialized = toml::to_string(&test_config()).unwrap();
+        assert!(!serialized.contains("json_logs"));
+    }
+
+    #[test]
+    fn test_validate_accepts_custom_and_relative_timestamp_formats() {
+        let names = vec!["web".to_string()];
+        for format in ["%Y-%m-%d %H:%M:%S%.3f", "relative", ""] {
+            let config = Config {
+                timestamp_format: Some(format.to_string()),
+                ..test_config()
+            };
+            assert!(config.validate(&names).is_ok(), "format {:?} should be valid", format);
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_timestamp_format() {
+        let config = Config {
+            timestamp_format: Some("%Q".to_string()),
+            ..test_config()
+        };
+        let err = config.validate(&["web".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("Invalid timestamp_format"));
+    }
+
+    #[test]
+    fn test_validate_idle_quit_ms() {
+        let names = vec!["web".to_string()];
+        let config = Config { idle_quit_ms: Some(5000), ..test_config() };
+        assert!(config.validate(&names).is_ok());
+
+        let config = Config { idle_quit_ms: Some(0), ..test_config() };
+        assert!(config.validate(&names).unwrap_err().to_string().contains("idle_quit_ms"));
+    }
+
+    #[test]
+    fn test_validate_ansi_mode() {
+        let names = vec!["web".to_string()];
+        for mode in ["preserve", "strip"] {
+            let config = Config {
+                ansi_mode: Some(mode.to_string()),
+                ..test_config()
+            };
+            assert!(config.validate(&names).is_ok());
+            assert_eq!(config.strip_ansi(), mode == "strip");
+        }
+
+        let config = Config {
+            ansi_mode: Some("rainbow".to_string()),
+            ..test_config()
+        };
+        assert!(config.validate(&names).unwrap_err().to_string().contains("Invalid ansi_mode"));
+        assert!(!test_config().strip_ansi());
+    }
+
+    #[test]
+    fn test_per_process_color_overrides_colors_table() {
+        let config: Config = toml::from_str(
+            r#"
+procfile = "Procfile"
+
+[colors]
+web = "green"
+db = "red"
+
+[processes.db]
+color = "blue"
+"#,
+        )
+        .unwrap();
+        let overrides = config.color_overrides();
+        assert_eq!(overrides["db"], "blue");
+        assert_eq!(overrides["web"], "green");
+        assert!(config.validate(&["db".to_string(), "web".to_string()]).is_ok());
+
+        let mut config = config;
+        config.processes.get_mut("db").unwrap().color = Some("blurple".to_string());
+        let err = config.validate(&["db".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("Invalid color 'blurple' for 'db'"), "{}", err);
+    }
+
+    #[test]
+    fn test_validate_notify_on_failure() {
+        let names = vec!["web".to_string()];
+        let config: Config = toml::from_str("procfile = \"Procfile\"\nnotify_on_failure = [\"bell\", \"desktop\"]").unwrap();
+        assert_eq!(config.notify_on_failure, vec!["bell", "desktop"]);
+        assert!(config.validate(&names).is_ok());
+
+        let config = Config { notify_on_failure: vec!["email".to_string()], ..test_config() };
+        assert!(config.validate(&names).unwrap_err().to_string().contains("Invalid notify_on_failure entry 'email'"));
+    }
+
+    #[test]
+    fn test_severity_config_parses_and_validates() {
+        let severity: SeverityConfig = toml::from_str(r#"error_patterns = ["\\bfatal\\b"]"#).unwrap();
+        let config = Config {
+            severity: Some(severity),
+            ..test_config()
+        };
+        let names = vec!["web".to_string()];
+        assert!(config.validate(&names).is_ok());
+        let matcher = config.severity_matcher();
+        assert_eq!(matcher.detect("FATAL: cannot bind"), Some("error"));
+        assert_eq!(matcher.detect("job failed"), None);
+
+        let invalid = Config {
+            severity: Some(SeverityConfig {
+                error_patterns: vec!["(unclosed".to_string()],
+                warning_patterns: vec![],
+            }),
+            ..test_config()
+        };
+        assert!(invalid.validate(&names).unwrap_err().to_string().contains("Invalid [severity] pattern"));
+    }
+
+    #[test]
+    fn test_timestamp_format_helpers() {
+        let relative = Config {
+            timestamp_format: Some("relative".to_string()),
+            ..test_config()
+        };
+        assert!(relative.relative_timestamps());
+        assert_eq!(relative.custom_timestamp_format(), None);
+
+        let custom = Config {
+            timestamp_format: Some("%H:%M".to_string()),
+            ..test_config()
+        };
+        assert!(!custom.relative_timestamps());
+        assert_eq!(custom.custom_timestamp_format(), Some("%H:%M"));
+    }
 }
@@ -0,0 +1,98 @@
+use notify::{Config as NotifyConfig, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use std::path::Path;
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// Quiet period after the last write before reloading, so an editor's
+/// save (often truncate + write, or write + rename) is applied once
+pub const RELOAD_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Watches the config file for `watch_config = true` and says when to reload
+pub struct ConfigWatcher {
+    // Dropping the watcher stops the events
+    _watcher: RecommendedWatcher,
+    events: mpsc::Receiver<()>,
+    debounce: Debounce,
+}
+
+impl ConfigWatcher {
+    /// Watch the file's directory rather than the file, so editors that save
+    /// by replacing it don't end the watch
+    pub fn new(path: &Path) -> notify::Result<Self> {
+        let (tx, events) = mpsc::channel();
+        let file_name = path.file_name().map(|name| name.to_os_string());
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => Path::new(".").to_path_buf(),
+        };
+
+        let mut watcher = RecommendedWatcher::new(
+            move |res: notify::Result<notify::Event>| {
+                if let Ok(event) = res {
+                    let is_relevant = matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_));
+                    let ours = event.paths.iter().any(|p| p.file_name() == file_name.as_deref());
+                    if is_relevant && ours {
+                        let _ = tx.send(());
+                    }
+                }
+            },
+            NotifyConfig::default(),
+        )?;
+        watcher.watch(&dir, RecursiveMode::NonRecursive)?;
+        Ok(Self { _watcher: watcher, events, debounce: Debounce::new(RELOAD_DEBOUNCE) })
+    }
+
+    /// Poll once per main-loop tick. True when the file changed and has since
+    /// been quiet for the debounce period.
+    pub fn poll(&mut self, now: Instant) -> bool {
+        while self.events.try_recv().is_ok() {
+            self.debounce.changed(now);
+        }
+        self.debounce.due(now)
+    }
+}
+
+/// Fires once, `delay` after the last of a burst of changes
+struct Debounce {
+    delay: Duration,
+    last_change: Option<Instant>,
+}
+
+impl Debounce {
+    fn new(delay: Duration) -> Self {
+        Self { delay, last_change: None }
+    }
+
+    fn changed(&mut self, now: Instant) {
+        self.last_change = Some(now);
+    }
+
+    fn due(&mut self, now: Instant) -> bool {
+        match self.last_change {
+            Some(at) if now.duration_since(at) >= self.delay => {
+                self.last_change = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_debounce_waits_for_writes_to_settle() {
+        let start = Instant::now();
+        let mut debounce = Debounce::new(Duration::from_millis(300));
+        assert!(!debounce.due(start));
+
+        debounce.changed(start);
+        debounce.changed(start + Duration::from_millis(200));
+        assert!(!debounce.due(start + Duration::from_millis(400)));
+        assert!(debounce.due(start + Duration::from_millis(500)));
+        // Fires once per burst
+        assert!(!debounce.due(start + Duration::from_millis(900)));
+    }
+}
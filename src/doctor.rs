@@ -0,0 +1,326 @@
+//! `oit doctor`: load the config and Procfile and check them without starting anything
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::config::{self, Config};
+use crate::procfile::{self, ProcfileEntry, ProcfileSource};
+
+/// Outcome of one doctor check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Pass,
+    /// Worth knowing but not an error (e.g. a log file that appears once the process starts)
+    Warn,
+    Fail,
+}
+
+/// One line of the doctor checklist
+#[derive(Debug, Clone, PartialEq)]
+pub struct Check {
+    pub status: CheckStatus,
+    pub message: String,
+}
+
+impl Check {
+    fn pass(message: impl Into<String>) -> Self {
+        Self { status: CheckStatus::Pass, message: message.into() }
+    }
+
+    fn warn(message: impl Into<String>) -> Self {
+        Self { status: CheckStatus::Warn, message: message.into() }
+    }
+
+    fn fail(message: impl Into<String>) -> Self {
+        Self { status: CheckStatus::Fail, message: message.into() }
+    }
+}
+
+/// Shell builtins and keywords a Procfile command may start with; they never resolve on PATH
+const SHELL_BUILTINS: &[&str] = &[
+    "cd", "export", "source", ".", "set", "ulimit", "umask", "trap", "eval", "echo", "printf",
+    "test", "[", "true", "false", ":", "(", "{", "if", "for", "while", "until", "case",
+];
+
+/// Run every check. Loading stops early only when the config or Procfile can't be read.
+pub fn run_checks(config_path: &str, procfile_overrides: &[String]) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    let mut config = match Config::from_file(config_path) {
+        Ok(config) => {
+            checks.push(Check::pass(format!("Config {} loads", config_path)));
+            config
+        }
+        Err(e) => {
+            checks.push(Check::fail(format!("Config {} does not load: {}", config_path, e)));
+            return checks;
+        }
+    };
+
+    checks.extend(pattern_checks(&config));
+
+    let procfile_paths: Vec<PathBuf> = if procfile_overrides.is_empty() {
+        vec![config.procfile.clone()]
+    } else {
+        procfile_overrides.iter().map(PathBuf::from).collect()
+    };
+    let loaded = ProcfileSource::for_paths(&procfile_paths).and_then(|mut sources| {
+        if let Some(dir) = &config.working_dir {
+            procfile::override_working_dir(&mut sources, dir)?;
+        }
+        procfile::load_entries(&sources).map(|entries| (sources, entries))
+    });
+    let (sources, entries) = match loaded {
+        Ok(loaded) => loaded,
+        Err(e) => {
+            checks.push(Check::fail(format!("Procfile does not load: {}", e)));
+            return checks;
+        }
+    };
+    checks.push(Check::pass(format!("Procfile loads ({} processes)", entries.len())));
+
+    config.namespace_groups = procfile::namespace_groups(&entries);
+    let mut process_names: Vec<String> = entries.keys().cloned().collect();
+    process_names.sort();
+    // Patterns were already checked one by one; validate everything else
+    let mut structural = config.clone();
+    structural.severity = None;
+    for process in structural.processes.values_mut() {
+        process.quiet_until = None;
+        process.strip_prefix = None;
+    }
+    match structural.validate(&process_names) {
+        Ok(()) => checks.push(Check::pass("Config is valid for the Procfile")),
+        Err(e) => checks.push(Check::fail(e.to_string())),
+    }
+
+    let path_var = std::env::var_os("PATH");
+    for name in &process_names {
+        checks.push(command_check(name, &entries[name], path_var.as_deref()));
+    }
+
+    checks.extend(log_file_checks(&config, &entries, &sources[0].dir));
+    checks
+}
+
+/// Every regex in the config compiles (status transitions, quiet_until, strip_prefix, severity)
+fn pattern_checks(config: &Config) -> Vec<Check> {
+    let mut names: Vec<&String> = config.processes.keys().collect();
+    names.sort();
+
+    let mut patterns: Vec<(String, &str)> = Vec::new();
+    for name in names {
+        let process = &config.processes[name];
+        if let Some(status) = &process.status {
+            for transition in &status.transitions {
+                patterns.push((format!("{}: status pattern", name), &transition.pattern));
+            }
+        }
+        if let Some(pattern) = &process.quiet_until {
+            patterns.push((format!("{}: quiet_until", name), pattern));
+        }
+        if let Some(pattern) = &process.strip_prefix {
+            patterns.push((format!("{}: strip_prefix", name), pattern));
+        }
+    }
+
+    let mut checks: Vec<Check> = patterns
+        .into_iter()
+        .map(|(label, pattern)| match regex::Regex::new(pattern) {
+            Ok(_) => Check::pass(format!("{} '{}' compiles", label, pattern)),
+            Err(e) => Check::fail(format!("{} '{}' does not compile: {}", label, pattern, regex_message(&e.to_string()))),
+        })
+        .collect();
+
+    if let Some(severity) = &config.severity {
+        checks.push(match severity.matcher() {
+            Ok(_) => Check::pass("[severity] patterns compile"),
+            Err(e) => Check::fail(format!("[severity] pattern does not compile: {}", regex_message(&e.to_string()))),
+        });
+    }
+    checks
+}
+
+/// The program a process command runs resolves to an executable
+fn command_check(name: &str, entry: &ProcfileEntry, path_var: Option<&std::ffi::OsStr>) -> Check {
+    let Some(program) = checked_program(&entry.command) else {
+        return Check::pass(format!("{}: command starts with a shell construct (not checked)", name));
+    };
+
+    let found = if program.contains('/') {
+        is_executable(&entry.dir.join(program))
+    } else {
+        path_var.is_some_and(|paths| std::env::split_paths(paths).any(|dir| is_executable(&dir.join(program))))
+    };
+
+    if found {
+        Check::pass(format!("{}: `{}` found", name, program))
+    } else if program.contains('/') {
+        Check::fail(format!("{}: `{}` does not exist or is not executable", name, program))
+    } else {
+        Check::fail(format!("{}: `{}` not found on PATH", name, program))
+    }
+}
+
+/// The program a shell command runs (see `process::command_program`). None when it
+/// starts with a builtin or something the shell expands.
+fn checked_program(command: &str) -> Option<&str> {
+    let program = crate::process::command_program(command)?;
+    let shell_expanded = program.contains(['$', '`', '~', '*']);
+    if SHELL_BUILTINS.contains(&program) || shell_expanded {
+        None
+    } else {
+        Some(program)
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    path.metadata().is_ok_and(|meta| meta.is_file() && meta.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+}
+
+/// Configured log files can be opened. Missing ones are warnings: they are tailed once
+/// they appear, like at startup.
+fn log_file_checks(config: &Config, entries: &HashMap<String, ProcfileEntry>, procfile_dir: &Path) -> Vec<Check> {
+    let mut names: Vec<&String> = config.processes.keys().collect();
+    names.sort();
+    let process_files = names.into_iter().filter_map(|name| {
+        let path = config.processes[name].log_file.as_ref()?;
+        let dir = entries.get(name).map_or(procfile_dir, |entry| entry.dir.as_path());
+        Some((name.as_str(), config::resolve_path(path, dir)))
+    });
+    let standalone_files = config
+        .log_files
+        .iter()
+        .map(|file| (file.name.as_str(), config::resolve_path(&file.path, procfile_dir)));
+
+    process_files
+        .chain(standalone_files)
+        .map(|(name, path)| {
+            if !path.exists() {
+                Check::warn(format!("{}: log file {} not found yet", name, path.display()))
+            } else {
+                match std::fs::File::open(&path) {
+                    Ok(_) => Check::pass(format!("{}: log file {} is readable", name, path.display())),
+                    Err(e) => Check::fail(format!("{}: log file {} is not readable: {}", name, path.display(), e)),
+                }
+            }
+        })
+        .collect()
+}
+
+/// Regex errors span several lines (pattern, caret, message); keep the message
+fn regex_message(message: &str) -> &str {
+    let last = message.lines().rev().find(|line| !line.trim().is_empty()).unwrap_or(message).trim();
+    last.strip_prefix("error: ").unwrap_or(last)
+}
+
+/// The printed checklist, ending with a summary line
+pub fn format_report(checks: &[Check]) -> String {
+    let mut report = String::new();
+    for check in checks {
+        let mark = match check.status {
+            CheckStatus::Pass => "✓",
+            CheckStatus::Warn => "!",
+            CheckStatus::Fail => "✗",
+        };
+        report.push_str(&format!("{} {}\n", mark, check.message));
+    }
+
+    let failed = checks.iter().filter(|check| check.status == CheckStatus::Fail).count();
+    if failed == 0 {
+        report.push_str(&format!("\nAll {} checks passed\n", checks.len()));
+    } else {
+        report.push_str(&format!("\n{} of {} checks failed\n", failed, checks.len()));
+    }
+    report
+}
+
+/// Print the checklist. Returns false when any check failed.
+pub fn run_doctor(config_path: &str, procfile_overrides: &[String]) -> bool {
+    let checks = run_checks(config_path, procfile_overrides);
+    print!("{}", format_report(&checks));
+    checks.iter().all(|check| check.status != CheckStatus::Fail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    fn write_project(config: &str, procfile: &str) -> (TempDir, String) {
+        let dir = TempDir::new().unwrap();
+        let procfile_path = dir.path().join("Procfile");
+        std::fs::write(&procfile_path, procfile).unwrap();
+        let config_path = dir.path().join(".overitall.toml");
+        std::fs::write(
+            &config_path,
+            format!("procfile = {:?}\n{}", procfile_path.display().to_string(), config),
+        )
+        .unwrap();
+        let config_path = config_path.display().to_string();
+        (dir, config_path)
+    }
+
+    fn failures(checks: &[Check]) -> Vec<&str> {
+        checks
+            .iter()
+            .filter(|check| check.status == CheckStatus::Fail)
+            .map(|check| check.message.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn healthy_project_passes() {
+        let (_dir, config_path) = write_project("", "web: sh -c 'sleep 1'\nshell: cd . && sh\n");
+        let checks = run_checks(&config_path, &[]);
+
+        assert!(failures(&checks).is_empty(), "{:?}", checks);
+        assert!(checks.iter().any(|c| c.message == "web: `sh` found"));
+        assert!(format_report(&checks).ends_with("checks passed\n"));
+    }
+
+    #[test]
+    fn known_problems_produce_failure_lines() {
+        let config = "\
+[processes.web.status]
+transitions = [{ pattern = \"(unclosed\", label = \"Ready\" }]
+
+[[log_files]]
+name = \"web\"
+path = \"web.log\"
+";
+        let (_dir, config_path) =
+            write_project(config, "web: FOO=1 oit-doctor-missing-binary --serve\nworker: ./bin/missing\n");
+        let checks = run_checks(&config_path, &[]);
+        let failed = failures(&checks);
+
+        assert!(failed.iter().any(|m| m.starts_with("web: status pattern '(unclosed' does not compile")), "{:?}", failed);
+        assert!(failed.contains(&"Log file name 'web' conflicts with a process name"), "{:?}", failed);
+        assert!(failed.contains(&"web: `oit-doctor-missing-binary` not found on PATH"), "{:?}", failed);
+        assert!(failed.contains(&"worker: `./bin/missing` does not exist or is not executable"), "{:?}", failed);
+        assert!(checks.iter().any(|c| c.status == CheckStatus::Warn && c.message.contains("not found yet")));
+        assert!(format_report(&checks).ends_with(&format!("{} of {} checks failed\n", failed.len(), checks.len())));
+    }
+
+    #[test]
+    fn unreadable_config_stops_early() {
+        let checks = run_checks("/nonexistent/.overitall.toml", &[]);
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0].status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn checked_program_skips_assignments_and_wrappers() {
+        assert_eq!(checked_program("RAILS_ENV=dev exec bundle exec puma"), Some("bundle"));
+        assert_eq!(checked_program("env PORT=3000 node server.js"), Some("node"));
+        assert_eq!(checked_program("cd web && npm start"), None);
+        assert_eq!(checked_program("$EDITOR"), None);
+    }
+}
@@ -1,8 +1,10 @@
-use crate::command::{Command, parse_command, CommandExecutor};
+use crate::command::{Command, parse_command_with_aliases, CommandExecutor};
 use crate::config::Config;
-use crate::operations::{batch, batch_window, clipboard, display, manual_trace, navigation, search, traces};
+use crate::operations::{batch, batch_window, clipboard, display, filter, manual_trace, navigation, search, traces};
+use crate::operations::navigation::JumpDirection;
 use crate::process::ProcessManager;
-use crate::ui::App;
+use crate::ui::{App, PaletteInvocation, PendingAction, PendingConfirmation};
+use crate::ui::display_state::HORIZONTAL_SCROLL_STEP;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind, MouseButton};
 use anyhow::Result;
 
@@ -23,6 +25,22 @@ impl<'a> EventHandler<'a> {
 
     pub async fn handle_key_event(&mut self, key: KeyEvent) -> Result<bool> {
         // Returns true if the app should quit, false otherwise
+
+        if self.app.display.status_dismiss_on_key {
+            self.app.display.status_dismiss_on_key = false;
+            self.app.display.status_message = None;
+        }
+
+        // A pending destructive action consumes the next key: `y` (or `q` for
+        // quit) confirms, anything else cancels
+        if !key.modifiers.contains(KeyModifiers::CONTROL)
+            && let Some(pending) = self.app.pending_confirm.take()
+            && !pending.is_expired()
+        {
+            self.handle_confirmation(key, pending.action).await;
+            return Ok(false);
+        }
+
         match key.code {
             // Ctrl-C triggers graceful shutdown
             // In raw mode, Ctrl+C is captured as a keyboard event, not a signal
@@ -32,6 +50,84 @@ impl<'a> EventHandler<'a> {
                 self.manager.set_all_terminating();
                 Ok(false) // Don't quit immediately - let the loop handle killing
             }
+            // Command palette: open with Ctrl+P, then it takes all typing until closed
+            KeyCode::Char('p') if key.modifiers.contains(KeyModifiers::CONTROL)
+                && !self.app.input.command_mode && !self.app.input.search_mode => {
+                if self.app.palette.open {
+                    self.app.palette.close();
+                } else {
+                    self.app.palette.open();
+                }
+                Ok(false)
+            }
+            KeyCode::Char(c) if self.app.palette.open && !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.app.palette.push_char(c);
+                Ok(false)
+            }
+            KeyCode::Backspace if self.app.palette.open => {
+                self.app.palette.pop_char();
+                Ok(false)
+            }
+            KeyCode::Up if self.app.palette.open => {
+                self.app.palette.select_prev();
+                Ok(false)
+            }
+            KeyCode::Down if self.app.palette.open => {
+                self.app.palette.select_next();
+                Ok(false)
+            }
+            KeyCode::Enter if self.app.palette.open => {
+                self.handle_palette_execute().await
+            }
+            // Process picker: arrows select, r/k/s act on the highlighted process
+            KeyCode::Char('m') if !self.app.input.command_mode && !self.app.input.search_mode
+                && !self.app.process_picker.open && !self.app.display.show_help
+                && !self.app.display.expanded_line_view => {
+                self.app.process_picker.open();
+                Ok(false)
+            }
+            KeyCode::Up if self.app.process_picker.open => {
+                self.app.process_picker.select_prev();
+                Ok(false)
+            }
+            KeyCode::Down if self.app.process_picker.open => {
+                let count = self.manager.get_processes().len();
+                self.app.process_picker.select_next(count);
+                Ok(false)
+            }
+            KeyCode::Char(c) if self.app.process_picker.open && !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.handle_process_picker_key(c).await;
+                Ok(false)
+            }
+            // `:lasterror` overlay is modal: q closes it, other keys are swallowed
+            KeyCode::Char(c) if self.app.last_output.is_some() && !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                if c == 'q' {
+                    self.app.last_output = None;
+                }
+                Ok(false)
+            }
+            // Help overlay `/` prompt: typing jumps to the first matching line
+            KeyCode::Char(c) if self.app.display.help_query_editing => {
+                let mut query = self.app.display.help_query.clone();
+                query.push(c);
+                self.app.display.set_help_query(query);
+                Ok(false)
+            }
+            KeyCode::Backspace if self.app.display.help_query_editing => {
+                let mut query = self.app.display.help_query.clone();
+                query.pop();
+                self.app.display.set_help_query(query);
+                Ok(false)
+            }
+            KeyCode::Enter if self.app.display.help_query_editing => {
+                // Keep the keyword highlighted, give the arrows back to scrolling
+                self.app.display.help_query_editing = false;
+                Ok(false)
+            }
+            KeyCode::Char('/') if self.app.display.show_help => {
+                self.app.display.start_help_query();
+                Ok(false)
+            }
             // Help mode
             KeyCode::Char('?') if !self.app.input.command_mode && !self.app.input.search_mode => {
                 self.handle_help_toggle();
@@ -98,10 +194,35 @@ impl<'a> EventHandler<'a> {
                 self.app.input.delete_char();
                 Ok(false)
             }
+            KeyCode::Up if self.app.input.search_mode => {
+                self.app.input.history_prev();
+                Ok(false)
+            }
+            KeyCode::Down if self.app.input.search_mode => {
+                self.app.input.history_next();
+                Ok(false)
+            }
             KeyCode::Char(c) if self.app.input.search_mode => {
                 self.app.input.add_char(c);
                 Ok(false)
             }
+            // Cursor movement while typing a command or search
+            KeyCode::Left if self.app.input.command_mode || self.app.input.search_mode => {
+                self.app.input.cursor_left();
+                Ok(false)
+            }
+            KeyCode::Right if self.app.input.command_mode || self.app.input.search_mode => {
+                self.app.input.cursor_right();
+                Ok(false)
+            }
+            KeyCode::Home if self.app.input.command_mode || self.app.input.search_mode => {
+                self.app.input.cursor_home();
+                Ok(false)
+            }
+            KeyCode::End if self.app.input.command_mode || self.app.input.search_mode => {
+                self.app.input.cursor_end();
+                Ok(false)
+            }
             // Trace selection mode
             KeyCode::Enter if self.app.trace.trace_selection_mode => {
                 traces::select_trace(self.app, self.manager);
@@ -168,6 +289,17 @@ impl<'a> EventHandler<'a> {
                 self.handle_copy_batch();
                 Ok(false)
             }
+            // Shareable line reference (process#id)
+            KeyCode::Char('Y') if !self.app.input.command_mode && !self.app.input.search_mode => {
+                self.handle_copy_reference();
+                Ok(false)
+            }
+            // Open the selected line's OSC 8 hyperlink
+            KeyCode::Char('o') if !self.app.input.command_mode && !self.app.input.search_mode
+                && !self.app.display.show_help => {
+                self.handle_open_link();
+                Ok(false)
+            }
             // Vim-style page navigation (Ctrl+B = page up, Ctrl+F = page down)
             // IMPORTANT: These must come BEFORE plain 'b' handler to match correctly
             KeyCode::Char('b') if key.modifiers.contains(KeyModifiers::CONTROL) && !self.app.input.command_mode && !self.app.input.search_mode => {
@@ -190,6 +322,36 @@ impl<'a> EventHandler<'a> {
                 self.handle_manual_trace_toggle();
                 Ok(false)
             }
+            // Toggle errors-only quick filter
+            KeyCode::Char('e') if !self.app.input.command_mode && !self.app.input.search_mode
+                && !self.app.display.show_help && !self.app.display.expanded_line_view => {
+                self.handle_toggle_errors_only();
+                Ok(false)
+            }
+            // Jump the selection to the next/previous error line
+            KeyCode::Char('E') if !self.app.input.command_mode && !self.app.input.search_mode
+                && !self.app.display.show_help && !self.app.display.expanded_line_view => {
+                self.handle_jump_to_error(JumpDirection::Next);
+                Ok(false)
+            }
+            KeyCode::Char('W') if !self.app.input.command_mode && !self.app.input.search_mode
+                && !self.app.display.show_help && !self.app.display.expanded_line_view => {
+                self.handle_jump_to_error(JumpDirection::Prev);
+                Ok(false)
+            }
+            // Horizontal scrolling of long lines (compact and full modes)
+            KeyCode::Left | KeyCode::Char('h') if !self.app.input.command_mode && !self.app.input.search_mode
+                && !self.app.display.show_help && !self.app.display.expanded_line_view
+                && !self.app.display.is_process_tree() && !self.app.palette.open && !self.app.process_picker.open => {
+                self.app.display.scroll_left(HORIZONTAL_SCROLL_STEP);
+                Ok(false)
+            }
+            KeyCode::Right | KeyCode::Char('l') if !self.app.input.command_mode && !self.app.input.search_mode
+                && !self.app.display.show_help && !self.app.display.expanded_line_view
+                && !self.app.display.is_process_tree() && !self.app.palette.open && !self.app.process_picker.open => {
+                self.app.display.scroll_right(HORIZONTAL_SCROLL_STEP);
+                Ok(false)
+            }
             // Cycle display mode (compact/full/wrap)
             KeyCode::Char('w') if !self.app.input.command_mode && !self.app.input.search_mode
                 && !self.app.display.show_help && !self.app.display.expanded_line_view => {
@@ -295,11 +457,10 @@ impl<'a> EventHandler<'a> {
                 self.manager.set_all_terminating();
                 Ok(false) // Don't quit immediately - let the loop handle killing
             }
-            // Regular 'q' only works when not in command/search mode
+            // Regular 'q' only works when not in command/search mode, and closes
+            // an open overlay or mode before it quits
             KeyCode::Char('q') if !self.app.input.command_mode && !self.app.input.search_mode && !self.app.shutting_down => {
-                self.app.start_shutdown();
-                // Set all processes to Terminating status immediately (UI will show this on next draw)
-                self.manager.set_all_terminating();
+                self.handle_quit_key();
                 Ok(false) // Don't quit immediately - let the loop handle killing
             }
             _ => Ok(false),
@@ -316,9 +477,20 @@ impl<'a> EventHandler<'a> {
         }
     }
 
+    async fn handle_confirmation(&mut self, key: KeyEvent, action: PendingAction) {
+        if matches!(key.code, KeyCode::Char(c) if c.to_ascii_lowercase() == action.confirm_key()) {
+            let mut executor = CommandExecutor::new(self.app, self.manager, self.config);
+            if let Err(e) = executor.execute_confirmed(action).await {
+                self.app.display.set_status_error(format!("Command error: {}", e));
+            }
+        } else {
+            self.app.display.set_status_info(format!("Cancelled {}", action.describe()));
+        }
+    }
+
     async fn handle_command_execute(&mut self) -> Result<bool> {
         let cmd_text = self.app.input.input.clone();
-        let cmd = parse_command(&cmd_text);
+        let cmd = parse_command_with_aliases(&cmd_text, &self.config.aliases);
 
         // Save to history before processing (don't save empty or quit commands)
         if !cmd_text.trim().is_empty() && !matches!(cmd, Command::Quit) {
@@ -340,6 +512,59 @@ impl<'a> EventHandler<'a> {
         Ok(false)
     }
 
+    /// Run the highlighted palette command, or open command mode prefilled
+    /// when it needs an argument
+    async fn handle_palette_execute(&mut self) -> Result<bool> {
+        let invocation = self.app.palette.selected_invocation();
+        self.app.palette.close();
+        let Some(invocation) = invocation else {
+            return Ok(false);
+        };
+        self.app.input.enter_command_mode();
+        self.app.display.status_message = None;
+        match invocation {
+            PaletteInvocation::Run(text) => {
+                self.app.input.set_input(text);
+                self.handle_command_execute().await
+            }
+            PaletteInvocation::Prefill(text) => {
+                self.app.input.set_input(text);
+                Ok(false)
+            }
+        }
+    }
+
+    /// `r`/`k`/`s` restart, kill or start the highlighted process through the
+    /// matching `:` command (so kill still asks for confirmation); `q` closes
+    async fn handle_process_picker_key(&mut self, c: char) {
+        let command = match c {
+            'q' => {
+                self.app.process_picker.close();
+                return;
+            }
+            'r' | 'k' | 's' => {
+                let Some(name) = self
+                    .app
+                    .process_picker
+                    .selected_process(self.manager, &self.app.display.pinned_processes)
+                else {
+                    return;
+                };
+                match c {
+                    'r' => Command::Restart(Some(name)),
+                    'k' => Command::Kill(name),
+                    _ => Command::Start(name),
+                }
+            }
+            _ => return,
+        };
+        self.app.process_picker.close();
+        let mut executor = CommandExecutor::new(self.app, self.manager, self.config);
+        if let Err(e) = executor.execute(command).await {
+            self.app.display.set_status_error(format!("Command error: {}", e));
+        }
+    }
+
     fn handle_search_execute(&mut self) {
         let search_text = self.app.input.input.clone();
         match search::execute_search(self.app, self.manager, &search_text) {
@@ -381,6 +606,20 @@ impl<'a> EventHandler<'a> {
         }
     }
 
+    fn handle_copy_reference(&mut self) {
+        match clipboard::copy_reference(self.app, self.manager) {
+            Ok(msg) => self.app.display.set_status_success(msg),
+            Err(msg) => self.app.display.set_status_error(msg),
+        }
+    }
+
+    fn handle_open_link(&mut self) {
+        match clipboard::open_link(self.app, self.manager) {
+            Ok(msg) => self.app.display.set_status_success(msg),
+            Err(msg) => self.app.display.set_status_error(msg),
+        }
+    }
+
     fn handle_copy_batch(&mut self) {
         match clipboard::copy_batch(self.app, self.manager) {
             Ok(msg) => self.app.display.set_status_success(msg),
@@ -446,6 +685,17 @@ impl<'a> EventHandler<'a> {
         }
     }
 
+    fn handle_toggle_errors_only(&mut self) {
+        let msg = filter::toggle_errors_only(self.app);
+        self.app.display.set_status_info(msg);
+    }
+
+    fn handle_jump_to_error(&mut self, direction: JumpDirection) {
+        if let Err(msg) = navigation::select_error(self.app, self.manager, direction, None) {
+            self.app.display.set_status_error(msg);
+        }
+    }
+
     fn handle_cycle_display_mode(&mut self) {
         let mode = display::cycle_display_mode(self.app, self.config);
         self.app.display.set_status_info(format!("Display mode: {}", mode));
@@ -470,9 +720,48 @@ impl<'a> EventHandler<'a> {
         }
     }
 
+    /// Handle a bare `q`: close the topmost overlay or mode, and only quit when
+    /// none is open. Order: expanded line → help → trace selection → process
+    /// tree → batch view → quit. (Command and search input take `q` as text.)
+    fn handle_quit_key(&mut self) {
+        if self.app.display.expanded_line_view {
+            self.app.display.close_expanded_view();
+        } else if self.app.display.show_help {
+            self.app.display.toggle_help();
+        } else if self.app.trace.trace_selection_mode {
+            self.app.trace.exit_trace_selection();
+            self.app.display.set_status_info("Trace selection cancelled".to_string());
+        } else if self.app.display.is_process_tree() {
+            self.app.display.show_logs();
+            self.app.display.set_status_info("Logs".to_string());
+        } else if self.app.batch.batch_view_mode {
+            self.exit_batch_view();
+        } else if self.config.confirm_quit == Some(true) && self.manager.running_count() > 0 {
+            let running = self.manager.running_count();
+            self.app.display.set_status_info(format!("Quit and kill {} processes? Press q again to confirm", running));
+            self.app.pending_confirm = Some(PendingConfirmation::new(PendingAction::Quit(running)));
+        } else {
+            self.app.start_shutdown();
+            // Set all processes to Terminating status immediately (UI will show this on next draw)
+            self.manager.set_all_terminating();
+        }
+    }
+
+    fn exit_batch_view(&mut self) {
+        self.app.batch.batch_view_mode = false;
+        self.app.batch.current_batch = None;
+        self.app.navigation.discard_snapshot();
+        self.app.input.clear_search();
+        self.app.navigation.scroll_to_bottom();
+        self.app.display.set_status_info("Exited batch view, resumed tailing".to_string());
+    }
+
     /// Handle Esc key - all escape logic in one place for clarity.
     /// Priority order (first match wins):
-    /// 0. Manual trace recording - cancel recording
+    /// 0. Command palette - close it
+    /// 0.25. Process picker - close it
+    /// 0.3. Final output overlay - close it
+    /// 0.5. Manual trace recording - cancel recording
     /// 1. Help overlay - close help
     /// 1.5. Process tree viewer - return to logs
     /// 2. Expanded line view - close modal
@@ -485,14 +774,36 @@ impl<'a> EventHandler<'a> {
     /// 9. Batch view mode - exit batch view
     /// 10. Default - jump to latest logs
     fn handle_escape(&mut self) {
-        // 0. Manual trace recording
+        // 0. Command palette (drawn on top of everything)
+        if self.app.palette.open {
+            self.app.palette.close();
+            return;
+        }
+
+        // 0.25. Process picker
+        if self.app.process_picker.open {
+            self.app.process_picker.close();
+            return;
+        }
+
+        // 0.3. Final output overlay (`:lasterror`)
+        if self.app.last_output.take().is_some() {
+            return;
+        }
+
+        // 0.5. Manual trace recording
         if self.app.trace.manual_trace_recording {
             manual_trace::cancel_recording(self.app);
             self.app.display.set_status_info("Recording cancelled".to_string());
             return;
         }
 
-        // 1. Help overlay
+        // 1. Help overlay (its `/` prompt first)
+        if self.app.display.help_query_editing {
+            self.app.display.help_query_editing = false;
+            self.app.display.help_query.clear();
+            return;
+        }
         if self.app.display.show_help {
             self.app.display.toggle_help();
             return;
@@ -542,7 +853,7 @@ impl<'a> EventHandler<'a> {
             self.app.navigation.unfreeze_display();
             self.app.navigation.discard_snapshot();
             self.app.input.search_mode = true;
-            self.app.input.input = self.app.input.search_pattern.clone();
+            self.app.input.set_input(self.app.input.search_pattern.clone());
             return;
         }
 
@@ -569,12 +880,7 @@ impl<'a> EventHandler<'a> {
 
         // 9. Batch view mode
         if self.app.batch.batch_view_mode {
-            self.app.batch.batch_view_mode = false;
-            self.app.batch.current_batch = None;
-            self.app.navigation.discard_snapshot();
-            self.app.input.clear_search();
-            self.app.navigation.scroll_to_bottom();
-            self.app.display.set_status_info("Exited batch view, resumed tailing".to_string());
+            self.exit_batch_view();
             return;
         }
 
@@ -662,3 +968,163 @@ impl<'a> EventHandler<'a> {
         Ok(false)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_config() -> Config {
+        toml::from_str("procfile = \"Procfile\"").unwrap()
+    }
+
+    async fn press(app: &mut App, code: KeyCode) {
+        let mut manager = ProcessManager::new();
+        let mut config = test_config();
+        let mut handler = EventHandler::new(app, &mut manager, &mut config);
+        handler.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn q_closes_overlays_from_the_top_before_quitting() {
+        let mut app = App::new();
+        app.display.expanded_line_view = true;
+        app.display.show_help = true;
+        app.batch.batch_view_mode = true;
+
+        press(&mut app, KeyCode::Char('q')).await;
+        assert!(!app.display.expanded_line_view);
+        assert!(app.display.show_help && !app.shutting_down);
+
+        press(&mut app, KeyCode::Char('q')).await;
+        assert!(!app.display.show_help);
+        assert!(app.batch.batch_view_mode && !app.shutting_down);
+
+        press(&mut app, KeyCode::Char('q')).await;
+        assert!(!app.batch.batch_view_mode);
+        assert!(!app.shutting_down);
+
+        press(&mut app, KeyCode::Char('q')).await;
+        assert!(app.shutting_down);
+    }
+
+    #[tokio::test]
+    async fn confirm_quit_needs_a_second_q_while_processes_run() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.add_process("worker".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.start_process("web").await.unwrap();
+        manager.start_process("worker").await.unwrap();
+        let mut config: Config = toml::from_str("procfile = \"Procfile\"\nconfirm_quit = true").unwrap();
+        let mut handler = EventHandler::new(&mut app, &mut manager, &mut config);
+        let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::NONE);
+
+        handler.handle_key_event(q).await.unwrap();
+        assert!(!handler.app.shutting_down);
+        assert_eq!(handler.app.pending_confirm.as_ref().map(|p| p.action.clone()), Some(PendingAction::Quit(2)));
+        let status = handler.app.display.status_message.as_ref().map(|(msg, _)| msg.clone());
+        assert_eq!(status.as_deref(), Some("Quit and kill 2 processes? Press q again to confirm"));
+
+        // Any other key cancels
+        handler.handle_key_event(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::NONE)).await.unwrap();
+        assert!(handler.app.pending_confirm.is_none() && !handler.app.shutting_down);
+
+        // A second q confirms
+        handler.handle_key_event(q).await.unwrap();
+        handler.handle_key_event(q).await.unwrap();
+        assert!(handler.app.shutting_down);
+
+        // Nothing running: quits straight away
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        let mut handler = EventHandler::new(&mut app, &mut manager, &mut config);
+        handler.handle_key_event(q).await.unwrap();
+        assert!(handler.app.shutting_down);
+    }
+
+    #[tokio::test]
+    async fn any_key_dismisses_a_transient_status() {
+        let mut app = App::new();
+        app.display.set_status_transient("Loaded 2 processes".to_string());
+
+        press(&mut app, KeyCode::Char('j')).await;
+        assert!(app.display.status_message.is_none());
+
+        app.display.set_status_info("sticky".to_string());
+        press(&mut app, KeyCode::Char('j')).await;
+        assert!(app.display.status_message.is_some());
+    }
+
+    #[tokio::test]
+    async fn q_is_typed_in_command_and_search_input() {
+        let mut app = App::new();
+        app.input.enter_command_mode();
+        press(&mut app, KeyCode::Char('q')).await;
+        assert_eq!(app.input.input, "q");
+        assert!(!app.shutting_down);
+
+        // `:q` still quits
+        press(&mut app, KeyCode::Enter).await;
+        assert!(app.should_quit);
+
+        let mut app = App::new();
+        app.input.enter_search_mode();
+        press(&mut app, KeyCode::Char('q')).await;
+        assert!(app.input.search_mode && !app.shutting_down);
+    }
+
+    #[tokio::test]
+    async fn process_picker_restarts_the_highlighted_process() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        manager.add_process("api".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.add_process("web".to_string(), "sleep 10".to_string(), None, None, None);
+        let mut config = test_config();
+        let mut handler = EventHandler::new(&mut app, &mut manager, &mut config);
+
+        for code in [KeyCode::Char('m'), KeyCode::Down, KeyCode::Char('r')] {
+            handler.handle_key_event(KeyEvent::new(code, KeyModifiers::NONE)).await.unwrap();
+        }
+
+        assert!(!app.process_picker.open);
+        assert_eq!(manager.get_status("web"), Some(crate::process::ProcessStatus::Restarting));
+        assert_ne!(manager.get_status("api"), Some(crate::process::ProcessStatus::Restarting));
+    }
+
+    #[tokio::test]
+    async fn process_picker_closes_with_esc_and_swallows_other_keys() {
+        let mut app = App::new();
+        press(&mut app, KeyCode::Char('m')).await;
+        assert!(app.process_picker.open);
+
+        press(&mut app, KeyCode::Char('e')).await;
+        assert!(app.process_picker.open && !app.filters.errors_only);
+
+        press(&mut app, KeyCode::Esc).await;
+        assert!(!app.process_picker.open);
+    }
+
+    #[tokio::test]
+    async fn last_output_overlay_closes_with_q_or_esc_without_quitting() {
+        let mut app = App::new();
+        app.last_output = Some("worker".to_string());
+        press(&mut app, KeyCode::Char('e')).await;
+        assert!(app.last_output.is_some() && !app.filters.errors_only);
+        press(&mut app, KeyCode::Char('q')).await;
+        assert!(app.last_output.is_none() && !app.should_quit);
+
+        app.last_output = Some("worker".to_string());
+        press(&mut app, KeyCode::Esc).await;
+        assert!(app.last_output.is_none());
+    }
+
+    #[tokio::test]
+    async fn q_leaves_the_process_tree_before_quitting() {
+        let mut app = App::new();
+        app.display.content_view = crate::ui::display_state::ContentView::ProcessTree;
+
+        press(&mut app, KeyCode::Char('q')).await;
+        assert!(!app.display.is_process_tree());
+        assert!(!app.shutting_down);
+    }
+}
@@ -0,0 +1,131 @@
+use std::io::Write;
+
+use serde::Serialize;
+
+use crate::process::{ProcessManager, ProcessStatus};
+
+/// How one process fared, for the summary printed when oit exits
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ProcessSummary {
+    pub name: String,
+    /// running, stopped, terminating, restarting, failed or done
+    pub status: String,
+    /// Failure message, for failed processes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub exit_code: Option<i32>,
+    pub exit_signal: Option<i32>,
+    /// Lines from the process still in the log buffer
+    pub lines: usize,
+}
+
+/// One entry per process, sorted by name, from the manager's final state
+pub fn summarize(manager: &ProcessManager) -> Vec<ProcessSummary> {
+    let line_counts = manager.line_counts();
+    let mut summary: Vec<ProcessSummary> = manager
+        .get_processes()
+        .iter()
+        .map(|(name, handle)| {
+            let (status, error) = match &handle.status {
+                ProcessStatus::Running => ("running", None),
+                ProcessStatus::Stopped => ("stopped", None),
+                ProcessStatus::Terminating => ("terminating", None),
+                ProcessStatus::Restarting => ("restarting", None),
+                ProcessStatus::Failed(msg) => ("failed", Some(msg.clone())),
+                ProcessStatus::Done => ("done", None),
+            };
+            ProcessSummary {
+                name: name.clone(),
+                status: status.to_string(),
+                error,
+                exit_code: handle.exit_code(),
+                exit_signal: handle.exit_signal(),
+                lines: line_counts.get(name.as_str()).copied().unwrap_or(0),
+            }
+        })
+        .collect();
+    summary.sort_by(|a, b| a.name.cmp(&b.name));
+    summary
+}
+
+/// Plain-text table: name, status, exit code (or signal) and line count
+pub fn format_table(summary: &[ProcessSummary]) -> String {
+    let name_width = summary.iter().map(|p| p.name.len()).chain(["PROCESS".len()]).max().unwrap_or(0);
+    let mut out = format!("{:<name_width$}  {:<11}  {:>4}  {:>7}\n", "PROCESS", "STATUS", "EXIT", "LINES");
+    for p in summary {
+        let exit = match (p.exit_code, p.exit_signal) {
+            (Some(code), _) => code.to_string(),
+            (None, Some(signal)) => format!("sig{}", signal),
+            (None, None) => "-".to_string(),
+        };
+        out.push_str(&format!("{:<name_width$}  {:<11}  {:>4}  {:>7}", p.name, p.status, exit, p.lines));
+        if let Some(error) = &p.error {
+            out.push_str(&format!("  {}", error));
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// `{"processes": [...]}`, for `--json`
+pub fn format_json(summary: &[ProcessSummary]) -> String {
+    serde_json::to_string_pretty(&serde_json::json!({ "processes": summary })).unwrap_or_default() + "\n"
+}
+
+/// Write the summary to `path` (replacing it), or to stderr
+pub fn write(summary: &[ProcessSummary], json: bool, path: Option<&str>) -> std::io::Result<()> {
+    let text = if json { format_json(summary) } else { format_table(summary) };
+    match path {
+        Some(path) => std::fs::write(path, text),
+        None => std::io::stderr().write_all(text.as_bytes()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::{LogLine, LogSource};
+
+    fn manager_with_mixed_statuses() -> ProcessManager {
+        let mut manager = ProcessManager::new();
+        for name in ["worker", "web", "db"] {
+            manager.add_process(name.to_string(), format!("run {}", name), None, None, None);
+        }
+        manager.set_process_status_for_testing("web", ProcessStatus::Running);
+        manager.set_process_status_for_testing("worker", ProcessStatus::Failed("Exited with code 1".to_string()));
+        for (process, line) in [("web", "Listening"), ("web", "GET /"), ("worker", "boom")] {
+            manager.add_test_log(LogLine::new(LogSource::ProcessStdout(process.to_string()), line.to_string()));
+        }
+        manager
+    }
+
+    #[test]
+    fn test_summarize_reports_each_process_final_state() {
+        let summary = summarize(&manager_with_mixed_statuses());
+
+        let names: Vec<&str> = summary.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["db", "web", "worker"]);
+        assert_eq!(summary[0].status, "stopped");
+        assert_eq!(summary[0].lines, 0);
+        assert_eq!(summary[1].status, "running");
+        assert_eq!(summary[1].lines, 2);
+        assert_eq!(summary[2].status, "failed");
+        assert_eq!(summary[2].error.as_deref(), Some("Exited with code 1"));
+        assert_eq!(summary[2].lines, 1);
+
+        let json: serde_json::Value = serde_json::from_str(&format_json(&summary)).unwrap();
+        assert_eq!(json["processes"][2]["name"], "worker");
+        assert_eq!(json["processes"][2]["status"], "failed");
+        assert!(json["processes"][2]["exit_code"].is_null());
+        assert!(json["processes"][0].get("error").is_none());
+    }
+
+    #[test]
+    fn test_format_table_aligns_columns() {
+        let table = format_table(&summarize(&manager_with_mixed_statuses()));
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows[0], "PROCESS  STATUS       EXIT    LINES");
+        assert_eq!(rows[1], "db       stopped         -        0");
+        assert_eq!(rows[3], "worker   failed          -        1  Exited with code 1");
+    }
+}
@@ -0,0 +1,112 @@
+use std::time::{Duration, Instant};
+
+/// Default `max_fps`
+pub const DEFAULT_MAX_FPS: u32 = 30;
+
+/// Redraw this often even when nothing changed, so clocks and expiring
+/// status messages stay current
+const HEARTBEAT: Duration = Duration::from_secs(1);
+
+/// Decides when the main loop redraws: only when something changed (or on the
+/// heartbeat), and no faster than `max_fps`. Keypresses skip the rate cap so
+/// typing and scrolling never wait for the next frame.
+pub struct FrameScheduler {
+    min_frame: Duration,
+    last_draw: Instant,
+    dirty: bool,
+    input: bool,
+}
+
+impl FrameScheduler {
+    pub fn new(max_fps: u32, now: Instant) -> Self {
+        Self {
+            min_frame: Duration::from_secs(1) / max_fps.max(1),
+            last_draw: now,
+            dirty: true,
+            input: false,
+        }
+    }
+
+    /// Logs arrived or state changed: draw on the next allowed frame
+    pub fn mark_dirty(&mut self) {
+        self.dirty = true;
+    }
+
+    /// The user pressed a key or used the mouse: draw right away
+    pub fn mark_input(&mut self) {
+        self.dirty = true;
+        self.input = true;
+    }
+
+    pub fn should_draw(&self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last_draw);
+        self.input || (self.dirty && elapsed >= self.min_frame) || elapsed >= HEARTBEAT
+    }
+
+    pub fn drawn(&mut self, now: Instant) {
+        self.last_draw = now;
+        self.dirty = false;
+        self.input = false;
+    }
+
+    /// How long the loop may sleep before it has a frame to draw
+    pub fn sleep_for(&self, now: Instant) -> Duration {
+        let elapsed = now.saturating_duration_since(self.last_draw);
+        if self.dirty {
+            self.min_frame.saturating_sub(elapsed)
+        } else {
+            HEARTBEAT.saturating_sub(elapsed)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drawn_at(start: Instant) -> FrameScheduler {
+        let mut frames = FrameScheduler::new(30, start);
+        frames.drawn(start);
+        frames
+    }
+
+    #[test]
+    fn clean_frames_wait_for_the_heartbeat() {
+        let start = Instant::now();
+        let frames = drawn_at(start);
+
+        assert!(!frames.should_draw(start + Duration::from_millis(500)));
+        assert!(frames.should_draw(start + HEARTBEAT));
+        assert_eq!(frames.sleep_for(start + Duration::from_millis(400)), Duration::from_millis(600));
+    }
+
+    #[test]
+    fn dirty_frames_are_capped_at_max_fps() {
+        let start = Instant::now();
+        let mut frames = drawn_at(start);
+        frames.mark_dirty();
+
+        assert!(!frames.should_draw(start + Duration::from_millis(10)));
+        assert!(frames.should_draw(start + Duration::from_millis(34)));
+        assert!(frames.sleep_for(start + Duration::from_millis(10)) <= Duration::from_millis(24));
+    }
+
+    #[test]
+    fn input_draws_immediately() {
+        let start = Instant::now();
+        let mut frames = drawn_at(start);
+        frames.mark_input();
+
+        assert!(frames.should_draw(start + Duration::from_millis(1)));
+        frames.drawn(start + Duration::from_millis(1));
+        assert!(!frames.should_draw(start + Duration::from_millis(2)));
+    }
+
+    #[test]
+    fn first_frame_is_drawn_and_zero_fps_is_clamped() {
+        let start = Instant::now();
+        let frames = FrameScheduler::new(0, start);
+        assert!(frames.should_draw(start + Duration::from_secs(1)));
+        assert_eq!(frames.min_frame, Duration::from_secs(1));
+    }
+}
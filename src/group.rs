@@ -1,14 +1,23 @@
+use std::borrow::Cow;
 use std::collections::HashMap;
 
 pub struct GroupResolver<'a> {
-    groups: &'a HashMap<String, Vec<String>>,
+    groups: Cow<'a, HashMap<String, Vec<String>>>,
     process_names: Vec<String>,
 }
 
 impl<'a> GroupResolver<'a> {
     pub fn new(groups: &'a HashMap<String, Vec<String>>, process_names: Vec<String>) -> Self {
         Self {
-            groups,
+            groups: Cow::Borrowed(groups),
+            process_names,
+        }
+    }
+
+    /// Resolver over a group map built on the fly (e.g. `Config::all_groups`)
+    pub fn owned(groups: HashMap<String, Vec<String>>, process_names: Vec<String>) -> Self {
+        Self {
+            groups: Cow::Owned(groups),
             process_names,
         }
     }
@@ -0,0 +1,234 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::config::ProcessConfig;
+use crate::process::ProcessStatus;
+
+/// A hook doesn't fire again for the same process and event within this window,
+/// so a hook that restarts a crashing process can't spin in a tight loop
+pub const HOOK_COOLDOWN: Duration = Duration::from_secs(2);
+
+/// Shell commands to run on a process's state changes (`on_start`, `on_exit`, `on_failure`)
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ProcessHooks {
+    pub on_start: Option<String>,
+    pub on_exit: Option<String>,
+    pub on_failure: Option<String>,
+}
+
+impl ProcessHooks {
+    pub fn from_config(config: &ProcessConfig) -> Self {
+        Self {
+            on_start: config.on_start.clone(),
+            on_exit: config.on_exit.clone(),
+            on_failure: config.on_failure.clone(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.on_start.is_none() && self.on_exit.is_none() && self.on_failure.is_none()
+    }
+
+    fn command_for(&self, event: HookEvent) -> Option<&str> {
+        match event {
+            HookEvent::Start => self.on_start.as_deref(),
+            HookEvent::Exit => self.on_exit.as_deref(),
+            HookEvent::Failure => self.on_failure.as_deref(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HookEvent {
+    Start,
+    Exit,
+    Failure,
+}
+
+/// A hook waiting to be spawned
+#[derive(Debug, Clone, PartialEq)]
+pub struct HookInvocation {
+    pub process: String,
+    pub event: HookEvent,
+    pub command: String,
+    pub cwd: Option<PathBuf>,
+    /// OIT_PROCESS, OIT_STATUS and (when known) OIT_EXIT_CODE
+    pub env: Vec<(String, String)>,
+}
+
+/// Status as passed in `OIT_STATUS`
+pub fn status_name(status: &ProcessStatus) -> &'static str {
+    match status {
+        ProcessStatus::Running => "running",
+        ProcessStatus::Stopped => "stopped",
+        ProcessStatus::Terminating => "terminating",
+        ProcessStatus::Restarting => "restarting",
+        ProcessStatus::Failed(_) => "failed",
+        ProcessStatus::Done => "done",
+    }
+}
+
+fn is_live(status: &ProcessStatus) -> bool {
+    matches!(status, ProcessStatus::Running | ProcessStatus::Terminating | ProcessStatus::Restarting)
+}
+
+/// Events for a status change. `exited` is whether the child is gone (a
+/// `marks_failed` status pattern fails a process that is still running).
+pub fn transition_events(previous: &ProcessStatus, current: &ProcessStatus, exited: bool) -> Vec<HookEvent> {
+    let mut events = Vec::new();
+    match current {
+        ProcessStatus::Running if *previous != ProcessStatus::Running => events.push(HookEvent::Start),
+        ProcessStatus::Stopped | ProcessStatus::Done if is_live(previous) => events.push(HookEvent::Exit),
+        ProcessStatus::Failed(_) if !matches!(previous, ProcessStatus::Failed(_)) => {
+            if exited && is_live(previous) {
+                events.push(HookEvent::Exit);
+            }
+            events.push(HookEvent::Failure);
+        }
+        _ => {}
+    }
+    events
+}
+
+/// Build the invocation for `event`, if the process has a hook for it
+pub fn invocation(
+    process: &str,
+    hooks: &ProcessHooks,
+    event: HookEvent,
+    status: &str,
+    exit_code: Option<i32>,
+    cwd: Option<PathBuf>,
+) -> Option<HookInvocation> {
+    let command = hooks.command_for(event)?;
+    let mut env = vec![
+        ("OIT_PROCESS".to_string(), process.to_string()),
+        ("OIT_STATUS".to_string(), status.to_string()),
+    ];
+    if let Some(code) = exit_code {
+        env.push(("OIT_EXIT_CODE".to_string(), code.to_string()));
+    }
+    Some(HookInvocation {
+        process: process.to_string(),
+        event,
+        command: command.to_string(),
+        cwd,
+        env,
+    })
+}
+
+/// Queue of hooks to spawn, plus the ones still running. Hooks run detached
+/// (`sh -c`, no stdio) so a slow one never blocks the event loop.
+#[derive(Default)]
+pub struct HookRunner {
+    pending: Vec<HookInvocation>,
+    running: HashMap<(String, HookEvent), Child>,
+    last_fired: HashMap<(String, HookEvent), Instant>,
+}
+
+impl HookRunner {
+    pub fn enqueue(&mut self, invocation: HookInvocation) {
+        self.pending.push(invocation);
+    }
+
+    #[cfg(test)]
+    pub fn pending(&self) -> &[HookInvocation] {
+        &self.pending
+    }
+
+    /// Spawn queued hooks. A hook is dropped if the same process/event hook is
+    /// still running or fired within `HOOK_COOLDOWN`. Returns spawn errors.
+    pub fn fire(&mut self, now: Instant) -> Vec<String> {
+        // Reap finished hooks so they don't linger as zombies
+        self.running.retain(|_, child| matches!(child.try_wait(), Ok(None)));
+
+        let mut errors = Vec::new();
+        for invocation in std::mem::take(&mut self.pending) {
+            let key = (invocation.process.clone(), invocation.event);
+            let cooling_down = self
+                .last_fired
+                .get(&key)
+                .is_some_and(|at| now.duration_since(*at) < HOOK_COOLDOWN);
+            if cooling_down || self.running.contains_key(&key) {
+                continue;
+            }
+
+            let mut cmd = Command::new("sh");
+            cmd.args(["-c", &invocation.command])
+                .envs(invocation.env.iter().map(|(k, v)| (k, v)))
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null());
+            if let Some(cwd) = &invocation.cwd {
+                cmd.current_dir(cwd);
+            }
+            match cmd.spawn() {
+                Ok(child) => {
+                    self.running.insert(key.clone(), child);
+                    self.last_fired.insert(key, now);
+                }
+                Err(e) => errors.push(format!("{} hook failed: {}", invocation.process, e)),
+            }
+        }
+        errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed() -> ProcessStatus {
+        ProcessStatus::Failed("Exit code: 1".to_string())
+    }
+
+    #[test]
+    fn transitions_map_to_events() {
+        use ProcessStatus::*;
+        assert_eq!(transition_events(&Stopped, &Running, false), vec![HookEvent::Start]);
+        assert_eq!(transition_events(&Restarting, &Running, false), vec![HookEvent::Start]);
+        assert_eq!(transition_events(&Running, &Stopped, true), vec![HookEvent::Exit]);
+        assert_eq!(transition_events(&Running, &failed(), true), vec![HookEvent::Exit, HookEvent::Failure]);
+        // Status pattern failure: still running, so no exit
+        assert_eq!(transition_events(&Running, &failed(), false), vec![HookEvent::Failure]);
+        assert!(transition_events(&Running, &Running, false).is_empty());
+        assert!(transition_events(&failed(), &failed(), true).is_empty());
+        assert!(transition_events(&Stopped, &Stopped, true).is_empty());
+    }
+
+    #[test]
+    fn invocation_only_for_configured_hooks() {
+        let hooks = ProcessHooks { on_failure: Some("notify".to_string()), ..ProcessHooks::default() };
+        assert!(invocation("web", &hooks, HookEvent::Start, "running", None, None).is_none());
+
+        let inv = invocation("web", &hooks, HookEvent::Failure, "failed", Some(3), None).unwrap();
+        assert_eq!(inv.command, "notify");
+        assert!(inv.env.contains(&("OIT_EXIT_CODE".to_string(), "3".to_string())));
+    }
+
+    #[test]
+    fn runner_skips_hooks_within_cooldown() {
+        let dir = tempfile::tempdir().unwrap();
+        let out = dir.path().join("count");
+        let hooks = ProcessHooks {
+            on_failure: Some(format!("echo x >> {}", out.display())),
+            ..ProcessHooks::default()
+        };
+        let mut runner = HookRunner::default();
+        let now = Instant::now();
+
+        runner.enqueue(invocation("web", &hooks, HookEvent::Failure, "failed", None, None).unwrap());
+        runner.enqueue(invocation("web", &hooks, HookEvent::Failure, "failed", None, None).unwrap());
+        assert!(runner.fire(now).is_empty());
+        assert!(runner.pending().is_empty());
+
+        // Wait for the first hook to finish, then fire again inside the cooldown
+        std::thread::sleep(Duration::from_millis(200));
+        runner.enqueue(invocation("web", &hooks, HookEvent::Failure, "failed", None, None).unwrap());
+        runner.fire(now + Duration::from_millis(500));
+
+        let lines = std::fs::read_to_string(&out).unwrap();
+        assert_eq!(lines.lines().count(), 1);
+    }
+}
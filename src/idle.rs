@@ -0,0 +1,49 @@
+use std::time::{Duration, Instant};
+
+/// Decides when `oit` should quit on its own (`idle_quit_ms`): every process
+/// has exited and nothing has happened for the timeout.
+pub struct IdleTracker {
+    timeout: Duration,
+    last_activity: Instant,
+}
+
+impl IdleTracker {
+    pub fn new(timeout: Duration, now: Instant) -> Self {
+        Self { timeout, last_activity: now }
+    }
+
+    /// Record one main-loop tick. `active` means logs arrived, a status changed,
+    /// or a process is still running. Returns true once the idle timeout has passed.
+    pub fn tick(&mut self, active: bool, now: Instant) -> bool {
+        if active {
+            self.last_activity = now;
+            return false;
+        }
+        now.duration_since(self.last_activity) >= self.timeout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_idle_fires_after_timeout_without_activity() {
+        let start = Instant::now();
+        let mut idle = IdleTracker::new(Duration::from_millis(500), start);
+
+        assert!(!idle.tick(false, start + Duration::from_millis(499)));
+        assert!(idle.tick(false, start + Duration::from_millis(500)));
+    }
+
+    #[test]
+    fn test_activity_resets_the_idle_clock() {
+        let start = Instant::now();
+        let mut idle = IdleTracker::new(Duration::from_millis(500), start);
+
+        assert!(!idle.tick(true, start + Duration::from_millis(400)));
+        assert!(!idle.tick(false, start + Duration::from_millis(800)));
+        assert!(idle.tick(false, start + Duration::from_millis(900)));
+    }
+
+}
@@ -1,6 +1,12 @@
 // Actions that IPC commands can trigger in the TUI
 // These are returned alongside IpcResponse and processed by the main event loop
 
+use std::collections::HashMap;
+
+use super::subscription::LevelFilter;
+use crate::operations::navigation::JumpDirection;
+use crate::ui::{DisplayMode, StatusType};
+
 /// Actions that IPC command handlers can emit to update TUI state
 #[derive(Debug, Clone, PartialEq)]
 #[allow(dead_code)]
@@ -21,14 +27,28 @@ pub enum IpcAction {
     ScrollDown { lines: usize },
     /// Scroll to the top of the log
     ScrollToTop,
+    /// Scroll to a position (0-100) through the filtered log, clamped by the viewer
+    ScrollToPercent { percent: u64 },
+    /// Select the first line received in the last `secs` seconds
+    GotoRecent { secs: u64 },
+    /// Select the nearest error line in a direction from `from_id` (or the selection)
+    GotoError { direction: JumpDirection, from_id: Option<u64> },
     /// Set the frozen (paused) state of the display
     SetFrozen { frozen: bool },
+    /// Enable or disable the errors-only quick filter
+    SetErrorsOnly { enabled: bool },
+    /// Set the log display mode (compact/full/wrap)
+    SetDisplayMode { mode: DisplayMode },
     /// Add a filter (include or exclude)
     AddFilter { pattern: String, is_exclude: bool },
     /// Remove a filter by pattern
     RemoveFilter { pattern: String },
     /// Clear all filters
     ClearFilters,
+    /// Switch a filter off or back on, keeping it listed
+    ToggleFilter { pattern: String },
+    /// Replace all filters with a saved preset
+    ApplyPreset { name: String },
     /// Hide a process from log view (runtime only, does not persist to config)
     HideProcess { name: String },
     /// Show a hidden process (runtime only, does not persist to config)
@@ -41,12 +61,50 @@ pub enum IpcAction {
     KillProcess { name: String },
     /// Start a stopped process
     StartProcess { name: String },
+    /// Kill every running process without quitting
+    KillAllProcesses,
+    /// Start every stopped or failed process
+    StartAllProcesses,
+    /// Tail a standalone log file added at runtime
+    AddLogFile { name: String, path: String },
+    /// Add an ad-hoc process (runtime only, not persisted) and start it
+    AddProcess {
+        name: String,
+        command: String,
+        cwd: Option<String>,
+        env: HashMap<String, String>,
+    },
+    /// Change the log buffer's memory limit, evicting old lines if it shrinks
+    SetBufferLimit { mb: usize },
+    /// Start a manual trace recording, optionally labeled
+    StartTraceRecording { label: Option<String> },
+    /// Stop the manual trace recording and open the captured window
+    StopTraceRecording,
+    /// Add a note line to the log during a recording
+    AnnotateTrace { note: String },
+    /// Show a message in the status bar (left by an agent for the person at the terminal)
+    SetStatus { message: String, status_type: StatusType },
+    /// Clear the status bar message
+    ClearStatus,
+    /// Return the lines in the TUI's current view, optionally also copying them to its clipboard
+    CopyVisible { clipboard: bool },
+    /// Place text on the TUI machine's clipboard
+    CopyText { text: String },
+    /// Return the newest `limit` lines from one process, oldest first
+    ProcessLogs { name: String, limit: usize, strip_ansi: bool },
+    /// Keep the requesting connection open and push it new lines at these levels
+    TailErrors { level: LevelFilter },
+    /// Hold the requesting connection's response until these processes are back up
+    WaitReady { names: Vec<String>, timeout_ms: u64 },
 }
 
 /// Result of handling an IPC command: response to send + actions to apply
 pub struct IpcHandlerResult {
     pub response: super::protocol::IpcResponse,
     pub actions: Vec<IpcAction>,
+    /// `pipeline` sub-command results, in order. Their actions apply to their
+    /// own response, which then replaces its entry in `responses`.
+    pub steps: Vec<IpcHandlerResult>,
 }
 
 impl IpcHandlerResult {
@@ -55,12 +113,20 @@ impl IpcHandlerResult {
         Self {
             response,
             actions: Vec::new(),
+            steps: Vec::new(),
         }
     }
 
     /// Create a result with a response and actions
     pub fn with_actions(response: super::protocol::IpcResponse, actions: Vec<IpcAction>) -> Self {
-        Self { response, actions }
+        Self { response, actions, steps: Vec::new() }
+    }
+
+    /// A `pipeline` result: `{"responses": [...]}` plus each step to apply
+    pub fn pipeline(steps: Vec<IpcHandlerResult>) -> Self {
+        let responses: Vec<&super::protocol::IpcResponse> = steps.iter().map(|step| &step.response).collect();
+        let response = super::protocol::IpcResponse::ok(serde_json::json!({ "responses": responses }));
+        Self { response, actions: Vec::new(), steps }
     }
 }
 
@@ -1,5 +1,6 @@
 use std::io;
 use std::path::Path;
+use std::time::Duration;
 
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
 use tokio::net::UnixStream;
@@ -53,6 +54,18 @@ impl IpcClient {
         self.send_request(request).await?;
         self.recv_response().await
     }
+
+    /// Connect and ping, returning true only if a server answers successfully
+    /// within `timeout` (applied to the connect and to the round trip).
+    pub async fn ping(socket_path: impl AsRef<Path>, timeout: Duration) -> bool {
+        let Ok(Ok(mut client)) = tokio::time::timeout(timeout, Self::connect(socket_path)).await else {
+            return false;
+        };
+        matches!(
+            tokio::time::timeout(timeout, client.call(&IpcRequest::new("ping"))).await,
+            Ok(Ok(response)) if response.success
+        )
+    }
 }
 
 #[cfg(test)]
@@ -60,7 +73,6 @@ mod tests {
     use super::*;
     use crate::ipc::server::IpcServer;
     use serde_json::json;
-    use std::time::Duration;
     use tempfile::TempDir;
 
     fn temp_socket_path() -> (TempDir, std::path::PathBuf) {
@@ -175,6 +187,44 @@ mod tests {
         assert_eq!(err.kind(), io::ErrorKind::NotFound);
     }
 
+    #[tokio::test]
+    async fn ping_returns_false_for_stale_socket() {
+        let (_dir, path) = temp_socket_path();
+        drop(std::os::unix::net::UnixListener::bind(&path).unwrap());
+
+        assert!(!IpcClient::ping(&path, Duration::from_millis(100)).await);
+    }
+
+    #[tokio::test]
+    async fn ping_returns_false_when_server_never_answers() {
+        let (_dir, path) = temp_socket_path();
+        // Listener accepts the connection (kernel backlog) but never responds
+        let _server = IpcServer::new(&path).unwrap();
+
+        assert!(!IpcClient::ping(&path, Duration::from_millis(100)).await);
+    }
+
+    #[tokio::test]
+    async fn ping_returns_true_for_live_server() {
+        let (_dir, path) = temp_socket_path();
+        let mut server = IpcServer::new(&path).unwrap();
+
+        let handle = tokio::spawn(async move {
+            for _ in 0..50 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+                server.accept_pending().unwrap();
+                if let Some((conn_id, _)) = server.poll_commands().unwrap().into_iter().next() {
+                    server.send_response(conn_id, IpcResponse::ok(json!({"pong": true}))).await.unwrap();
+                    break;
+                }
+            }
+            server
+        });
+
+        assert!(IpcClient::ping(&path, Duration::from_millis(500)).await);
+        let _ = handle.await;
+    }
+
     #[tokio::test]
     async fn client_handles_server_disconnect() {
         let (_dir, path) = temp_socket_path();
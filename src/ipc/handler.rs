@@ -1,61 +1,673 @@
 use serde_json::{json, Value};
+use std::borrow::Cow;
+use std::collections::HashMap;
 
 use super::action::{IpcAction, IpcHandlerResult};
 use super::protocol::{IpcRequest, IpcResponse};
-use super::state::StateSnapshot;
+use super::state::{LogLineInfo, ProcessInfo, StateSnapshot, ViewModeInfo};
+use super::subscription::LevelFilter;
+use crate::operations::navigation::JumpDirection;
 use crate::group::GroupResolver;
+use crate::log::{format_reference, parse_reference, strip_ansi, SeverityMatcher, VolumeHistogram};
+use crate::ui::{detect_batches_by, BatchStrategy, DisplayMode, StatusType};
+
+/// Default value of an optional command argument
+#[derive(Debug, Clone, Copy)]
+pub enum ArgDefault {
+    Number(i64),
+    Bool(bool),
+    Str(&'static str),
+}
+
+/// One argument of an IPC command, as listed by `help`
+#[derive(Debug)]
+pub struct ArgSpec {
+    pub name: &'static str,
+    /// JSON type: "string", "number", "boolean", "object" or "array"
+    pub kind: &'static str,
+    pub required: bool,
+    pub default: Option<ArgDefault>,
+    pub description: &'static str,
+}
+
+/// Upper bound on `histogram` buckets, so a response stays small
+const MAX_HISTOGRAM_BUCKETS: u64 = 360;
+
+/// Upper bound on a `histogram` bucket width (one day), so the window
+/// arithmetic can't overflow.
+const MAX_HISTOGRAM_BUCKET_SECS: u64 = 86_400;
+
+/// Largest `clipboard_copy` payload, so an agent can't flood the clipboard
+const MAX_CLIPBOARD_BYTES: usize = 1024 * 1024;
+
+const fn arg(name: &'static str, kind: &'static str, description: &'static str) -> ArgSpec {
+    ArgSpec { name, kind, required: false, default: None, description }
+}
+
+impl ArgSpec {
+    const fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    const fn default(mut self, default: ArgDefault) -> Self {
+        self.default = Some(default);
+        self
+    }
+
+    /// Check a supplied value against `kind`. Strings holding a number or
+    /// `true`/`false` are coerced (`"50"` → `50`) so shell-built JSON still
+    /// works; returns the coerced value, or None when it's fine as given.
+    fn coerce(&self, value: &Value) -> Result<Option<Value>, String> {
+        let invalid = |expected: &str| Err(format!("argument '{}' must be {}", self.name, expected));
+        match (self.kind, value) {
+            ("number", Value::Number(n)) if n.is_u64() => Ok(None),
+            ("number", Value::Number(_)) => invalid("a non-negative integer"),
+            ("number", Value::String(s)) => match s.trim().parse::<u64>() {
+                Ok(n) => Ok(Some(json!(n))),
+                Err(_) => invalid("a number"),
+            },
+            ("number", _) => invalid("a number"),
+            ("boolean", Value::Bool(_)) => Ok(None),
+            ("boolean", Value::String(s)) => match s.as_str() {
+                "true" => Ok(Some(json!(true))),
+                "false" => Ok(Some(json!(false))),
+                _ => invalid("a boolean"),
+            },
+            ("boolean", _) => invalid("a boolean"),
+            ("string", Value::String(_)) | ("object", Value::Object(_)) | ("array", Value::Array(_)) => Ok(None),
+            ("string", _) => invalid("a string"),
+            ("object", _) => invalid("an object"),
+            ("array", _) => invalid("an array"),
+            _ => Ok(None),
+        }
+    }
+
+    fn to_json(&self) -> Value {
+        let mut value = json!({
+            "name": self.name,
+            "type": self.kind,
+            "required": self.required,
+            "description": self.description,
+        });
+        if let Some(default) = self.default {
+            value["default"] = match default {
+                ArgDefault::Number(n) => json!(n),
+                ArgDefault::Bool(b) => json!(b),
+                ArgDefault::Str(s) => json!(s),
+            };
+        }
+        value
+    }
+}
+
+type Dispatch = fn(&IpcCommandHandler, &IpcRequest, Option<&StateSnapshot>) -> IpcHandlerResult;
+
+/// An IPC command: its help metadata and the handler it dispatches to
+pub struct CommandSpec {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub args: &'static [ArgSpec],
+    dispatch: Dispatch,
+}
+
+impl CommandSpec {
+    /// Validate the declared arguments that are present (missing or null ones
+    /// are left for the handler to default), coercing where `ArgSpec::coerce` allows
+    fn check_args<'a>(&self, args: &'a Value) -> Result<Cow<'a, Value>, String> {
+        let mut coerced: Option<Value> = None;
+        for arg in self.args {
+            let Some(value) = args.get(arg.name).filter(|v| !v.is_null()) else {
+                continue;
+            };
+            if let Some(value) = arg.coerce(value)? {
+                coerced.get_or_insert_with(|| args.clone())[arg.name] = value;
+            }
+        }
+        Ok(coerced.map_or(Cow::Borrowed(args), Cow::Owned))
+    }
+}
+
+/// Every IPC command. `handle` dispatches through this table and `help` lists
+/// it, so a command added here is documented automatically.
+pub const COMMANDS: &[CommandSpec] = &[
+    CommandSpec {
+        name: "ping",
+        description: "Check if TUI is running",
+        args: &[],
+        dispatch: |h, _, s| IpcHandlerResult::response_only(h.handle_ping(s)),
+    },
+    CommandSpec {
+        name: "status",
+        description: "Get TUI status including version, process count, and buffer usage",
+        args: &[],
+        dispatch: |h, r, s| IpcHandlerResult::response_only(h.handle_status(&r.args, s)),
+    },
+    CommandSpec {
+        name: "info",
+        description: "Where this instance runs: version, working directory, config path, Procfile paths and socket path",
+        args: &[],
+        dispatch: |h, _, _| IpcHandlerResult::response_only(h.handle_info()),
+    },
+    CommandSpec {
+        name: "processes",
+        description: "List all processes and their current status",
+        args: &[arg("status", "string", "Only processes in this status: running, stopped, failed, done, restarting, terminating, or unhealthy (failed or stopped)")],
+        dispatch: |h, r, s| IpcHandlerResult::response_only(h.handle_processes(&r.args, s)),
+    },
+    CommandSpec {
+        name: "describe",
+        description: "Full detail for one process: status, exit code/signal, command, cwd, env, recent lines, last error, label, visibility",
+        args: &[arg("name", "string", "Process name to describe").required()],
+        dispatch: |h, r, s| IpcHandlerResult::response_only(h.handle_describe(&r.args, s)),
+    },
+    CommandSpec {
+        name: "last_output",
+        description: "The last lines a process emitted before its most recent failure (its stack trace, usually)",
+        args: &[
+            arg("name", "string", "Process name").required(),
+            arg("strip_ansi", "boolean", "Return content without ANSI escape codes").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, s| IpcHandlerResult::response_only(h.handle_last_output(&r.args, s)),
+    },
+    CommandSpec {
+        name: "colors",
+        description: "Get the display color assigned to each process and log file (empty when coloring is off)",
+        args: &[],
+        dispatch: |h, _, s| IpcHandlerResult::response_only(h.handle_colors(s)),
+    },
+    CommandSpec {
+        name: "logs",
+        description: "Get recent log lines from the buffer",
+        args: &[
+            arg("limit", "number", "Maximum number of lines to return").default(ArgDefault::Number(100)),
+            arg("offset", "number", "Number of lines to skip").default(ArgDefault::Number(0)),
+            arg("strip_ansi", "boolean", "Return content without ANSI escape codes").default(ArgDefault::Bool(false)),
+            arg("stream", "string", "Output stream to return: stdout, stderr or both").default(ArgDefault::Str("both")),
+            arg("with_batches", "boolean", "Detect batches among the returned lines and set batch_id (the id of the batch's first line)").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, s| IpcHandlerResult::response_only(h.handle_logs(&r.args, s)),
+    },
+    CommandSpec {
+        name: "process_logs",
+        description: "Get the most recent log lines from one process or log file, oldest first",
+        args: &[
+            arg("name", "string", "Process or log file name").required(),
+            arg("limit", "number", "Maximum number of lines to return").default(ArgDefault::Number(100)),
+            arg("strip_ansi", "boolean", "Return content without ANSI escape codes").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, s| h.handle_process_logs(&r.args, s),
+    },
+    CommandSpec {
+        name: "recent",
+        description: "Get lines received in the last N seconds (by arrival time) and scroll the TUI to the start of that window",
+        args: &[
+            arg("secs", "number", "Window length in seconds").required(),
+            arg("limit", "number", "Maximum number of lines to return (the newest are kept)").default(ArgDefault::Number(100)),
+            arg("strip_ansi", "boolean", "Return content without ANSI escape codes").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, s| h.handle_recent(&r.args, s),
+    },
+    CommandSpec {
+        name: "histogram",
+        description: "Count recent lines per time bucket (by arrival time), oldest bucket first",
+        args: &[
+            arg("bucket_secs", "number", "Bucket width in seconds").default(ArgDefault::Number(10)),
+            arg("buckets", "number", "Number of buckets, ending now (at most 360)").default(ArgDefault::Number(30)),
+            arg("per_process", "boolean", "Also break the counts down by process").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, s| h.handle_histogram(&r.args, s),
+    },
+    CommandSpec {
+        name: "copy_visible",
+        description: "Get every line in the TUI's current view as [time] process: message text",
+        args: &[
+            arg("clipboard", "boolean", "Also place the text on the TUI machine's clipboard").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, _| h.handle_copy_visible(&r.args),
+    },
+    CommandSpec {
+        name: "clipboard_copy",
+        description: "Place text, or a log line's content by ID, on the TUI machine's clipboard",
+        args: &[
+            arg("text", "string", "Text to copy (give this or id)"),
+            arg("id", "number", "Copy this log line's content, without ANSI codes (give this or text)"),
+        ],
+        dispatch: |h, r, s| h.handle_clipboard_copy(&r.args, s),
+    },
+    CommandSpec {
+        name: "search",
+        description: "Search log lines for a pattern and highlight in TUI",
+        args: &[
+            arg("pattern", "string", "Search pattern (substring match)").required(),
+            arg("limit", "number", "Maximum matches to return").default(ArgDefault::Number(100)),
+            arg("case_sensitive", "boolean", "Enable case-sensitive matching").default(ArgDefault::Bool(false)),
+            arg("strip_ansi", "boolean", "Match and return content without ANSI escape codes").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, s| h.handle_search(&r.args, s),
+    },
+    CommandSpec {
+        name: "search_next",
+        description: "Move the TUI selection to the next line matching the active search (wraps)",
+        args: &[],
+        dispatch: |h, _, s| h.handle_search_step(s, true),
+    },
+    CommandSpec {
+        name: "search_prev",
+        description: "Move the TUI selection to the previous line matching the active search (wraps)",
+        args: &[],
+        dispatch: |h, _, s| h.handle_search_step(s, false),
+    },
+    CommandSpec {
+        name: "select",
+        description: "Select a log line by ID and open expanded view in TUI",
+        args: &[
+            arg("id", "number", "Log line ID to select").required(),
+        ],
+        dispatch: |h, r, s| h.handle_select(&r.args, s),
+    },
+    CommandSpec {
+        name: "context",
+        description: "Get context lines around a specific log line",
+        args: &[
+            arg("id", "number", "Log line ID").required(),
+            arg("before", "number", "Lines before target").default(ArgDefault::Number(5)),
+            arg("after", "number", "Lines after target").default(ArgDefault::Number(5)),
+        ],
+        dispatch: |h, r, s| h.handle_context(&r.args, s),
+    },
+    CommandSpec {
+        name: "goto",
+        description: "Jump to a specific log line by ID (scrolls view without expanding)",
+        args: &[
+            arg("id", "number", "Log line ID to scroll to (required unless ref is given)"),
+            arg("ref", "string", "Line reference like web#4821 (from 'reference')"),
+        ],
+        dispatch: |h, r, s| h.handle_goto(&r.args, s),
+    },
+    CommandSpec {
+        name: "goto_error",
+        description: "Select the nearest error line before or after a line (wraps); returns its id",
+        args: &[
+            arg("direction", "string", "next or prev").default(ArgDefault::Str("next")),
+            arg("from_id", "number", "Line to search from (default: the TUI selection, else the tail)"),
+        ],
+        dispatch: |h, r, s| h.handle_goto_error(&r.args, s),
+    },
+    CommandSpec {
+        name: "reference",
+        description: "Get a shareable <process>#<id> reference for a log line",
+        args: &[
+            arg("id", "number", "Log line ID").required(),
+        ],
+        dispatch: |h, r, s| IpcHandlerResult::response_only(h.handle_reference(&r.args, s)),
+    },
+    CommandSpec {
+        name: "scroll",
+        description: "Scroll the log view up, down, to top, or to bottom",
+        args: &[
+            arg("direction", "string", "Scroll direction: up, down, top, or bottom").required(),
+            arg("lines", "number", "Number of lines to scroll (for up/down)").default(ArgDefault::Number(20)),
+        ],
+        dispatch: |h, r, s| h.handle_scroll(&r.args, s),
+    },
+    CommandSpec {
+        name: "scroll_percent",
+        description: "Scroll the log view to a position through the filtered log (0 = oldest, 100 = newest)",
+        args: &[
+            arg("percent", "number", "Position 0-100; larger values are clamped to 100").required(),
+        ],
+        dispatch: |h, r, _| h.handle_scroll_percent(&r.args),
+    },
+    CommandSpec {
+        name: "freeze",
+        description: "Freeze or unfreeze the TUI display (pauses auto-scroll)",
+        args: &[
+            arg("mode", "string", "Mode: on, off, or toggle").default(ArgDefault::Str("toggle")),
+        ],
+        dispatch: |h, r, s| h.handle_freeze(&r.args, s),
+    },
+    CommandSpec {
+        name: "errors_only",
+        description: "Show only error and warning lines in the TUI (composes with filters)",
+        args: &[
+            arg("mode", "string", "Mode: on, off, or toggle").default(ArgDefault::Str("toggle")),
+        ],
+        dispatch: |h, r, s| h.handle_errors_only(&r.args, s),
+    },
+    CommandSpec {
+        name: "set_view",
+        description: "Set display mode, wrapping and auto-scroll in one call; returns the resulting view_mode",
+        args: &[
+            arg("display_mode", "string", "compact, full, or wrap"),
+            arg("wrap", "boolean", "Wrap long lines (false switches wrap to full)"),
+            arg("auto_scroll", "boolean", "Follow new logs"),
+        ],
+        dispatch: |h, r, s| h.handle_set_view(&r.args, s),
+    },
+    CommandSpec {
+        name: "help",
+        description: "List available IPC commands",
+        args: &[],
+        dispatch: |h, _, _| IpcHandlerResult::response_only(h.handle_help()),
+    },
+    CommandSpec {
+        name: "trace",
+        description: "Get trace recording status and active trace info",
+        args: &[],
+        dispatch: |h, _, s| IpcHandlerResult::response_only(h.handle_trace(s)),
+    },
+    CommandSpec {
+        name: "trace_start",
+        description: "Start a manual trace recording; returns its trace_id",
+        args: &[arg("label", "string", "Label shown while recording")],
+        dispatch: |h, r, s| h.handle_trace_start(&r.args, s),
+    },
+    CommandSpec {
+        name: "trace_stop",
+        description: "Stop the manual trace recording and show the captured lines",
+        args: &[],
+        dispatch: |h, _, s| h.handle_trace_stop(s),
+    },
+    CommandSpec {
+        name: "trace_annotate",
+        description: "Add a note line to the log during a trace recording",
+        args: &[arg("note", "string", "Note text").required()],
+        dispatch: |h, r, s| h.handle_trace_annotate(&r.args, s),
+    },
+    CommandSpec {
+        name: "set_status",
+        description: "Show a message in the TUI status bar for the person watching it",
+        args: &[
+            arg("message", "string", "Message text").required(),
+            arg("type", "string", "Message style: info, success or error").default(ArgDefault::Str("info")),
+        ],
+        dispatch: |h, r, _| h.handle_set_status(&r.args),
+    },
+    CommandSpec {
+        name: "clear_status",
+        description: "Clear the TUI status bar message",
+        args: &[],
+        dispatch: |_, _, _| {
+            IpcHandlerResult::with_actions(IpcResponse::ok(json!({"cleared": true})), vec![IpcAction::ClearStatus])
+        },
+    },
+    CommandSpec {
+        name: "filters",
+        description: "List current filters",
+        args: &[],
+        dispatch: |h, _, s| IpcHandlerResult::response_only(h.handle_filters(s)),
+    },
+    CommandSpec {
+        name: "filter_add",
+        description: "Add a new filter (persists to config file)",
+        args: &[
+            arg("pattern", "string", "Filter pattern to match").required(),
+            arg("exclude", "boolean", "Exclude matching lines instead of including").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, _| h.handle_filter_add(&r.args),
+    },
+    CommandSpec {
+        name: "filter_remove",
+        description: "Remove a filter by pattern (persists to config file)",
+        args: &[
+            arg("pattern", "string", "Filter pattern to remove").required(),
+        ],
+        dispatch: |h, r, _| h.handle_filter_remove(&r.args),
+    },
+    CommandSpec {
+        name: "filter_toggle",
+        description: "Switch a filter off or back on without removing it (persists to config file)",
+        args: &[arg("pattern", "string", "Pattern of the filter to toggle").required()],
+        dispatch: |h, r, s| h.handle_filter_toggle(&r.args, s),
+    },
+    CommandSpec {
+        name: "filter_clear",
+        description: "Remove all filters (persists to config file)",
+        args: &[],
+        dispatch: |h, _, _| h.handle_filter_clear(),
+    },
+    CommandSpec {
+        name: "presets",
+        description: "List saved filter presets",
+        args: &[],
+        dispatch: |h, _, s| IpcHandlerResult::response_only(h.handle_presets(s)),
+    },
+    CommandSpec {
+        name: "preset_apply",
+        description: "Replace all filters with a saved preset (persists to config file)",
+        args: &[
+            arg("name", "string", "Preset name").required(),
+        ],
+        dispatch: |h, r, s| h.handle_preset_apply(&r.args, s),
+    },
+    CommandSpec {
+        name: "visibility",
+        description: "List visibility status for all processes (which are shown/hidden)",
+        args: &[],
+        dispatch: |h, _, s| IpcHandlerResult::response_only(h.handle_visibility(s)),
+    },
+    CommandSpec {
+        name: "hide",
+        description: "Hide a process from log view (runtime only, does not persist)",
+        args: &[
+            arg("name", "string", "Process name to hide").required(),
+        ],
+        dispatch: |h, r, s| h.handle_hide(&r.args, s),
+    },
+    CommandSpec {
+        name: "show",
+        description: "Show a hidden process (runtime only, does not persist)",
+        args: &[
+            arg("name", "string", "Process name to show").required(),
+        ],
+        dispatch: |h, r, s| h.handle_show(&r.args, s),
+    },
+    CommandSpec {
+        name: "restart",
+        description: "Restart a process or all processes",
+        args: &[
+            arg("name", "string", "Process name to restart (restarts all if omitted)"),
+            arg("dry_run", "boolean", "Report what would be restarted without doing it").default(ArgDefault::Bool(false)),
+            arg("wait_ready", "boolean", "Respond only once the restarted processes are running (and ready, if configured)").default(ArgDefault::Bool(false)),
+            arg("timeout_ms", "number", "How long wait_ready waits before responding with an error").default(ArgDefault::Number(30000)),
+        ],
+        dispatch: |h, r, s| h.handle_restart(&r.args, s),
+    },
+    CommandSpec {
+        name: "kill",
+        description: "Kill a running process",
+        args: &[
+            arg("name", "string", "Process name to kill").required(),
+            arg("dry_run", "boolean", "Report what would be killed without doing it").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, s| h.handle_kill(&r.args, s),
+    },
+    CommandSpec {
+        name: "start",
+        description: "Start a stopped process",
+        args: &[
+            arg("name", "string", "Process name to start").required(),
+        ],
+        dispatch: |h, r, s| h.handle_start(&r.args, s),
+    },
+    CommandSpec {
+        name: "kill_all",
+        description: "Kill every running process without quitting oit (they stay stopped until started)",
+        args: &[],
+        dispatch: |_, _, s| IpcCommandHandler::handle_bulk(s, "kill", &["running", "restarting"], IpcAction::KillAllProcesses),
+    },
+    CommandSpec {
+        name: "start_all",
+        description: "Start every stopped or failed process",
+        args: &[],
+        dispatch: |_, _, s| IpcCommandHandler::handle_bulk(s, "start", &["stopped", "failed"], IpcAction::StartAllProcesses),
+    },
+    CommandSpec {
+        name: "process_add",
+        description: "Add and start an ad-hoc process that isn't in the Procfile (runtime only)",
+        args: &[
+            arg("name", "string", "Name for the new process").required(),
+            arg("command", "string", "Shell command to run").required(),
+            arg("cwd", "string", "Working directory (relative to the Procfile directory)"),
+            arg("env", "object", "Extra environment variables"),
+        ],
+        dispatch: |h, r, s| h.handle_process_add(&r.args, s),
+    },
+    CommandSpec {
+        name: "add_log_file",
+        description: "Start tailing a standalone log file at runtime; it may not exist yet (runtime only)",
+        args: &[
+            arg("name", "string", "Name to show the file's lines under").required(),
+            arg("path", "string", "File path (relative to the Procfile directory)").required(),
+        ],
+        dispatch: |h, r, s| h.handle_add_log_file(&r.args, s),
+    },
+    CommandSpec {
+        name: "set_buffer",
+        description: "Change the log buffer memory limit for this session, evicting old lines if it shrinks",
+        args: &[arg("mb", "number", "New buffer limit in megabytes").required()],
+        dispatch: |h, r, s| h.handle_set_buffer(&r.args, s),
+    },
+    CommandSpec {
+        name: "errors",
+        description: "Get recent log lines containing error or warning patterns",
+        args: &[
+            arg("limit", "number", "Maximum lines to return").default(ArgDefault::Number(50)),
+            arg("level", "string", "Level filter: error, warning, or error_or_warning").default(ArgDefault::Str("error")),
+            arg("process", "string", "Filter by process name"),
+        ],
+        dispatch: |h, r, s| IpcHandlerResult::response_only(h.handle_errors(&r.args, s)),
+    },
+    CommandSpec {
+        name: "tail_errors",
+        description: "Stay connected and receive each new error/warning line as it arrives",
+        args: &[
+            arg("level", "string", "Level filter: error, warning, or error_or_warning").default(ArgDefault::Str("error")),
+        ],
+        dispatch: |h, r, _| h.handle_tail_errors(&r.args),
+    },
+    CommandSpec {
+        name: "summary",
+        description: "Get comprehensive AI-friendly summary of current state",
+        args: &[],
+        dispatch: |h, _, s| IpcHandlerResult::response_only(h.handle_summary(s)),
+    },
+    CommandSpec {
+        name: "snapshot",
+        description: "Get the full state snapshot (processes, filters, view mode, buffer, visibility, traces) as JSON",
+        args: &[arg("recent_logs", "number", "Maximum recent log lines to include").default(ArgDefault::Number(20))],
+        dispatch: |h, r, s| IpcHandlerResult::response_only(h.handle_snapshot(&r.args, s)),
+    },
+    CommandSpec {
+        name: "batch",
+        description: "Get all log lines from a specific batch",
+        args: &[
+            arg("id", "number", "Batch ID to retrieve").required(),
+            arg("scroll", "boolean", "Scroll TUI to first line of batch").default(ArgDefault::Bool(false)),
+        ],
+        dispatch: |h, r, s| h.handle_batch(&r.args, s),
+    },
+    CommandSpec {
+        name: "pipeline",
+        description: "Run several commands in one round-trip against the same state; returns their responses in order",
+        args: &[
+            arg("commands", "array", "Array of {command, args} objects").required(),
+        ],
+        dispatch: |h, r, s| h.handle_pipeline(&r.args, s),
+    },
+];
+
+/// Every IPC command with its description and args (the `help` response, also
+/// shown in the TUI help overlay)
+pub fn command_list() -> Value {
+    COMMANDS
+        .iter()
+        .map(|c| {
+            json!({
+                "name": c.name,
+                "description": c.description,
+                "args": c.args.iter().map(ArgSpec::to_json).collect::<Vec<_>>(),
+            })
+        })
+        .collect()
+}
 
 /// Handles IPC commands from CLI clients
 ///
 /// This handler processes incoming requests and returns appropriate responses.
 /// It's designed to be simple and stateless for basic commands like ping/status.
+/// Paths resolved at startup, reported by `info`
+#[derive(Debug, Clone, Default)]
+pub struct InstancePaths {
+    pub working_dir: String,
+    /// None when running without a config file (replay mode)
+    pub config_path: Option<String>,
+    pub procfiles: Vec<String>,
+    /// None when the IPC server couldn't be created
+    pub socket_path: Option<String>,
+}
+
 pub struct IpcCommandHandler {
     version: String,
+    severity: SeverityMatcher,
+    paths: InstancePaths,
 }
 
 impl IpcCommandHandler {
     pub fn new(version: impl Into<String>) -> Self {
         Self {
             version: version.into(),
+            severity: SeverityMatcher::default(),
+            paths: InstancePaths::default(),
         }
     }
 
+    /// Report these startup paths from `info`
+    pub fn with_paths(mut self, paths: InstancePaths) -> Self {
+        self.paths = paths;
+        self
+    }
+
+    /// Use custom error/warning patterns for `errors` and `summary`
+    pub fn with_severity(mut self, severity: SeverityMatcher) -> Self {
+        self.severity = severity;
+        self
+    }
+
+    /// Severity rules used to classify lines for `errors` and `tail_errors`
+    pub fn severity(&self) -> &SeverityMatcher {
+        &self.severity
+    }
+
     pub fn handle(&self, request: &IpcRequest, state: Option<&StateSnapshot>) -> IpcHandlerResult {
-        match request.command.as_str() {
-            "ping" => IpcHandlerResult::response_only(self.handle_ping()),
-            "status" => IpcHandlerResult::response_only(self.handle_status(&request.args, state)),
-            "processes" => IpcHandlerResult::response_only(self.handle_processes(state)),
-            "logs" => IpcHandlerResult::response_only(self.handle_logs(&request.args, state)),
-            "search" => self.handle_search(&request.args, state),
-            "select" => self.handle_select(&request.args, state),
-            "context" => self.handle_context(&request.args, state),
-            "goto" => self.handle_goto(&request.args, state),
-            "scroll" => self.handle_scroll(&request.args, state),
-            "freeze" => self.handle_freeze(&request.args, state),
-            "filters" => IpcHandlerResult::response_only(self.handle_filters(state)),
-            "filter_add" => self.handle_filter_add(&request.args),
-            "filter_remove" => self.handle_filter_remove(&request.args),
-            "filter_clear" => self.handle_filter_clear(),
-            "visibility" => IpcHandlerResult::response_only(self.handle_visibility(state)),
-            "hide" => self.handle_hide(&request.args, state),
-            "show" => self.handle_show(&request.args, state),
-            "restart" => self.handle_restart(&request.args, state),
-            "kill" => self.handle_kill(&request.args, state),
-            "start" => self.handle_start(&request.args, state),
-            "errors" => IpcHandlerResult::response_only(self.handle_errors(&request.args, state)),
-            "summary" => IpcHandlerResult::response_only(self.handle_summary(state)),
-            "batch" => self.handle_batch(&request.args, state),
-            "help" => IpcHandlerResult::response_only(self.handle_help()),
-            "trace" => IpcHandlerResult::response_only(self.handle_trace(state)),
-            _ => IpcHandlerResult::response_only(IpcResponse::err(format!(
+        match COMMANDS.iter().find(|c| c.name == request.command) {
+            Some(spec) => match spec.check_args(&request.args) {
+                Ok(Cow::Borrowed(_)) => (spec.dispatch)(self, request, state),
+                Ok(Cow::Owned(args)) => {
+                    let request = IpcRequest { command: request.command.clone(), args };
+                    (spec.dispatch)(self, &request, state)
+                }
+                Err(msg) => IpcHandlerResult::response_only(IpcResponse::err(msg)),
+            },
+            None => IpcHandlerResult::response_only(IpcResponse::err(format!(
                 "unknown command: {}",
                 request.command
             ))),
         }
     }
 
-    fn handle_ping(&self) -> IpcResponse {
-        IpcResponse::ok(json!({"pong": true}))
+    fn handle_ping(&self, state: Option<&StateSnapshot>) -> IpcResponse {
+        match state {
+            // Live server: report enough for a watchdog to spot a stalled loop
+            Some(snapshot) => IpcResponse::ok(json!({
+                "pong": true,
+                "uptime_secs": snapshot.uptime_secs,
+                "server_time": chrono::Local::now().to_rfc3339(),
+                "tick": snapshot.tick
+            })),
+            None => IpcResponse::ok(json!({"pong": true})),
+        }
     }
 
     fn handle_status(&self, _args: &Value, state: Option<&StateSnapshot>) -> IpcResponse {
@@ -79,7 +691,8 @@ impl IpcCommandHandler {
                     "buffer": {
                         "bytes": snapshot.buffer_stats.buffer_bytes,
                         "max_bytes": snapshot.buffer_stats.max_buffer_bytes,
-                        "usage_percent": snapshot.buffer_stats.usage_percent
+                        "usage_percent": snapshot.buffer_stats.usage_percent,
+                        "ingest_backlog": snapshot.buffer_stats.ingest_backlog
                     }
                 }))
             }
@@ -93,24 +706,56 @@ impl IpcCommandHandler {
         }
     }
 
-    fn handle_processes(&self, state: Option<&StateSnapshot>) -> IpcResponse {
+    fn handle_info(&self) -> IpcResponse {
+        IpcResponse::ok(json!({
+            "version": self.version,
+            "working_dir": self.paths.working_dir,
+            "config_path": self.paths.config_path,
+            "procfiles": self.paths.procfiles,
+            "socket_path": self.paths.socket_path,
+        }))
+    }
+
+    fn handle_processes(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcResponse {
+        let status = args.get("status").and_then(|v| v.as_str());
+        if let Some(status) = status
+            && !matches!(status, "running" | "stopped" | "failed" | "done" | "restarting" | "terminating" | "unhealthy")
+        {
+            return IpcResponse::err(format!(
+                "invalid status '{}': expected running, stopped, failed, done, restarting, terminating or unhealthy",
+                status
+            ));
+        }
+        let wanted = |p: &&ProcessInfo| match status {
+            None => true,
+            Some("unhealthy") => matches!(p.status.as_str(), "failed" | "stopped"),
+            Some(status) => p.status == status,
+        };
+
         match state {
             Some(snapshot) => {
                 let processes: Vec<Value> = snapshot
                     .processes
                     .iter()
+                    .filter(wanted)
                     .map(|p| {
                         let mut obj = json!({
                             "name": p.name,
                             "status": p.status,
                             "error": p.error
                         });
+                        if let Some(kind) = p.error.as_deref().and_then(crate::process::failure_kind) {
+                            obj["error_kind"] = json!(kind);
+                        }
                         if let Some(label) = &p.custom_label {
                             obj["custom_label"] = json!(label);
                         }
                         if let Some(color) = &p.custom_color {
                             obj["custom_color"] = json!(color);
                         }
+                        if let Some(color) = &p.color {
+                            obj["color"] = json!(color);
+                        }
                         obj
                     })
                     .collect();
@@ -123,6 +768,76 @@ impl IpcCommandHandler {
         }
     }
 
+    fn handle_describe(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcResponse {
+        let Some(name) = args.get("name").and_then(|v| v.as_str()) else {
+            return IpcResponse::err("missing required argument: name".to_string());
+        };
+        let found = state.and_then(|s| Some((s, s.processes.iter().find(|p| p.name == name)?)));
+        let Some((snapshot, process)) = found else {
+            return IpcResponse::err(format!("Unknown process: {}", name));
+        };
+        let detail = snapshot.process_details.get(name).cloned().unwrap_or_default();
+
+        IpcResponse::ok(json!({
+            "name": process.name,
+            "status": process.status,
+            "error": process.error,
+            "exit_code": detail.exit_code,
+            "exit_signal": detail.exit_signal,
+            "command": detail.command,
+            "cwd": detail.working_dir,
+            "env": detail.env,
+            "recent_lines": detail.recent_lines,
+            "last_error": detail.last_error,
+            "dropped_lines": detail.dropped_lines,
+            "custom_label": process.custom_label,
+            "custom_color": process.custom_color,
+            "color": process.color,
+            "hidden": snapshot.hidden_processes.contains(&process.name),
+        }))
+    }
+
+    fn handle_last_output(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcResponse {
+        let Some(name) = args.get("name").and_then(|v| v.as_str()) else {
+            return IpcResponse::err("missing required argument: name".to_string());
+        };
+        let strip = args.get("strip_ansi").and_then(|v| v.as_bool()).unwrap_or(false);
+        let Some(detail) = state.and_then(|s| s.process_details.get(name)) else {
+            return IpcResponse::err(format!("Unknown process: {}", name));
+        };
+        let Some(output) = &detail.final_output else {
+            return IpcResponse::err(format!("{} has not failed", name));
+        };
+
+        let lines: Vec<Value> = output
+            .lines
+            .iter()
+            .map(|log| {
+                json!({
+                    "id": log.id,
+                    "content": log_content(&log.content, strip),
+                    "timestamp": log.timestamp,
+                    "stderr": log.stderr,
+                    "run": log.run,
+                })
+            })
+            .collect();
+        IpcResponse::ok(json!({
+            "name": name,
+            "error": output.error,
+            "failed_at": output.failed_at,
+            "lines": lines,
+        }))
+    }
+
+    fn handle_colors(&self, state: Option<&StateSnapshot>) -> IpcResponse {
+        let colors = state.map(|s| s.process_colors.clone()).unwrap_or_default();
+        IpcResponse::ok(json!({
+            "coloring_enabled": !colors.is_empty(),
+            "colors": colors,
+        }))
+    }
+
     fn handle_logs(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcResponse {
         // Parse optional limit and offset from args
         let limit = args
@@ -135,24 +850,45 @@ impl IpcCommandHandler {
             .and_then(|v| v.as_u64())
             .map(|v| v as usize)
             .unwrap_or(0);
+        let strip = args.get("strip_ansi").and_then(|v| v.as_bool()).unwrap_or(false);
+        // Which output stream to keep: "stdout" drops stderr lines, "stderr" keeps only them
+        let stream = args.get("stream").and_then(|v| v.as_str()).unwrap_or("both");
+        if !matches!(stream, "stdout" | "stderr" | "both") {
+            return IpcResponse::err(format!(
+                "invalid stream '{}': expected stdout, stderr or both",
+                stream
+            ));
+        }
+
+        let with_batches = args.get("with_batches").and_then(|v| v.as_bool()).unwrap_or(false);
 
         match state {
             Some(snapshot) => {
-                // Apply offset and limit to recent_logs
-                let logs: Vec<Value> = snapshot
+                // Apply stream filter, then offset and limit to recent_logs
+                let window: Vec<&LogLineInfo> = snapshot
                     .recent_logs
                     .iter()
+                    .filter(|log| match stream {
+                        "stdout" => !log.stderr,
+                        "stderr" => log.stderr,
+                        _ => true,
+                    })
                     .skip(offset)
                     .take(limit)
-                    .map(|log| {
-                        json!({
-                            "id": log.id,
-                            "process": log.process,
-                            "content": log.content,
-                            "timestamp": log.timestamp,
-                            "batch_id": log.batch_id
-                        })
-                    })
+                    .collect();
+
+                // Batch detection only covers the returned window, so it stays cheap
+                let mut batch_ids = vec![None; window.len()];
+                if with_batches {
+                    for (start, end) in detect_log_batches(&window, &snapshot.view_mode) {
+                        batch_ids[start..=end].fill(Some(window[start].id as usize));
+                    }
+                }
+
+                let logs: Vec<Value> = window
+                    .iter()
+                    .zip(batch_ids)
+                    .map(|(log, batch_id)| log_entry(log, strip, batch_id))
                     .collect();
 
                 IpcResponse::ok(json!({
@@ -195,6 +931,7 @@ impl IpcCommandHandler {
             .get("case_sensitive")
             .and_then(|v| v.as_bool())
             .unwrap_or(false);
+        let strip = args.get("strip_ansi").and_then(|v| v.as_bool()).unwrap_or(false);
 
         // Create actions to update TUI: set search pattern and disable auto-scroll
         // so the user sees the same frozen view as the CLI results
@@ -215,19 +952,20 @@ impl IpcCommandHandler {
                     .recent_logs
                     .iter()
                     .rev() // Newest first
-                    .filter(|log| {
+                    .map(|log| (log, log_content(&log.content, strip)))
+                    .filter(|(_, content)| {
                         if case_sensitive {
-                            log.content.contains(pattern)
+                            content.contains(pattern)
                         } else {
-                            log.content.to_lowercase().contains(&pattern_lower)
+                            content.to_lowercase().contains(&pattern_lower)
                         }
                     })
                     .take(limit)
-                    .map(|log| {
+                    .map(|(log, content)| {
                         json!({
                             "id": log.id,
                             "process": log.process,
-                            "content": log.content,
+                            "content": content,
                             "timestamp": log.timestamp
                         })
                     })
@@ -260,6 +998,78 @@ impl IpcCommandHandler {
         }
     }
 
+    /// Move the selection to the next/previous line matching the active search pattern.
+    /// Steps through the lines the log view itself shows for the search
+    /// (`search_match_ids`, built with the view's filters).
+    fn handle_search_step(&self, state: Option<&StateSnapshot>, forward: bool) -> IpcHandlerResult {
+        let snapshot = match state {
+            Some(s) => s,
+            None => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "no state available".to_string(),
+                ));
+            }
+        };
+
+        let pattern = match snapshot.search_pattern.as_deref() {
+            Some(p) if !p.is_empty() => p,
+            _ => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "no active search pattern".to_string(),
+                ));
+            }
+        };
+
+        let matches = &snapshot.search_match_ids;
+        if matches.is_empty() {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                "no matches for pattern: {}",
+                pattern
+            )));
+        }
+
+        let total = matches.len();
+        let current = snapshot
+            .selected_line_id
+            .and_then(|id| matches.iter().position(|&m| m == id));
+
+        // Without a selected match, start from the newest match going backward
+        // or the oldest going forward; otherwise step with wrap-around.
+        let index = match (current, forward) {
+            (Some(i), true) => (i + 1) % total,
+            (Some(i), false) => (i + total - 1) % total,
+            (None, true) => match snapshot.selected_line_id {
+                Some(id) => matches.iter().position(|&m| m > id).unwrap_or(0),
+                None => 0,
+            },
+            (None, false) => match snapshot.selected_line_id {
+                Some(id) => matches.iter().rposition(|&m| m < id).unwrap_or(total - 1),
+                None => total - 1,
+            },
+        };
+
+        let id = matches[index];
+        // Matches older than the recent lines carry no details
+        let log = snapshot.recent_logs.iter().find(|log| log.id == id);
+        let actions = vec![
+            IpcAction::ScrollToLine { id },
+            IpcAction::SetAutoScroll { enabled: false },
+        ];
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "pattern": pattern,
+                "index": index,
+                "total": total,
+                "id": id,
+                "process": log.map(|log| &log.process),
+                "content": log.map(|log| &log.content),
+                "timestamp": log.map(|log| &log.timestamp)
+            })),
+            actions,
+        )
+    }
+
     fn handle_select(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
         // ID is required
         let id = match args.get("id").and_then(|v| v.as_u64()) {
@@ -272,16 +1082,12 @@ impl IpcCommandHandler {
         };
 
         // Verify the log line exists in current state
-        let line_exists = state
-            .map(|s| s.recent_logs.iter().any(|log| log.id == id))
-            .unwrap_or(false);
-
-        if !line_exists {
+        let Some(line) = state.and_then(|s| s.recent_logs.iter().find(|log| log.id == id)) else {
             return IpcHandlerResult::response_only(IpcResponse::err(format!(
                 "log line with id {} not found",
                 id
             )));
-        }
+        };
 
         // Emit action to select and expand the line, also disable auto-scroll
         let actions = vec![
@@ -289,13 +1095,14 @@ impl IpcCommandHandler {
             IpcAction::SetAutoScroll { enabled: false },
         ];
 
-        IpcHandlerResult::with_actions(
-            IpcResponse::ok(json!({
-                "selected": true,
-                "id": id
-            })),
-            actions,
-        )
+        let mut result = json!({
+            "selected": true,
+            "id": id,
+            "process": line.process,
+            "source_type": line.source_type
+        });
+        add_source_path(&mut result, line);
+        IpcHandlerResult::with_actions(IpcResponse::ok(result), actions)
     }
 
     fn handle_context(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
@@ -370,25 +1177,40 @@ impl IpcCommandHandler {
     }
 
     fn handle_goto(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
-        // ID is required
-        let id = match args.get("id").and_then(|v| v.as_u64()) {
-            Some(id) => id,
-            None => {
-                return IpcHandlerResult::response_only(IpcResponse::err(
-                    "missing required argument: id".to_string(),
-                ));
+        // Either a numeric id or a `<process>#<id>` reference is required
+        let (id, process) = if let Some(reference) = args.get("ref").and_then(|v| v.as_str()) {
+            match parse_reference(reference) {
+                Ok((process, id)) => (id, Some(process)),
+                Err(e) => return IpcHandlerResult::response_only(IpcResponse::err(e)),
+            }
+        } else {
+            match args.get("id").and_then(|v| v.as_u64()) {
+                Some(id) => (id, None),
+                None => {
+                    return IpcHandlerResult::response_only(IpcResponse::err(
+                        "missing required argument: id (or ref)".to_string(),
+                    ));
+                }
             }
         };
 
-        // Verify the log line exists in current state
+        // Verify the log line exists in current state (and belongs to the referenced process)
         let line_exists = state
-            .map(|s| s.recent_logs.iter().any(|log| log.id == id))
+            .map(|s| {
+                s.recent_logs
+                    .iter()
+                    .any(|log| log.id == id && process.is_none_or(|p| log.process == p))
+            })
             .unwrap_or(false);
 
         if !line_exists {
+            let target = match process {
+                Some(p) => format_reference(p, id),
+                None => format!("with id {}", id),
+            };
             return IpcHandlerResult::response_only(IpcResponse::err(format!(
-                "log line with id {} not found",
-                id
+                "log line {} not found",
+                target
             )));
         }
 
@@ -406,6 +1228,23 @@ impl IpcCommandHandler {
         )
     }
 
+    fn handle_reference(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcResponse {
+        let id = match args.get("id").and_then(|v| v.as_u64()) {
+            Some(id) => id,
+            None => return IpcResponse::err("missing required argument: id".to_string()),
+        };
+
+        let log = state.and_then(|s| s.recent_logs.iter().find(|log| log.id == id));
+        match log {
+            Some(log) => IpcResponse::ok(json!({
+                "reference": format_reference(&log.process, log.id),
+                "id": log.id,
+                "process": log.process,
+            })),
+            None => IpcResponse::err(format!("log line with id {} not found", id)),
+        }
+    }
+
     fn handle_scroll(&self, args: &Value, _state: Option<&StateSnapshot>) -> IpcHandlerResult {
         // Direction is required
         let direction = match args.get("direction").and_then(|v| v.as_str()) {
@@ -455,6 +1294,25 @@ impl IpcCommandHandler {
         }
     }
 
+    fn handle_scroll_percent(&self, args: &Value) -> IpcHandlerResult {
+        let Some(percent) = args.get("percent").and_then(|v| v.as_u64()) else {
+            return IpcHandlerResult::response_only(IpcResponse::err(
+                "missing required argument: percent".to_string(),
+            ));
+        };
+        let percent = percent.min(100);
+
+        // The offset depends on the filtered line count, which the main loop
+        // adds to the response as `offset` and `total` when it applies the action
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "percent": percent,
+                "auto_scroll": false
+            })),
+            vec![IpcAction::ScrollToPercent { percent }, IpcAction::SetAutoScroll { enabled: false }],
+        )
+    }
+
     fn handle_freeze(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
         // Parse mode: on, off, or toggle (default: toggle)
         let mode = args
@@ -486,6 +1344,88 @@ impl IpcCommandHandler {
         )
     }
 
+    fn handle_errors_only(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        // Parse mode: on, off, or toggle (default: toggle)
+        let mode = args
+            .get("mode")
+            .and_then(|v| v.as_str())
+            .unwrap_or("toggle");
+
+        let enabled = match mode {
+            "on" => true,
+            "off" => false,
+            "toggle" => state.map(|s| !s.view_mode.errors_only).unwrap_or(true),
+            _ => {
+                return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                    "invalid mode: {}. Valid options: on, off, toggle",
+                    mode
+                )));
+            }
+        };
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "errors_only": enabled,
+                "mode": mode
+            })),
+            vec![IpcAction::SetErrorsOnly { enabled }],
+        )
+    }
+
+    fn handle_set_view(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let mode_arg = args.get("display_mode").and_then(|v| v.as_str());
+        let wrap = args.get("wrap").and_then(|v| v.as_bool());
+        let auto_scroll = args.get("auto_scroll").and_then(|v| v.as_bool());
+
+        if mode_arg.is_none() && wrap.is_none() && auto_scroll.is_none() {
+            return IpcHandlerResult::response_only(IpcResponse::err(
+                "set_view requires at least one of: display_mode, wrap, auto_scroll",
+            ));
+        }
+
+        let current = state
+            .and_then(|s| DisplayMode::from_name(&s.view_mode.display_mode))
+            .unwrap_or_default();
+        let mut mode = match mode_arg {
+            Some(name) => match DisplayMode::from_name(name) {
+                Some(mode) => mode,
+                None => {
+                    return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                        "invalid display_mode: {}. Valid options: compact, full, wrap",
+                        name
+                    )));
+                }
+            },
+            None => current,
+        };
+        // wrap is shorthand for the wrap display mode; turning it off falls back to full
+        match wrap {
+            Some(true) => mode = DisplayMode::Wrap,
+            Some(false) if mode == DisplayMode::Wrap => mode = DisplayMode::Full,
+            _ => {}
+        }
+
+        let mut actions = Vec::new();
+        if mode_arg.is_some() || wrap.is_some() {
+            actions.push(IpcAction::SetDisplayMode { mode });
+        }
+        if let Some(enabled) = auto_scroll {
+            actions.push(IpcAction::SetAutoScroll { enabled });
+        }
+
+        let mut view_mode = state.map(|s| s.view_mode.clone()).unwrap_or_default();
+        view_mode.display_mode = mode.name().to_string();
+        let auto_scroll = auto_scroll.unwrap_or_else(|| state.map(|s| s.auto_scroll).unwrap_or(true));
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "view_mode": view_mode,
+                "auto_scroll": auto_scroll
+            })),
+            actions,
+        )
+    }
+
     fn handle_filters(&self, state: Option<&StateSnapshot>) -> IpcResponse {
         match state {
             Some(snapshot) => {
@@ -495,7 +1435,8 @@ impl IpcCommandHandler {
                     .map(|f| {
                         json!({
                             "pattern": f.pattern,
-                            "type": f.filter_type
+                            "type": f.filter_type,
+                            "enabled": f.enabled
                         })
                     })
                     .collect();
@@ -544,6 +1485,25 @@ impl IpcCommandHandler {
         )
     }
 
+    fn handle_filter_toggle(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let Some(pattern) = args.get("pattern").and_then(|v| v.as_str()) else {
+            return IpcHandlerResult::response_only(IpcResponse::err(
+                "missing required argument: pattern".to_string(),
+            ));
+        };
+        let Some(filter) = state.and_then(|s| s.active_filters.iter().find(|f| f.pattern == pattern)) else {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!("no filter with pattern: {}", pattern)));
+        };
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "pattern": pattern,
+                "enabled": !filter.enabled
+            })),
+            vec![IpcAction::ToggleFilter { pattern: pattern.to_string() }],
+        )
+    }
+
     fn handle_filter_remove(&self, args: &Value) -> IpcHandlerResult {
         // Pattern is required
         let pattern = match args.get("pattern").and_then(|v| v.as_str()) {
@@ -573,35 +1533,235 @@ impl IpcCommandHandler {
         )
     }
 
-    fn handle_visibility(&self, state: Option<&StateSnapshot>) -> IpcResponse {
-        match state {
-            Some(snapshot) => {
-                // Build visibility info for each process
-                let visibility: Vec<Value> = snapshot
-                    .processes
-                    .iter()
-                    .map(|p| {
-                        let is_hidden = snapshot.hidden_processes.contains(&p.name);
-                        let mut obj = json!({
-                            "name": p.name,
-                            "visible": !is_hidden,
-                            "status": p.status
-                        });
-                        if let Some(label) = &p.custom_label {
-                            obj["custom_label"] = json!(label);
-                        }
-                        if let Some(color) = &p.custom_color {
-                            obj["custom_color"] = json!(color);
-                        }
-                        obj
-                    })
-                    .collect();
+    fn handle_process_logs(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let Some(name) = args.get("name").and_then(|v| v.as_str()) else {
+            return IpcHandlerResult::response_only(IpcResponse::err("missing required argument: name".to_string()));
+        };
+        let known = state.is_some_and(|s| {
+            s.processes.iter().any(|p| p.name == name) || s.log_files.iter().any(|f| f == name)
+        });
+        if !known {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!("Process not found: {}", name)));
+        }
+        let limit = args.get("limit").and_then(|v| v.as_u64()).map(|v| v as usize).unwrap_or(100);
+        let strip_ansi = args.get("strip_ansi").and_then(|v| v.as_bool()).unwrap_or(false);
+        // The main loop fills in the lines from the whole buffer, not just the snapshot's recent logs
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({"process": name, "limit": limit})),
+            vec![IpcAction::ProcessLogs { name: name.to_string(), limit, strip_ansi }],
+        )
+    }
 
-                IpcResponse::ok(json!({
-                    "processes": visibility,
-                    "hidden_count": snapshot.hidden_processes.len(),
-                    "total_count": snapshot.processes.len()
-                }))
+    fn handle_recent(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let Some(secs) = args.get("secs").and_then(|v| v.as_u64()) else {
+            return IpcHandlerResult::response_only(IpcResponse::err("missing required argument: secs".to_string()));
+        };
+        let Some(snapshot) = state else {
+            return IpcHandlerResult::response_only(IpcResponse::err("TUI state not available".to_string()));
+        };
+        let limit = args.get("limit").and_then(|v| v.as_u64()).map(|v| v as usize).unwrap_or(100);
+        let strip = args.get("strip_ansi").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        // arrival_time, not timestamp: a line's own timestamp can be stale or from another clock
+        let since = chrono::Local::now() - chrono::Duration::seconds(secs as i64);
+        let in_window: Vec<&LogLineInfo> = snapshot
+            .recent_logs
+            .iter()
+            .filter(|log| {
+                log.arrival_time
+                    .as_deref()
+                    .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                    .is_some_and(|t| t >= since)
+            })
+            .collect();
+        let logs: Vec<Value> = in_window[in_window.len().saturating_sub(limit)..]
+            .iter()
+            .map(|log| {
+                let mut entry = json!({
+                    "id": log.id,
+                    "process": log.process,
+                    "content": log_content(&log.content, strip),
+                    "timestamp": log.timestamp,
+                    "arrival_time": log.arrival_time,
+                    "run": log.run,
+                    "source_type": log.source_type
+                });
+                add_source_path(&mut entry, log);
+                entry
+            })
+            .collect();
+
+        let response = IpcResponse::ok(json!({
+            "secs": secs,
+            "since": since.to_rfc3339(),
+            "count": in_window.len(),
+            "logs": logs
+        }));
+        if in_window.is_empty() {
+            IpcHandlerResult::response_only(response)
+        } else {
+            IpcHandlerResult::with_actions(response, vec![IpcAction::GotoRecent { secs }])
+        }
+    }
+
+    fn handle_histogram(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let Some(snapshot) = state else {
+            return IpcHandlerResult::response_only(IpcResponse::err("TUI state not available".to_string()));
+        };
+        let bucket_secs = args.get("bucket_secs").and_then(|v| v.as_u64()).unwrap_or(10);
+        let buckets = args.get("buckets").and_then(|v| v.as_u64()).unwrap_or(30);
+        if bucket_secs == 0
+            || bucket_secs > MAX_HISTOGRAM_BUCKET_SECS
+            || buckets == 0
+            || buckets > MAX_HISTOGRAM_BUCKETS
+        {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                "bucket_secs must be between 1 and {} and buckets between 1 and {}",
+                MAX_HISTOGRAM_BUCKET_SECS, MAX_HISTOGRAM_BUCKETS
+            )));
+        }
+        let per_process = args.get("per_process").and_then(|v| v.as_bool()).unwrap_or(false);
+
+        // Same window as `recent`: arrival_time over the snapshot's recent lines
+        let arrivals = snapshot.recent_logs.iter().rev().filter_map(|log| {
+            let arrival = chrono::DateTime::parse_from_rfc3339(log.arrival_time.as_deref()?).ok()?;
+            Some((log.process.as_str(), arrival.with_timezone(&chrono::Local)))
+        });
+        let histogram =
+            VolumeHistogram::compute(arrivals, chrono::Local::now(), bucket_secs as i64, buckets as usize, per_process);
+
+        let bucket_list: Vec<Value> = histogram
+            .counts
+            .iter()
+            .enumerate()
+            .map(|(i, count)| json!({"start": histogram.bucket_start(i).to_rfc3339(), "count": count}))
+            .collect();
+        let mut result = json!({
+            "bucket_secs": bucket_secs,
+            "since": histogram.start.to_rfc3339(),
+            "total": histogram.total(),
+            "buckets": bucket_list
+        });
+        if per_process {
+            result["per_process"] = json!(histogram.per_process);
+        }
+        IpcHandlerResult::response_only(IpcResponse::ok(result))
+    }
+
+    fn handle_copy_visible(&self, args: &Value) -> IpcHandlerResult {
+        let clipboard = args.get("clipboard").and_then(|v| v.as_bool()).unwrap_or(false);
+        // The main loop fills in text and line_count from the live view
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({"clipboard": clipboard})),
+            vec![IpcAction::CopyVisible { clipboard }],
+        )
+    }
+
+    fn handle_clipboard_copy(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let text = args.get("text").and_then(|v| v.as_str());
+        let id = args.get("id").and_then(|v| v.as_u64());
+        let (text, mut result) = match (text, id) {
+            (Some(text), None) => (text.to_string(), json!({})),
+            (None, Some(id)) => {
+                let Some(line) = state.and_then(|s| s.recent_logs.iter().find(|log| log.id == id)) else {
+                    return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                        "log line with id {} not found",
+                        id
+                    )));
+                };
+                (strip_ansi(&line.content), json!({"id": id, "process": line.process}))
+            }
+            (Some(_), Some(_)) => {
+                return IpcHandlerResult::response_only(IpcResponse::err("give either text or id, not both".to_string()));
+            }
+            (None, None) => {
+                return IpcHandlerResult::response_only(IpcResponse::err("missing required argument: text or id".to_string()));
+            }
+        };
+        if text.len() > MAX_CLIPBOARD_BYTES {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                "text is {} bytes; clipboard_copy accepts at most {}",
+                text.len(),
+                MAX_CLIPBOARD_BYTES
+            )));
+        }
+
+        result["bytes"] = json!(text.len());
+        // The main loop does the copy and reports a clipboard failure as an error
+        IpcHandlerResult::with_actions(IpcResponse::ok(result), vec![IpcAction::CopyText { text }])
+    }
+
+    fn handle_presets(&self, state: Option<&StateSnapshot>) -> IpcResponse {
+        let presets: Vec<Value> = state
+            .map(|snapshot| {
+                snapshot
+                    .presets
+                    .iter()
+                    .map(|p| json!({"name": p.name, "include": p.include, "exclude": p.exclude}))
+                    .collect()
+            })
+            .unwrap_or_default();
+        IpcResponse::ok(json!({
+            "presets": presets,
+            "count": presets.len()
+        }))
+    }
+
+    fn handle_preset_apply(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let name = match args.get("name").and_then(|v| v.as_str()) {
+            Some(n) => n.to_string(),
+            None => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "missing required argument: name".to_string(),
+                ));
+            }
+        };
+
+        // Resolve the preset if state is available
+        let preset = state.and_then(|s| s.presets.iter().find(|p| p.name == name));
+        if state.is_some() && preset.is_none() {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!("Unknown preset: {}", name)));
+        }
+
+        let filter_count = preset.map(|p| p.include.len() + p.exclude.len());
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "applied": name,
+                "filter_count": filter_count
+            })),
+            vec![IpcAction::ApplyPreset { name }],
+        )
+    }
+
+    fn handle_visibility(&self, state: Option<&StateSnapshot>) -> IpcResponse {
+        match state {
+            Some(snapshot) => {
+                // Build visibility info for each process
+                let visibility: Vec<Value> = snapshot
+                    .processes
+                    .iter()
+                    .map(|p| {
+                        let is_hidden = snapshot.hidden_processes.contains(&p.name);
+                        let mut obj = json!({
+                            "name": p.name,
+                            "visible": !is_hidden,
+                            "status": p.status
+                        });
+                        if let Some(label) = &p.custom_label {
+                            obj["custom_label"] = json!(label);
+                        }
+                        if let Some(color) = &p.custom_color {
+                            obj["custom_color"] = json!(color);
+                        }
+                        obj
+                    })
+                    .collect();
+
+                IpcResponse::ok(json!({
+                    "processes": visibility,
+                    "hidden_count": snapshot.hidden_processes.len(),
+                    "total_count": snapshot.processes.len()
+                }))
             }
             None => IpcResponse::ok(json!({
                 "processes": [],
@@ -682,6 +1842,9 @@ impl IpcCommandHandler {
     fn handle_restart(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
         // Name is optional - if not provided, restart all processes
         let name = args.get("name").and_then(|v| v.as_str());
+        let dry_run = args.get("dry_run").and_then(|v| v.as_bool()).unwrap_or(false);
+        let wait_ready = args.get("wait_ready").and_then(|v| v.as_bool()).unwrap_or(false);
+        let timeout_ms = args.get("timeout_ms").and_then(|v| v.as_u64()).unwrap_or(30000);
 
         match name {
             Some(name) => {
@@ -707,10 +1870,22 @@ impl IpcCommandHandler {
                     }
                 }
 
-                let actions: Vec<IpcAction> = resolved
+                if dry_run {
+                    return IpcHandlerResult::response_only(IpcResponse::ok(json!({
+                        "dry_run": true,
+                        "action": "restart",
+                        "processes": resolved,
+                        "targets": dry_run_targets(&resolved, state)
+                    })));
+                }
+
+                let mut actions: Vec<IpcAction> = resolved
                     .iter()
                     .map(|n| IpcAction::RestartProcess { name: n.clone() })
                     .collect();
+                if wait_ready {
+                    actions.push(IpcAction::WaitReady { names: resolved.clone(), timeout_ms });
+                }
 
                 IpcHandlerResult::with_actions(
                     IpcResponse::ok(json!({
@@ -721,13 +1896,37 @@ impl IpcCommandHandler {
                 )
             }
             None => {
+                // Restart-all only affects running processes
+                let running: Vec<String> = state
+                    .map(|s| {
+                        s.processes
+                            .iter()
+                            .filter(|p| p.status == "running")
+                            .map(|p| p.name.clone())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+                if dry_run {
+                    return IpcHandlerResult::response_only(IpcResponse::ok(json!({
+                        "dry_run": true,
+                        "action": "restart",
+                        "process": "all",
+                        "processes": running,
+                        "targets": dry_run_targets(&running, state)
+                    })));
+                }
+
                 // Restart all processes
+                let mut actions = vec![IpcAction::RestartAllProcesses];
+                if wait_ready {
+                    actions.push(IpcAction::WaitReady { names: running, timeout_ms });
+                }
                 IpcHandlerResult::with_actions(
                     IpcResponse::ok(json!({
                         "restarting": true,
                         "process": "all"
                     })),
-                    vec![IpcAction::RestartAllProcesses],
+                    actions,
                 )
             }
         }
@@ -765,6 +1964,15 @@ impl IpcCommandHandler {
             }
         }
 
+        if args.get("dry_run").and_then(|v| v.as_bool()).unwrap_or(false) {
+            return IpcHandlerResult::response_only(IpcResponse::ok(json!({
+                "dry_run": true,
+                "action": "kill",
+                "names": resolved,
+                "targets": dry_run_targets(&resolved, state)
+            })));
+        }
+
         let actions: Vec<IpcAction> = resolved
             .iter()
             .map(|n| IpcAction::KillProcess { name: n.clone() })
@@ -825,177 +2033,141 @@ impl IpcCommandHandler {
         )
     }
 
+    /// `kill_all` / `start_all`: report which processes the action will touch (by
+    /// status in the snapshot) and which it skips; the TUI reports per-process results.
+    fn handle_bulk(
+        state: Option<&StateSnapshot>,
+        action: &str,
+        target_statuses: &[&str],
+        ipc_action: IpcAction,
+    ) -> IpcHandlerResult {
+        let processes = state.map(|s| s.processes.as_slice()).unwrap_or_default();
+        let (targets, skipped): (Vec<_>, Vec<_>) =
+            processes.iter().partition(|p| target_statuses.contains(&p.status.as_str()));
+        let targets: Vec<&str> = targets.iter().map(|p| p.name.as_str()).collect();
+        let skipped: Vec<&str> = skipped.iter().map(|p| p.name.as_str()).collect();
+
+        IpcHandlerResult::with_actions(
+            // `names` and `failed` are replaced with the real outcome once the action runs
+            IpcResponse::ok(json!({
+                "action": action,
+                "names": targets,
+                "failed": [],
+                "skipped": skipped
+            })),
+            vec![ipc_action],
+        )
+    }
+
+    fn handle_set_buffer(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let mb = match args.get("mb").and_then(|v| v.as_u64()) {
+            Some(0) => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "mb must be at least 1".to_string(),
+                ));
+            }
+            Some(mb) => mb as usize,
+            None => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "missing required argument: mb".to_string(),
+                ));
+            }
+        };
+        let previous_mb = state.map(|s| s.buffer_stats.max_buffer_bytes / (1024 * 1024));
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "max_buffer_mb": mb,
+                "previous_mb": previous_mb
+            })),
+            vec![IpcAction::SetBufferLimit { mb }],
+        )
+    }
+
+    fn handle_process_add(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let name = match args.get("name").and_then(|v| v.as_str()) {
+            Some(n) if !n.is_empty() => n,
+            _ => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "missing required argument: name".to_string(),
+                ));
+            }
+        };
+        let command = match args.get("command").and_then(|v| v.as_str()) {
+            Some(c) if !c.trim().is_empty() => c,
+            _ => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "missing required argument: command".to_string(),
+                ));
+            }
+        };
+        let cwd = args.get("cwd").and_then(|v| v.as_str()).map(|s| s.to_string());
+
+        let mut env = HashMap::new();
+        if let Some(env_arg) = args.get("env").filter(|v| !v.is_null()) {
+            let Some(object) = env_arg.as_object() else {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "env must be an object of string values".to_string(),
+                ));
+            };
+            for (key, value) in object {
+                let Some(value) = value.as_str() else {
+                    return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                        "env value for {} must be a string",
+                        key
+                    )));
+                };
+                env.insert(key.clone(), value.to_string());
+            }
+        }
+
+        if let Some(s) = state
+            && (s.processes.iter().any(|p| p.name == name) || s.log_files.iter().any(|n| n == name))
+        {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                "process already exists: {}",
+                name
+            )));
+        }
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "added": true,
+                "name": name,
+                "command": command
+            })),
+            vec![IpcAction::AddProcess {
+                name: name.to_string(),
+                command: command.to_string(),
+                cwd,
+                env,
+            }],
+        )
+    }
+
+    fn handle_add_log_file(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let Some(name) = args.get("name").and_then(|v| v.as_str()).filter(|n| !n.trim().is_empty()) else {
+            return IpcHandlerResult::response_only(IpcResponse::err("missing required argument: name".to_string()));
+        };
+        let Some(path) = args.get("path").and_then(|v| v.as_str()).filter(|p| !p.trim().is_empty()) else {
+            return IpcHandlerResult::response_only(IpcResponse::err("missing required argument: path".to_string()));
+        };
+        if let Some(s) = state
+            && (s.processes.iter().any(|p| p.name == name) || s.log_files.iter().any(|n| n == name))
+        {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!("process already exists: {}", name)));
+        }
+
+        // The main loop resolves the path and adds `path` and `exists`
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({"added": true, "name": name})),
+            vec![IpcAction::AddLogFile { name: name.to_string(), path: path.to_string() }],
+        )
+    }
+
     fn handle_help(&self) -> IpcResponse {
         IpcResponse::ok(json!({
-            "commands": [
-                {
-                    "name": "ping",
-                    "description": "Check if TUI is running",
-                    "args": []
-                },
-                {
-                    "name": "status",
-                    "description": "Get TUI status including version, process count, and buffer usage",
-                    "args": []
-                },
-                {
-                    "name": "processes",
-                    "description": "List all processes and their current status",
-                    "args": []
-                },
-                {
-                    "name": "logs",
-                    "description": "Get recent log lines from the buffer",
-                    "args": [
-                        {"name": "limit", "type": "number", "default": 100, "description": "Maximum number of lines to return"},
-                        {"name": "offset", "type": "number", "default": 0, "description": "Number of lines to skip"}
-                    ]
-                },
-                {
-                    "name": "search",
-                    "description": "Search log lines for a pattern and highlight in TUI",
-                    "args": [
-                        {"name": "pattern", "type": "string", "required": true, "description": "Search pattern (substring match)"},
-                        {"name": "limit", "type": "number", "default": 100, "description": "Maximum matches to return"},
-                        {"name": "case_sensitive", "type": "boolean", "default": false, "description": "Enable case-sensitive matching"}
-                    ]
-                },
-                {
-                    "name": "select",
-                    "description": "Select a log line by ID and open expanded view in TUI",
-                    "args": [
-                        {"name": "id", "type": "number", "required": true, "description": "Log line ID to select"}
-                    ]
-                },
-                {
-                    "name": "context",
-                    "description": "Get context lines around a specific log line",
-                    "args": [
-                        {"name": "id", "type": "number", "required": true, "description": "Log line ID"},
-                        {"name": "before", "type": "number", "default": 5, "description": "Lines before target"},
-                        {"name": "after", "type": "number", "default": 5, "description": "Lines after target"}
-                    ]
-                },
-                {
-                    "name": "goto",
-                    "description": "Jump to a specific log line by ID (scrolls view without expanding)",
-                    "args": [
-                        {"name": "id", "type": "number", "required": true, "description": "Log line ID to scroll to"}
-                    ]
-                },
-                {
-                    "name": "scroll",
-                    "description": "Scroll the log view up, down, to top, or to bottom",
-                    "args": [
-                        {"name": "direction", "type": "string", "required": true, "description": "Scroll direction: up, down, top, or bottom"},
-                        {"name": "lines", "type": "number", "default": 20, "description": "Number of lines to scroll (for up/down)"}
-                    ]
-                },
-                {
-                    "name": "freeze",
-                    "description": "Freeze or unfreeze the TUI display (pauses auto-scroll)",
-                    "args": [
-                        {"name": "mode", "type": "string", "default": "toggle", "description": "Mode: on, off, or toggle"}
-                    ]
-                },
-                {
-                    "name": "help",
-                    "description": "List available IPC commands",
-                    "args": []
-                },
-                {
-                    "name": "trace",
-                    "description": "Get trace recording status and active trace info",
-                    "args": []
-                },
-                {
-                    "name": "filters",
-                    "description": "List current filters",
-                    "args": []
-                },
-                {
-                    "name": "filter_add",
-                    "description": "Add a new filter (persists to config file)",
-                    "args": [
-                        {"name": "pattern", "type": "string", "required": true, "description": "Filter pattern to match"},
-                        {"name": "exclude", "type": "boolean", "default": false, "description": "Exclude matching lines instead of including"}
-                    ]
-                },
-                {
-                    "name": "filter_remove",
-                    "description": "Remove a filter by pattern (persists to config file)",
-                    "args": [
-                        {"name": "pattern", "type": "string", "required": true, "description": "Filter pattern to remove"}
-                    ]
-                },
-                {
-                    "name": "filter_clear",
-                    "description": "Remove all filters (persists to config file)",
-                    "args": []
-                },
-                {
-                    "name": "visibility",
-                    "description": "List visibility status for all processes (which are shown/hidden)",
-                    "args": []
-                },
-                {
-                    "name": "hide",
-                    "description": "Hide a process from log view (runtime only, does not persist)",
-                    "args": [
-                        {"name": "name", "type": "string", "required": true, "description": "Process name to hide"}
-                    ]
-                },
-                {
-                    "name": "show",
-                    "description": "Show a hidden process (runtime only, does not persist)",
-                    "args": [
-                        {"name": "name", "type": "string", "required": true, "description": "Process name to show"}
-                    ]
-                },
-                {
-                    "name": "restart",
-                    "description": "Restart a process or all processes",
-                    "args": [
-                        {"name": "name", "type": "string", "required": false, "description": "Process name to restart (restarts all if omitted)"}
-                    ]
-                },
-                {
-                    "name": "kill",
-                    "description": "Kill a running process",
-                    "args": [
-                        {"name": "name", "type": "string", "required": true, "description": "Process name to kill"}
-                    ]
-                },
-                {
-                    "name": "start",
-                    "description": "Start a stopped process",
-                    "args": [
-                        {"name": "name", "type": "string", "required": true, "description": "Process name to start"}
-                    ]
-                },
-                {
-                    "name": "errors",
-                    "description": "Get recent log lines containing error or warning patterns",
-                    "args": [
-                        {"name": "limit", "type": "number", "default": 50, "description": "Maximum lines to return"},
-                        {"name": "level", "type": "string", "default": "error", "description": "Level filter: error, warning, or error_or_warning"},
-                        {"name": "process", "type": "string", "required": false, "description": "Filter by process name"}
-                    ]
-                },
-                {
-                    "name": "summary",
-                    "description": "Get comprehensive AI-friendly summary of current state",
-                    "args": []
-                },
-                {
-                    "name": "batch",
-                    "description": "Get all log lines from a specific batch",
-                    "args": [
-                        {"name": "id", "type": "number", "required": true, "description": "Batch ID to retrieve"},
-                        {"name": "scroll", "type": "boolean", "default": false, "description": "Scroll TUI to first line of batch"}
-                    ]
-                }
-            ],
+            "commands": command_list(),
             "version": self.version
         }))
     }
@@ -1005,6 +2177,7 @@ impl IpcCommandHandler {
             Some(snapshot) => {
                 IpcResponse::ok(json!({
                     "recording": snapshot.trace_recording,
+                    "recording_trace_id": snapshot.recording_trace_id,
                     "active_trace_id": snapshot.active_trace_id,
                     "trace_filter_active": snapshot.view_mode.trace_filter,
                     "trace_selection_active": snapshot.view_mode.trace_selection
@@ -1021,6 +2194,119 @@ impl IpcCommandHandler {
         }
     }
 
+    fn handle_trace_start(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        if let Some(recording) = state.filter(|s| s.trace_recording) {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                "trace recording already in progress ({}); stop it first",
+                recording.recording_trace_id.as_deref().unwrap_or("unnamed")
+            )));
+        }
+        let label = args.get("label").and_then(|v| v.as_str()).map(|s| s.to_string());
+
+        // The TUI assigns the trace_id when it applies the action
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({"recording": true, "label": label})),
+            vec![IpcAction::StartTraceRecording { label }],
+        )
+    }
+
+    fn handle_trace_stop(&self, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let Some(snapshot) = state.filter(|s| s.trace_recording) else {
+            return IpcHandlerResult::response_only(IpcResponse::err(
+                "no trace recording in progress".to_string(),
+            ));
+        };
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "recording": false,
+                "trace_id": snapshot.recording_trace_id
+            })),
+            vec![IpcAction::StopTraceRecording],
+        )
+    }
+
+    fn handle_trace_annotate(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let note = match args.get("note").and_then(|v| v.as_str()) {
+            Some(n) if !n.trim().is_empty() => n.to_string(),
+            _ => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "missing required argument: note".to_string(),
+                ));
+            }
+        };
+        let Some(snapshot) = state.filter(|s| s.trace_recording) else {
+            return IpcHandlerResult::response_only(IpcResponse::err(
+                "no trace recording in progress".to_string(),
+            ));
+        };
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({
+                "trace_id": snapshot.recording_trace_id,
+                "note": note
+            })),
+            vec![IpcAction::AnnotateTrace { note }],
+        )
+    }
+
+    fn handle_goto_error(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        if state.is_none() {
+            return IpcHandlerResult::response_only(IpcResponse::err("TUI state not available".to_string()));
+        }
+        let value = args.get("direction").and_then(|v| v.as_str()).unwrap_or("next");
+        let Some(direction) = JumpDirection::parse(value) else {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                "invalid direction '{}': expected next or prev",
+                value
+            )));
+        };
+        let from_id = args.get("from_id").and_then(|v| v.as_u64());
+
+        // The main loop resolves the line against the current view and adds its id
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({"direction": value})),
+            vec![IpcAction::GotoError { direction, from_id }],
+        )
+    }
+
+    fn handle_set_status(&self, args: &Value) -> IpcHandlerResult {
+        let message = match args.get("message").and_then(|v| v.as_str()) {
+            Some(m) if !m.trim().is_empty() => m.to_string(),
+            _ => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "missing required argument: message".to_string(),
+                ));
+            }
+        };
+        let kind = args.get("type").and_then(|v| v.as_str()).unwrap_or("info");
+        let Some(status_type) = StatusType::parse(kind) else {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                "invalid type '{}': expected info, success or error",
+                kind
+            )));
+        };
+
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({"message": message, "type": kind})),
+            vec![IpcAction::SetStatus { message, status_type }],
+        )
+    }
+
+    fn handle_tail_errors(&self, args: &Value) -> IpcHandlerResult {
+        let level = args.get("level").and_then(|v| v.as_str()).unwrap_or("error");
+        let Some(filter) = LevelFilter::parse(level) else {
+            return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                "invalid level '{}': expected error, warning or error_or_warning",
+                level
+            )));
+        };
+        IpcHandlerResult::with_actions(
+            IpcResponse::ok(json!({"subscribed": "tail_errors", "level": filter.as_str()})),
+            vec![IpcAction::TailErrors { level: filter }],
+        )
+    }
+
     fn handle_errors(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcResponse {
         let limit = args
             .get("limit")
@@ -1050,7 +2336,7 @@ impl IpcCommandHandler {
                         }
 
                         // Check for error/warning patterns
-                        if let Some(level) = detect_log_level(&log.content) {
+                        if let Some(level) = self.severity.detect(&log.content) {
                             match level_filter {
                                 "error" => level == "error",
                                 "warning" => level == "warning",
@@ -1063,7 +2349,7 @@ impl IpcCommandHandler {
                     })
                     .take(limit)
                     .map(|log| {
-                        let level = detect_log_level(&log.content).unwrap_or("error");
+                        let level = self.severity.detect(&log.content).unwrap_or("error");
                         json!({
                             "id": log.id,
                             "process": log.process,
@@ -1092,10 +2378,27 @@ impl IpcCommandHandler {
         }
     }
 
-    fn handle_summary(&self, state: Option<&StateSnapshot>) -> IpcResponse {
-        match state {
-            Some(snapshot) => {
-                // Count process statuses
+    /// The whole `StateSnapshot`, keeping only the newest `recent_logs` lines
+    fn handle_snapshot(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcResponse {
+        let Some(snapshot) = state else {
+            return IpcResponse::err("no state available".to_string());
+        };
+        let limit = args.get("recent_logs").and_then(|v| v.as_u64()).unwrap_or(20) as usize;
+
+        let mut snapshot = snapshot.clone();
+        let skip = snapshot.recent_logs.len().saturating_sub(limit);
+        snapshot.recent_logs.drain(..skip);
+
+        match serde_json::to_value(&snapshot) {
+            Ok(value) => IpcResponse::ok(value),
+            Err(e) => IpcResponse::err(format!("failed to serialize snapshot: {}", e)),
+        }
+    }
+
+    fn handle_summary(&self, state: Option<&StateSnapshot>) -> IpcResponse {
+        match state {
+            Some(snapshot) => {
+                // Count process statuses
                 let running_count = snapshot
                     .processes
                     .iter()
@@ -1130,6 +2433,9 @@ impl IpcCommandHandler {
                         if let Some(color) = &p.custom_color {
                             obj["custom_color"] = json!(color);
                         }
+                        if let Some(color) = &p.color {
+                            obj["color"] = json!(color);
+                        }
                         obj
                     })
                     .collect();
@@ -1140,7 +2446,7 @@ impl IpcCommandHandler {
                     .iter()
                     .rev()
                     .take(100)
-                    .filter(|log| detect_log_level(&log.content) == Some("error"))
+                    .filter(|log| self.severity.detect(&log.content) == Some("error"))
                     .collect();
 
                 let recent_error_count = error_logs.len();
@@ -1157,7 +2463,7 @@ impl IpcCommandHandler {
                 let filter_strings: Vec<String> = snapshot
                     .active_filters
                     .iter()
-                    .map(|f| format!("{} ({})", f.pattern, f.filter_type))
+                    .map(|f| format!("{} ({}{})", f.pattern, f.filter_type, if f.enabled { "" } else { ", off" }))
                     .collect();
 
                 IpcResponse::ok(json!({
@@ -1228,6 +2534,45 @@ impl IpcCommandHandler {
         }
     }
 
+    /// Run several commands against the same snapshot in one round-trip.
+    /// Responses come back in order; actions from all commands are applied together.
+    fn handle_pipeline(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
+        let commands = match args.get("commands").and_then(|v| v.as_array()) {
+            Some(commands) if !commands.is_empty() => commands,
+            _ => {
+                return IpcHandlerResult::response_only(IpcResponse::err(
+                    "missing required argument: commands (non-empty array of {command, args})".to_string(),
+                ));
+            }
+        };
+
+        let requests: Vec<IpcRequest> = match commands
+            .iter()
+            .map(|c| serde_json::from_value::<IpcRequest>(c.clone()))
+            .collect()
+        {
+            Ok(requests) => requests,
+            Err(e) => {
+                return IpcHandlerResult::response_only(IpcResponse::err(format!(
+                    "invalid pipeline command: {}",
+                    e
+                )));
+            }
+        };
+
+        let steps = requests
+            .iter()
+            .map(|request| {
+                if request.command == "pipeline" {
+                    IpcHandlerResult::response_only(IpcResponse::err("pipeline cannot be nested".to_string()))
+                } else {
+                    self.handle(request, state)
+                }
+            })
+            .collect();
+        IpcHandlerResult::pipeline(steps)
+    }
+
     fn handle_batch(&self, args: &Value, state: Option<&StateSnapshot>) -> IpcHandlerResult {
         // Batch ID is required
         let batch_id: usize = match args.get("id").and_then(|v| v.as_u64()) {
@@ -1247,10 +2592,17 @@ impl IpcCommandHandler {
         match state {
             Some(snapshot) => {
                 // Filter logs by batch_id (chronological order - oldest first within batch)
-                let batch_lines: Vec<Value> = snapshot
+                let mut batch: Vec<&LogLineInfo> = snapshot
                     .recent_logs
                     .iter()
                     .filter(|log| log.batch_id == Some(batch_id))
+                    .collect();
+                // IDs from `logs --with-batches` name the batch's first line
+                if batch.is_empty() {
+                    batch = batch_starting_at(snapshot, batch_id as u64);
+                }
+                let batch_lines: Vec<Value> = batch
+                    .iter()
                     .map(|log| {
                         json!({
                             "id": log.id,
@@ -1269,18 +2621,8 @@ impl IpcCommandHandler {
                 }
 
                 let count = batch_lines.len();
-                let process = snapshot
-                    .recent_logs
-                    .iter()
-                    .find(|log| log.batch_id == Some(batch_id))
-                    .map(|log| log.process.clone())
-                    .unwrap_or_default();
-
-                let first_line_id = snapshot
-                    .recent_logs
-                    .iter()
-                    .find(|log| log.batch_id == Some(batch_id))
-                    .map(|log| log.id);
+                let process = batch[0].process.clone();
+                let first_line_id = Some(batch[0].id);
 
                 let response = IpcResponse::ok(json!({
                     "batch_id": batch_id,
@@ -1312,45 +2654,79 @@ impl IpcCommandHandler {
     }
 }
 
-/// Detect if a log line contains error or warning patterns
-fn detect_log_level(content: &str) -> Option<&'static str> {
-    let content_lower = content.to_lowercase();
-
-    // Check for error patterns first (higher priority)
-    let error_patterns = [
-        "error", "fail", "failed", "panic", "exception", "fatal",
-    ];
-    let error_prefixes = ["[error]", "error:", "[err]", "err:"];
-
-    for prefix in &error_prefixes {
-        if content_lower.starts_with(prefix) {
-            return Some("error");
-        }
-    }
+/// Batch ranges among `logs`, using the TUI's batch window and strategy
+fn detect_log_batches(logs: &[&LogLineInfo], view: &ViewModeInfo) -> Vec<(usize, usize)> {
+    let strategy = BatchStrategy::parse(&view.batch_strategy).unwrap_or_default();
+    // Arrival times are RFC 3339 strings; a line without one joins the previous line's batch
+    let mut previous = chrono::DateTime::<chrono::Local>::default();
+    let lines: Vec<(&str, chrono::DateTime<chrono::Local>)> = logs
+        .iter()
+        .map(|log| {
+            let arrival = log.arrival_time.as_deref().unwrap_or(&log.timestamp);
+            if let Ok(time) = chrono::DateTime::parse_from_rfc3339(arrival) {
+                previous = time.with_timezone(&chrono::Local);
+            }
+            (log.process.as_str(), previous)
+        })
+        .collect();
+    detect_batches_by(&lines, view.batch_window_ms, strategy, |line| line.0, |line| line.1)
+}
 
-    for pattern in &error_patterns {
-        if content_lower.contains(pattern) {
-            return Some("error");
-        }
+/// The batch that begins at line `id`, detected from there forward
+fn batch_starting_at(snapshot: &StateSnapshot, id: u64) -> Vec<&LogLineInfo> {
+    let Some(start) = snapshot.recent_logs.iter().position(|log| log.id == id) else {
+        return Vec::new();
+    };
+    let rest: Vec<&LogLineInfo> = snapshot.recent_logs[start..].iter().collect();
+    match detect_log_batches(&rest, &snapshot.view_mode).first() {
+        Some(&(_, end)) => rest[..=end].to_vec(),
+        None => Vec::new(),
     }
+}
 
-    // Check for warning patterns
-    let warning_patterns = ["warn", "warning"];
-    let warning_prefixes = ["[warn]", "warn:", "[warning]", "warning:"];
+/// One line as returned by `logs` and `process_logs`
+pub fn log_entry(log: &LogLineInfo, strip: bool, batch_id: Option<usize>) -> Value {
+    let mut entry = json!({
+        "id": log.id,
+        "process": log.process,
+        "content": log_content(&log.content, strip),
+        "timestamp": log.timestamp,
+        "batch_id": batch_id.or(log.batch_id),
+        "run": log.run,
+        "source_type": log.source_type
+    });
+    add_source_path(&mut entry, log);
+    entry
+}
 
-    for prefix in &warning_prefixes {
-        if content_lower.starts_with(prefix) {
-            return Some("warning");
-        }
+/// Tell file-sourced lines apart when several files feed one process name
+fn add_source_path(entry: &mut Value, log: &LogLineInfo) {
+    if let Some(path) = &log.source_path {
+        entry["source_path"] = json!(path);
     }
+}
 
-    for pattern in &warning_patterns {
-        if content_lower.contains(pattern) {
-            return Some("warning");
-        }
+/// Log content for IPC output, without ANSI escape codes when `strip_ansi` was requested
+fn log_content(content: &str, strip: bool) -> Cow<'_, str> {
+    if strip {
+        Cow::Owned(strip_ansi(content))
+    } else {
+        Cow::Borrowed(content)
     }
+}
 
-    None
+/// Describe the processes a dry-run would affect, with their current status
+fn dry_run_targets(names: &[String], state: Option<&StateSnapshot>) -> Vec<Value> {
+    names
+        .iter()
+        .map(|name| {
+            let status = state
+                .and_then(|s| s.processes.iter().find(|p| &p.name == name))
+                .map(|p| p.status.as_str())
+                .unwrap_or("unknown");
+            json!({"name": name, "status": status})
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -1373,6 +2749,26 @@ mod tests {
         assert!(result.actions.is_empty());
     }
 
+    #[test]
+    fn info_reports_startup_paths() {
+        let handler = test_handler().with_paths(InstancePaths {
+            working_dir: "/home/dev/app".to_string(),
+            config_path: Some("/home/dev/app/.overitall.toml".to_string()),
+            procfiles: vec!["/home/dev/app/Procfile".to_string()],
+            socket_path: Some("/home/dev/app/.oit.sock".to_string()),
+        });
+        let result = handler.handle(&IpcRequest::new("info"), None);
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["version"], "0.1.0-test");
+        for field in ["working_dir", "config_path", "socket_path"] {
+            assert!(!data[field].as_str().unwrap().is_empty(), "{} empty", field);
+        }
+        assert_eq!(data["procfiles"], json!(["/home/dev/app/Procfile"]));
+        assert!(result.actions.is_empty());
+    }
+
     #[test]
     fn status_returns_version_and_running() {
         let handler = test_handler();
@@ -1447,6 +2843,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
                 ProcessInfo {
                     name: "worker".to_string(),
@@ -1454,6 +2851,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
             ],
             log_files: Vec::new(),
@@ -1467,6 +2865,9 @@ mod tests {
                 trace_filter: true,
                 trace_selection: false,
                 display_mode: "compact".to_string(),
+                errors_only: false,
+                batch_window_ms: 100,
+                batch_strategy: "time_window".to_string(),
             },
             auto_scroll: false,
             log_count: 1500,
@@ -1474,12 +2875,22 @@ mod tests {
                 buffer_bytes: 5000000,
                 max_buffer_bytes: 52428800,
                 usage_percent: 9.54,
+                ingest_backlog: 0,
             },
             trace_recording: true,
             active_trace_id: Some("abc123".to_string()),
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 1500,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let handler_result = handler.handle(&request, Some(&snapshot));
@@ -1536,6 +2947,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
                 ProcessInfo {
                     name: "worker".to_string(),
@@ -1543,6 +2955,7 @@ mod tests {
                     error: Some("Exit code: 1".to_string()),
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
             ],
             log_files: Vec::new(),
@@ -1556,9 +2969,18 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 0,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -1613,6 +3035,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -1620,6 +3048,11 @@ mod tests {
                     content: "Server started".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: Some(1),
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -1627,10 +3060,18 @@ mod tests {
                     content: "Processing job".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 1500,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -1675,6 +3116,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -1682,6 +3129,11 @@ mod tests {
                     content: "First log".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -1689,6 +3141,11 @@ mod tests {
                     content: "Second log".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 3,
@@ -1696,10 +3153,18 @@ mod tests {
                     content: "Third log".to_string(),
                     timestamp: "2025-12-17T10:00:02Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 3,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -1771,6 +3236,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -1778,6 +3249,11 @@ mod tests {
                     content: "Server started".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -1785,6 +3261,11 @@ mod tests {
                     content: "Error: connection failed".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 3,
@@ -1792,6 +3273,11 @@ mod tests {
                     content: "Processing job".to_string(),
                     timestamp: "2025-12-17T10:00:02Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 4,
@@ -1799,10 +3285,18 @@ mod tests {
                     content: "Job error: timeout".to_string(),
                     timestamp: "2025-12-17T10:00:03Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 4,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -1851,6 +3345,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -1858,6 +3358,11 @@ mod tests {
                     content: "Error: connection failed".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -1865,10 +3370,18 @@ mod tests {
                     content: "Job error: timeout".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 2,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -1911,6 +3424,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -1918,6 +3437,11 @@ mod tests {
                     content: "Log line 1".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -1925,6 +3449,11 @@ mod tests {
                     content: "Log line 2".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 3,
@@ -1932,6 +3461,11 @@ mod tests {
                     content: "Log line 3".to_string(),
                     timestamp: "2025-12-17T10:00:02Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 4,
@@ -1939,10 +3473,18 @@ mod tests {
                     content: "Log line 4".to_string(),
                     timestamp: "2025-12-17T10:00:03Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 4,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -1990,15 +3532,29 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![LogLineInfo {
                 id: 1,
                 process: "web".to_string(),
                 content: "Test log".to_string(),
                 timestamp: "2025-12-17T10:00:00Z".to_string(),
                 batch_id: None,
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: None,
             }],
             total_log_lines: 1,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2028,6 +3584,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 42,
@@ -2035,6 +3597,11 @@ mod tests {
                     content: "The important log".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 43,
@@ -2042,10 +3609,18 @@ mod tests {
                     content: "Another log".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 2,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2164,15 +3739,27 @@ mod tests {
                 trace_filter: true,
                 trace_selection: false,
                 display_mode: "full".to_string(),
+                errors_only: false,
+                batch_window_ms: 100,
+                batch_strategy: "time_window".to_string(),
             },
             auto_scroll: true,
             log_count: 0,
             buffer_stats: BufferStats::default(),
             trace_recording: true,
             active_trace_id: Some("abc123def".to_string()),
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 0,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2220,15 +3807,29 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![LogLineInfo {
                 id: 1,
                 process: "web".to_string(),
                 content: "Test log".to_string(),
                 timestamp: "2025-12-17T10:00:00Z".to_string(),
                 batch_id: None,
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: None,
             }],
             total_log_lines: 1,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2258,6 +3859,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 42,
@@ -2265,6 +3872,11 @@ mod tests {
                     content: "Target log line".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 43,
@@ -2272,10 +3884,18 @@ mod tests {
                     content: "Another log".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 2,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2293,6 +3913,87 @@ mod tests {
         assert_eq!(result.actions[1], IpcAction::SetAutoScroll { enabled: false });
     }
 
+    fn reference_snapshot() -> StateSnapshot {
+        StateSnapshot {
+            recent_logs: vec![
+                LogLineInfo {
+                    id: 4821,
+                    process: "web".to_string(),
+                    content: "GET /".to_string(),
+                    timestamp: "2025-12-17T10:00:00Z".to_string(),
+                    batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
+                },
+                LogLineInfo {
+                    id: 4822,
+                    process: "worker".to_string(),
+                    content: "job done".to_string(),
+                    timestamp: "2025-12-17T10:00:01Z".to_string(),
+                    batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
+                },
+            ],
+            total_log_lines: 2,
+            ..StateSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn reference_returns_process_and_id() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("reference", json!({"id": 4822}));
+        let result = handler.handle(&request, Some(&reference_snapshot()));
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["reference"], "worker#4822");
+        assert_eq!(data["process"], "worker");
+    }
+
+    #[test]
+    fn reference_with_unknown_id_returns_error() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("reference", json!({"id": 1}));
+        let result = handler.handle(&request, Some(&reference_snapshot()));
+
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("not found"));
+    }
+
+    #[test]
+    fn goto_by_reference_scrolls_to_line() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("goto", json!({"ref": "web#4821"}));
+        let result = handler.handle(&request, Some(&reference_snapshot()));
+
+        assert!(result.response.success);
+        assert_eq!(result.response.result.unwrap()["scrolled_to"], 4821);
+        assert_eq!(result.actions[0], IpcAction::ScrollToLine { id: 4821 });
+    }
+
+    #[test]
+    fn goto_by_reference_rejects_invalid_or_mismatched_refs() {
+        let handler = test_handler();
+        let snapshot = reference_snapshot();
+
+        let result = handler.handle(&IpcRequest::with_args("goto", json!({"ref": "web4821"})), Some(&snapshot));
+        assert!(result.response.error.unwrap().contains("invalid reference"));
+        assert!(result.actions.is_empty());
+
+        // Right id, wrong process
+        let result = handler.handle(&IpcRequest::with_args("goto", json!({"ref": "worker#4821"})), Some(&snapshot));
+        assert_eq!(result.response.error.unwrap(), "log line worker#4821 not found");
+        assert!(result.actions.is_empty());
+    }
+
     #[test]
     fn help_includes_goto_command() {
         let handler = test_handler();
@@ -2399,6 +4100,27 @@ mod tests {
         assert_eq!(result.actions[1], IpcAction::SetAutoScroll { enabled: false });
     }
 
+    #[test]
+    fn scroll_percent_clamps_and_emits_scroll_action() {
+        let handler = test_handler();
+        for (given, expected) in [(0, 0), (50, 50), (100, 100), (140, 100)] {
+            let request = IpcRequest::with_args("scroll_percent", json!({"percent": given}));
+            let result = handler.handle(&request, None);
+
+            let data = result.response.result.unwrap();
+            assert_eq!(data["percent"], expected);
+            assert_eq!(data["auto_scroll"], false);
+            assert_eq!(
+                result.actions,
+                vec![IpcAction::ScrollToPercent { percent: expected }, IpcAction::SetAutoScroll { enabled: false }]
+            );
+        }
+
+        let result = handler.handle(&IpcRequest::new("scroll_percent"), None);
+        assert!(result.response.error.unwrap().contains("percent"));
+        assert!(result.actions.is_empty());
+    }
+
     #[test]
     fn scroll_bottom_enables_auto_scroll() {
         let handler = test_handler();
@@ -2484,15 +4206,27 @@ mod tests {
                 trace_filter: false,
                 trace_selection: false,
                 display_mode: "compact".to_string(),
+                errors_only: false,
+                batch_window_ms: 100,
+                batch_strategy: "time_window".to_string(),
             },
             auto_scroll: true,
             log_count: 0,
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 0,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2528,15 +4262,27 @@ mod tests {
                 trace_filter: false,
                 trace_selection: false,
                 display_mode: "compact".to_string(),
+                errors_only: false,
+                batch_window_ms: 100,
+                batch_strategy: "time_window".to_string(),
             },
             auto_scroll: false,
             log_count: 0,
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 0,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2659,10 +4405,12 @@ mod tests {
                 FilterInfo {
                     pattern: "error".to_string(),
                     filter_type: "include".to_string(),
+                    enabled: true,
                 },
                 FilterInfo {
                     pattern: "debug".to_string(),
                     filter_type: "exclude".to_string(),
+                    enabled: true,
                 },
             ],
             search_pattern: None,
@@ -2672,9 +4420,18 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 0,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2836,6 +4593,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
                 ProcessInfo {
                     name: "worker".to_string(),
@@ -2843,6 +4601,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
             ],
             log_files: Vec::new(),
@@ -2856,9 +4615,18 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 0,
             hidden_processes: vec!["worker".to_string()],
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -2998,6 +4766,21 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn restart_with_wait_ready_holds_for_the_restarted_processes() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("restart", json!({"name": "web", "wait_ready": true, "timeout_ms": 5000}));
+        let result = handler.handle(&request, None);
+
+        assert_eq!(
+            result.actions,
+            vec![
+                IpcAction::RestartProcess { name: "web".to_string() },
+                IpcAction::WaitReady { names: vec!["web".to_string()], timeout_ms: 5000 },
+            ]
+        );
+    }
+
     #[test]
     fn kill_without_name_returns_error() {
         let handler = test_handler();
@@ -3058,6 +4841,66 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn process_add_returns_add_action() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args(
+            "process_add",
+            json!({"name": "tailer", "command": "tail -f log", "cwd": "tmp", "env": {"DEBUG": "1"}}),
+        );
+        let result = handler.handle(&request, Some(&snapshot_with_groups()));
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["added"], true);
+        assert_eq!(data["name"], "tailer");
+        assert_eq!(
+            result.actions,
+            vec![IpcAction::AddProcess {
+                name: "tailer".to_string(),
+                command: "tail -f log".to_string(),
+                cwd: Some("tmp".to_string()),
+                env: HashMap::from([("DEBUG".to_string(), "1".to_string())]),
+            }]
+        );
+    }
+
+    #[test]
+    fn process_add_requires_name_and_command() {
+        let handler = test_handler();
+
+        let result = handler.handle(&IpcRequest::with_args("process_add", json!({"command": "ls"})), None);
+        assert!(result.response.error.unwrap().contains("name"));
+
+        let result = handler.handle(&IpcRequest::with_args("process_add", json!({"name": "x"})), None);
+        assert!(result.response.error.unwrap().contains("command"));
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn process_add_rejects_duplicate_name() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("process_add", json!({"name": "puma", "command": "ls"}));
+        let result = handler.handle(&request, Some(&snapshot_with_groups()));
+
+        assert!(!result.response.success);
+        assert_eq!(result.response.error.unwrap(), "process already exists: puma");
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn process_add_rejects_non_string_env() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args(
+            "process_add",
+            json!({"name": "x", "command": "ls", "env": {"PORT": 3000}}),
+        );
+        let result = handler.handle(&request, None);
+
+        assert!(result.response.error.unwrap().contains("PORT"));
+        assert!(result.actions.is_empty());
+    }
+
     #[test]
     fn help_includes_process_control_commands() {
         let handler = test_handler();
@@ -3074,6 +4917,7 @@ mod tests {
         assert!(command_names.contains(&"restart"));
         assert!(command_names.contains(&"kill"));
         assert!(command_names.contains(&"start"));
+        assert!(command_names.contains(&"process_add"));
     }
 
     #[test]
@@ -3091,9 +4935,12 @@ mod tests {
             .unwrap();
 
         let args = restart_cmd["args"].as_array().unwrap();
-        assert_eq!(args.len(), 1);
+        assert_eq!(args.len(), 4);
         assert_eq!(args[0]["name"], "name");
         assert_eq!(args[0]["required"], false);
+        assert_eq!(args[1]["name"], "dry_run");
+        assert_eq!(args[2]["name"], "wait_ready");
+        assert_eq!(args[3]["name"], "timeout_ms");
     }
 
     #[test]
@@ -3111,9 +4958,10 @@ mod tests {
             .unwrap();
 
         let args = kill_cmd["args"].as_array().unwrap();
-        assert_eq!(args.len(), 1);
+        assert_eq!(args.len(), 2);
         assert_eq!(args[0]["name"], "name");
         assert_eq!(args[0]["required"], true);
+        assert_eq!(args[1]["name"], "dry_run");
     }
 
     #[test]
@@ -3136,42 +4984,6 @@ mod tests {
         assert_eq!(args[0]["required"], true);
     }
 
-    // Tests for detect_log_level helper function
-
-    #[test]
-    fn detect_log_level_finds_error_patterns() {
-        assert_eq!(detect_log_level("Error: connection failed"), Some("error"));
-        assert_eq!(detect_log_level("FATAL: out of memory"), Some("error"));
-        assert_eq!(detect_log_level("panic at line 42"), Some("error"));
-        assert_eq!(detect_log_level("exception thrown"), Some("error"));
-        assert_eq!(detect_log_level("Job failed with exit code 1"), Some("error"));
-        assert_eq!(detect_log_level("[ERROR] Something went wrong"), Some("error"));
-    }
-
-    #[test]
-    fn detect_log_level_finds_warning_patterns() {
-        assert_eq!(detect_log_level("Warning: deprecated function"), Some("warning"));
-        assert_eq!(detect_log_level("WARN: low memory"), Some("warning"));
-        assert_eq!(detect_log_level("[WARN] Resource usage high"), Some("warning"));
-        assert_eq!(detect_log_level("[WARNING] Connection unstable"), Some("warning"));
-    }
-
-    #[test]
-    fn detect_log_level_returns_none_for_normal_logs() {
-        assert_eq!(detect_log_level("Server started on port 3000"), None);
-        assert_eq!(detect_log_level("Processing job 123"), None);
-        assert_eq!(detect_log_level("Request completed in 42ms"), None);
-    }
-
-    #[test]
-    fn detect_log_level_error_takes_priority_over_warning() {
-        // If a line contains both error and warning patterns, error wins
-        assert_eq!(
-            detect_log_level("Error occurred, please check warnings"),
-            Some("error")
-        );
-    }
-
     // Tests for handle_errors
 
     #[test]
@@ -3210,6 +5022,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -3217,6 +5035,11 @@ mod tests {
                     content: "Server started".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -3224,6 +5047,11 @@ mod tests {
                     content: "Error: connection failed".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 3,
@@ -3231,10 +5059,18 @@ mod tests {
                     content: "Warning: low memory".to_string(),
                     timestamp: "2025-12-17T10:00:02Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 3,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3270,6 +5106,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -3277,6 +5119,11 @@ mod tests {
                     content: "Error: connection failed".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -3284,10 +5131,18 @@ mod tests {
                     content: "Warning: low memory".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 2,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3323,6 +5178,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -3330,6 +5191,11 @@ mod tests {
                     content: "Error: connection failed".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -3337,10 +5203,18 @@ mod tests {
                     content: "Warning: low memory".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 2,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3373,6 +5247,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -3380,6 +5260,11 @@ mod tests {
                     content: "Error: web error".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -3387,10 +5272,18 @@ mod tests {
                     content: "Error: worker error".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 2,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3424,6 +5317,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -3431,6 +5330,11 @@ mod tests {
                     content: "Error: first".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -3438,10 +5342,18 @@ mod tests {
                     content: "Error: second".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 2,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3458,6 +5370,48 @@ mod tests {
 
     // Tests for handle_summary
 
+    fn severity_snapshot() -> StateSnapshot {
+        let log = |id: u64, content: &str| LogLineInfo {
+            id,
+            process: "web".to_string(),
+            content: content.to_string(),
+            timestamp: "2025-12-10T10:00:00Z".to_string(),
+            batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
+        };
+        StateSnapshot {
+            recent_logs: vec![
+                log(1, "FATAL: cannot bind to port 3000"),
+                log(2, "health check failed, retrying"),
+                log(3, "request ok"),
+            ],
+            ..StateSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn summary_and_errors_use_custom_severity_patterns() {
+        let snapshot = severity_snapshot();
+
+        let default = test_handler().handle(&IpcRequest::new("summary"), Some(&snapshot));
+        assert_eq!(default.response.result.unwrap()["errors"]["recent_count"], 2);
+
+        let matcher = SeverityMatcher::new(&[r"\bfatal\b".to_string()], &[]).unwrap();
+        let handler = test_handler().with_severity(matcher);
+
+        let summary = handler.handle(&IpcRequest::new("summary"), Some(&snapshot)).response.result.unwrap();
+        assert_eq!(summary["errors"]["recent_count"], 1);
+        assert_eq!(summary["errors"]["last_error"]["id"], 1);
+
+        let errors = handler.handle(&IpcRequest::new("errors"), Some(&snapshot)).response.result.unwrap();
+        assert_eq!(errors["count"], 1);
+        assert_eq!(errors["errors"][0]["id"], 1);
+    }
+
     #[test]
     fn summary_without_state_returns_defaults() {
         let handler = test_handler();
@@ -3493,6 +5447,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
                 ProcessInfo {
                     name: "worker".to_string(),
@@ -3500,6 +5455,7 @@ mod tests {
                     error: Some("Exit code: 1".to_string()),
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
             ],
             log_files: Vec::new(),
@@ -3508,6 +5464,7 @@ mod tests {
             active_filters: vec![FilterInfo {
                 pattern: "debug".to_string(),
                 filter_type: "exclude".to_string(),
+                enabled: true,
             }],
             search_pattern: None,
             view_mode: ViewModeInfo {
@@ -3516,6 +5473,9 @@ mod tests {
                 trace_filter: false,
                 trace_selection: false,
                 display_mode: "compact".to_string(),
+                errors_only: false,
+                batch_window_ms: 100,
+                batch_strategy: "time_window".to_string(),
             },
             auto_scroll: false,
             log_count: 100,
@@ -3523,9 +5483,16 @@ mod tests {
                 buffer_bytes: 5000000,
                 max_buffer_bytes: 52428800,
                 usage_percent: 9.54,
+                ingest_backlog: 0,
             },
             trace_recording: true,
             active_trace_id: Some("abc123".to_string()),
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -3533,6 +5500,11 @@ mod tests {
                     content: "Server started".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -3540,10 +5512,18 @@ mod tests {
                     content: "Error: connection failed".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 1500,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3626,15 +5606,29 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![LogLineInfo {
                 id: 1,
                 process: "web".to_string(),
                 content: "Test log".to_string(),
                 timestamp: "2025-12-17T10:00:00Z".to_string(),
                 batch_id: Some(1),
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: None,
             }],
             total_log_lines: 1,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3664,6 +5658,12 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![
                 LogLineInfo {
                     id: 1,
@@ -3671,6 +5671,11 @@ mod tests {
                     content: "Other batch".to_string(),
                     timestamp: "2025-12-17T10:00:00Z".to_string(),
                     batch_id: Some(4),
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 2,
@@ -3678,6 +5683,11 @@ mod tests {
                     content: "Batch 5 line 1".to_string(),
                     timestamp: "2025-12-17T10:00:01Z".to_string(),
                     batch_id: Some(5),
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
                 LogLineInfo {
                     id: 3,
@@ -3685,10 +5695,18 @@ mod tests {
                     content: "Batch 5 line 2".to_string(),
                     timestamp: "2025-12-17T10:00:02Z".to_string(),
                     batch_id: Some(5),
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
                 },
             ],
             total_log_lines: 3,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3729,15 +5747,29 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![LogLineInfo {
                 id: 42,
                 process: "web".to_string(),
                 content: "Batch line".to_string(),
                 timestamp: "2025-12-17T10:00:00Z".to_string(),
                 batch_id: Some(5),
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: None,
             }],
             total_log_lines: 1,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let result = handler.handle(&request, Some(&snapshot));
@@ -3834,6 +5866,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
                 ProcessInfo {
                     name: "workers".to_string(),
@@ -3841,6 +5874,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
                 ProcessInfo {
                     name: "api".to_string(),
@@ -3848,6 +5882,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
             ],
             log_files: Vec::new(),
@@ -3861,9 +5896,18 @@ mod tests {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 0,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         }
     }
 
@@ -4024,4 +6068,1411 @@ mod tests {
             IpcAction::HideProcess { name } if name == "puma"
         ));
     }
+
+    fn ansi_snapshot() -> StateSnapshot {
+        StateSnapshot {
+            recent_logs: vec![LogLineInfo {
+                id: 1,
+                process: "web".to_string(),
+                content: "\x1b[31mERROR\x1b[0m db down".to_string(),
+                timestamp: "2025-12-10T10:00:00Z".to_string(),
+                batch_id: None,
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: None,
+            }],
+            total_log_lines: 1,
+            ..StateSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn logs_strip_ansi_returns_clean_content() {
+        let handler = test_handler();
+        let snapshot = ansi_snapshot();
+
+        let raw = handler.handle(&IpcRequest::new("logs"), Some(&snapshot));
+        assert_eq!(raw.response.result.unwrap()["logs"][0]["content"], "\x1b[31mERROR\x1b[0m db down");
+
+        let request = IpcRequest::with_args("logs", json!({"strip_ansi": true}));
+        let stripped = handler.handle(&request, Some(&snapshot));
+        assert_eq!(stripped.response.result.unwrap()["logs"][0]["content"], "ERROR db down");
+    }
+
+    #[test]
+    fn search_strip_ansi_matches_and_returns_clean_content() {
+        let handler = test_handler();
+        let snapshot = ansi_snapshot();
+
+        // The escape code splits "ERROR db" in the raw line
+        let raw = handler.handle(&IpcRequest::with_args("search", json!({"pattern": "error db"})), Some(&snapshot));
+        assert_eq!(raw.response.result.unwrap()["count"], 0);
+
+        let request = IpcRequest::with_args("search", json!({"pattern": "error db", "strip_ansi": true}));
+        let data = handler.handle(&request, Some(&snapshot)).response.result.unwrap();
+        assert_eq!(data["count"], 1);
+        assert_eq!(data["matches"][0]["content"], "ERROR db down");
+    }
+
+    fn search_step_snapshot(pattern: Option<&str>, selected: Option<u64>) -> StateSnapshot {
+        use super::super::state::FilterInfo;
+
+        let log = |id: u64, process: &str, content: &str| LogLineInfo {
+            id,
+            process: process.to_string(),
+            content: content.to_string(),
+            timestamp: "2025-12-10T10:00:00Z".to_string(),
+            batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
+        };
+
+        StateSnapshot {
+            search_pattern: pattern.map(|p| p.to_string()),
+            active_filters: vec![FilterInfo {
+                pattern: "noisy".to_string(),
+                filter_type: "exclude".to_string(),
+                enabled: true,
+            }],
+            recent_logs: vec![
+                log(1, "web", "ERROR one"),
+                log(2, "web", "all good"),
+                log(3, "web", "error noisy"),
+                log(4, "worker", "error hidden"),
+                log(5, "web", "error two"),
+            ],
+            hidden_processes: vec!["worker".to_string()],
+            // What the view shows for "error": 3 is filtered out, 4 hidden
+            search_match_ids: if pattern.is_some() { vec![1, 5] } else { Vec::new() },
+            selected_line_id: selected,
+            ..StateSnapshot::default()
+        }
+    }
+
+    #[test]
+    fn search_next_without_pattern_returns_error() {
+        let handler = test_handler();
+        let snapshot = search_step_snapshot(None, None);
+        let result = handler.handle(&IpcRequest::new("search_next"), Some(&snapshot));
+
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("no active search"));
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn search_next_steps_through_view_matches() {
+        let handler = test_handler();
+        let snapshot = search_step_snapshot(Some("error"), Some(1));
+        let result = handler.handle(&IpcRequest::new("search_next"), Some(&snapshot));
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["id"], 5);
+        assert_eq!(data["index"], 1);
+        assert_eq!(data["total"], 2);
+        assert_eq!(data["content"], "error two");
+        assert_eq!(result.actions[0], IpcAction::ScrollToLine { id: 5 });
+        assert_eq!(result.actions[1], IpcAction::SetAutoScroll { enabled: false });
+    }
+
+    #[test]
+    fn search_next_wraps_to_first_match() {
+        let handler = test_handler();
+        let snapshot = search_step_snapshot(Some("error"), Some(5));
+        let result = handler.handle(&IpcRequest::new("search_next"), Some(&snapshot));
+
+        let data = result.response.result.unwrap();
+        assert_eq!(data["id"], 1);
+        assert_eq!(data["index"], 0);
+    }
+
+    #[test]
+    fn search_prev_wraps_to_last_match() {
+        let handler = test_handler();
+        let snapshot = search_step_snapshot(Some("error"), Some(1));
+        let result = handler.handle(&IpcRequest::new("search_prev"), Some(&snapshot));
+
+        let data = result.response.result.unwrap();
+        assert_eq!(data["id"], 5);
+        assert_eq!(data["index"], 1);
+    }
+
+    #[test]
+    fn search_prev_without_selection_starts_at_newest_match() {
+        let handler = test_handler();
+        let snapshot = search_step_snapshot(Some("error"), None);
+        let result = handler.handle(&IpcRequest::new("search_prev"), Some(&snapshot));
+
+        let data = result.response.result.unwrap();
+        assert_eq!(data["id"], 5);
+    }
+
+    #[test]
+    fn search_next_from_non_matching_selection_moves_forward() {
+        let handler = test_handler();
+        let snapshot = search_step_snapshot(Some("error"), Some(2));
+        let result = handler.handle(&IpcRequest::new("search_next"), Some(&snapshot));
+
+        let data = result.response.result.unwrap();
+        assert_eq!(data["id"], 5);
+    }
+
+    #[test]
+    fn help_includes_search_step_commands() {
+        let handler = test_handler();
+        let result = handler.handle(&IpcRequest::new("help"), None);
+
+        let data = result.response.result.unwrap();
+        let names: Vec<&str> = data["commands"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|c| c["name"].as_str())
+            .collect();
+        assert!(names.contains(&"search_next"));
+        assert!(names.contains(&"search_prev"));
+    }
+
+    #[test]
+    fn errors_only_on_emits_action() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("errors_only", json!({"mode": "on"}));
+        let result = handler.handle(&request, None);
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["errors_only"], true);
+        assert_eq!(result.actions, vec![IpcAction::SetErrorsOnly { enabled: true }]);
+    }
+
+    #[test]
+    fn errors_only_toggle_uses_current_state() {
+        let handler = test_handler();
+        let mut snapshot = StateSnapshot::default();
+        snapshot.view_mode.errors_only = true;
+
+        let result = handler.handle(&IpcRequest::new("errors_only"), Some(&snapshot));
+
+        assert!(result.response.success);
+        assert_eq!(result.actions, vec![IpcAction::SetErrorsOnly { enabled: false }]);
+    }
+
+    #[test]
+    fn errors_only_with_invalid_mode_returns_error() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("errors_only", json!({"mode": "maybe"}));
+        let result = handler.handle(&request, None);
+
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("invalid mode"));
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn set_view_display_mode_emits_action() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("set_view", json!({"display_mode": "full"}));
+        let result = handler.handle(&request, Some(&StateSnapshot::default()));
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["view_mode"]["display_mode"], "full");
+        assert_eq!(result.actions, vec![IpcAction::SetDisplayMode { mode: DisplayMode::Full }]);
+    }
+
+    #[test]
+    fn set_view_wrap_toggles_wrap_mode() {
+        let handler = test_handler();
+        let mut snapshot = StateSnapshot::default();
+
+        let on = handler.handle(&IpcRequest::with_args("set_view", json!({"wrap": true})), Some(&snapshot));
+        assert_eq!(on.response.result.unwrap()["view_mode"]["display_mode"], "wrap");
+        assert_eq!(on.actions, vec![IpcAction::SetDisplayMode { mode: DisplayMode::Wrap }]);
+
+        snapshot.view_mode.display_mode = "wrap".to_string();
+        let off = handler.handle(&IpcRequest::with_args("set_view", json!({"wrap": false})), Some(&snapshot));
+        assert_eq!(off.response.result.unwrap()["view_mode"]["display_mode"], "full");
+        assert_eq!(off.actions, vec![IpcAction::SetDisplayMode { mode: DisplayMode::Full }]);
+
+        // wrap=false leaves a non-wrapping mode alone
+        snapshot.view_mode.display_mode = "compact".to_string();
+        let unchanged = handler.handle(&IpcRequest::with_args("set_view", json!({"wrap": false})), Some(&snapshot));
+        assert_eq!(unchanged.response.result.unwrap()["view_mode"]["display_mode"], "compact");
+    }
+
+    #[test]
+    fn set_view_auto_scroll_only_keeps_display_mode() {
+        let handler = test_handler();
+        let mut snapshot = StateSnapshot::default();
+        snapshot.view_mode.display_mode = "full".to_string();
+
+        let request = IpcRequest::with_args("set_view", json!({"auto_scroll": false}));
+        let result = handler.handle(&request, Some(&snapshot));
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["auto_scroll"], false);
+        assert_eq!(data["view_mode"]["display_mode"], "full");
+        assert_eq!(result.actions, vec![IpcAction::SetAutoScroll { enabled: false }]);
+    }
+
+    #[test]
+    fn set_view_rejects_unknown_display_mode_and_empty_args() {
+        let handler = test_handler();
+
+        let result = handler.handle(&IpcRequest::with_args("set_view", json!({"display_mode": "tiny"})), None);
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("invalid display_mode: tiny"));
+        assert!(result.actions.is_empty());
+
+        let result = handler.handle(&IpcRequest::new("set_view"), None);
+        assert!(!result.response.success);
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn kill_dry_run_reports_targets_without_actions() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("kill", json!({"name": "rails", "dry_run": true}));
+        let snapshot = snapshot_with_groups();
+
+        let result = handler.handle(&request, Some(&snapshot));
+
+        assert!(result.response.success);
+        assert!(result.actions.is_empty());
+        let data = result.response.result.unwrap();
+        assert_eq!(data["dry_run"], true);
+        assert_eq!(data["action"], "kill");
+        assert_eq!(data["names"], json!(["puma", "workers"]));
+        assert_eq!(
+            data["targets"],
+            json!([
+                {"name": "puma", "status": "running"},
+                {"name": "workers", "status": "running"}
+            ])
+        );
+    }
+
+    #[test]
+    fn restart_dry_run_with_name_reports_targets_without_actions() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("restart", json!({"name": "api", "dry_run": true}));
+        let snapshot = snapshot_with_groups();
+
+        let result = handler.handle(&request, Some(&snapshot));
+
+        assert!(result.response.success);
+        assert!(result.actions.is_empty());
+        let data = result.response.result.unwrap();
+        assert_eq!(data["dry_run"], true);
+        assert_eq!(data["action"], "restart");
+        assert_eq!(data["processes"], json!(["api"]));
+        assert_eq!(data["targets"], json!([{"name": "api", "status": "running"}]));
+    }
+
+    #[test]
+    fn restart_all_dry_run_lists_only_running_processes() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("restart", json!({"dry_run": true}));
+        let mut snapshot = snapshot_with_groups();
+        snapshot.processes[1].status = "stopped".to_string();
+
+        let result = handler.handle(&request, Some(&snapshot));
+
+        assert!(result.actions.is_empty());
+        let data = result.response.result.unwrap();
+        assert_eq!(data["process"], "all");
+        let mut processes: Vec<String> =
+            serde_json::from_value(data["processes"].clone()).unwrap();
+        processes.sort();
+        assert_eq!(processes, vec!["api", "puma"]);
+    }
+
+    #[test]
+    fn kill_dry_run_without_state_marks_status_unknown() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("kill", json!({"name": "web", "dry_run": true}));
+        let result = handler.handle(&request, None);
+
+        assert!(result.actions.is_empty());
+        let data = result.response.result.unwrap();
+        assert_eq!(data["targets"], json!([{"name": "web", "status": "unknown"}]));
+    }
+    #[test]
+    fn pipeline_runs_commands_against_one_snapshot() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args(
+            "pipeline",
+            json!({"commands": [
+                {"command": "logs", "args": {"limit": 1}},
+                {"command": "goto", "args": {"ref": "web#4821"}}
+            ]}),
+        );
+        let result = handler.handle(&request, Some(&reference_snapshot()));
+
+        assert!(result.response.success);
+        let responses = result.response.result.unwrap()["responses"].clone();
+        let responses = responses.as_array().unwrap();
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["success"], true);
+        assert_eq!(responses[0]["result"]["total"], 2);
+        assert_eq!(responses[1]["result"]["scrolled_to"], 4821);
+
+        // Each sub-command's actions stay with its own result for the caller to apply
+        assert!(result.actions.is_empty());
+        assert!(result.steps[0].actions.is_empty());
+        assert_eq!(
+            result.steps[1].actions,
+            vec![IpcAction::ScrollToLine { id: 4821 }, IpcAction::SetAutoScroll { enabled: false }]
+        );
+    }
+
+    #[test]
+    fn pipeline_reports_sub_command_errors_in_place() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args(
+            "pipeline",
+            json!({"commands": [{"command": "nope"}, {"command": "pipeline"}, {"command": "ping"}]}),
+        );
+        let result = handler.handle(&request, None);
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        assert!(data["responses"][0]["error"].as_str().unwrap().contains("unknown command"));
+        assert_eq!(data["responses"][1]["error"], "pipeline cannot be nested");
+        assert_eq!(data["responses"][2]["result"]["pong"], true);
+
+        let result = handler.handle(&IpcRequest::with_args("pipeline", json!({"commands": []})), None);
+        assert!(result.response.error.unwrap().contains("commands"));
+        let result = handler.handle(&IpcRequest::with_args("pipeline", json!({"commands": [{"args": {}}]})), None);
+        assert!(result.response.error.unwrap().contains("invalid pipeline command"));
+    }
+    #[test]
+    fn processes_and_colors_include_assigned_colors() {
+        use super::super::state::ProcessInfo;
+
+        let handler = test_handler();
+        let mut process_colors = HashMap::new();
+        process_colors.insert("web".to_string(), "green".to_string());
+        process_colors.insert("rails".to_string(), "#076678".to_string());
+        let snapshot = StateSnapshot {
+            processes: vec![ProcessInfo {
+                name: "web".to_string(),
+                status: "running".to_string(),
+                error: None,
+                custom_label: None,
+                custom_color: None,
+                color: Some("green".to_string()),
+            }],
+            process_colors,
+            ..StateSnapshot::default()
+        };
+
+        let result = handler.handle(&IpcRequest::new("processes"), Some(&snapshot));
+        assert_eq!(result.response.result.unwrap()["processes"][0]["color"], "green");
+
+        let result = handler.handle(&IpcRequest::new("colors"), Some(&snapshot));
+        let data = result.response.result.unwrap();
+        assert_eq!(data["coloring_enabled"], true);
+        assert_eq!(data["colors"], json!({"rails": "#076678", "web": "green"}));
+    }
+
+    #[test]
+    fn colors_is_empty_when_coloring_is_off() {
+        let handler = test_handler();
+        let result = handler.handle(&IpcRequest::new("colors"), Some(&StateSnapshot::default()));
+        let data = result.response.result.unwrap();
+        assert_eq!(data["coloring_enabled"], false);
+        assert_eq!(data["colors"], json!({}));
+    }
+
+    fn stream_snapshot() -> StateSnapshot {
+        let line = |id: u64, content: &str, stderr: bool| LogLineInfo {
+            id,
+            process: "web".to_string(),
+            content: content.to_string(),
+            timestamp: "2025-12-17T10:00:00Z".to_string(),
+            batch_id: None,
+            stderr,
+            run: None,
+            source_type: if stderr { "stderr" } else { "stdout" }.to_string(),
+            source_path: None,
+            arrival_time: None,
+        };
+        StateSnapshot {
+            recent_logs: vec![
+                line(1, "listening", false),
+                line(2, "deprecation warning", true),
+                line(3, "GET /", false),
+                line(4, "stack trace", true),
+            ],
+            total_log_lines: 4,
+            ..StateSnapshot::default()
+        }
+    }
+
+    fn stream_ids(stream: &str) -> Vec<u64> {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("logs", json!({"stream": stream}));
+        let result = handler.handle(&request, Some(&stream_snapshot()));
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        data["logs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|log| log["id"].as_u64().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn logs_stream_filters_by_output_stream() {
+        assert_eq!(stream_ids("stdout"), vec![1, 3]);
+        assert_eq!(stream_ids("stderr"), vec![2, 4]);
+        assert_eq!(stream_ids("both"), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn logs_stream_applies_before_offset_and_limit() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("logs", json!({"stream": "stderr", "offset": 1, "limit": 1}));
+        let result = handler.handle(&request, Some(&stream_snapshot()));
+
+        let data = result.response.result.unwrap();
+        let logs = data["logs"].as_array().unwrap();
+        assert_eq!(logs.len(), 1);
+        assert_eq!(logs[0]["id"], 4);
+    }
+
+    #[test]
+    fn logs_rejects_unknown_stream() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("logs", json!({"stream": "stdin"}));
+        let result = handler.handle(&request, Some(&stream_snapshot()));
+
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("invalid stream 'stdin'"));
+    }
+
+    #[test]
+    fn kill_all_emits_single_bulk_action_and_lists_targets() {
+        let handler = test_handler();
+        let mut snapshot = snapshot_with_groups();
+        snapshot.processes[1].status = "stopped".to_string();
+        snapshot.processes[2].status = "restarting".to_string();
+
+        let result = handler.handle(&IpcRequest::new("kill_all"), Some(&snapshot));
+
+        assert!(result.response.success);
+        assert_eq!(result.actions, vec![IpcAction::KillAllProcesses]);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["action"], "kill");
+        assert_eq!(data["names"], json!(["puma", "api"]));
+        assert_eq!(data["skipped"], json!(["workers"]));
+    }
+
+    #[test]
+    fn start_all_targets_stopped_and_failed_processes() {
+        let handler = test_handler();
+        let mut snapshot = snapshot_with_groups();
+        snapshot.processes[0].status = "failed".to_string();
+        snapshot.processes[1].status = "stopped".to_string();
+
+        let result = handler.handle(&IpcRequest::new("start_all"), Some(&snapshot));
+
+        assert!(result.response.success);
+        assert_eq!(result.actions, vec![IpcAction::StartAllProcesses]);
+        let data = result.response.result.unwrap();
+        assert_eq!(data["action"], "start");
+        assert_eq!(data["names"], json!(["puma", "workers"]));
+        assert_eq!(data["skipped"], json!(["api"]));
+    }
+
+    #[test]
+    fn kill_all_without_state_still_emits_action() {
+        let handler = test_handler();
+        let result = handler.handle(&IpcRequest::new("kill_all"), None);
+
+        assert!(result.response.success);
+        assert_eq!(result.actions, vec![IpcAction::KillAllProcesses]);
+        assert_eq!(result.response.result.unwrap()["names"], json!([]));
+    }
+
+    #[test]
+    fn every_dispatchable_command_appears_in_help() {
+        let handler = test_handler();
+        let response = handler.handle(&IpcRequest::new("help"), None).response;
+        let listed: Vec<String> = response.result.unwrap()["commands"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["name"].as_str().unwrap().to_string())
+            .collect();
+
+        assert_eq!(listed.len(), COMMANDS.len());
+        let unique: std::collections::HashSet<_> = listed.iter().collect();
+        assert_eq!(unique.len(), listed.len(), "duplicate command names in {:?}", listed);
+
+        for name in &listed {
+            let result = handler.handle(&IpcRequest::new(name.as_str()), Some(&snapshot_with_groups()));
+            let error = result.response.error.unwrap_or_default();
+            assert!(!error.starts_with("unknown command"), "{} is in help but doesn't dispatch", name);
+        }
+        let result = handler.handle(&IpcRequest::new("no_such_command"), None);
+        assert_eq!(result.response.error.as_deref(), Some("unknown command: no_such_command"));
+    }
+
+    #[test]
+    fn help_args_have_type_required_and_defaults() {
+        let commands = command_list();
+        let logs = commands.as_array().unwrap().iter().find(|c| c["name"] == "logs").unwrap();
+        let limit = &logs["args"][0];
+        assert_eq!(limit["name"], "limit");
+        assert_eq!(limit["type"], "number");
+        assert_eq!(limit["required"], false);
+        assert_eq!(limit["default"], 100);
+
+        let select = commands.as_array().unwrap().iter().find(|c| c["name"] == "select").unwrap();
+        assert_eq!(select["args"][0]["required"], true);
+        assert!(select["args"][0].get("default").is_none());
+    }
+
+    #[test]
+    fn describe_returns_full_detail_for_known_process() {
+        use super::super::state::{ProcessDetail, ProcessInfo};
+
+        let handler = test_handler();
+        let mut snapshot = StateSnapshot {
+            processes: vec![ProcessInfo {
+                name: "worker".to_string(),
+                status: "failed".to_string(),
+                error: Some("Exit code: 3".to_string()),
+                custom_label: Some("Draining".to_string()),
+                custom_color: Some("yellow".to_string()),
+                color: None,
+            }],
+            hidden_processes: vec!["worker".to_string()],
+            ..Default::default()
+        };
+        snapshot.process_details.insert(
+            "worker".to_string(),
+            ProcessDetail {
+                command: "bin/worker --queue default".to_string(),
+                working_dir: Some("services/jobs".to_string()),
+                env: [("QUEUE".to_string(), "default".to_string())].into(),
+                exit_code: Some(3),
+                exit_signal: None,
+                recent_lines: 42,
+                last_error: Some("ERROR: redis unavailable".to_string()),
+                dropped_lines: 7,
+                final_output: None,
+            },
+        );
+
+        let request = IpcRequest::with_args("describe", json!({"name": "worker"}));
+        let result = handler.handle(&request, Some(&snapshot));
+
+        assert!(result.response.success);
+        let body = result.response.result.unwrap();
+        assert_eq!(body["status"], "failed");
+        assert_eq!(body["exit_code"], 3);
+        assert!(body["exit_signal"].is_null());
+        assert_eq!(body["command"], "bin/worker --queue default");
+        assert_eq!(body["cwd"], "services/jobs");
+        assert_eq!(body["env"]["QUEUE"], "default");
+        assert_eq!(body["recent_lines"], 42);
+        assert_eq!(body["last_error"], "ERROR: redis unavailable");
+        assert_eq!(body["dropped_lines"], 7);
+        assert_eq!(body["custom_label"], "Draining");
+        assert_eq!(body["hidden"], true);
+    }
+
+    #[test]
+    fn describe_rejects_unknown_process() {
+        let handler = test_handler();
+        let snapshot = snapshot_with_groups();
+
+        let request = IpcRequest::with_args("describe", json!({"name": "nope"}));
+        let result = handler.handle(&request, Some(&snapshot));
+        assert!(!result.response.success);
+        assert_eq!(result.response.error.as_deref(), Some("Unknown process: nope"));
+
+        let missing = handler.handle(&IpcRequest::new("describe"), Some(&snapshot));
+        assert!(!missing.response.success);
+    }
+
+    #[test]
+    fn last_output_returns_lines_captured_at_failure() {
+        use super::super::state::{FinalOutputInfo, ProcessDetail};
+
+        let handler = test_handler();
+        let line = |id: u64, content: &str, stderr: bool| LogLineInfo {
+            id,
+            process: "worker".to_string(),
+            content: content.to_string(),
+            timestamp: "2025-12-17T10:00:00Z".to_string(),
+            batch_id: None,
+            stderr,
+            run: Some(2),
+            source_type: if stderr { "stderr" } else { "stdout" }.to_string(),
+            source_path: None,
+            arrival_time: None,
+        };
+        let mut snapshot = StateSnapshot::default();
+        snapshot.process_details.insert(
+            "worker".to_string(),
+            ProcessDetail {
+                final_output: Some(FinalOutputInfo {
+                    error: "Exit code: 1".to_string(),
+                    failed_at: "2025-12-17T10:00:01Z".to_string(),
+                    lines: vec![line(7, "\x1b[31mRuntimeError\x1b[0m", true), line(8, "  from worker.rb:12", true)],
+                }),
+                ..Default::default()
+            },
+        );
+        snapshot.process_details.insert("web".to_string(), ProcessDetail::default());
+
+        let request = IpcRequest::with_args("last_output", json!({"name": "worker", "strip_ansi": true}));
+        let result = handler.handle(&request, Some(&snapshot));
+        assert!(result.response.success);
+        let body = result.response.result.unwrap();
+        assert_eq!(body["error"], "Exit code: 1");
+        assert_eq!(body["failed_at"], "2025-12-17T10:00:01Z");
+        assert_eq!(body["lines"][0]["content"], "RuntimeError");
+        assert_eq!(body["lines"][1]["content"], "  from worker.rb:12");
+        assert_eq!(body["lines"][1]["stderr"], true);
+
+        let never_failed = handler.handle(&IpcRequest::with_args("last_output", json!({"name": "web"})), Some(&snapshot));
+        assert_eq!(never_failed.response.error.as_deref(), Some("web has not failed"));
+        let unknown = handler.handle(&IpcRequest::with_args("last_output", json!({"name": "nope"})), Some(&snapshot));
+        assert_eq!(unknown.response.error.as_deref(), Some("Unknown process: nope"));
+    }
+
+    #[test]
+    fn test_set_buffer_emits_action() {
+        let handler = test_handler();
+        let mut state = StateSnapshot::default();
+        state.buffer_stats.max_buffer_bytes = 50 * 1024 * 1024;
+        let request = IpcRequest::with_args("set_buffer", json!({"mb": 10}));
+        let result = handler.handle(&request, Some(&state));
+
+        assert!(result.response.success);
+        let body = result.response.result.unwrap();
+        assert_eq!(body["max_buffer_mb"], 10);
+        assert_eq!(body["previous_mb"], 50);
+        assert_eq!(result.actions, vec![IpcAction::SetBufferLimit { mb: 10 }]);
+    }
+
+    #[test]
+    fn test_set_buffer_rejects_zero_and_missing_mb() {
+        let handler = test_handler();
+        for args in [json!({"mb": 0}), json!({})] {
+            let result = handler.handle(&IpcRequest::with_args("set_buffer", args), None);
+            assert!(!result.response.success);
+            assert!(result.actions.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_snapshot_returns_major_sections_and_limits_recent_logs() {
+        let handler = test_handler();
+        let mut state = snapshot_with_groups();
+        state.hidden_processes = vec!["worker".to_string()];
+        state.pinned_processes = vec!["web".to_string()];
+        state.recent_logs = (1..=5)
+            .map(|id| LogLineInfo {
+                id,
+                process: "web".to_string(),
+                content: format!("line {}", id),
+                timestamp: "2025-12-17T10:00:00Z".to_string(),
+                batch_id: None,
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: None,
+            })
+            .collect();
+
+        let request = IpcRequest::with_args("snapshot", json!({"recent_logs": 2}));
+        let result = handler.handle(&request, Some(&state));
+
+        assert!(result.response.success);
+        let body = result.response.result.unwrap();
+        for section in ["processes", "active_filters", "view_mode", "buffer_stats", "hidden_processes", "trace_recording", "groups"] {
+            assert!(body.get(section).is_some(), "missing section {}", section);
+        }
+        assert_eq!(body["hidden_processes"], json!(["worker"]));
+        assert_eq!(body["pinned_processes"], json!(["web"]));
+        let ids: Vec<u64> = body["recent_logs"].as_array().unwrap().iter().map(|l| l["id"].as_u64().unwrap()).collect();
+        assert_eq!(ids, vec![4, 5]);
+    }
+
+    #[test]
+    fn test_snapshot_without_state_is_an_error() {
+        let result = test_handler().handle(&IpcRequest::new("snapshot"), None);
+        assert!(!result.response.success);
+    }
+
+    fn recording_snapshot() -> StateSnapshot {
+        StateSnapshot {
+            trace_recording: true,
+            recording_trace_id: Some("rec-1".to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_trace_start_emits_action_with_label() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("trace_start", json!({"label": "checkout bug"}));
+        let result = handler.handle(&request, Some(&StateSnapshot::default()));
+
+        assert!(result.response.success);
+        assert_eq!(
+            result.actions,
+            vec![IpcAction::StartTraceRecording { label: Some("checkout bug".to_string()) }]
+        );
+    }
+
+    #[test]
+    fn test_trace_start_while_recording_is_an_error() {
+        let handler = test_handler();
+        let result = handler.handle(&IpcRequest::new("trace_start"), Some(&recording_snapshot()));
+
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("rec-1"));
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn test_trace_stop_returns_recording_id() {
+        let handler = test_handler();
+        let result = handler.handle(&IpcRequest::new("trace_stop"), Some(&recording_snapshot()));
+
+        assert!(result.response.success);
+        assert_eq!(result.response.result.unwrap()["trace_id"], "rec-1");
+        assert_eq!(result.actions, vec![IpcAction::StopTraceRecording]);
+
+        let idle = handler.handle(&IpcRequest::new("trace_stop"), Some(&StateSnapshot::default()));
+        assert!(!idle.response.success);
+        assert!(idle.actions.is_empty());
+    }
+
+    #[test]
+    fn test_trace_annotate_requires_note_and_recording() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("trace_annotate", json!({"note": "clicked pay"}));
+
+        let result = handler.handle(&request, Some(&recording_snapshot()));
+        assert_eq!(result.actions, vec![IpcAction::AnnotateTrace { note: "clicked pay".to_string() }]);
+
+        assert!(!handler.handle(&request, Some(&StateSnapshot::default())).response.success);
+        let missing = handler.handle(&IpcRequest::new("trace_annotate"), Some(&recording_snapshot()));
+        assert_eq!(missing.response.error.as_deref(), Some("missing required argument: note"));
+    }
+
+    #[test]
+    fn processes_report_error_kind_for_launch_failures() {
+        use super::super::state::ProcessInfo;
+
+        let failed = |name: &str, error: &str| ProcessInfo {
+            name: name.to_string(),
+            status: "failed".to_string(),
+            error: Some(error.to_string()),
+            custom_label: None,
+            custom_color: None,
+            color: None,
+        };
+        let snapshot = StateSnapshot {
+            processes: vec![
+                failed("web", "command not found: rails (is it installed / on PATH?)"),
+                failed("worker", "permission denied: ./bin/worker (is it executable?)"),
+                failed("cron", "Exit code: 1"),
+            ],
+            ..Default::default()
+        };
+
+        let result = test_handler().handle(&IpcRequest::new("processes"), Some(&snapshot));
+        let data = result.response.result.unwrap();
+        let processes = data["processes"].as_array().unwrap();
+        assert_eq!(processes[0]["error_kind"], "command_not_found");
+        assert_eq!(processes[1]["error_kind"], "permission_denied");
+        assert!(processes[2].get("error_kind").is_none());
+    }
+
+    fn status_snapshot(statuses: &[(&str, &str)]) -> StateSnapshot {
+        StateSnapshot {
+            processes: statuses
+                .iter()
+                .map(|(name, status)| ProcessInfo {
+                    name: name.to_string(),
+                    status: status.to_string(),
+                    error: None,
+                    custom_label: None,
+                    custom_color: None,
+                    color: None,
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    fn process_names_with_status(snapshot: &StateSnapshot, status: &str) -> Vec<String> {
+        let request = IpcRequest::with_args("processes", json!({"status": status}));
+        let result = test_handler().handle(&request, Some(snapshot));
+        assert!(result.response.success, "{:?}", result.response.error);
+        result.response.result.unwrap()["processes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["name"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn processes_status_filter_returns_only_matching_processes() {
+        let snapshot = status_snapshot(&[("web", "running"), ("worker", "failed"), ("cron", "stopped"), ("api", "restarting")]);
+
+        assert_eq!(process_names_with_status(&snapshot, "failed"), vec!["worker"]);
+        assert_eq!(process_names_with_status(&snapshot, "restarting"), vec!["api"]);
+        assert_eq!(process_names_with_status(&snapshot, "unhealthy"), vec!["worker", "cron"]);
+    }
+
+    #[test]
+    fn processes_status_filter_with_no_match_is_an_empty_list() {
+        let snapshot = status_snapshot(&[("web", "running"), ("worker", "running")]);
+        assert!(process_names_with_status(&snapshot, "failed").is_empty());
+        assert!(process_names_with_status(&snapshot, "unhealthy").is_empty());
+
+        let request = IpcRequest::with_args("processes", json!({"status": "broken"}));
+        let result = test_handler().handle(&request, Some(&snapshot));
+        assert!(result.response.error.unwrap().contains("invalid status 'broken'"));
+    }
+
+    #[test]
+    fn tail_errors_subscribes_at_requested_level() {
+        let request = IpcRequest::with_args("tail_errors", json!({"level": "warning"}));
+        let result = test_handler().handle(&request, None);
+
+        assert!(result.response.success);
+        assert_eq!(result.response.result.unwrap()["level"], "warning");
+        assert_eq!(result.actions, vec![IpcAction::TailErrors { level: LevelFilter::Warning }]);
+
+        let result = test_handler().handle(&IpcRequest::new("tail_errors"), None);
+        assert_eq!(result.actions, vec![IpcAction::TailErrors { level: LevelFilter::Error }]);
+    }
+
+    #[test]
+    fn tail_errors_rejects_unknown_level() {
+        let request = IpcRequest::with_args("tail_errors", json!({"level": "info"}));
+        let result = test_handler().handle(&request, None);
+
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("invalid level 'info'"));
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn logs_include_process_run() {
+        let mut snapshot = stream_snapshot();
+        snapshot.recent_logs[0].run = Some(2);
+
+        let result = test_handler().handle(&IpcRequest::new("logs"), Some(&snapshot));
+        let data = result.response.result.unwrap();
+        let logs = data["logs"].as_array().unwrap();
+        assert_eq!(logs[0]["run"], 2);
+        assert!(logs[1]["run"].is_null());
+    }
+
+    #[test]
+    fn ping_with_state_reports_uptime_server_time_and_tick() {
+        let handler = test_handler();
+        let state = StateSnapshot {
+            uptime_secs: 3,
+            tick: 7,
+            ..Default::default()
+        };
+        let result = handler.handle(&IpcRequest::new("ping"), Some(&state));
+
+        let data = result.response.result.unwrap();
+        assert_eq!(data["pong"], true);
+        assert_eq!(data["uptime_secs"], 3);
+        assert_eq!(data["tick"], 7);
+        let server_time = data["server_time"].as_str().unwrap();
+        assert!(chrono::DateTime::parse_from_rfc3339(server_time).is_ok());
+    }
+
+    fn preset_snapshot() -> StateSnapshot {
+        StateSnapshot {
+            presets: vec![crate::ipc::state::PresetInfo {
+                name: "http_errors".to_string(),
+                include: vec!["status=5".to_string()],
+                exclude: vec!["/health".to_string()],
+            }],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn presets_lists_saved_presets() {
+        let handler = test_handler();
+        let result = handler.handle(&IpcRequest::new("presets"), Some(&preset_snapshot()));
+
+        let data = result.response.result.unwrap();
+        assert_eq!(data["count"], 1);
+        assert_eq!(data["presets"][0]["name"], "http_errors");
+        assert_eq!(data["presets"][0]["exclude"], json!(["/health"]));
+    }
+
+    #[test]
+    fn preset_apply_queues_action_for_known_preset() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("preset_apply", json!({"name": "http_errors"}));
+        let result = handler.handle(&request, Some(&preset_snapshot()));
+
+        assert!(result.response.success);
+        assert_eq!(result.response.result.unwrap()["filter_count"], 2);
+        assert!(matches!(&result.actions[..], [IpcAction::ApplyPreset { name }] if name == "http_errors"));
+    }
+
+    #[test]
+    fn preset_apply_rejects_unknown_or_missing_name() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("preset_apply", json!({"name": "nope"}));
+        let result = handler.handle(&request, Some(&preset_snapshot()));
+        assert_eq!(result.response.error.as_deref(), Some("Unknown preset: nope"));
+        assert!(result.actions.is_empty());
+
+        let result = handler.handle(&IpcRequest::new("preset_apply"), Some(&preset_snapshot()));
+        assert!(!result.response.success);
+    }
+
+    #[test]
+    fn copy_visible_queues_action_with_clipboard_flag() {
+        let handler = test_handler();
+        let result = handler.handle(&IpcRequest::new("copy_visible"), Some(&StateSnapshot::default()));
+        assert!(matches!(&result.actions[..], [IpcAction::CopyVisible { clipboard: false }]));
+
+        let request = IpcRequest::with_args("copy_visible", json!({"clipboard": true}));
+        let result = handler.handle(&request, None);
+        assert_eq!(result.response.result, Some(json!({"clipboard": true})));
+        assert!(matches!(&result.actions[..], [IpcAction::CopyVisible { clipboard: true }]));
+    }
+
+    #[test]
+    fn clipboard_copy_by_id_resolves_line_content() {
+        let handler = test_handler();
+        let line = LogLineInfo {
+            id: 42,
+            process: "web".to_string(),
+            content: "\x1b[31mdeploy failed\x1b[0m".to_string(),
+            timestamp: "2025-12-17T10:00:00Z".to_string(),
+            batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
+        };
+        let snapshot = StateSnapshot { recent_logs: vec![line], ..Default::default() };
+
+        let request = IpcRequest::with_args("clipboard_copy", json!({"id": 42}));
+        let result = handler.handle(&request, Some(&snapshot));
+        assert!(result.response.success);
+        assert_eq!(result.response.result, Some(json!({"id": 42, "process": "web", "bytes": 13})));
+        assert!(matches!(&result.actions[..], [IpcAction::CopyText { text }] if text == "deploy failed"));
+
+        let request = IpcRequest::with_args("clipboard_copy", json!({"id": 7}));
+        let result = handler.handle(&request, Some(&snapshot));
+        assert!(!result.response.success);
+        assert_eq!(result.response.error.as_deref(), Some("log line with id 7 not found"));
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn clipboard_copy_rejects_missing_and_oversized_text() {
+        let handler = test_handler();
+        let result = handler.handle(&IpcRequest::new("clipboard_copy"), None);
+        assert!(!result.response.success);
+
+        let huge = "x".repeat(MAX_CLIPBOARD_BYTES + 1);
+        let result = handler.handle(&IpcRequest::with_args("clipboard_copy", json!({"text": huge})), None);
+        assert!(result.response.error.unwrap().contains("at most"));
+        assert!(result.actions.is_empty());
+
+        let result = handler.handle(&IpcRequest::with_args("clipboard_copy", json!({"text": "hi"})), None);
+        assert!(matches!(&result.actions[..], [IpcAction::CopyText { text }] if text == "hi"));
+    }
+
+    #[test]
+    fn process_logs_queues_action_for_known_process() {
+        use super::super::state::ProcessInfo;
+
+        let handler = test_handler();
+        let snapshot = StateSnapshot {
+            processes: vec![ProcessInfo {
+                name: "web".to_string(),
+                status: "running".to_string(),
+                error: None,
+                custom_label: None,
+                custom_color: None,
+                color: None,
+            }],
+            log_files: vec!["nginx".to_string()],
+            ..Default::default()
+        };
+
+        let request = IpcRequest::with_args("process_logs", json!({"name": "web", "limit": 5}));
+        let result = handler.handle(&request, Some(&snapshot));
+        assert!(result.response.success);
+        assert!(matches!(
+            &result.actions[..],
+            [IpcAction::ProcessLogs { name, limit: 5, strip_ansi: false }] if name == "web"
+        ));
+
+        let request = IpcRequest::with_args("process_logs", json!({"name": "nginx"}));
+        let result = handler.handle(&request, Some(&snapshot));
+        assert!(matches!(&result.actions[..], [IpcAction::ProcessLogs { limit: 100, .. }]));
+    }
+
+    #[test]
+    fn process_logs_rejects_unknown_process() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("process_logs", json!({"name": "db"}));
+        let result = handler.handle(&request, Some(&StateSnapshot::default()));
+        assert!(!result.response.success);
+        assert_eq!(result.response.error.as_deref(), Some("Process not found: db"));
+        assert!(result.actions.is_empty());
+
+        let result = handler.handle(&IpcRequest::new("process_logs"), Some(&StateSnapshot::default()));
+        assert!(!result.response.success);
+    }
+
+    fn file_sourced_snapshot() -> StateSnapshot {
+        let mut snapshot = stream_snapshot();
+        snapshot.recent_logs[0].source_type = "file".to_string();
+        snapshot.recent_logs[0].source_path = Some("/var/log/rails.log".to_string());
+        snapshot.recent_logs[2].source_type = "standalone".to_string();
+        snapshot.recent_logs[2].source_path = Some("log/nginx.log".to_string());
+        snapshot
+    }
+
+    #[test]
+    fn logs_include_source_type_and_path_for_file_lines() {
+        let result = test_handler().handle(&IpcRequest::new("logs"), Some(&file_sourced_snapshot()));
+        let data = result.response.result.unwrap();
+        let logs = data["logs"].as_array().unwrap();
+
+        assert_eq!(logs[0]["source_type"], "file");
+        assert_eq!(logs[0]["source_path"], "/var/log/rails.log");
+        assert_eq!(logs[1]["source_type"], "stderr");
+        assert!(logs[1].get("source_path").is_none());
+        assert_eq!(logs[2]["source_type"], "standalone");
+        assert_eq!(logs[2]["source_path"], "log/nginx.log");
+    }
+
+    #[test]
+    fn select_reports_source_of_file_lines() {
+        let handler = test_handler();
+        let snapshot = file_sourced_snapshot();
+
+        let request = IpcRequest::with_args("select", json!({"id": 1}));
+        let data = handler.handle(&request, Some(&snapshot)).response.result.unwrap();
+        assert_eq!(data["process"], "web");
+        assert_eq!(data["source_type"], "file");
+        assert_eq!(data["source_path"], "/var/log/rails.log");
+
+        let request = IpcRequest::with_args("select", json!({"id": 2}));
+        let data = handler.handle(&request, Some(&snapshot)).response.result.unwrap();
+        assert_eq!(data["source_type"], "stderr");
+        assert!(data.get("source_path").is_none());
+    }
+
+    #[test]
+    fn wrong_typed_arguments_are_errors_not_defaults() {
+        let handler = test_handler();
+        let state = StateSnapshot::default();
+
+        for (command, args, expected) in [
+            ("logs", json!({"limit": "abc"}), "argument 'limit' must be a number"),
+            ("logs", json!({"limit": -5}), "argument 'limit' must be a non-negative integer"),
+            ("logs", json!({"strip_ansi": 1}), "argument 'strip_ansi' must be a boolean"),
+            ("describe", json!({"name": 7}), "argument 'name' must be a string"),
+            ("pipeline", json!({"commands": "ping"}), "argument 'commands' must be an array"),
+        ] {
+            let result = handler.handle(&IpcRequest::with_args(command, args), Some(&state));
+            assert!(!result.response.success, "{} should fail", command);
+            assert_eq!(result.response.error.as_deref(), Some(expected));
+        }
+    }
+
+    #[test]
+    fn numeric_and_boolean_strings_are_coerced() {
+        let handler = test_handler();
+        let state = StateSnapshot {
+            recent_logs: (1..=5)
+                .map(|id| LogLineInfo {
+                    id,
+                    process: "web".to_string(),
+                    content: format!("line {}", id),
+                    timestamp: "2025-12-17T10:00:00Z".to_string(),
+                    batch_id: None,
+                    stderr: false,
+                    run: None,
+                    source_type: "stdout".to_string(),
+                    source_path: None,
+                    arrival_time: None,
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let request = IpcRequest::with_args("logs", json!({"limit": "2", "strip_ansi": "true", "offset": null}));
+        let result = handler.handle(&request, Some(&state));
+
+        assert!(result.response.success);
+        assert_eq!(result.response.result.unwrap()["logs"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn set_status_emits_action_with_status_type() {
+        let handler = test_handler();
+        for (kind, status_type) in [("info", StatusType::Info), ("success", StatusType::Success), ("error", StatusType::Error)] {
+            let request = IpcRequest::with_args("set_status", json!({"message": "Agent investigating worker timeout", "type": kind}));
+            let result = handler.handle(&request, None);
+
+            assert!(result.response.success);
+            assert_eq!(result.response.result.unwrap()["type"], kind);
+            assert_eq!(
+                result.actions,
+                vec![IpcAction::SetStatus { message: "Agent investigating worker timeout".to_string(), status_type }]
+            );
+        }
+    }
+
+    #[test]
+    fn set_status_defaults_to_info_and_validates_type_and_message() {
+        let handler = test_handler();
+
+        let result = handler.handle(&IpcRequest::with_args("set_status", json!({"message": "hi"})), None);
+        assert_eq!(result.actions, vec![IpcAction::SetStatus { message: "hi".to_string(), status_type: StatusType::Info }]);
+
+        let result = handler.handle(&IpcRequest::with_args("set_status", json!({"message": "hi", "type": "warning"})), None);
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("expected info, success or error"));
+        assert!(result.actions.is_empty());
+
+        let result = handler.handle(&IpcRequest::with_args("set_status", json!({"message": " "})), None);
+        assert_eq!(result.response.error.as_deref(), Some("missing required argument: message"));
+    }
+
+    #[test]
+    fn clear_status_emits_clear_action() {
+        let result = test_handler().handle(&IpcRequest::new("clear_status"), None);
+        assert!(result.response.success);
+        assert_eq!(result.actions, vec![IpcAction::ClearStatus]);
+    }
+
+    #[test]
+    fn goto_error_emits_action_with_direction_and_from_id() {
+        let handler = test_handler();
+        let state = StateSnapshot::default();
+
+        let result = handler.handle(&IpcRequest::with_args("goto_error", json!({"direction": "prev", "from_id": 12})), Some(&state));
+        assert!(result.response.success);
+        assert_eq!(result.actions, vec![IpcAction::GotoError { direction: JumpDirection::Prev, from_id: Some(12) }]);
+
+        let result = handler.handle(&IpcRequest::new("goto_error"), Some(&state));
+        assert_eq!(result.actions, vec![IpcAction::GotoError { direction: JumpDirection::Next, from_id: None }]);
+    }
+
+    #[test]
+    fn goto_error_rejects_unknown_direction() {
+        let result = test_handler().handle(
+            &IpcRequest::with_args("goto_error", json!({"direction": "sideways"})),
+            Some(&StateSnapshot::default()),
+        );
+        assert!(!result.response.success);
+        assert!(result.response.error.unwrap().contains("expected next or prev"));
+        assert!(result.actions.is_empty());
+    }
+
+    fn arrival_snapshot(ages_secs: &[i64]) -> StateSnapshot {
+        let now = chrono::Local::now();
+        let recent_logs = ages_secs
+            .iter()
+            .enumerate()
+            .map(|(i, age)| LogLineInfo {
+                id: i as u64 + 1,
+                process: "web".to_string(),
+                content: format!("line {}", i + 1),
+                // Parsed timestamps are deliberately fresh; only arrival_time counts
+                timestamp: now.to_rfc3339(),
+                batch_id: None,
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: Some((now - chrono::Duration::seconds(*age)).to_rfc3339()),
+            })
+            .collect();
+        StateSnapshot { recent_logs, ..StateSnapshot::default() }
+    }
+
+    #[test]
+    fn logs_with_batches_assigns_batch_ids_within_the_window() {
+        let state = arrival_snapshot(&[60, 60, 30, 30, 30, 5]);
+        let batch_ids = |args: Value| -> Vec<Value> {
+            let result = test_handler().handle(&IpcRequest::with_args("logs", args), Some(&state));
+            result.response.result.unwrap()["logs"].as_array().unwrap().iter().map(|l| l["batch_id"].clone()).collect()
+        };
+
+        // Off by default
+        assert!(batch_ids(json!({})).iter().all(Value::is_null));
+
+        // Each batch is named after its first line in the window, even one cut by the offset
+        assert_eq!(batch_ids(json!({"with_batches": true})), vec![json!(1), json!(1), json!(3), json!(3), json!(3), json!(6)]);
+        assert_eq!(batch_ids(json!({"with_batches": true, "offset": 1, "limit": 4})), vec![json!(2), json!(3), json!(3), json!(3)]);
+
+        // The batch command resolves those IDs
+        let result = test_handler().handle(&IpcRequest::with_args("batch", json!({"id": 3})), Some(&state));
+        let data = result.response.result.unwrap();
+        let ids: Vec<u64> = data["lines"].as_array().unwrap().iter().map(|l| l["id"].as_u64().unwrap()).collect();
+        assert_eq!(ids, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn recent_returns_lines_inside_the_arrival_window() {
+        let state = arrival_snapshot(&[120, 45, 25, 3]);
+        let result = test_handler().handle(&IpcRequest::with_args("recent", json!({"secs": 30})), Some(&state));
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        let ids: Vec<u64> = data["logs"].as_array().unwrap().iter().map(|l| l["id"].as_u64().unwrap()).collect();
+        assert_eq!(ids, vec![3, 4]);
+        assert_eq!(data["count"], 2);
+        assert_eq!(result.actions, vec![IpcAction::GotoRecent { secs: 30 }]);
+    }
+
+    #[test]
+    fn recent_limit_keeps_the_newest_and_empty_window_has_no_action() {
+        let state = arrival_snapshot(&[20, 10, 5]);
+        let result = test_handler().handle(&IpcRequest::with_args("recent", json!({"secs": 60, "limit": 1})), Some(&state));
+        let data = result.response.result.unwrap();
+        assert_eq!(data["logs"][0]["id"], 3);
+        assert_eq!(data["count"], 3);
+
+        let result = test_handler().handle(&IpcRequest::with_args("recent", json!({"secs": 2})), Some(&state));
+        assert!(result.response.success);
+        assert_eq!(result.response.result.unwrap()["count"], 0);
+        assert!(result.actions.is_empty());
+
+        let result = test_handler().handle(&IpcRequest::new("recent"), Some(&state));
+        assert!(!result.response.success);
+    }
+
+    #[test]
+    fn histogram_counts_lines_per_arrival_bucket() {
+        let state = arrival_snapshot(&[95, 25, 22, 14, 3, 1]);
+        let result =
+            test_handler().handle(&IpcRequest::with_args("histogram", json!({"bucket_secs": 10, "buckets": 3})), Some(&state));
+
+        assert!(result.response.success);
+        let data = result.response.result.unwrap();
+        let counts: Vec<u64> = data["buckets"].as_array().unwrap().iter().map(|b| b["count"].as_u64().unwrap()).collect();
+        // [30s ago, 20s), [20s, 10s), [10s, now]; the 95s-old line is outside the window
+        assert_eq!(counts, vec![2, 1, 2]);
+        assert_eq!(data["total"], 5);
+        assert_eq!(data["bucket_secs"], 10);
+        assert!(data.get("per_process").is_none());
+        assert!(result.actions.is_empty());
+    }
+
+    #[test]
+    fn histogram_per_process_splits_the_same_buckets() {
+        let mut state = arrival_snapshot(&[50, 35, 15, 5]);
+        state.recent_logs[1].process = "worker".to_string();
+        state.recent_logs[3].process = "worker".to_string();
+        let result = test_handler()
+            .handle(&IpcRequest::with_args("histogram", json!({"bucket_secs": 30, "per_process": true})), Some(&state));
+
+        let data = result.response.result.unwrap();
+        // Defaults to 30 buckets; the last two hold every line
+        assert_eq!(data["buckets"].as_array().unwrap().len(), 30);
+        assert_eq!(data["buckets"][28]["count"], 2);
+        assert_eq!(data["buckets"][29]["count"], 2);
+        assert_eq!(data["per_process"]["web"][28], 1);
+        assert_eq!(data["per_process"]["web"][29], 1);
+        assert_eq!(data["per_process"]["worker"][28], 1);
+        assert_eq!(data["per_process"]["worker"][29], 1);
+    }
+
+    #[test]
+    fn histogram_rejects_empty_or_oversized_windows() {
+        let state = arrival_snapshot(&[1]);
+        for args in [
+            json!({"bucket_secs": 0}),
+            json!({"bucket_secs": 86_401}),
+            json!({"bucket_secs": u64::MAX}),
+            json!({"buckets": 0}),
+            json!({"buckets": 361}),
+        ] {
+            let result = test_handler().handle(&IpcRequest::with_args("histogram", args), Some(&state));
+            assert!(!result.response.success);
+        }
+        let result = test_handler().handle(&IpcRequest::new("histogram"), None);
+        assert!(!result.response.success);
+    }
+
+    #[test]
+    fn add_log_file_emits_action_and_rejects_duplicates() {
+        let handler = test_handler();
+        let request = IpcRequest::with_args("add_log_file", json!({"name": "jobs", "path": "log/jobs.log"}));
+        let result = handler.handle(&request, Some(&snapshot_with_groups()));
+        assert!(result.response.success);
+        assert!(matches!(
+            result.actions.as_slice(),
+            [IpcAction::AddLogFile { name, path }] if name == "jobs" && path == "log/jobs.log"
+        ));
+
+        let request = IpcRequest::with_args("add_log_file", json!({"name": "puma", "path": "log/puma.log"}));
+        let result = handler.handle(&request, Some(&snapshot_with_groups()));
+        assert_eq!(result.response.error.unwrap(), "process already exists: puma");
+        assert!(result.actions.is_empty());
+
+        let result = handler.handle(&IpcRequest::with_args("add_log_file", json!({"name": "jobs"})), None);
+        assert!(result.response.error.unwrap().contains("path"));
+    }
+
+    #[test]
+    fn filter_toggle_flips_known_filter_and_rejects_unknown() {
+        use super::super::state::FilterInfo;
+
+        let handler = test_handler();
+        let state = StateSnapshot {
+            active_filters: vec![FilterInfo {
+                pattern: "DEBUG".to_string(),
+                filter_type: "exclude".to_string(),
+                enabled: true,
+            }],
+            ..Default::default()
+        };
+
+        let request = IpcRequest::with_args("filter_toggle", json!({"pattern": "DEBUG"}));
+        let result = handler.handle(&request, Some(&state));
+        assert_eq!(result.response.result.unwrap()["enabled"], false);
+        assert!(matches!(result.actions.as_slice(), [IpcAction::ToggleFilter { pattern }] if pattern == "DEBUG"));
+
+        let request = IpcRequest::with_args("filter_toggle", json!({"pattern": "INFO"}));
+        let result = handler.handle(&request, Some(&state));
+        assert_eq!(result.response.error.unwrap(), "no filter with pattern: INFO");
+        assert!(result.actions.is_empty());
+    }
 }
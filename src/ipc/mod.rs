@@ -7,9 +7,10 @@ pub mod handler;
 pub mod protocol;
 pub mod server;
 pub mod state;
+pub mod subscription;
 
 pub use action::IpcAction;
 pub use client::IpcClient;
-pub use handler::IpcCommandHandler;
+pub use handler::{InstancePaths, IpcCommandHandler};
 pub use protocol::IpcRequest;
 pub use server::IpcServer;
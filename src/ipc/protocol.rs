@@ -1,6 +1,10 @@
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+/// Version of the request/response format. Bump it when a change would break
+/// older clients (renamed commands or fields, different result shapes).
+pub const PROTOCOL_VERSION: u32 = 1;
+
 /// Request message sent from CLI client to TUI server
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct IpcRequest {
@@ -38,6 +42,12 @@ pub struct IpcResponse {
     /// Error message on failure
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
+    /// Server's crate version (set on every response the server sends)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    /// Server's `PROTOCOL_VERSION` (absent from servers that predate it)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<u32>,
 }
 
 #[allow(dead_code)]
@@ -47,6 +57,8 @@ impl IpcResponse {
             success: true,
             result: Some(result),
             error: None,
+            version: None,
+            protocol: None,
         }
     }
 
@@ -55,6 +67,8 @@ impl IpcResponse {
             success: true,
             result: None,
             error: None,
+            version: None,
+            protocol: None,
         }
     }
 
@@ -63,6 +77,42 @@ impl IpcResponse {
             success: false,
             result: None,
             error: Some(message.into()),
+            version: None,
+            protocol: None,
+        }
+    }
+
+    /// Merge extra fields into an object result (used when applying an action adds detail)
+    pub fn merge_result(&mut self, extra: Value) {
+        if let (Some(Value::Object(result)), Value::Object(extra)) = (self.result.as_mut(), extra) {
+            result.extend(extra);
+        }
+    }
+
+    /// Stamp the envelope with this build's crate and protocol versions
+    pub fn with_versions(mut self) -> Self {
+        self.version = Some(env!("CARGO_PKG_VERSION").to_string());
+        self.protocol = Some(PROTOCOL_VERSION);
+        self
+    }
+
+    /// Warning for a client when the server speaks a different protocol version
+    pub fn protocol_mismatch(&self) -> Option<String> {
+        let server_version = self.version.as_deref().unwrap_or("unknown");
+        match self.protocol {
+            Some(PROTOCOL_VERSION) => None,
+            Some(protocol) => Some(format!(
+                "oit TUI {} speaks protocol {}, this CLI ({}) speaks protocol {}; restart oit or update the CLI",
+                server_version,
+                protocol,
+                env!("CARGO_PKG_VERSION"),
+                PROTOCOL_VERSION
+            )),
+            None => Some(format!(
+                "oit TUI predates protocol versioning, this CLI ({}) speaks protocol {}; restart oit",
+                env!("CARGO_PKG_VERSION"),
+                PROTOCOL_VERSION
+            )),
         }
     }
 }
@@ -166,4 +216,37 @@ mod tests {
         assert!(json.contains("result"));
         assert!(!json.contains("error"));
     }
+
+    #[test]
+    fn with_versions_stamps_envelope() {
+        let resp = IpcResponse::ok(json!({})).with_versions();
+        assert_eq!(resp.version.as_deref(), Some(env!("CARGO_PKG_VERSION")));
+        assert_eq!(resp.protocol, Some(PROTOCOL_VERSION));
+
+        let json = serde_json::to_value(&resp).unwrap();
+        assert_eq!(json["protocol"], PROTOCOL_VERSION);
+        assert!(resp.protocol_mismatch().is_none());
+    }
+
+    #[test]
+    fn protocol_mismatch_detects_other_and_missing_versions() {
+        let mut resp = IpcResponse::ok(json!({})).with_versions();
+        resp.protocol = Some(PROTOCOL_VERSION + 1);
+        assert!(resp.protocol_mismatch().unwrap().contains(&format!("protocol {}", PROTOCOL_VERSION + 1)));
+
+        // Responses from older servers have no version fields
+        let old: IpcResponse = serde_json::from_str(r#"{"success": true}"#).unwrap();
+        assert!(old.protocol_mismatch().unwrap().contains("predates"));
+    }
+
+    #[test]
+    fn merge_result_adds_fields_to_object_result() {
+        let mut resp = IpcResponse::ok(json!({"max_buffer_mb": 10}));
+        resp.merge_result(json!({"evicted_lines": 3}));
+        assert_eq!(resp.result, Some(json!({"max_buffer_mb": 10, "evicted_lines": 3})));
+
+        let mut failed = IpcResponse::err("nope");
+        failed.merge_result(json!({"evicted_lines": 3}));
+        assert_eq!(failed.result, None);
+    }
 }
@@ -9,11 +9,11 @@ use tokio::net::{UnixListener, UnixStream};
 use super::protocol::{IpcRequest, IpcResponse};
 
 /// Unique identifier for a client connection
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ConnectionId(u64);
 
 impl ConnectionId {
-    fn new(id: u64) -> Self {
+    pub(crate) fn new(id: u64) -> Self {
         Self(id)
     }
 }
@@ -171,7 +171,7 @@ impl IpcServer {
             .get_mut(&conn_id)
             .ok_or_else(|| io::Error::new(io::ErrorKind::NotConnected, "connection not found"))?;
 
-        let mut json = serde_json::to_string(&response)
+        let mut json = serde_json::to_string(&response.with_versions())
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
         json.push('\n');
 
@@ -342,6 +342,9 @@ mod tests {
         let parsed: IpcResponse = serde_json::from_str(&line).unwrap();
         assert!(parsed.success);
         assert_eq!(parsed.result, Some(json!({"status": "ok"})));
+        // Every response carries the envelope versions
+        assert_eq!(parsed.protocol, Some(crate::ipc::protocol::PROTOCOL_VERSION));
+        assert!(parsed.protocol_mismatch().is_none());
     }
 
     #[tokio::test]
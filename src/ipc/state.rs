@@ -2,7 +2,7 @@
 // These are simple data structures passed to the IPC handler for state-dependent commands
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Snapshot of application state for IPC commands
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -43,6 +43,30 @@ pub struct StateSnapshot {
     /// Active trace ID if any
     pub active_trace_id: Option<String>,
 
+    /// ID of the manual trace being recorded, if any
+    #[serde(default)]
+    pub recording_trace_id: Option<String>,
+
+    /// Seconds since oit started
+    #[serde(default)]
+    pub uptime_secs: u64,
+
+    /// Main-loop iteration count, so clients can tell the loop is turning
+    #[serde(default)]
+    pub tick: u64,
+
+    /// Saved filter presets, sorted by name
+    #[serde(default)]
+    pub presets: Vec<PresetInfo>,
+
+    /// Processes drawn first in the process list, in pin order
+    #[serde(default)]
+    pub pinned_processes: Vec<String>,
+
+    /// IDs of the lines the log view shows for the search pattern, oldest first
+    #[serde(default)]
+    pub search_match_ids: Vec<u64>,
+
     /// Recent log lines for IPC logs command
     pub recent_logs: Vec<LogLineInfo>,
 
@@ -51,6 +75,17 @@ pub struct StateSnapshot {
 
     /// List of hidden process names (runtime visibility state)
     pub hidden_processes: Vec<String>,
+
+    /// ID of the currently selected log line, if any
+    pub selected_line_id: Option<u64>,
+
+    /// Assigned display color per process/log file name (empty when coloring is off)
+    #[serde(default)]
+    pub process_colors: HashMap<String, String>,
+
+    /// Per-process detail for the `describe` command, keyed by process name
+    #[serde(default)]
+    pub process_details: HashMap<String, ProcessDetail>,
 }
 
 /// Information about a single process
@@ -72,6 +107,60 @@ pub struct ProcessInfo {
     /// Custom status color from config (e.g., "green", "yellow")
     #[serde(skip_serializing_if = "Option::is_none")]
     pub custom_color: Option<String>,
+
+    /// Display color assigned to the process name, when coloring is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+/// Detail about one process beyond `ProcessInfo`, gathered from its handle
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ProcessDetail {
+    /// Command as run through `sh -c`
+    pub command: String,
+
+    /// Working directory, when not the current one
+    pub working_dir: Option<String>,
+
+    /// Environment overrides from the Procfile
+    pub env: BTreeMap<String, String>,
+
+    /// Exit code of the last run, when it exited normally
+    pub exit_code: Option<i32>,
+
+    /// Signal that terminated the last run
+    pub exit_signal: Option<i32>,
+
+    /// Lines from this process among the snapshot's recent logs
+    pub recent_lines: usize,
+
+    /// Newest error line among the snapshot's recent logs
+    pub last_error: Option<String>,
+
+    /// Lines dropped because the process's output queue was full
+    #[serde(default)]
+    pub dropped_lines: u64,
+
+    /// Output captured at the process's most recent failure
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub final_output: Option<FinalOutputInfo>,
+}
+
+/// A process's last lines before it failed, for the `last_output` command
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FinalOutputInfo {
+    /// Failure message shown in the status bar
+    pub error: String,
+
+    /// When the failure was detected, ISO8601
+    pub failed_at: String,
+
+    /// Oldest first
+    pub lines: Vec<LogLineInfo>,
+}
+
+fn default_true() -> bool {
+    true
 }
 
 /// Information about a filter
@@ -82,6 +171,18 @@ pub struct FilterInfo {
 
     /// Filter type: "include" or "exclude"
     pub filter_type: String,
+
+    /// False while the filter is switched off (`:ft`, `filter_toggle`)
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+/// A saved filter preset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PresetInfo {
+    pub name: String,
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
 }
 
 /// View mode state
@@ -101,6 +202,18 @@ pub struct ViewModeInfo {
 
     /// Display mode: "compact", "full", or "wrap"
     pub display_mode: String,
+
+    /// Whether the errors-only quick filter is active
+    #[serde(default)]
+    pub errors_only: bool,
+
+    /// Batch window in milliseconds, for batch detection on request
+    #[serde(default)]
+    pub batch_window_ms: i64,
+
+    /// Batch strategy name: time_window or hybrid
+    #[serde(default)]
+    pub batch_strategy: String,
 }
 
 /// Information about a log line for IPC responses
@@ -120,6 +233,26 @@ pub struct LogLineInfo {
 
     /// Batch ID if available
     pub batch_id: Option<usize>,
+
+    /// Whether the line came from the process's stderr
+    #[serde(default)]
+    pub stderr: bool,
+
+    /// Which start of its process produced the line (1 = first run)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run: Option<u32>,
+
+    /// Where the line came from: stdout, stderr, file or standalone
+    #[serde(default)]
+    pub source_type: String,
+
+    /// File the line was read from, for file and standalone sources
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_path: Option<String>,
+
+    /// When oit received the line, ISO8601 (`timestamp` may be parsed from the line itself)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub arrival_time: Option<String>,
 }
 
 /// Buffer statistics
@@ -133,6 +266,10 @@ pub struct BufferStats {
 
     /// Buffer usage as a percentage (0.0 - 100.0)
     pub usage_percent: f64,
+
+    /// Lines received but not yet ingested (non-zero while catching up after a flood)
+    #[serde(default)]
+    pub ingest_backlog: usize,
 }
 
 impl Default for StateSnapshot {
@@ -150,9 +287,18 @@ impl Default for StateSnapshot {
             buffer_stats: BufferStats::default(),
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 0,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: HashMap::new(),
+            process_details: HashMap::new(),
         }
     }
 }
@@ -165,6 +311,9 @@ impl Default for ViewModeInfo {
             trace_filter: false,
             trace_selection: false,
             display_mode: "compact".to_string(),
+            errors_only: false,
+            batch_window_ms: 100,
+            batch_strategy: "time_window".to_string(),
         }
     }
 }
@@ -175,6 +324,7 @@ impl Default for BufferStats {
             buffer_bytes: 0,
             max_buffer_bytes: 0,
             usage_percent: 0.0,
+            ingest_backlog: 0,
         }
     }
 }
@@ -191,6 +341,7 @@ mod tests {
             error: None,
             custom_label: None,
             custom_color: None,
+            color: None,
         };
 
         let json = serde_json::to_string(&info).unwrap();
@@ -209,6 +360,7 @@ mod tests {
             error: Some("command not found: node".to_string()),
             custom_label: None,
             custom_color: None,
+            color: None,
         };
 
         let json = serde_json::to_string(&info).unwrap();
@@ -224,6 +376,7 @@ mod tests {
         let filter = FilterInfo {
             pattern: "error".to_string(),
             filter_type: "include".to_string(),
+            enabled: true,
         };
 
         let json = serde_json::to_string(&filter).unwrap();
@@ -241,6 +394,9 @@ mod tests {
             trace_filter: true,
             trace_selection: false,
             display_mode: "compact".to_string(),
+            errors_only: false,
+            batch_window_ms: 100,
+            batch_strategy: "time_window".to_string(),
         };
 
         let json = serde_json::to_string(&view).unwrap();
@@ -259,6 +415,7 @@ mod tests {
             buffer_bytes: 1024000,
             max_buffer_bytes: 10240000,
             usage_percent: 10.0,
+            ingest_backlog: 0,
         };
 
         let json = serde_json::to_string(&stats).unwrap();
@@ -279,6 +436,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
                 ProcessInfo {
                     name: "worker".to_string(),
@@ -286,6 +444,7 @@ mod tests {
                     error: None,
                     custom_label: None,
                     custom_color: None,
+                    color: None,
                 },
             ],
             log_files: Vec::new(),
@@ -295,10 +454,12 @@ mod tests {
                 FilterInfo {
                     pattern: "error".to_string(),
                     filter_type: "include".to_string(),
+                    enabled: true,
                 },
                 FilterInfo {
                     pattern: "debug".to_string(),
                     filter_type: "exclude".to_string(),
+                    enabled: true,
                 },
             ],
             search_pattern: Some("panic".to_string()),
@@ -308,6 +469,9 @@ mod tests {
                 trace_filter: false,
                 trace_selection: false,
                 display_mode: "compact".to_string(),
+                errors_only: false,
+                batch_window_ms: 100,
+                batch_strategy: "time_window".to_string(),
             },
             auto_scroll: false,
             log_count: 1523,
@@ -315,18 +479,33 @@ mod tests {
                 buffer_bytes: 5120000,
                 max_buffer_bytes: 10240000,
                 usage_percent: 50.0,
+                ingest_backlog: 0,
             },
             trace_recording: true,
             active_trace_id: Some("abc123".to_string()),
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: vec![LogLineInfo {
                 id: 1,
                 process: "web".to_string(),
                 content: "Server started".to_string(),
                 timestamp: "2025-12-17T10:00:00Z".to_string(),
                 batch_id: Some(1),
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: None,
             }],
             total_log_lines: 1523,
             hidden_processes: vec!["worker".to_string()],
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let json = serde_json::to_string(&snapshot).unwrap();
@@ -378,6 +557,7 @@ mod tests {
                 error: None,
                 custom_label: None,
                 custom_color: None,
+                color: None,
             }],
             log_files: Vec::new(),
             groups: HashMap::new(),
@@ -385,6 +565,7 @@ mod tests {
             active_filters: vec![FilterInfo {
                 pattern: "info".to_string(),
                 filter_type: "include".to_string(),
+                enabled: true,
             }],
             search_pattern: None,
             view_mode: ViewModeInfo::default(),
@@ -394,12 +575,22 @@ mod tests {
                 buffer_bytes: 1000,
                 max_buffer_bytes: 10000,
                 usage_percent: 10.0,
+                ingest_backlog: 0,
             },
             trace_recording: false,
             active_trace_id: None,
+            recording_trace_id: None,
+            uptime_secs: 0,
+            tick: 0,
+            presets: Vec::new(),
+            pinned_processes: Vec::new(),
+            search_match_ids: Vec::new(),
             recent_logs: Vec::new(),
             total_log_lines: 100,
             hidden_processes: Vec::new(),
+            selected_line_id: None,
+            process_colors: std::collections::HashMap::new(),
+            process_details: std::collections::HashMap::new(),
         };
 
         let json = serde_json::to_string_pretty(&snapshot).unwrap();
@@ -427,6 +618,11 @@ mod tests {
             content: "Server started on port 3000".to_string(),
             timestamp: "2025-12-17T10:00:00Z".to_string(),
             batch_id: Some(5),
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         };
 
         let json = serde_json::to_string(&log).unwrap();
@@ -447,6 +643,11 @@ mod tests {
             content: "Processing job".to_string(),
             timestamp: "2025-12-17T10:01:00Z".to_string(),
             batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         };
 
         let json = serde_json::to_string(&log).unwrap();
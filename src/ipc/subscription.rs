@@ -0,0 +1,342 @@
+// Streaming subscriptions: connections that asked to be pushed matching new
+// log lines as they arrive, instead of polling, and `restart` requests with
+// `wait_ready` whose response is held until the processes are back up
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde_json::json;
+
+use super::protocol::IpcResponse;
+use super::server::ConnectionId;
+use crate::log::{LogLine, SeverityMatcher};
+use crate::process::Readiness;
+
+/// Lines of a process's output included when a `wait_ready` restart fails
+const WAIT_READY_OUTPUT_LINES: usize = 10;
+
+/// Which detected levels a `tail_errors` subscriber wants
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelFilter {
+    Error,
+    Warning,
+    ErrorOrWarning,
+}
+
+impl LevelFilter {
+    pub fn parse(level: &str) -> Option<Self> {
+        match level {
+            "error" => Some(Self::Error),
+            "warning" => Some(Self::Warning),
+            "error_or_warning" => Some(Self::ErrorOrWarning),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warning => "warning",
+            Self::ErrorOrWarning => "error_or_warning",
+        }
+    }
+
+    fn matches(self, level: &str) -> bool {
+        match self {
+            Self::Error => level == "error",
+            Self::Warning => level == "warning",
+            Self::ErrorOrWarning => true,
+        }
+    }
+}
+
+/// A held `restart` response, waiting for its processes to come back up
+#[derive(Debug, Clone)]
+pub struct ReadyWait {
+    pub names: Vec<String>,
+    pub started: Instant,
+    pub timeout: Duration,
+}
+
+/// Where a held `restart` stands on this tick
+#[derive(Debug, Clone, PartialEq)]
+pub enum WaitOutcome {
+    Pending,
+    Ready,
+    /// A process went down, or the timeout passed first
+    Failed { name: String, reason: String },
+}
+
+impl ReadyWait {
+    pub fn new(names: Vec<String>, timeout: Duration, now: Instant) -> Self {
+        Self { names, started: now, timeout }
+    }
+
+    /// Ready once every process is; a process going down fails the wait
+    /// straight away rather than at the timeout
+    pub fn resolve(&self, now: Instant, readiness: impl Fn(&str) -> Readiness) -> WaitOutcome {
+        let mut not_ready = None;
+        for name in &self.names {
+            match readiness(name) {
+                Readiness::Ready => {}
+                Readiness::Starting => {
+                    not_ready.get_or_insert(name);
+                }
+                Readiness::Down(reason) => return WaitOutcome::Failed { name: name.clone(), reason },
+            }
+        }
+        match not_ready {
+            None => WaitOutcome::Ready,
+            Some(name) if now.duration_since(self.started) >= self.timeout => WaitOutcome::Failed {
+                name: name.clone(),
+                reason: format!("not ready after {}ms", self.timeout.as_millis()),
+            },
+            Some(_) => WaitOutcome::Pending,
+        }
+    }
+
+    fn response(&self, outcome: WaitOutcome, now: Instant, last_output: impl Fn(&str, usize) -> Vec<String>) -> IpcResponse {
+        let waited_ms = now.duration_since(self.started).as_millis() as u64;
+        match outcome {
+            WaitOutcome::Failed { name, reason } => {
+                let output = last_output(&name, WAIT_READY_OUTPUT_LINES);
+                let mut message = format!("{} did not come back up: {}", name, reason);
+                if !output.is_empty() {
+                    message.push_str("\nLast output:\n");
+                    message.push_str(&output.join("\n"));
+                }
+                let mut response = IpcResponse::err(message);
+                response.result = Some(json!({
+                    "process": name,
+                    "reason": reason,
+                    "waited_ms": waited_ms,
+                    "last_output": output
+                }));
+                response
+            }
+            _ => IpcResponse::ok(json!({
+                "restarted": true,
+                "ready": true,
+                "processes": self.names,
+                "waited_ms": waited_ms
+            })),
+        }
+    }
+}
+
+/// Active subscriptions, keyed by connection
+#[derive(Debug, Default)]
+pub struct Subscriptions {
+    tail_errors: HashMap<ConnectionId, LevelFilter>,
+    ready_waits: HashMap<ConnectionId, ReadyWait>,
+}
+
+impl Subscriptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subscribe a connection to error/warning lines (replaces an earlier level)
+    pub fn add_tail_errors(&mut self, conn_id: ConnectionId, level: LevelFilter) {
+        self.tail_errors.insert(conn_id, level);
+    }
+
+    /// Hold a connection's `restart` response until `wait` resolves
+    pub fn add_ready_wait(&mut self, conn_id: ConnectionId, wait: ReadyWait) {
+        self.ready_waits.insert(conn_id, wait);
+    }
+
+    pub fn remove(&mut self, conn_id: ConnectionId) {
+        self.tail_errors.remove(&conn_id);
+        self.ready_waits.remove(&conn_id);
+    }
+
+    pub fn has_ready_waits(&self) -> bool {
+        !self.ready_waits.is_empty()
+    }
+
+    /// Responses for the held restarts that resolved by `now`; those are dropped
+    pub fn resolve_ready_waits(
+        &mut self,
+        now: Instant,
+        readiness: impl Fn(&str) -> Readiness,
+        last_output: impl Fn(&str, usize) -> Vec<String>,
+    ) -> Vec<(ConnectionId, IpcResponse)> {
+        let mut responses = Vec::new();
+        self.ready_waits.retain(|&conn_id, wait| match wait.resolve(now, &readiness) {
+            WaitOutcome::Pending => true,
+            outcome => {
+                responses.push((conn_id, wait.response(outcome, now, &last_output)));
+                false
+            }
+        });
+        responses
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tail_errors.is_empty() && self.ready_waits.is_empty()
+    }
+
+    /// One frame per (subscriber, matching line) for newly ingested lines,
+    /// oldest line first
+    pub fn frames(&self, new_logs: &[&LogLine], severity: &SeverityMatcher) -> Vec<(ConnectionId, IpcResponse)> {
+        let mut frames = Vec::new();
+        if self.tail_errors.is_empty() {
+            return frames;
+        }
+        for log in new_logs {
+            let Some(level) = severity.detect_lowercase(log.line_lowercase()) else {
+                continue;
+            };
+            for (&conn_id, filter) in &self.tail_errors {
+                if filter.matches(level) {
+                    frames.push((conn_id, log_frame(log, level)));
+                }
+            }
+        }
+        frames
+    }
+}
+
+fn log_frame(log: &LogLine, level: &str) -> IpcResponse {
+    IpcResponse::ok(json!({
+        "event": "log",
+        "id": log.id,
+        "process": log.source.process_name(),
+        "content": log.line,
+        "timestamp": log.timestamp.to_rfc3339(),
+        "level": level
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::LogSource;
+
+    fn line(text: &str) -> LogLine {
+        LogLine::new(LogSource::ProcessStdout("web".to_string()), text.to_string())
+    }
+
+    fn pushed(subscriptions: &Subscriptions, logs: &[LogLine]) -> Vec<(ConnectionId, String, String)> {
+        let refs: Vec<&LogLine> = logs.iter().collect();
+        subscriptions
+            .frames(&refs, &SeverityMatcher::default())
+            .into_iter()
+            .map(|(conn, frame)| {
+                let result = frame.result.unwrap();
+                (conn, result["content"].as_str().unwrap().to_string(), result["level"].as_str().unwrap().to_string())
+            })
+            .collect()
+    }
+
+    fn mixed_lines() -> Vec<LogLine> {
+        vec![
+            line("GET /health 200"),
+            line("ERROR: database timeout"),
+            line("WARN: slow query"),
+            line("Listening on 3000"),
+        ]
+    }
+
+    #[test]
+    fn error_subscription_only_receives_errors() {
+        let mut subscriptions = Subscriptions::new();
+        subscriptions.add_tail_errors(ConnectionId::new(1), LevelFilter::Error);
+
+        let frames = pushed(&subscriptions, &mixed_lines());
+        assert_eq!(
+            frames,
+            vec![(ConnectionId::new(1), "ERROR: database timeout".to_string(), "error".to_string())]
+        );
+    }
+
+    #[test]
+    fn each_subscriber_gets_its_own_levels() {
+        let mut subscriptions = Subscriptions::new();
+        subscriptions.add_tail_errors(ConnectionId::new(1), LevelFilter::Warning);
+        subscriptions.add_tail_errors(ConnectionId::new(2), LevelFilter::ErrorOrWarning);
+
+        let mut frames = pushed(&subscriptions, &mixed_lines());
+        frames.sort();
+        assert_eq!(
+            frames,
+            vec![
+                (ConnectionId::new(1), "WARN: slow query".to_string(), "warning".to_string()),
+                (ConnectionId::new(2), "ERROR: database timeout".to_string(), "error".to_string()),
+                (ConnectionId::new(2), "WARN: slow query".to_string(), "warning".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn removed_subscriber_gets_nothing() {
+        let mut subscriptions = Subscriptions::new();
+        subscriptions.add_tail_errors(ConnectionId::new(1), LevelFilter::ErrorOrWarning);
+        subscriptions.remove(ConnectionId::new(1));
+
+        assert!(subscriptions.is_empty());
+        assert!(pushed(&subscriptions, &mixed_lines()).is_empty());
+    }
+
+    fn readiness_of(states: &[(&str, Readiness)]) -> impl Fn(&str) -> Readiness + use<> {
+        let states: HashMap<String, Readiness> = states.iter().map(|(n, r)| (n.to_string(), r.clone())).collect();
+        move |name| states.get(name).cloned().unwrap_or(Readiness::Down("not found".to_string()))
+    }
+
+    #[test]
+    fn ready_wait_resolves_once_every_process_is_ready() {
+        let start = Instant::now();
+        let wait = ReadyWait::new(vec!["web".to_string(), "worker".to_string()], Duration::from_secs(5), start);
+
+        let booting = readiness_of(&[("web", Readiness::Ready), ("worker", Readiness::Starting)]);
+        assert_eq!(wait.resolve(start + Duration::from_secs(1), booting), WaitOutcome::Pending);
+
+        let up = readiness_of(&[("web", Readiness::Ready), ("worker", Readiness::Ready)]);
+        assert_eq!(wait.resolve(start + Duration::from_secs(2), up), WaitOutcome::Ready);
+    }
+
+    #[test]
+    fn ready_wait_fails_on_crash_or_timeout() {
+        let start = Instant::now();
+        let wait = ReadyWait::new(vec!["web".to_string(), "worker".to_string()], Duration::from_secs(5), start);
+
+        let crashed = readiness_of(&[("web", Readiness::Starting), ("worker", Readiness::Down("exit 1".to_string()))]);
+        assert_eq!(
+            wait.resolve(start, crashed),
+            WaitOutcome::Failed { name: "worker".to_string(), reason: "exit 1".to_string() }
+        );
+
+        let stuck = readiness_of(&[("web", Readiness::Starting), ("worker", Readiness::Ready)]);
+        assert_eq!(
+            wait.resolve(start + Duration::from_secs(5), stuck),
+            WaitOutcome::Failed { name: "web".to_string(), reason: "not ready after 5000ms".to_string() }
+        );
+    }
+
+    #[test]
+    fn resolved_wait_responds_once_with_last_output() {
+        let start = Instant::now();
+        let mut subscriptions = Subscriptions::new();
+        subscriptions.add_ready_wait(ConnectionId::new(1), ReadyWait::new(vec!["web".to_string()], Duration::from_secs(5), start));
+
+        let last_output = |name: &str, _: usize| vec![format!("{}: address already in use", name)];
+        let down = readiness_of(&[("web", Readiness::Down("exited".to_string()))]);
+        let responses = subscriptions.resolve_ready_waits(start, down, last_output);
+
+        assert_eq!(responses.len(), 1);
+        let (conn, response) = &responses[0];
+        assert_eq!(*conn, ConnectionId::new(1));
+        assert!(!response.success);
+        assert!(response.error.as_ref().unwrap().contains("web: address already in use"));
+        assert_eq!(response.result.as_ref().unwrap()["reason"], "exited");
+        assert!(!subscriptions.has_ready_waits());
+    }
+
+    #[test]
+    fn level_filter_parses_known_levels_only() {
+        assert_eq!(LevelFilter::parse("warning"), Some(LevelFilter::Warning));
+        assert_eq!(LevelFilter::parse("error_or_warning").map(LevelFilter::as_str), Some("error_or_warning"));
+        assert_eq!(LevelFilter::parse("info"), None);
+    }
+}
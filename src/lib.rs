@@ -4,7 +4,9 @@
 pub mod clipboard;
 pub mod command;
 pub mod config;
+pub mod doctor;
 pub mod group;
+pub mod hooks;
 pub mod ipc;
 pub mod log;
 pub mod operations;
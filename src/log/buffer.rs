@@ -1,5 +1,5 @@
 use super::LogLine;
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 
 /// A circular buffer for storing log lines
 pub struct LogBuffer {
@@ -60,6 +60,39 @@ impl LogBuffer {
             .collect()
     }
 
+    /// The newest `n` lines from one process or log file, oldest first
+    pub fn get_last_from(&self, process: &str, n: usize) -> Vec<&LogLine> {
+        let mut logs: Vec<&LogLine> = self
+            .logs
+            .iter()
+            .rev()
+            .filter(|log| log.source.process_name() == process)
+            .take(n)
+            .collect();
+        logs.reverse();
+        logs
+    }
+
+    /// The newest line from each of `names`, found in one pass from the end
+    pub fn last_per_process<'a>(&'a self, names: &[&str]) -> HashMap<&'a str, &'a LogLine> {
+        let mut last = HashMap::new();
+        for log in self.logs.iter().rev() {
+            if last.len() == names.len() {
+                break;
+            }
+            let name = log.source.process_name();
+            if names.contains(&name) {
+                last.entry(name).or_insert(log);
+            }
+        }
+        last
+    }
+
+    /// Lines from the newest back, without collecting them
+    pub fn newest_first(&self) -> impl Iterator<Item = &LogLine> {
+        self.logs.iter().rev()
+    }
+
     pub fn get_all(&self) -> Vec<&LogLine> {
         self.logs.iter().collect()
     }
@@ -124,6 +157,20 @@ impl LogBuffer {
             .collect()
     }
 
+    /// Change the memory limit, evicting the oldest lines until the buffer fits
+    /// (the newest line is always kept). Returns how many lines were evicted.
+    pub fn set_memory_limit(&mut self, max_memory_mb: usize) -> usize {
+        self.max_memory_bytes = max_memory_mb * 1024 * 1024;
+        let mut evicted = 0;
+        while self.current_memory_bytes > self.max_memory_bytes && self.logs.len() > 1 {
+            if let Some(log) = self.logs.pop_front() {
+                self.current_memory_bytes = self.current_memory_bytes.saturating_sub(log.memory_size());
+                evicted += 1;
+            }
+        }
+        evicted
+    }
+
     pub fn get_memory_usage_bytes(&self) -> usize {
         self.current_memory_bytes
     }
@@ -421,4 +468,39 @@ mod tests {
         assert_eq!(buffer.get_memory_usage_bytes(), 0, "Memory should be 0 after clear");
         assert_eq!(buffer.len(), 0, "Buffer should be empty after clear");
     }
+
+    #[test]
+    fn test_set_memory_limit_evicts_oldest_to_fit() {
+        let mut buffer = LogBuffer::new_with_memory_limit(4);
+        let content = "x".repeat(8_000);
+        for i in 0..60 {
+            buffer.push(LogLine::new(LogSource::ProcessStdout("test".into()), format!("{} {}", content, i)));
+        }
+        let before = buffer.len();
+        assert_eq!(before, 60, "4 MB holds all 60 lines");
+
+        let evicted = buffer.set_memory_limit(1);
+
+        assert!(evicted > 0);
+        assert_eq!(buffer.len(), before - evicted);
+        assert!(buffer.get_memory_usage_bytes() <= 1024 * 1024);
+        assert_eq!(buffer.get_memory_limit_mb(), 1);
+        assert!(buffer.get_all().last().unwrap().line.ends_with(" 59"), "newest lines are kept");
+
+        // Growing the limit never evicts
+        assert_eq!(buffer.set_memory_limit(8), 0);
+    }
+
+    #[test]
+    fn test_last_per_process() {
+        let mut buffer = LogBuffer::new(10);
+        buffer.push(LogLine::new(LogSource::ProcessStdout("web".into()), "web 1".into()));
+        buffer.push(LogLine::new(LogSource::ProcessStdout("worker".into()), "worker 1".into()));
+        buffer.push(LogLine::new(LogSource::ProcessStderr("web".into()), "web 2".into()));
+
+        let last = buffer.last_per_process(&["web", "worker", "db"]);
+        assert_eq!(last["web"].line, "web 2");
+        assert_eq!(last["worker"].line, "worker 1");
+        assert!(!last.contains_key("db"));
+    }
 }
@@ -1,9 +1,9 @@
 use super::{LogLine, LogSource};
 use anyhow::Result;
 use notify::{Config, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
-use std::fs::File as StdFile;
+use std::fs::{File as StdFile, Metadata};
 use std::io::{BufRead, BufReader, Seek, SeekFrom};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use tokio::sync::mpsc;
 use tokio::task::JoinHandle;
 use tokio::time::Duration;
@@ -14,6 +14,7 @@ pub struct FileReader {
     path: PathBuf,
     is_standalone: bool,
     task: Option<JoinHandle<()>>,
+    log_tx: Option<mpsc::UnboundedSender<LogLine>>,
 }
 
 impl FileReader {
@@ -23,6 +24,7 @@ impl FileReader {
             path,
             is_standalone: false,
             task: None,
+            log_tx: None,
         }
     }
 
@@ -32,6 +34,7 @@ impl FileReader {
             path,
             is_standalone: true,
             task: None,
+            log_tx: None,
         }
     }
 
@@ -50,16 +53,35 @@ impl FileReader {
             return Ok(()); // Already running
         }
 
+        self.spawn(log_tx, false);
+        Ok(())
+    }
+
+    /// Restart tailing from the top of the file (`:retail`).
+    /// Returns false if the reader was never started.
+    pub fn retail(&mut self) -> bool {
+        let Some(log_tx) = self.log_tx.clone() else {
+            return false;
+        };
+        self.stop();
+        self.spawn(log_tx, true);
+        true
+    }
+
+    fn spawn(&mut self, log_tx: mpsc::UnboundedSender<LogLine>, from_start: bool) {
         let name = self.name.clone();
         let path = self.path.clone();
         let is_standalone = self.is_standalone;
+        // A file that doesn't exist yet is read from the top once it appears.
+        // Checked here rather than in the task so a file created right after is not skipped.
+        let from_start = from_start || !path.exists();
+        self.log_tx = Some(log_tx.clone());
 
         let task = tokio::spawn(async move {
-            tail_file_with_notify(path, name, is_standalone, log_tx).await;
+            tail_file_with_notify(path, name, is_standalone, from_start, log_tx).await;
         });
 
         self.task = Some(task);
-        Ok(())
     }
 
     pub fn stop(&mut self) {
@@ -75,14 +97,66 @@ impl Drop for FileReader {
     }
 }
 
-/// Tail the file using notify for file system events
+/// Identity of the file behind a path, to notice it being replaced (log rotation).
+/// None where the platform has no inode.
+#[cfg(unix)]
+fn file_identity(metadata: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+#[cfg(not(unix))]
+fn file_identity(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
+
+/// Why the reader has to start over from the top of the file, if it does
+#[derive(Debug, PartialEq)]
+enum FileReset {
+    Rotated,
+    Truncated,
+}
+
+fn detect_reset(
+    previous_identity: Option<(u64, u64)>,
+    identity: Option<(u64, u64)>,
+    file_len: u64,
+    position: u64,
+) -> Option<FileReset> {
+    if previous_identity.is_some() && identity.is_some() && previous_identity != identity {
+        Some(FileReset::Rotated)
+    } else if file_len < position {
+        Some(FileReset::Truncated)
+    } else {
+        None
+    }
+}
+
+fn file_source(name: &str, path: &Path, is_standalone: bool) -> LogSource {
+    if is_standalone {
+        LogSource::StandaloneFile {
+            name: name.to_string(),
+            path: path.to_path_buf(),
+        }
+    } else {
+        LogSource::File {
+            process_name: name.to_string(),
+            path: path.to_path_buf(),
+        }
+    }
+}
+
+/// Tail the file using notify for file system events.
+/// `from_start` reads existing content instead of starting at the end.
 async fn tail_file_with_notify(
     path: PathBuf,
     name: String,
     is_standalone: bool,
+    from_start: bool,
     log_tx: mpsc::UnboundedSender<LogLine>,
 ) {
-    let mut position: Option<u64> = None;
+    let mut position: Option<u64> = from_start.then_some(0);
+    let mut identity: Option<(u64, u64)> = None;
     let mut line_buffer = String::new();
 
     // Create a channel for file change notifications
@@ -140,21 +214,35 @@ async fn tail_file_with_notify(
         };
 
         // Get current file size
-        let file_len = match file.metadata() {
-            Ok(m) => m.len(),
+        let metadata = match file.metadata() {
+            Ok(m) => m,
             Err(_) => {
                 tokio::time::sleep(Duration::from_millis(100)).await;
                 continue;
             }
         };
+        let file_len = metadata.len();
+        let current_identity = file_identity(&metadata);
 
         // Initialize position to end of file on first run (tail -f behavior)
         let pos = position.get_or_insert(file_len);
 
-        // If file was truncated, reset position to start
-        if file_len < *pos {
+        // If the file was rotated or truncated, start over from the top
+        if let Some(reset) = detect_reset(identity, current_identity, file_len, *pos) {
             *pos = 0;
+            let what = match reset {
+                FileReset::Rotated => "rotated",
+                FileReset::Truncated => "truncated",
+            };
+            let warning = LogLine::new(
+                file_source(&name, &path, is_standalone),
+                format!("[oit] {} was {}; reading from the start", path.display(), what),
+            );
+            if log_tx.send(warning).is_err() {
+                return; // Channel closed
+            }
         }
+        identity = current_identity;
 
         // Read any new content
         if file_len > *pos {
@@ -174,18 +262,7 @@ async fn tail_file_with_notify(
                                 continue;
                             }
 
-                            let source = if is_standalone {
-                                LogSource::StandaloneFile {
-                                    name: name.clone(),
-                                    path: path.clone(),
-                                }
-                            } else {
-                                LogSource::File {
-                                    process_name: name.clone(),
-                                    path: path.clone(),
-                                }
-                            };
-                            let log_line = LogLine::new(source, line.to_string());
+                            let log_line = LogLine::new(file_source(&name, &path, is_standalone), line.to_string());
 
                             if log_tx.send(log_line).is_err() {
                                 return; // Channel closed
@@ -301,4 +378,96 @@ mod tests {
 
         reader.stop();
     }
+
+    async fn collect_lines(log_rx: &mut mpsc::UnboundedReceiver<LogLine>, want: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        for _ in 0..30 {
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            while let Ok(log) = log_rx.try_recv() {
+                lines.push(log.line);
+            }
+            if lines.len() >= want {
+                break;
+            }
+        }
+        lines
+    }
+
+    #[test]
+    fn test_detect_reset() {
+        assert_eq!(detect_reset(Some((1, 10)), Some((1, 10)), 100, 50), None);
+        assert_eq!(detect_reset(Some((1, 10)), Some((1, 11)), 100, 50), Some(FileReset::Rotated));
+        assert_eq!(detect_reset(Some((1, 10)), Some((1, 10)), 20, 50), Some(FileReset::Truncated));
+        // First open has nothing to compare against
+        assert_eq!(detect_reset(None, Some((1, 10)), 100, 50), None);
+    }
+
+    #[tokio::test]
+    async fn test_file_reader_rereads_truncated_file() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "a fairly long line that was already in the file").unwrap();
+        temp_file.flush().unwrap();
+        let path = temp_file.path().to_path_buf();
+
+        let (log_tx, mut log_rx) = mpsc::unbounded_channel();
+        let mut reader = FileReader::new("test".to_string(), path.clone());
+        reader.start(log_tx).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // Truncate in place (logrotate copytruncate) and write fresh lines
+        std::fs::write(&path, "after 1\nafter 2\n").unwrap();
+
+        let lines = collect_lines(&mut log_rx, 3).await;
+        assert_eq!(lines.len(), 3, "got {:?}", lines);
+        assert!(lines[0].contains("was truncated"), "got {:?}", lines);
+        assert_eq!(lines[1], "after 1");
+        assert_eq!(lines[2], "after 2");
+
+        reader.stop();
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_file_reader_follows_rotated_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("app.log");
+        std::fs::write(&path, "old\n").unwrap();
+
+        let (log_tx, mut log_rx) = mpsc::unbounded_channel();
+        let mut reader = FileReader::new("test".to_string(), path.clone());
+        reader.start(log_tx).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        // Move the file away and create a new one at the same path (bigger than the old offset)
+        std::fs::rename(&path, dir.path().join("app.log.1")).unwrap();
+        std::fs::write(&path, "rotated line one\n").unwrap();
+
+        let lines = collect_lines(&mut log_rx, 2).await;
+        assert_eq!(lines.len(), 2, "got {:?}", lines);
+        assert!(lines[0].contains("was rotated"), "got {:?}", lines);
+        assert_eq!(lines[1], "rotated line one");
+
+        reader.stop();
+    }
+
+    #[tokio::test]
+    async fn test_file_reader_retail_reads_from_top() {
+        let mut temp_file = NamedTempFile::new().unwrap();
+        writeln!(temp_file, "existing 1").unwrap();
+        writeln!(temp_file, "existing 2").unwrap();
+        temp_file.flush().unwrap();
+
+        let (log_tx, mut log_rx) = mpsc::unbounded_channel();
+        let mut reader = FileReader::new("test".to_string(), temp_file.path().to_path_buf());
+        assert!(!reader.retail(), "retail before start does nothing");
+        reader.start(log_tx).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        assert!(log_rx.try_recv().is_err());
+
+        assert!(reader.retail());
+        let lines = collect_lines(&mut log_rx, 2).await;
+        assert_eq!(lines, vec!["existing 1", "existing 2"]);
+
+        reader.stop();
+    }
 }
@@ -0,0 +1,120 @@
+//! Log volume per time bucket over the buffer's arrival times, for `oit
+//! histogram` and the `volume_histogram` status bar sparkline
+
+use chrono::{DateTime, Duration, Local};
+use std::collections::BTreeMap;
+
+use super::velocity::render_sparkline;
+
+/// Line counts in contiguous buckets ending at the time it was computed
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeHistogram {
+    pub bucket_secs: i64,
+    /// Start of the oldest bucket
+    pub start: DateTime<Local>,
+    /// Oldest bucket first
+    pub counts: Vec<usize>,
+    /// The same buckets split by process (filled only when asked for)
+    pub per_process: BTreeMap<String, Vec<usize>>,
+}
+
+impl VolumeHistogram {
+    /// Count lines into `buckets` buckets of `bucket_secs` ending at `now`.
+    /// `newest_first` yields (process, arrival time) from the end of the buffer
+    /// and is only read back to the first line older than the window.
+    pub fn compute<'a>(
+        newest_first: impl Iterator<Item = (&'a str, DateTime<Local>)>,
+        now: DateTime<Local>,
+        bucket_secs: i64,
+        buckets: usize,
+        per_process: bool,
+    ) -> Self {
+        let start = now - Duration::seconds(bucket_secs * buckets as i64);
+        let mut histogram = Self { bucket_secs, start, counts: vec![0; buckets], per_process: BTreeMap::new() };
+        if buckets == 0 {
+            return histogram;
+        }
+
+        for (process, arrival) in newest_first {
+            if arrival < start {
+                break;
+            }
+            // Lines stamped after `now` (clock adjustments) count as newest
+            let index = (((arrival - start).num_milliseconds() / (bucket_secs * 1000)) as usize).min(buckets - 1);
+            histogram.counts[index] += 1;
+            if per_process {
+                histogram.per_process.entry(process.to_string()).or_insert_with(|| vec![0; buckets])[index] += 1;
+            }
+        }
+        histogram
+    }
+
+    pub fn bucket_start(&self, index: usize) -> DateTime<Local> {
+        self.start + Duration::seconds(self.bucket_secs * index as i64)
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    pub fn peak(&self) -> usize {
+        self.counts.iter().copied().max().unwrap_or(0)
+    }
+
+    pub fn sparkline(&self) -> String {
+        render_sparkline(self.counts.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arrivals(now: DateTime<Local>, lines: &[(&'static str, i64)]) -> Vec<(&'static str, DateTime<Local>)> {
+        // Buffer order is oldest first; `compute` reads it from the end
+        lines.iter().rev().map(|(process, age_secs)| (*process, now - Duration::seconds(*age_secs))).collect()
+    }
+
+    #[test]
+    fn counts_lines_into_buckets_ending_now() {
+        let now = Local::now();
+        let lines = arrivals(now, &[("web", 95), ("web", 25), ("worker", 22), ("web", 21), ("web", 3), ("worker", 0)]);
+
+        let histogram = VolumeHistogram::compute(lines.into_iter(), now, 10, 3, false);
+        // Buckets: [30s ago, 20s), [20s, 10s), [10s, now]; the 95s-old line is outside
+        assert_eq!(histogram.counts, vec![3, 0, 2]);
+        assert_eq!(histogram.total(), 5);
+        assert_eq!(histogram.peak(), 3);
+        assert_eq!(histogram.bucket_start(2), now - Duration::seconds(10));
+        assert!(histogram.per_process.is_empty());
+    }
+
+    #[test]
+    fn splits_counts_per_process() {
+        let now = Local::now();
+        let lines = arrivals(now, &[("web", 15), ("worker", 12), ("web", 4), ("web", 1)]);
+
+        let histogram = VolumeHistogram::compute(lines.into_iter(), now, 10, 2, true);
+        assert_eq!(histogram.counts, vec![2, 2]);
+        assert_eq!(histogram.per_process["web"], vec![1, 2]);
+        assert_eq!(histogram.per_process["worker"], vec![1, 0]);
+    }
+
+    #[test]
+    fn stops_reading_at_the_first_line_before_the_window() {
+        let now = Local::now();
+        let lines = arrivals(now, &[("web", 5), ("web", 500), ("web", 2)]);
+
+        // The 5s-old line sits behind an older one, so it isn't reached
+        let histogram = VolumeHistogram::compute(lines.into_iter(), now, 10, 6, false);
+        assert_eq!(histogram.total(), 1);
+    }
+
+    #[test]
+    fn sparkline_scales_to_the_peak() {
+        let now = Local::now();
+        let lines = arrivals(now, &[("web", 25), ("web", 5), ("web", 4), ("web", 3), ("web", 2)]);
+        let histogram = VolumeHistogram::compute(lines.into_iter(), now, 10, 3, false);
+        assert_eq!(histogram.sparkline(), "▃ █");
+    }
+}
@@ -0,0 +1,89 @@
+/// Start of an OSC 8 hyperlink sequence: `ESC ] 8 ; params ; url ST`, where ST
+/// is `ESC \` or BEL. A sequence with an empty url closes the link.
+const OSC8: &str = "\x1b]8;";
+const UNDERLINE_ON: &str = "\x1b[4m";
+const UNDERLINE_OFF: &str = "\x1b[24m";
+
+/// Replace OSC 8 hyperlinks with their visible text, underlined with plain SGR
+/// codes that `ansi_to_tui` understands, and collect the link targets in order.
+/// Other escape codes are left untouched. Returns None when the line has no links.
+pub fn extract_hyperlinks(line: &str) -> Option<(String, Vec<String>)> {
+    if !line.contains(OSC8) {
+        return None;
+    }
+
+    let mut text = String::with_capacity(line.len());
+    let mut links = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find(OSC8) {
+        text.push_str(&rest[..start]);
+        let sequence = &rest[start + OSC8.len()..];
+        let Some((url, after)) = parse_osc8(sequence) else {
+            // Unterminated sequence: keep it as-is rather than eat the rest of the line
+            text.push_str(&rest[start..]);
+            rest = "";
+            break;
+        };
+        if url.is_empty() {
+            text.push_str(UNDERLINE_OFF);
+        } else {
+            text.push_str(UNDERLINE_ON);
+            links.push(url.to_string());
+        }
+        rest = after;
+    }
+    text.push_str(rest);
+    Some((text, links))
+}
+
+/// Split `params;url ST...` into the url and whatever follows the terminator
+fn parse_osc8(sequence: &str) -> Option<(&str, &str)> {
+    let (_params, after_params) = sequence.split_once(';')?;
+    let end = after_params.find(['\x07', '\x1b'])?;
+    let after = &after_params[end..];
+    let after = after.strip_prefix('\x07').or_else(|| after.strip_prefix("\x1b\\"))?;
+    Some((&after_params[..end], after))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::strip_ansi;
+
+    #[test]
+    fn test_extract_hyperlink_target_and_text() {
+        let line = "see \x1b]8;;https://example.com/docs\x1b\\the docs\x1b]8;;\x1b\\ for details";
+        let (text, links) = extract_hyperlinks(line).unwrap();
+        assert_eq!(links, vec!["https://example.com/docs".to_string()]);
+        assert_eq!(strip_ansi(&text), "see the docs for details");
+        assert!(text.contains("\x1b[4mthe docs\x1b[24m"));
+    }
+
+    #[test]
+    fn test_extract_hyperlinks_bel_terminator_and_params() {
+        let line = "\x1b]8;id=1;file:///tmp/a.rs\x07a.rs\x1b]8;;\x07 and \x1b]8;;file:///tmp/b.rs\x07b.rs\x1b]8;;\x07";
+        let (text, links) = extract_hyperlinks(line).unwrap();
+        assert_eq!(links, vec!["file:///tmp/a.rs".to_string(), "file:///tmp/b.rs".to_string()]);
+        assert_eq!(strip_ansi(&text), "a.rs and b.rs");
+    }
+
+    #[test]
+    fn test_extract_hyperlinks_keeps_other_ansi() {
+        let line = "\x1b[31mred\x1b[0m \x1b]8;;https://example.com\x1b\\link\x1b]8;;\x1b\\";
+        let (text, _) = extract_hyperlinks(line).unwrap();
+        assert!(text.starts_with("\x1b[31mred\x1b[0m "));
+    }
+
+    #[test]
+    fn test_extract_hyperlinks_none_without_links() {
+        assert!(extract_hyperlinks("\x1b[32mplain\x1b[0m").is_none());
+    }
+
+    #[test]
+    fn test_extract_hyperlinks_unterminated_is_left_alone() {
+        let line = "broken \x1b]8;;https://example.com";
+        let (text, links) = extract_hyperlinks(line).unwrap();
+        assert!(links.is_empty());
+        assert_eq!(text, line);
+    }
+}
@@ -0,0 +1,146 @@
+use serde_json::{Map, Value};
+
+use super::detect_log_level;
+
+const DEFAULT_MESSAGE_FIELDS: &[&str] = &["msg", "message"];
+const DEFAULT_LEVEL_FIELDS: &[&str] = &["level", "severity"];
+
+/// Field names used to pull the message and level out of a JSON log line
+#[derive(Debug, Clone, PartialEq)]
+pub struct JsonLogFields {
+    pub message_fields: Vec<String>,
+    pub level_fields: Vec<String>,
+}
+
+impl Default for JsonLogFields {
+    fn default() -> Self {
+        Self::new(None, None)
+    }
+}
+
+impl JsonLogFields {
+    /// Build field lists, replacing the defaults with any configured override
+    pub fn new(message_field: Option<&str>, level_field: Option<&str>) -> Self {
+        let to_vec = |field: Option<&str>, defaults: &[&str]| match field {
+            Some(name) => vec![name.to_string()],
+            None => defaults.iter().map(|s| s.to_string()).collect(),
+        };
+        Self {
+            message_fields: to_vec(message_field, DEFAULT_MESSAGE_FIELDS),
+            level_fields: to_vec(level_field, DEFAULT_LEVEL_FIELDS),
+        }
+    }
+}
+
+/// Message and level extracted from a JSON log line
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedJsonLine {
+    pub message: String,
+    pub level: Option<String>,
+}
+
+impl ParsedJsonLine {
+    /// Text shown in the log viewer: `LEVEL message`, with the level colored
+    /// red for errors and yellow for warnings.
+    pub fn display_line(&self) -> String {
+        let Some(level) = &self.level else {
+            return self.message.clone();
+        };
+        let label = level.to_uppercase();
+        match detect_log_level(level) {
+            Some("error") => format!("\x1b[31m{}\x1b[0m {}", label, self.message),
+            Some("warning") => format!("\x1b[33m{}\x1b[0m {}", label, self.message),
+            _ => format!("{} {}", label, self.message),
+        }
+    }
+}
+
+/// Parse a log line as a JSON object and extract its message and level.
+/// Returns None for non-JSON lines, malformed JSON, or objects without a message field.
+pub fn parse_json_line(line: &str, fields: &JsonLogFields) -> Option<ParsedJsonLine> {
+    let trimmed = line.trim();
+    if !trimmed.starts_with('{') {
+        return None;
+    }
+    let object: Map<String, Value> = serde_json::from_str(trimmed).ok()?;
+
+    let message = find_field(&object, &fields.message_fields)?;
+    let level = find_field(&object, &fields.level_fields);
+    Some(ParsedJsonLine { message, level })
+}
+
+/// Pretty-print a raw JSON line, or None if it does not parse
+pub fn pretty_print_json(raw: &str) -> Option<String> {
+    let value: Value = serde_json::from_str(raw.trim()).ok()?;
+    serde_json::to_string_pretty(&value).ok()
+}
+
+fn find_field(object: &Map<String, Value>, names: &[String]) -> Option<String> {
+    names.iter().find_map(|name| match object.get(name)? {
+        Value::String(s) => Some(s.clone()),
+        Value::Null => None,
+        other => Some(other.to_string()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_json_line_extracts_default_fields() {
+        let parsed = parse_json_line(
+            r#"{"level":"error","msg":"db down","request_id":"abc"}"#,
+            &JsonLogFields::default(),
+        )
+        .unwrap();
+        assert_eq!(parsed.message, "db down");
+        assert_eq!(parsed.level.as_deref(), Some("error"));
+    }
+
+    #[test]
+    fn test_parse_json_line_falls_back_to_message_field() {
+        let parsed = parse_json_line(r#"{"message":"hello"}"#, &JsonLogFields::default()).unwrap();
+        assert_eq!(parsed.message, "hello");
+        assert_eq!(parsed.level, None);
+    }
+
+    #[test]
+    fn test_parse_json_line_uses_overrides() {
+        let fields = JsonLogFields::new(Some("text"), Some("lvl"));
+        let parsed = parse_json_line(r#"{"text":"hi","lvl":"warn","msg":"ignored"}"#, &fields).unwrap();
+        assert_eq!(parsed.message, "hi");
+        assert_eq!(parsed.level.as_deref(), Some("warn"));
+    }
+
+    #[test]
+    fn test_parse_json_line_rejects_non_json_and_malformed() {
+        let fields = JsonLogFields::default();
+        assert_eq!(parse_json_line("plain text line", &fields), None);
+        assert_eq!(parse_json_line(r#"{"msg":"truncated"#, &fields), None);
+        assert_eq!(parse_json_line(r#"{"other":"no message"}"#, &fields), None);
+        assert_eq!(parse_json_line("[1, 2, 3]", &fields), None);
+    }
+
+    #[test]
+    fn test_display_line_colors_by_level() {
+        let error = ParsedJsonLine { message: "boom".into(), level: Some("error".into()) };
+        assert_eq!(error.display_line(), "\x1b[31mERROR\x1b[0m boom");
+
+        let warn = ParsedJsonLine { message: "hmm".into(), level: Some("warn".into()) };
+        assert_eq!(warn.display_line(), "\x1b[33mWARN\x1b[0m hmm");
+
+        let info = ParsedJsonLine { message: "ok".into(), level: Some("info".into()) };
+        assert_eq!(info.display_line(), "INFO ok");
+
+        let none = ParsedJsonLine { message: "bare".into(), level: None };
+        assert_eq!(none.display_line(), "bare");
+    }
+
+    #[test]
+    fn test_pretty_print_json() {
+        let pretty = pretty_print_json(r#"{"msg":"hi"}"#).unwrap();
+        assert_eq!(pretty, "{\n  \"msg\": \"hi\"\n}");
+        assert_eq!(pretty_print_json("not json"), None);
+    }
+}
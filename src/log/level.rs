@@ -0,0 +1,162 @@
+use regex::{Regex, RegexBuilder};
+
+/// Detect if a log line contains error or warning patterns
+pub fn detect_log_level(content: &str) -> Option<&'static str> {
+    detect_log_level_lowercase(&content.to_lowercase())
+}
+
+/// Same as `detect_log_level`, for a line that is already lowercased
+pub fn detect_log_level_lowercase(content_lower: &str) -> Option<&'static str> {
+    // Check for error patterns first (higher priority)
+    if builtin_error(content_lower) {
+        return Some("error");
+    }
+    if builtin_warning(content_lower) {
+        return Some("warning");
+    }
+    None
+}
+
+fn builtin_error(content_lower: &str) -> bool {
+    let error_patterns = [
+        "error", "fail", "failed", "panic", "exception", "fatal",
+    ];
+    let error_prefixes = ["[error]", "error:", "[err]", "err:"];
+
+    error_prefixes.iter().any(|prefix| content_lower.starts_with(prefix))
+        || error_patterns.iter().any(|pattern| content_lower.contains(pattern))
+}
+
+fn builtin_warning(content_lower: &str) -> bool {
+    let warning_patterns = ["warn", "warning"];
+    let warning_prefixes = ["[warn]", "warn:", "[warning]", "warning:"];
+
+    warning_prefixes.iter().any(|prefix| content_lower.starts_with(prefix))
+        || warning_patterns.iter().any(|pattern| content_lower.contains(pattern))
+}
+
+/// Error/warning classification with optional custom patterns (`[severity]` config).
+/// A non-empty pattern list replaces the built-in patterns for that level;
+/// matching is case-insensitive.
+#[derive(Debug, Clone, Default)]
+pub struct SeverityMatcher {
+    error_patterns: Vec<Regex>,
+    warning_patterns: Vec<Regex>,
+}
+
+impl SeverityMatcher {
+    /// Compile custom patterns. Returns Err if any pattern is not a valid regex.
+    pub fn new(error_patterns: &[String], warning_patterns: &[String]) -> Result<Self, regex::Error> {
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|p| RegexBuilder::new(p).case_insensitive(true).build())
+                .collect::<Result<Vec<_>, _>>()
+        };
+        Ok(Self {
+            error_patterns: compile(error_patterns)?,
+            warning_patterns: compile(warning_patterns)?,
+        })
+    }
+
+    /// Classify a line as "error", "warning", or None
+    pub fn detect(&self, content: &str) -> Option<&'static str> {
+        self.detect_lowercase(&content.to_lowercase())
+    }
+
+    /// Same as `detect`, for a line that is already lowercased
+    pub fn detect_lowercase(&self, content_lower: &str) -> Option<&'static str> {
+        let is_error = if self.error_patterns.is_empty() {
+            builtin_error(content_lower)
+        } else {
+            self.error_patterns.iter().any(|re| re.is_match(content_lower))
+        };
+        if is_error {
+            return Some("error");
+        }
+
+        let is_warning = if self.warning_patterns.is_empty() {
+            builtin_warning(content_lower)
+        } else {
+            self.warning_patterns.iter().any(|re| re.is_match(content_lower))
+        };
+        is_warning.then_some("warning")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_log_level_finds_error_patterns() {
+        assert_eq!(detect_log_level("Error: connection failed"), Some("error"));
+        assert_eq!(detect_log_level("FATAL: out of memory"), Some("error"));
+        assert_eq!(detect_log_level("panic at line 42"), Some("error"));
+        assert_eq!(detect_log_level("exception thrown"), Some("error"));
+        assert_eq!(detect_log_level("Job failed with exit code 1"), Some("error"));
+        assert_eq!(detect_log_level("[ERROR] Something went wrong"), Some("error"));
+    }
+
+    #[test]
+    fn detect_log_level_finds_warning_patterns() {
+        assert_eq!(detect_log_level("Warning: deprecated function"), Some("warning"));
+        assert_eq!(detect_log_level("WARN: low memory"), Some("warning"));
+        assert_eq!(detect_log_level("[WARN] Resource usage high"), Some("warning"));
+        assert_eq!(detect_log_level("[WARNING] Connection unstable"), Some("warning"));
+    }
+
+    #[test]
+    fn detect_log_level_returns_none_for_normal_logs() {
+        assert_eq!(detect_log_level("Server started on port 3000"), None);
+        assert_eq!(detect_log_level("Processing job 123"), None);
+        assert_eq!(detect_log_level("Request completed in 42ms"), None);
+    }
+
+    #[test]
+    fn detect_log_level_error_takes_priority_over_warning() {
+        // If a line contains both error and warning patterns, error wins
+        assert_eq!(
+            detect_log_level("Error occurred, please check warnings"),
+            Some("error")
+        );
+    }
+
+    fn patterns(list: &[&str]) -> Vec<String> {
+        list.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn severity_matcher_default_matches_builtin() {
+        let matcher = SeverityMatcher::default();
+        for line in ["Job failed", "WARN: low memory", "all good", "[ERROR] boom"] {
+            assert_eq!(matcher.detect(line), detect_log_level(line), "line {:?}", line);
+        }
+    }
+
+    #[test]
+    fn severity_matcher_custom_error_patterns_replace_builtin() {
+        let matcher = SeverityMatcher::new(&patterns(&[r"\b(error|fatal)\b"]), &[]).unwrap();
+
+        assert_eq!(matcher.detect("FATAL: cannot bind"), Some("error"));
+        assert_eq!(matcher.detect("Error: connection lost"), Some("error"));
+        // "fail" is an error by default, but not with the custom list
+        assert_eq!(matcher.detect("Job failed, will retry"), None);
+        // Warnings still use the built-in patterns
+        assert_eq!(matcher.detect("warning: disk 90% full"), Some("warning"));
+    }
+
+    #[test]
+    fn severity_matcher_custom_warning_patterns() {
+        let matcher = SeverityMatcher::new(&[], &patterns(&["deprecat"])).unwrap();
+
+        assert_eq!(matcher.detect("DEPRECATION: use v2 api"), Some("warning"));
+        assert_eq!(matcher.detect("WARN: low memory"), None);
+        assert_eq!(matcher.detect("panic at line 42"), Some("error"));
+    }
+
+    #[test]
+    fn severity_matcher_rejects_invalid_regex() {
+        assert!(SeverityMatcher::new(&patterns(&["(unclosed"]), &[]).is_err());
+    }
+}
@@ -1,13 +1,24 @@
 use chrono::{DateTime, Local};
-use std::path::PathBuf;
+use regex::Regex;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicU64, Ordering};
 
 pub mod buffer;
 pub mod display;
 pub mod file;
+pub mod histogram;
+pub mod hyperlink;
+pub mod json;
+pub mod level;
+pub mod reference;
+pub mod replay;
 pub mod velocity;
 
 pub use display::{condense_log_line, strip_ansi};
+pub use json::{parse_json_line, pretty_print_json, JsonLogFields};
+pub use level::{detect_log_level, SeverityMatcher};
+pub use reference::{format_reference, parse_reference};
+pub use histogram::VolumeHistogram;
 pub use velocity::LogVelocityTracker;
 
 // Re-export commonly used types
@@ -32,15 +43,29 @@ pub struct LogLine {
     formatted_timestamp: String,
     /// Pre-computed line with ANSI codes stripped
     stripped_line: String,
-    /// Pre-computed condensed version of line (metadata collapsed)
+    /// Pre-computed condensed version of the displayed text (metadata collapsed)
     condensed_line: String,
     /// Pre-computed condensed line with ANSI codes stripped
     condensed_stripped_line: String,
+    /// Viewer text (`LEVEL message`) for a structured JSON log; `line` keeps the JSON
+    json_display: Option<String>,
+    /// `json_display` with ANSI codes stripped
+    json_display_stripped: Option<String>,
+    /// Original text when the process's `strip_prefix` removed its leading portion
+    raw_line: Option<String>,
+    /// OSC 8 hyperlink targets, in order; the linked text stays in `line`, underlined
+    links: Vec<String>,
+    /// Startup boilerplate logged before the process's `quiet_until` match
+    pub quiet: bool,
+    /// Which start of its process produced the line (1 = first run); None for
+    /// log files and oit's own lines
+    pub run: Option<u32>,
 }
 
 impl LogLine {
     pub fn new(source: LogSource, line: String) -> Self {
         let now = Local::now();
+        let (line, links) = hyperlink::extract_hyperlinks(&line).unwrap_or((line, Vec::new()));
         let line_lowercase = line.to_lowercase();
         let formatted_timestamp = now.format("%H:%M:%S").to_string();
         let stripped_line = strip_ansi(&line);
@@ -57,12 +82,26 @@ impl LogLine {
             stripped_line,
             condensed_line,
             condensed_stripped_line,
+            json_display: None,
+            json_display_stripped: None,
+            raw_line: None,
+            links,
+            quiet: false,
+            run: None,
         }
     }
 
+    /// Give a line that was held back (`:pause`) a new ID and arrival time as
+    /// it is finally buffered, so buffer order stays ID and arrival order
+    pub fn restamp(&mut self) {
+        self.id = NEXT_LOG_ID.fetch_add(1, Ordering::Relaxed);
+        self.arrival_time = Local::now();
+    }
+
     /// Create a log line with specific timestamp (for benchmarks and tests)
     #[allow(dead_code)]
     pub fn new_with_time(source: LogSource, line: String, time: DateTime<Local>) -> Self {
+        let (line, links) = hyperlink::extract_hyperlinks(&line).unwrap_or((line, Vec::new()));
         let line_lowercase = line.to_lowercase();
         let formatted_timestamp = time.format("%H:%M:%S").to_string();
         let stripped_line = strip_ansi(&line);
@@ -79,7 +118,93 @@ impl LogLine {
             stripped_line,
             condensed_line,
             condensed_stripped_line,
+            json_display: None,
+            json_display_stripped: None,
+            raw_line: None,
+            links,
+            quiet: false,
+            run: None,
+        }
+    }
+
+    /// Rebuild a log line from exported data (replay). Reuses `id` when given;
+    /// later ids are bumped past it so they can't collide.
+    pub fn restored(id: Option<u64>, source: LogSource, line: String, time: DateTime<Local>) -> Self {
+        let mut log = Self::new_with_time(source, line, time);
+        if let Some(id) = id {
+            NEXT_LOG_ID.fetch_max(id + 1, Ordering::Relaxed);
+            log.id = id;
         }
+        log
+    }
+
+    /// Tag the line with the process run that produced it
+    pub fn with_run(mut self, run: u32) -> Self {
+        self.run = Some(run);
+        self
+    }
+
+    /// Recompute the precomputed timestamp with a custom chrono format.
+    /// The format must already be validated (see `Config::validate`).
+    pub fn set_timestamp_format(&mut self, format: &str) {
+        self.formatted_timestamp = self.timestamp.format(format).to_string();
+    }
+
+    /// Show a JSON log line as its extracted message. `line` keeps the JSON,
+    /// so search, filters, copy and IPC still see it; only the viewer uses the
+    /// `LEVEL message` text. Returns false if it isn't a usable JSON object.
+    pub fn apply_json_fields(&mut self, fields: &JsonLogFields) -> bool {
+        let Some(parsed) = parse_json_line(&self.line, fields) else {
+            return false;
+        };
+        let display = parsed.display_line();
+        self.condensed_line = condense_log_line(&display);
+        self.condensed_stripped_line = strip_ansi(&self.condensed_line);
+        self.json_display_stripped = Some(strip_ansi(&display));
+        self.json_display = Some(display);
+        true
+    }
+
+    /// Remove a leading portion matching `pattern` (e.g. the process's own
+    /// timestamp), keeping the original text. Returns false, leaving the line
+    /// untouched, unless the pattern matches at the very start.
+    pub fn strip_prefix(&mut self, pattern: &Regex) -> bool {
+        let end = match pattern.find(&self.line) {
+            Some(m) if m.start() == 0 && !m.is_empty() => m.end(),
+            _ => return false,
+        };
+        let display = self.line[end..].to_string();
+        self.line_lowercase = display.to_lowercase();
+        self.stripped_line = strip_ansi(&display);
+        self.condensed_line = condense_log_line(&display);
+        self.condensed_stripped_line = strip_ansi(&self.condensed_line);
+        self.raw_line = Some(std::mem::replace(&mut self.line, display));
+        true
+    }
+
+    /// Get the line as the process printed it, if `strip_prefix` shortened it
+    pub fn raw_line(&self) -> Option<&str> {
+        self.raw_line.as_deref()
+    }
+
+    /// Get the OSC 8 hyperlink targets found in the line
+    pub fn links(&self) -> &[String] {
+        &self.links
+    }
+
+    /// Get the original JSON text, if this line was parsed as a structured log
+    pub fn raw_json(&self) -> Option<&str> {
+        self.json_display.as_ref().map(|_| self.line.as_str())
+    }
+
+    /// Text the log viewer shows: the extracted message for a structured log, else `line`
+    pub fn display_line(&self) -> &str {
+        self.json_display.as_deref().unwrap_or(&self.line)
+    }
+
+    /// `display_line` with ANSI codes stripped
+    pub fn display_stripped_line(&self) -> &str {
+        self.json_display_stripped.as_deref().unwrap_or(&self.stripped_line)
     }
 
     /// Get the pre-computed lowercase version of the line
@@ -116,6 +241,10 @@ impl LogLine {
         size += self.stripped_line.capacity();
         size += self.condensed_line.capacity();
         size += self.condensed_stripped_line.capacity();
+        size += self.json_display.as_ref().map_or(0, |display| display.capacity());
+        size += self.json_display_stripped.as_ref().map_or(0, |display| display.capacity());
+        size += self.raw_line.as_ref().map_or(0, |raw| raw.capacity());
+        size += self.links.iter().map(String::capacity).sum::<usize>();
 
         match &self.source {
             LogSource::ProcessStdout(name) => size += name.capacity(),
@@ -128,6 +257,7 @@ impl LogLine {
                 size += name.capacity();
                 size += path.as_os_str().len();
             }
+            LogSource::Annotation => {}
         }
 
         size
@@ -147,8 +277,13 @@ pub enum LogSource {
         name: String,
         path: PathBuf,
     },
+    /// A line oit adds itself, such as a trace note
+    Annotation,
 }
 
+/// Name shown for `LogSource::Annotation` lines
+pub const ANNOTATION_SOURCE_NAME: &str = "oit";
+
 #[allow(dead_code)]
 impl LogSource {
     pub fn process_name(&self) -> &str {
@@ -157,6 +292,7 @@ impl LogSource {
             LogSource::ProcessStderr(name) => name,
             LogSource::File { process_name, .. } => process_name,
             LogSource::StandaloneFile { name, .. } => name,
+            LogSource::Annotation => ANNOTATION_SOURCE_NAME,
         }
     }
 
@@ -175,11 +311,36 @@ impl LogSource {
     pub fn is_standalone_file(&self) -> bool {
         matches!(self, LogSource::StandaloneFile { .. })
     }
+
+    pub fn is_annotation(&self) -> bool {
+        matches!(self, LogSource::Annotation)
+    }
+
+    /// `stdout`, `stderr`, `file` (a process's `log_file`), `standalone` (a `[[log_files]]` entry)
+    /// or `annotation` (added by oit)
+    pub fn source_type(&self) -> &'static str {
+        match self {
+            LogSource::ProcessStdout(_) => "stdout",
+            LogSource::ProcessStderr(_) => "stderr",
+            LogSource::File { .. } => "file",
+            LogSource::StandaloneFile { .. } => "standalone",
+            LogSource::Annotation => "annotation",
+        }
+    }
+
+    /// The file a line was read from (None for process output)
+    pub fn path(&self) -> Option<&Path> {
+        match self {
+            LogSource::File { path, .. } | LogSource::StandaloneFile { path, .. } => Some(path),
+            LogSource::ProcessStdout(_) | LogSource::ProcessStderr(_) | LogSource::Annotation => None,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use chrono::TimeZone;
 
     #[test]
     fn test_log_source_process_name_for_stdout() {
@@ -226,4 +387,78 @@ mod tests {
         assert!(!source.is_file());
         assert!(source.is_standalone_file());
     }
+
+    #[test]
+    fn test_set_timestamp_format_uses_custom_format() {
+        let time = Local.with_ymd_and_hms(2024, 3, 5, 14, 7, 9).unwrap();
+        let mut log = LogLine::new_with_time(LogSource::ProcessStdout("web".to_string()), "hi".to_string(), time);
+        assert_eq!(log.formatted_timestamp(), "14:07:09");
+
+        log.set_timestamp_format("%m/%d %H:%M");
+        assert_eq!(log.formatted_timestamp(), "03/05 14:07");
+
+        log.set_timestamp_format("");
+        assert_eq!(log.formatted_timestamp(), "");
+    }
+
+    #[test]
+    fn test_apply_json_fields_extracts_message_and_keeps_raw() {
+        let raw = r#"{"level":"error","msg":"db down","request_id":"abc"}"#;
+        let mut log = LogLine::new(LogSource::ProcessStdout("api".to_string()), raw.to_string());
+
+        assert!(log.apply_json_fields(&JsonLogFields::default()));
+        assert_eq!(log.display_stripped_line(), "ERROR db down");
+        assert_eq!(log.display_line(), "\x1b[31mERROR\x1b[0m db down");
+        assert_eq!(log.condensed_stripped_line(), "ERROR db down");
+        // Everything but the viewer sees the JSON
+        assert_eq!(log.line, raw);
+        assert_eq!(log.stripped_line(), raw);
+        assert!(log.line_lowercase().contains("request_id"));
+        assert_eq!(log.raw_json(), Some(raw));
+        assert_eq!(detect_log_level(&log.line), Some("error"));
+    }
+
+    #[test]
+    fn test_apply_json_fields_leaves_malformed_json_raw() {
+        let raw = r#"{"level":"error","msg":"cut off"#;
+        let mut log = LogLine::new(LogSource::ProcessStdout("api".to_string()), raw.to_string());
+
+        assert!(!log.apply_json_fields(&JsonLogFields::default()));
+        assert_eq!(log.line, raw);
+        assert_eq!(log.display_line(), raw);
+        assert_eq!(log.stripped_line(), raw);
+        assert_eq!(log.raw_json(), None);
+    }
+
+    #[test]
+    fn test_strip_prefix_removes_leading_match_and_keeps_raw() {
+        let raw = "[2024-12-10 12:00:00] INFO foo";
+        let mut log = LogLine::new(LogSource::ProcessStdout("rails".to_string()), raw.to_string());
+        let pattern = Regex::new(r"\[[^\]]*\] \w+ ").unwrap();
+
+        assert!(log.strip_prefix(&pattern));
+        assert_eq!(log.line, "foo");
+        assert_eq!(log.stripped_line(), "foo");
+        assert_eq!(log.line_lowercase(), "foo");
+        assert_eq!(log.raw_line(), Some(raw));
+    }
+
+    #[test]
+    fn test_strip_prefix_leaves_non_matching_lines_alone() {
+        let pattern = Regex::new(r"\[[^\]]*\] \w+ ").unwrap();
+        for text in ["Started GET /users", "GET [cached] INFO later in the line"] {
+            let mut log = LogLine::new(LogSource::ProcessStdout("rails".to_string()), text.to_string());
+            assert!(!log.strip_prefix(&pattern));
+            assert_eq!(log.line, text);
+            assert_eq!(log.raw_line(), None);
+        }
+    }
+
+    #[test]
+    fn test_new_extracts_osc8_links() {
+        let raw = "open \x1b]8;;https://example.com/build/42\x1b\\build 42\x1b]8;;\x1b\\ now";
+        let log = LogLine::new(LogSource::ProcessStdout("ci".to_string()), raw.to_string());
+        assert_eq!(log.links(), ["https://example.com/build/42".to_string()]);
+        assert_eq!(log.stripped_line(), "open build 42 now");
+    }
 }
@@ -0,0 +1,39 @@
+/// Shareable reference to a log line: `<process>#<id>`, e.g. `web#4821`
+pub fn format_reference(process: &str, id: u64) -> String {
+    format!("{}#{}", process, id)
+}
+
+/// Parse a `<process>#<id>` reference into its process name and line ID
+pub fn parse_reference(reference: &str) -> Result<(&str, u64), String> {
+    let invalid = || format!("invalid reference '{}' (expected <process>#<id>)", reference);
+    let (process, id) = reference.trim().rsplit_once('#').ok_or_else(invalid)?;
+    if process.is_empty() {
+        return Err(invalid());
+    }
+    let id = id.parse::<u64>().map_err(|_| invalid())?;
+    Ok((process, id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_reference() {
+        assert_eq!(format_reference("web", 4821), "web#4821");
+    }
+
+    #[test]
+    fn test_parse_reference_round_trips() {
+        assert_eq!(parse_reference("web#4821"), Ok(("web", 4821)));
+        assert_eq!(parse_reference(&format_reference("api:v2", 7)), Ok(("api:v2", 7)));
+    }
+
+    #[test]
+    fn test_parse_reference_rejects_invalid() {
+        for bad in ["web", "web#", "#42", "web#abc", "web#-1"] {
+            let err = parse_reference(bad).unwrap_err();
+            assert!(err.contains("invalid reference"), "{}: {}", bad, err);
+        }
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use serde_json::Value;
+
+use super::{LogLine, LogSource};
+
+/// Process name used for text lines that don't carry one
+const UNKNOWN_PROCESS: &str = "replay";
+
+/// Parse an exported log dump back into log lines. Accepts:
+/// - the JSON printed by `oit logs` / `oit search` (`{"result": {"logs": [...]}}`)
+/// - JSONL with one `{"id", "process", "content", "timestamp"}` object per line
+/// - the clipboard text format: `[YYYY-MM-DD HH:MM:SS] process: content`
+///
+/// Header lines (`=== Batch 1 ... ===`) and blank lines are skipped; other text
+/// lines are kept as-is under the previous line's process and timestamp.
+pub fn parse_replay(content: &str) -> Vec<LogLine> {
+    let entries = match serde_json::from_str::<Value>(content) {
+        Ok(value) if !value.is_object() || exported_entries(&value).is_some() => {
+            exported_entries(&value).cloned().unwrap_or_default()
+        }
+        _ => Vec::new(),
+    };
+    let mut logs = if entries.is_empty() {
+        parse_lines(content)
+    } else {
+        entries.iter().filter_map(parse_json_entry).collect()
+    };
+
+    // Logs are stored oldest first; `oit search` exports newest first
+    logs.sort_by_key(|log| log.timestamp);
+    dedupe_ids(&mut logs);
+    logs
+}
+
+/// The log array of an `oit logs`/`oit search` response, with or without the envelope
+fn exported_entries(value: &Value) -> Option<&Vec<Value>> {
+    let result = value.get("result").unwrap_or(value);
+    result
+        .get("logs")
+        .or_else(|| result.get("matches"))
+        .and_then(|v| v.as_array())
+}
+
+fn parse_lines(content: &str) -> Vec<LogLine> {
+    let mut logs: Vec<LogLine> = Vec::new();
+    for line in content.lines() {
+        if line.trim().is_empty() || line.starts_with("===") {
+            continue;
+        }
+        if line.trim_start().starts_with('{')
+            && let Ok(value) = serde_json::from_str::<Value>(line)
+            && let Some(log) = parse_json_entry(&value)
+        {
+            logs.push(log);
+            continue;
+        }
+        if let Some(log) = parse_text_line(line) {
+            logs.push(log);
+            continue;
+        }
+        let (process, time) = logs
+            .last()
+            .map(|prev| (prev.source.process_name().to_string(), prev.timestamp))
+            .unwrap_or_else(|| (UNKNOWN_PROCESS.to_string(), Local::now()));
+        logs.push(LogLine::restored(None, LogSource::ProcessStdout(process), line.to_string(), time));
+    }
+    logs
+}
+
+fn parse_json_entry(value: &Value) -> Option<LogLine> {
+    let content = value
+        .get("content")
+        .or_else(|| value.get("line"))
+        .and_then(|v| v.as_str())?;
+    let process = value
+        .get("process")
+        .and_then(|v| v.as_str())
+        .unwrap_or(UNKNOWN_PROCESS);
+    let time = value
+        .get("timestamp")
+        .and_then(|v| v.as_str())
+        .and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|t| t.with_timezone(&Local))
+        .unwrap_or_else(Local::now);
+    let id = value.get("id").and_then(|v| v.as_u64());
+    Some(LogLine::restored(
+        id,
+        LogSource::ProcessStdout(process.to_string()),
+        content.to_string(),
+        time,
+    ))
+}
+
+/// `[2025-12-10 10:00:00] process: content`
+fn parse_text_line(line: &str) -> Option<LogLine> {
+    let rest = line.strip_prefix('[')?;
+    let (stamp, rest) = rest.split_once("] ")?;
+    let (process, content) = rest.split_once(": ")?;
+    let naive = NaiveDateTime::parse_from_str(stamp, "%Y-%m-%d %H:%M:%S").ok()?;
+    let time = Local.from_local_datetime(&naive).earliest()?;
+    Some(LogLine::restored(
+        None,
+        LogSource::ProcessStdout(process.to_string()),
+        content.to_string(),
+        time,
+    ))
+}
+
+/// Concatenated exports can repeat ids; give repeats a fresh one so selection stays unambiguous
+fn dedupe_ids(logs: &mut [LogLine]) {
+    let mut seen = HashSet::new();
+    for log in logs.iter_mut() {
+        if !seen.insert(log.id) {
+            let fresh = LogLine::restored(None, log.source.clone(), String::new(), log.timestamp).id;
+            log.id = fresh;
+            seen.insert(fresh);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_replay_reads_jsonl() {
+        let content = concat!(
+            r#"{"id": 7001, "process": "web", "content": "GET /", "timestamp": "2025-12-10T10:00:00Z"}"#,
+            "\n",
+            r#"{"id": 7002, "process": "worker", "content": "job done", "timestamp": "2025-12-10T10:00:01Z"}"#,
+            "\n",
+        );
+        let logs = parse_replay(content);
+
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].id, 7001);
+        assert_eq!(logs[0].source.process_name(), "web");
+        assert_eq!(logs[0].line, "GET /");
+        assert_eq!(logs[1].source.process_name(), "worker");
+        assert_eq!(logs[1].timestamp.to_utc().to_rfc3339(), "2025-12-10T10:00:01+00:00");
+    }
+
+    #[test]
+    fn test_replay_round_trips_oit_logs_export() {
+        use crate::ipc::state::{LogLineInfo, StateSnapshot};
+        use crate::ipc::{IpcCommandHandler, IpcRequest};
+
+        let base = Local::now();
+        let originals = vec![
+            LogLine::new_with_time(LogSource::ProcessStdout("web".to_string()), "GET /".to_string(), base),
+            LogLine::new_with_time(
+                LogSource::ProcessStderr("worker".to_string()),
+                "ERROR job failed".to_string(),
+                base + chrono::Duration::milliseconds(1500),
+            ),
+        ];
+        let state = StateSnapshot {
+            recent_logs: originals
+                .iter()
+                .map(|log| LogLineInfo {
+                    id: log.id,
+                    process: log.source.process_name().to_string(),
+                    content: log.line.clone(),
+                    timestamp: log.timestamp.to_rfc3339(),
+                    batch_id: None,
+                    stderr: log.source.is_stderr(),
+                    run: None,
+                    source_type: log.source.source_type().to_string(),
+                    source_path: log.source.path().map(|p| p.display().to_string()),
+                    arrival_time: Some(log.arrival_time.to_rfc3339()),
+                })
+                .collect(),
+            total_log_lines: originals.len(),
+            ..StateSnapshot::default()
+        };
+
+        // Serialize exactly as `oit logs` prints it
+        let handler = IpcCommandHandler::new("0.1.0-test");
+        let result = handler.handle(&IpcRequest::new("logs"), Some(&state));
+        let export = serde_json::to_string_pretty(&result.response).unwrap();
+
+        let replayed = parse_replay(&export);
+        assert_eq!(replayed.len(), originals.len());
+        for (replayed, original) in replayed.iter().zip(&originals) {
+            assert_eq!(replayed.id, original.id);
+            assert_eq!(replayed.source.process_name(), original.source.process_name());
+            assert_eq!(replayed.line, original.line);
+            assert_eq!(replayed.timestamp, original.timestamp);
+        }
+    }
+
+    #[test]
+    fn test_parse_replay_reads_clipboard_text() {
+        let content = "=== Batch 1 (2 lines) ===\n\
+            [2025-12-10 10:00:00] web: Started GET /\n\
+            [2025-12-10 10:00:02] web: Completed 200 OK\n\
+            \x20 continuation line\n";
+        let logs = parse_replay(content);
+
+        assert_eq!(logs.len(), 3);
+        assert_eq!(logs[0].line, "Started GET /");
+        assert_eq!(logs[0].timestamp.format("%H:%M:%S").to_string(), "10:00:00");
+        assert_eq!(logs[2].line, "  continuation line");
+        assert_eq!(logs[2].source.process_name(), "web");
+        assert_eq!(logs[2].timestamp, logs[1].timestamp);
+    }
+
+    #[test]
+    fn test_parse_replay_sorts_and_dedupes_ids() {
+        let content = r#"{"result": {"matches": [
+            {"id": 9001, "process": "web", "content": "newer", "timestamp": "2025-12-10T10:00:05Z"},
+            {"id": 9001, "process": "web", "content": "older", "timestamp": "2025-12-10T10:00:00Z"}
+        ]}}"#;
+        let logs = parse_replay(content);
+
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].line, "older");
+        assert_eq!(logs[1].line, "newer");
+        assert_ne!(logs[0].id, logs[1].id);
+    }
+}
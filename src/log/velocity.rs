@@ -3,6 +3,21 @@ use std::collections::VecDeque;
 
 const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
 
+/// One block per count, scaled to the largest; empty buckets are blank
+pub fn render_sparkline(counts: impl Iterator<Item = usize> + Clone) -> String {
+    let max = counts.clone().max().unwrap_or(1).max(1);
+    counts
+        .map(|count| {
+            if count == 0 {
+                ' '
+            } else {
+                let idx = ((count as f64 / max as f64) * 7.0).round() as usize;
+                BLOCKS[idx.min(7)]
+            }
+        })
+        .collect()
+}
+
 /// Tracks log arrival counts in time buckets for sparkline display
 pub struct LogVelocityTracker {
     /// Counts per bucket (most recent at back)
@@ -78,23 +93,7 @@ impl LogVelocityTracker {
 
     /// Generate sparkline string from current buckets
     pub fn sparkline(&self) -> String {
-        if self.buckets.is_empty() {
-            return String::new();
-        }
-
-        let max = *self.buckets.iter().max().unwrap_or(&1).max(&1);
-
-        self.buckets
-            .iter()
-            .map(|&count| {
-                if count == 0 {
-                    ' '
-                } else {
-                    let idx = ((count as f64 / max as f64) * 7.0).round() as usize;
-                    BLOCKS[idx.min(7)]
-                }
-            })
-            .collect()
+        render_sparkline(self.buckets.iter().map(|&count| count as usize))
     }
 
     /// Generate sparkline with minimum bar for zero (maintains visual line)
@@ -1,10 +1,17 @@
 mod cli;
 mod command;
 mod config;
+mod config_watch;
+mod doctor;
 mod event_handler;
+mod exit_summary;
+mod frame;
 mod group;
+mod hooks;
+mod idle;
 mod ipc;
 mod log;
+mod notify;
 mod operations;
 mod procfile;
 mod process;
@@ -15,14 +22,17 @@ mod traces;
 mod ui;
 mod updater;
 
-use cli::{check_already_running, get_socket_path, Cli, Commands, EditorAction, SkillAction, init_config, install_vscode_extension, run_ipc_command};
+use cli::{check_already_running, get_socket_path, Cli, Commands, EditorAction, SkillAction, init_config, install_vscode_extension, InitOptions, run_ipc_command};
 use config::Config;
 use event_handler::EventHandler;
-use ipc::state::{BufferStats, FilterInfo, LogLineInfo, ProcessInfo, StateSnapshot, ViewModeInfo};
-use ipc::{IpcAction, IpcCommandHandler, IpcServer};
-use procfile::Procfile;
-use process::{ProcessManager, ProcessStatus};
-use ui::{App, DisplayMode, FilterType};
+use ipc::state::{BufferStats, FilterInfo, FinalOutputInfo, LogLineInfo, PresetInfo, ProcessDetail, ProcessInfo, StateSnapshot, ViewModeInfo};
+use ipc::{IpcAction, IpcCommandHandler, IpcServer, InstancePaths};
+use ipc::protocol::IpcResponse;
+use ipc::subscription::{ReadyWait, Subscriptions};
+use procfile::ProcfileSource;
+use process::{ProcessManager, ProcessStatus, Readiness, StartupPlan};
+use ui::{App, DisplayMode, FilterType, StatusType};
+use ui::display_state::ProcessPanelViewMode;
 
 use std::io::Write;
 use std::panic;
@@ -70,11 +80,14 @@ async fn main() -> anyhow::Result<()> {
     }
 
     // Check for updates (unless disabled via --no-update or config file)
-    // If update succeeds, this will re-exec and never return
-    let config_disables_update = Config::from_file(config_path)
-        .map(|c| c.disable_auto_update.unwrap_or(false))
-        .unwrap_or(false);
-    if !cli.no_update && !config_disables_update {
+    // If update succeeds, this will re-exec and never return.
+    // With background_update_check the TUI starts first and only reports a newer release.
+    let startup_config = Config::from_file(config_path).ok();
+    let config_disables_update = startup_config.as_ref().and_then(|c| c.disable_auto_update).unwrap_or(false);
+    let check_updates = !cli.no_update && !config_disables_update;
+    let update_in_background = cli.background_update_check
+        || startup_config.as_ref().and_then(|c| c.background_update_check).unwrap_or(false);
+    if check_updates && !update_in_background {
         if let Err(e) = updater::check_and_update(VERSION) {
             eprintln!("Warning: Could not check for updates: {}", e);
         }
@@ -82,11 +95,17 @@ async fn main() -> anyhow::Result<()> {
 
     // Handle --init flag
     if cli.init {
-        return init_config(config_path, cli.procfile.as_deref());
+        return init_config(config_path, cli.procfile.first().map(String::as_str), InitOptions { dry_run: cli.dry_run, force: cli.force });
     }
 
-    // Handle vscode/cursor/skill subcommands (don't need IPC)
+    // Handle doctor/vscode/cursor/skill subcommands (don't need IPC)
     match &cli.command {
+        Some(Commands::Doctor) => {
+            if !doctor::run_doctor(config_path, &cli.procfile) {
+                std::process::exit(1);
+            }
+            return Ok(());
+        }
         Some(Commands::Vscode { action } | Commands::Cursor { action }) => {
             return match action {
                 EditorAction::Install => install_vscode_extension(),
@@ -102,111 +121,15 @@ async fn main() -> anyhow::Result<()> {
 
     // Handle IPC subcommands (ping, status, etc.)
     // These communicate with a running TUI instance and exit
-    if let Some(ref command) = cli.command {
-        return run_ipc_command(command).await;
-    }
-
-    // Check if config file exists and provide helpful error if not
-    if !std::path::Path::new(config_path).exists() {
-        eprintln!("Error: Config file '{}' not found.\n", config_path);
-        eprintln!("To get started:");
-        eprintln!("  1. Create a Procfile with your processes (e.g., 'web: rails server')");
-        eprintln!("  2. Run 'oit --init' to generate a config file");
-        eprintln!("  3. Run 'oit' to start the TUI\n");
-        eprintln!("Or specify a config file with: oit --config <path>\n");
-        eprintln!("For more help, run: oit --help");
-        std::process::exit(1);
-    }
-
-    // Check if another instance is already running in this directory
-    if check_already_running().await? {
-        eprintln!("Error: oit is already running in this directory.");
-        eprintln!("Use 'oit ping' to verify, or remove .oit.sock if the previous instance crashed.");
-        std::process::exit(1);
-    }
-
-    // Load config
-    let mut config = Config::from_file(config_path)?;
-    config.config_path = Some(std::path::PathBuf::from(config_path));
-
-    // Use CLI-specified procfile as a temporary override (not saved to config)
-    let runtime_procfile_path = cli.procfile
-        .as_ref()
-        .map(std::path::PathBuf::from)
-        .unwrap_or_else(|| config.procfile.clone());
-
-    // Parse procfile
-    let procfile = Procfile::from_file(&runtime_procfile_path)?;
-
-    // Validate config (check for name collisions between processes and log files)
-    let process_names: Vec<String> = procfile.processes.keys().cloned().collect();
-    config.validate(&process_names)?;
-
-    // Validate CLI-specified process names exist in Procfile
-    for name in &cli.processes {
-        if !process_names.contains(name) {
-            eprintln!("Error: Process '{}' not found in Procfile.\n", name);
-            eprintln!("Available processes:");
-            for pn in &process_names {
-                eprintln!("  - {}", pn);
-            }
-            std::process::exit(1);
-        }
-    }
-
-    // Determine working directory from Procfile path
-    // If procfile is just "Procfile" (no directory), parent() returns Some("")
-    // We need to filter out empty paths and use current_dir instead
-    let procfile_dir = runtime_procfile_path
-        .parent()
-        .filter(|p| !p.as_os_str().is_empty())
-        .map(|p| p.to_path_buf())
-        .unwrap_or_else(|| std::env::current_dir().unwrap());
-
-    // Create process manager
-    let max_buffer_mb = config.max_log_buffer_mb.unwrap_or(50);
-    let mut manager = ProcessManager::new_with_buffer_limit(max_buffer_mb);
-    manager.set_procfile_path(runtime_procfile_path.clone(), procfile_dir.clone());
-
-    // Add ALL processes from Procfile (skip only ignored ones)
-    for (name, command) in &procfile.processes {
-        // Skip if in ignored_processes (permanent config-based ignore)
-        if config.ignored_processes.contains(name) {
-            continue;
-        }
-
-        // Get status config if available
-        let status_config = config.processes.get(name)
-            .and_then(|pc| pc.status.as_ref());
-
-        // Get stdin config if available
-        let stdin_config = config.processes.get(name)
-            .and_then(|pc| pc.stdin.as_deref());
-
-        manager.add_process(name.clone(), command.clone(), Some(procfile_dir.clone()), status_config, stdin_config);
-
-        // If this process has a log file configured, add it
-        if let Some(proc_config) = config.processes.get(name) {
-            if let Some(log_file) = &proc_config.log_file {
-                let log_path = procfile_dir.join(log_file);
-                manager.add_log_file(name.clone(), log_path).await?;
-            }
-        }
-    }
-
-    // Add standalone log files from config
-    for log_file_config in &config.log_files {
-        let log_path = procfile_dir.join(&log_file_config.path);
-        manager.add_standalone_log_file(log_file_config.name.clone(), log_path).await?;
-    }
+    let replay_file = match &cli.command {
+        Some(Commands::Replay { file }) => Some(file.clone()),
+        Some(command) => return run_ipc_command(command).await,
+        None => None,
+    };
 
-    // Start processes: CLI args override config; empty means start all
-    let start_failures = if !cli.processes.is_empty() {
-        manager.start_specific(&cli.processes).await
-    } else if !config.start_processes.is_empty() {
-        manager.start_specific(&config.start_processes).await
-    } else {
-        manager.start_all().await
+    let Session { mut config, mut manager, start_failures, warnings, session_start } = match &replay_file {
+        Some(file) => load_replay_session(&cli, file)?,
+        None => start_live_session(&cli).await?,
     };
 
     // Install panic hook to restore terminal on panic
@@ -231,8 +154,8 @@ async fn main() -> anyhow::Result<()> {
     // Create app state
     let mut app = App::new();
 
-    // Initialize theme from config (defaults to dark) before any color setup
-    app.set_theme(ui::Theme::from_config(config.theme.as_deref()));
+    // Initialize theme from --theme or config (defaults to dark) before any color setup
+    app.set_theme(ui::Theme::from_config(cli.theme.as_deref().or(config.theme.as_deref())));
 
     // Load batch window from config if specified
     if let Some(batch_window_ms) = config.batch_window_ms {
@@ -241,6 +164,16 @@ async fn main() -> anyhow::Result<()> {
             app.navigation.scroll_offset = 0;
         }
     }
+    if let Some(strategy) = config.batch_strategy.as_deref().and_then(ui::BatchStrategy::parse) {
+        app.batch.set_batch_strategy(strategy);
+    }
+    if let Some(position) = config
+        .process_list_position
+        .as_deref()
+        .and_then(ui::display_state::ProcessListPosition::parse)
+    {
+        app.display.process_list_position = position;
+    }
 
     // Load filters from config
     for pattern in &config.filters.include {
@@ -249,18 +182,58 @@ async fn main() -> anyhow::Result<()> {
     for pattern in &config.filters.exclude {
         app.filters.add_exclude_filter(pattern.clone());
     }
+    for pattern in &config.filters.disabled {
+        app.filters.toggle_filter(pattern);
+    }
 
     // Load hidden processes from config
     app.filters.hidden_processes = config.hidden_processes.iter().cloned().collect();
+    app.display.pinned_processes = config.pinned_processes.clone();
 
     // Initialize process colors from config (only if enabled)
     if config.process_coloring == Some(true) {
         let process_names: Vec<String> = manager.get_processes().keys().cloned().collect();
         let log_file_names = manager.get_standalone_log_file_names();
-        app.init_process_colors(&process_names, &log_file_names, &config.colors);
+        app.init_process_colors(&process_names, &log_file_names, &config.color_overrides());
         app.display.coloring_enabled = true;
     }
 
+    if config.relative_timestamps() {
+        app.display.relative_timestamp_origin = Some(session_start);
+    }
+
+    app.filters.severity = config.severity_matcher();
+
+    // CLI --ansi-mode is a runtime override and is not saved to the config
+    app.display.strip_ansi = match cli.ansi_mode.as_deref() {
+        Some(mode) => mode == "strip",
+        None => config.strip_ansi(),
+    };
+
+    app.display.mark_stderr = config.mark_stderr == Some(true);
+    app.display.tail_preview = config.tail_preview == Some(true);
+    app.display.stable_process_layout = config.stable_process_layout == Some(true);
+    app.display.show_date_dividers = config.show_date_dividers != Some(false);
+    app.display.align_messages = config.align_messages == Some(true);
+    app.display.show_last_line = config.show_last_line == Some(true);
+    app.display.show_commands = config.show_commands == Some(true);
+    app.display.sticky_header = config.sticky_header == Some(true);
+    app.display.highlight_syntax = config.highlight_syntax == Some(true);
+    app.display.volume_histogram = config.volume_histogram == Some(true);
+    if let Some(suffix) = &config.truncation_suffix {
+        app.display.truncation_suffix = suffix.clone();
+    }
+    app.display.line_margin = config.line_margin.unwrap_or(0);
+    app.display.condensed = config.condensed_display == Some(true);
+    app.display.process_panel_mode = ProcessPanelViewMode::initial(manager.get_processes().len());
+    app.navigation.stick.lines = config.scroll_stick_lines.unwrap_or(0);
+
+    let persist_search_history = config.persist_search_history == Some(true);
+    let search_history_path = std::path::Path::new(operations::search::SEARCH_HISTORY_FILE);
+    if persist_search_history {
+        app.input.search_history = operations::search::load_search_history(search_history_path);
+    }
+
     // Load display mode from config (default: Compact if not specified)
     // Config stores bool for backwards compat: true = Compact, false = Full
     if let Some(compact_mode) = config.compact_mode {
@@ -271,22 +244,36 @@ async fn main() -> anyhow::Result<()> {
         }
     }
 
-    // Show startup failures in status bar
+    // Show startup failures (or config warnings) in status bar
     if !start_failures.is_empty() {
         let failure_names: Vec<&str> = start_failures.iter().map(|(n, _)| n.as_str()).collect();
         app.display.set_status_error(format!("Failed to start: {}", failure_names.join(", ")));
+    } else if !warnings.is_empty() {
+        app.display.set_status_info(warnings.join("; "));
+    } else if config.startup_banner == Some(true) {
+        app.display.set_status_transient(operations::config::startup_banner(&config, &manager));
     }
 
-    // Create IPC server for remote control
-    let socket_path = get_socket_path();
-    let mut ipc_server = match IpcServer::new(&socket_path) {
-        Ok(server) => Some(server),
-        Err(e) => {
-            app.display.set_status_error(format!(
-                "Could not create IPC server at {:?}: {}",
-                socket_path, e
-            ));
-            None
+    // Create IPC server for remote control (not in replay mode, so a live
+    // instance in this directory keeps its socket)
+    let mut ipc_server = if let Some(file) = &replay_file {
+        app.display.set_status_info(format!(
+            "Replay mode: {} lines from {} (process control disabled)",
+            manager.get_all_logs().len(),
+            file
+        ));
+        None
+    } else {
+        let socket_path = get_socket_path();
+        match IpcServer::new(&socket_path) {
+            Ok(server) => Some(server),
+            Err(e) => {
+                app.display.set_status_error(format!(
+                    "Could not create IPC server at {:?}: {}",
+                    socket_path, e
+                ));
+                None
+            }
         }
     };
 
@@ -294,7 +281,21 @@ async fn main() -> anyhow::Result<()> {
     let log_rx = manager.take_log_receiver();
 
     // TUI event loop
-    let result = run_app(&mut terminal, &mut app, &mut manager, &mut config, &mut ipc_server, log_rx).await;
+    // Replay has nothing to wait for, so idle auto-quit only applies to live sessions
+    let idle_quit = cli.idle_quit_ms
+        .or(config.idle_quit_ms)
+        .filter(|_| replay_file.is_none())
+        .map(Duration::from_millis);
+    let background = Background {
+        idle_quit,
+        update_check: (check_updates && update_in_background)
+            .then(|| tokio::task::spawn_blocking(|| updater::check_for_update(VERSION))),
+    };
+    let result = run_app(&mut terminal, &mut app, &mut manager, &mut config, &mut ipc_server, log_rx, background).await;
+
+    if persist_search_history {
+        let _ = operations::search::save_search_history(search_history_path, &app.input.search_history);
+    }
 
     // Cleanup IPC socket
     if let Some(ref server) = ipc_server {
@@ -307,10 +308,207 @@ async fn main() -> anyhow::Result<()> {
     // Kill all processes before exiting
     manager.kill_all().await?;
 
+    // How each process fared, for CI logs and post-mortems
+    let summary = exit_summary::summarize(&manager);
+    if !summary.is_empty() {
+        if let Err(e) = exit_summary::write(&summary, cli.json, cli.summary_file.as_deref()) {
+            eprintln!("Warning: Could not write exit summary: {}", e);
+        }
+    }
+
     // Return result
     result
 }
 
+/// Everything the TUI needs from startup, for a live or replay session
+struct Session {
+    config: Config,
+    manager: ProcessManager,
+    /// Processes that failed to spawn, with the error
+    start_failures: Vec<(String, String)>,
+    /// Non-fatal config problems (e.g. log files that don't exist yet)
+    warnings: Vec<String>,
+    session_start: chrono::DateTime<chrono::Local>,
+}
+
+/// Load the config and Procfile, then spawn processes
+async fn start_live_session(cli: &Cli) -> anyhow::Result<Session> {
+    let config_path = &cli.config;
+
+    // Check if config file exists and provide helpful error if not
+    if !std::path::Path::new(config_path).exists() {
+        eprintln!("Error: Config file '{}' not found.\n", config_path);
+        eprintln!("To get started:");
+        eprintln!("  1. Create a Procfile with your processes (e.g., 'web: rails server')");
+        eprintln!("  2. Run 'oit --init' to generate a config file");
+        eprintln!("  3. Run 'oit' to start the TUI\n");
+        eprintln!("Or specify a config file with: oit --config <path>\n");
+        eprintln!("For more help, run: oit --help");
+        std::process::exit(1);
+    }
+
+    // Check if another instance is already running in this directory
+    if check_already_running().await? {
+        eprintln!("Error: oit is already running in this directory.");
+        eprintln!("Use 'oit ping' to verify.");
+        std::process::exit(1);
+    }
+
+    // Load config
+    let mut config = Config::from_file(config_path)?;
+    config.config_path = Some(std::path::PathBuf::from(config_path));
+
+    // Use CLI-specified procfiles as a temporary override (not saved to config)
+    let procfile_paths: Vec<std::path::PathBuf> = if cli.procfile.is_empty() {
+        vec![config.procfile.clone()]
+    } else {
+        cli.procfile.iter().map(std::path::PathBuf::from).collect()
+    };
+
+    // Parse procfiles (several are namespaced as <dir>/<name>)
+    let mut sources = ProcfileSource::for_paths(&procfile_paths)?;
+    // Working directory: --working-dir, then working_dir, then the Procfile's directory
+    let working_dir = cli.working_dir.as_ref().map(std::path::PathBuf::from).or_else(|| config.working_dir.clone());
+    if let Some(dir) = &working_dir {
+        procfile::override_working_dir(&mut sources, dir)?;
+    }
+    let entries = procfile::load_entries(&sources)?;
+    config.namespace_groups = procfile::namespace_groups(&entries);
+
+    // Validate config (check for name collisions between processes and log files)
+    let process_names: Vec<String> = entries.keys().cloned().collect();
+    config.validate(&process_names)?;
+
+    // Validate CLI-specified process names exist in Procfile
+    for name in &cli.processes {
+        if !process_names.contains(name) {
+            eprintln!("Error: Process '{}' not found in Procfile.\n", name);
+            eprintln!("Available processes:");
+            for pn in &process_names {
+                eprintln!("  - {}", pn);
+            }
+            std::process::exit(1);
+        }
+    }
+
+    // Standalone log files resolve against the first Procfile's directory (or the working_dir override)
+    let procfile_dir = sources[0].dir.clone();
+
+    // Create process manager
+    let max_buffer_mb = config.max_log_buffer_mb.unwrap_or(50);
+    let mut manager = ProcessManager::new_with_buffer_limit(max_buffer_mb);
+    let session_start = chrono::Local::now();
+    manager.set_procfiles(sources);
+    manager.set_json_logs(config.json_logs.as_ref().and_then(|j| j.fields()));
+    manager.set_timestamp_format(config.custom_timestamp_format().map(|f| f.to_string()));
+    manager.set_severity(config.severity_matcher());
+    manager.set_max_ingest_per_tick(config.max_ingest_per_tick.unwrap_or(process::DEFAULT_MAX_INGEST_PER_TICK));
+
+    // Add ALL processes from the Procfiles (skip only ignored ones)
+    for (name, entry) in &entries {
+        // Skip if in ignored_processes (permanent config-based ignore)
+        if config.ignored_processes.contains(name) {
+            continue;
+        }
+
+        // Get status config if available
+        let status_config = config.processes.get(name)
+            .and_then(|pc| pc.status.as_ref());
+
+        // Get stdin config if available
+        let stdin_config = config.processes.get(name)
+            .and_then(|pc| pc.stdin.as_deref());
+
+        manager.add_process(name.clone(), entry.command.clone(), Some(entry.dir.clone()), status_config, stdin_config);
+        manager.apply_process_options(name, &entry.options, Some(&entry.dir));
+        manager.apply_process_config(name, config.processes.get(name));
+
+        // If this process has a log file configured, add it
+        if let Some(log_file) = config.processes.get(name).and_then(|pc| pc.log_file.as_ref()) {
+            let log_path = config::resolve_path(log_file, &entry.dir);
+            manager.add_log_file(name.clone(), log_path).await?;
+        }
+    }
+
+    manager.set_ports(&config.assigned_ports(&process_names));
+
+    // Add standalone log files from config
+    for log_file_config in &config.log_files {
+        let log_path = config::resolve_path(&log_file_config.path, &procfile_dir);
+        manager.add_standalone_log_file(log_file_config.name.clone(), log_path).await?;
+    }
+
+    // Start processes: CLI args override config; empty means start all (except
+    // autostart = false), up to max_processes
+    let mut warnings = Vec::new();
+    let start_failures = if cli.no_autostart {
+        Vec::new()
+    } else if !cli.processes.is_empty() {
+        manager.start_specific(&cli.processes).await
+    } else if !config.start_processes.is_empty() {
+        manager.start_specific(&config.start_processes).await
+    } else {
+        let max = config.max_processes.unwrap_or(process::DEFAULT_MAX_PROCESSES);
+        let plan = StartupPlan::new(manager.autostart_names(), max, cli.force);
+        if let Some(warning) = plan.warning(max) {
+            eprintln!("Warning: {}", warning);
+            warnings.push(warning);
+        }
+        manager.start_specific(&plan.start).await
+    };
+
+    warnings.extend(config.log_file_warnings(&procfile_dir));
+
+    Ok(Session { config, manager, start_failures, warnings, session_start })
+}
+
+/// Load an exported log file for `oit replay`. The config is optional and
+/// never saved; no processes are spawned.
+fn load_replay_session(cli: &Cli, file: &str) -> anyhow::Result<Session> {
+    let content = std::fs::read_to_string(file)
+        .map_err(|e| anyhow::anyhow!("Could not read replay file '{}': {}", file, e))?;
+    let logs = log::replay::parse_replay(&content);
+    if logs.is_empty() {
+        anyhow::bail!("No log lines found in '{}'", file);
+    }
+
+    let mut config = if std::path::Path::new(&cli.config).exists() {
+        Config::from_file(&cli.config)?
+    } else {
+        toml::from_str("procfile = \"Procfile\"")?
+    };
+    config.config_path = None;
+    config.validate(&[])?;
+
+    let session_start = logs[0].timestamp;
+    let mut manager = ProcessManager::new_with_buffer_limit(config.max_log_buffer_mb.unwrap_or(50));
+    manager.set_timestamp_format(config.custom_timestamp_format().map(|f| f.to_string()));
+    manager.load_replay(logs);
+
+    Ok(Session { config, manager, start_failures: Vec::new(), warnings: Vec::new(), session_start })
+}
+
+/// Settings and startup work the event loop keeps polling
+struct Background {
+    /// Auto-quit once everything has exited and gone quiet (`idle_quit_ms`)
+    idle_quit: Option<Duration>,
+    /// Update check running off the startup path (`background_update_check`)
+    update_check: Option<tokio::task::JoinHandle<Option<String>>>,
+}
+
+/// Absolute startup paths for the IPC `info` command
+fn instance_paths(config: &Config, manager: &ProcessManager, ipc_server: Option<&IpcServer>) -> InstancePaths {
+    let display = |path: &std::path::Path| {
+        std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf()).display().to_string()
+    };
+    InstancePaths {
+        working_dir: std::env::current_dir().map(|dir| dir.display().to_string()).unwrap_or_default(),
+        config_path: config.config_path.as_deref().map(display),
+        procfiles: manager.procfiles().iter().map(|source| display(&source.path)).collect(),
+        socket_path: ipc_server.map(|server| display(server.socket_path())),
+    }
+}
+
 async fn run_app(
     terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
     app: &mut App,
@@ -318,16 +516,19 @@ async fn run_app(
     config: &mut Config,
     ipc_server: &mut Option<IpcServer>,
     mut log_rx: tokio::sync::mpsc::UnboundedReceiver<process::LogLine>,
+    mut background: Background,
 ) -> anyhow::Result<()> {
     let mut shutdown_ui_shown = false;
     let mut kill_signals_sent = false;
     let mut headless_shutdown = false; // True when terminal is gone (SIGHUP)
-    let ipc_handler = IpcCommandHandler::new(VERSION);
+    let ipc_handler = IpcCommandHandler::new(VERSION)
+        .with_severity(config.severity_matcher())
+        .with_paths(instance_paths(config, manager, ipc_server.as_ref()));
+    let mut subscriptions = Subscriptions::new();
 
-    // Rate limiting for redraws - target ~60fps max
-    let min_frame_duration = tokio::time::Duration::from_millis(16);
-    let mut last_draw = tokio::time::Instant::now();
-    let mut needs_redraw = true;
+    // Redraw only when something changed, capped at max_fps
+    let max_fps = config.max_fps.unwrap_or(frame::DEFAULT_MAX_FPS);
+    let mut frames = frame::FrameScheduler::new(max_fps, std::time::Instant::now());
 
     // Set up signal handlers for graceful shutdown on SIGINT/SIGTERM/SIGHUP
     // SIGINT is typically Ctrl+C when not in raw mode, or sent via `kill -INT <pid>`
@@ -340,11 +541,38 @@ async fn run_app(
     // Create async event stream for terminal events
     let mut event_stream = EventStream::new();
 
+    let mut idle_tracker = background.idle_quit.map(|timeout| idle::IdleTracker::new(timeout, std::time::Instant::now()));
+    let mut failure_notifier = notify::FailureNotifier::from_config(&config.notify_on_failure);
+
+    // Re-apply the config when it's edited on disk (`watch_config = true`)
+    let mut config_watcher = match config.config_path.as_deref().filter(|_| config.watch_config == Some(true)) {
+        Some(path) => match config_watch::ConfigWatcher::new(path) {
+            Ok(watcher) => Some(watcher),
+            Err(e) => {
+                app.display.set_status_error(format!("Could not watch {}: {}", path.display(), e));
+                None
+            }
+        },
+        None => None,
+    };
+
+    // Uptime and main-loop iterations, reported by `ping` so a watchdog can see the loop turning
+    let started_at = std::time::Instant::now();
+    let mut tick: u64 = 0;
+
     loop {
-        // Drain any pending logs (non-blocking)
-        while let Ok(log) = log_rx.try_recv() {
-            manager.process_single_log(log);
-            needs_redraw = true;
+        tick += 1;
+
+        // Drain pending logs (non-blocking, capped per tick so a flood can't starve input)
+        let drained = manager.drain_logs(&mut log_rx);
+        // Lines buffered since the last pass, here or by the select below
+        let new_lines = manager.take_new_line_count();
+        if drained > 0 || new_lines > 0 {
+            frames.mark_dirty();
+        }
+        // Un-mute processes whose `:mute ... until` pattern just arrived
+        if new_lines > 0 && app.filters.has_active_mutes() {
+            operations::visibility::check_unmutes(app, &manager.get_recent_logs(new_lines));
         }
 
         // Handle IPC requests from CLI clients
@@ -352,41 +580,116 @@ async fn run_app(
             // Accept any pending new connections
             let _ = server.accept_pending();
 
+            // Push newly ingested lines to streaming subscribers
+            if new_lines > 0 && !subscriptions.is_empty() {
+                let new_logs = manager.get_recent_logs(new_lines);
+                for (conn_id, frame) in subscriptions.frames(&new_logs, ipc_handler.severity()) {
+                    if server.send_response(conn_id, frame).await.is_err() {
+                        subscriptions.remove(conn_id);
+                    }
+                }
+            }
+
             // Poll for incoming commands
             if let Ok(requests) = server.poll_commands() {
                 for (conn_id, request) in requests {
-                    let snapshot = create_state_snapshot(app, manager, config);
+                    let mut snapshot = create_state_snapshot(app, manager, config);
+                    snapshot.uptime_secs = started_at.elapsed().as_secs();
+                    snapshot.tick = tick;
                     let handler_result = ipc_handler.handle(&request, Some(&snapshot));
 
                     // Process any actions from the handler
+                    let mut response = handler_result.response;
+                    let mut held = false;
                     for action in handler_result.actions {
-                        apply_ipc_action(app, config, manager, action).await;
+                        held |= subscribe_connection(&mut subscriptions, conn_id, &action);
+                        apply_ipc_action(app, config, manager, action, &mut response).await;
+                    }
+                    // Pipeline steps: each action merges into (or fails) its own step's response
+                    if !handler_result.steps.is_empty() {
+                        let mut responses = Vec::with_capacity(handler_result.steps.len());
+                        for step in handler_result.steps {
+                            let mut step_response = step.response;
+                            for action in step.actions {
+                                held |= subscribe_connection(&mut subscriptions, conn_id, &action);
+                                apply_ipc_action(app, config, manager, action, &mut step_response).await;
+                            }
+                            responses.push(step_response);
+                        }
+                        response = IpcResponse::ok(serde_json::json!({ "responses": responses }));
+                    }
+
+                    if !held {
+                        let _ = server.send_response(conn_id, response).await;
                     }
+                    frames.mark_dirty();
+                }
+            }
 
-                    let _ = server.send_response(conn_id, handler_result.response).await;
+            // Answer held `restart --wait-ready` requests whose processes are up (or down)
+            if subscriptions.has_ready_waits() {
+                let resolved = subscriptions.resolve_ready_waits(
+                    std::time::Instant::now(),
+                    |name| manager.get_processes().get(name).map_or(Readiness::Down("not found".to_string()), |p| p.readiness()),
+                    |name, n| manager.get_recent_process_logs(name, n).iter().map(|l| l.line.clone()).collect(),
+                );
+                for (conn_id, response) in resolved {
+                    let _ = server.send_response(conn_id, response).await;
                 }
             }
         }
 
+        if let Some(watcher) = config_watcher.as_mut()
+            && watcher.poll(std::time::Instant::now())
+        {
+            operations::config::reload_config(app, manager, config);
+            frames.mark_dirty();
+        }
+
+        if let Some(check) = background.update_check.take_if(|check| check.is_finished())
+            && let Ok(Some(message)) = check.await
+        {
+            app.display.set_status_info(message);
+            frames.mark_dirty();
+        }
+
         // Check for newly failed processes (not during shutdown)
         if !app.shutting_down {
             let newly_failed = manager.check_all_status().await;
+            if let Some(notifier) = failure_notifier.as_mut() {
+                notifier.notify(&newly_failed, std::time::Instant::now());
+            }
             if !newly_failed.is_empty() {
                 // Show the first failure in status bar (to avoid overwhelming)
                 let (name, msg) = &newly_failed[0];
                 app.display.set_status_error(format!("{}: {}", name, msg));
+                frames.mark_dirty();
+            }
+            if let Some((name, msg)) = manager.start_waiting().await.into_iter().next() {
+                app.display.set_status_error(format!("{}: {}", name, msg));
+                frames.mark_dirty();
+            }
+            if let Some(err) = manager.fire_hooks().into_iter().next() {
+                app.display.set_status_error(err);
+            }
+
+            // Auto-quit once everything has exited and gone quiet (idle_quit_ms)
+            if let Some(tracker) = idle_tracker.as_mut() {
+                let active = drained > 0 || !newly_failed.is_empty() || !manager.all_exited();
+                if tracker.tick(active, std::time::Instant::now()) {
+                    app.start_shutdown();
+                    frames.mark_dirty();
+                }
             }
         }
 
-        // Draw UI with rate limiting (skip if terminal is gone during headless shutdown)
-        let now = tokio::time::Instant::now();
-        let time_since_last_draw = now.duration_since(last_draw);
-        if !headless_shutdown && needs_redraw && time_since_last_draw >= min_frame_duration {
+        // Draw UI when due (skip if terminal is gone during headless shutdown)
+        let now = std::time::Instant::now();
+        if !headless_shutdown && frames.should_draw(now) {
             terminal.draw(|f| {
                 ui::draw(f, app, manager);
             })?;
-            last_draw = now;
-            needs_redraw = false;
+            frames.drawn(now);
         }
 
         // Handle pending restarts (after UI has been drawn showing "Restarting" status)
@@ -401,10 +704,10 @@ async fn run_app(
         if !failed.is_empty() {
             let failed_names: Vec<&str> = failed.iter().map(|(n, _)| n.as_str()).collect();
             app.display.set_status_error(format!("Restart failed: {}", failed_names.join(", ")));
-            needs_redraw = true;
+            frames.mark_dirty();
         } else if !succeeded.is_empty() {
             app.display.set_status_success(format!("Restarted: {}", succeeded.join(", ")));
-            needs_redraw = true;
+            frames.mark_dirty();
         }
 
         // Check if we're shutting down
@@ -466,7 +769,7 @@ async fn run_app(
                                 if event_handler.handle_key_event(key).await? {
                                     return Ok(()); // Quit was requested
                                 }
-                                needs_redraw = true;
+                                frames.mark_input();
 
                                 // After navigation keys, drain duplicate keys to prevent scroll inertia.
                                 // When holding a key, the OS queues repeated KeyPress events faster than
@@ -519,10 +822,10 @@ async fn run_app(
                         Event::Mouse(mouse) => {
                             let mut event_handler = EventHandler::new(app, manager, config);
                             event_handler.handle_mouse_event(mouse)?;
-                            needs_redraw = true;
+                            frames.mark_input();
                         }
                         Event::Resize(_, _) => {
-                            needs_redraw = true;
+                            frames.mark_dirty();
                         }
                         _ => {}
                     }
@@ -531,18 +834,12 @@ async fn run_app(
             // Wake up immediately when new logs arrive (event-driven refresh)
             maybe_log = log_rx.recv() => {
                 if let Some(log) = maybe_log {
-                    manager.process_single_log(log);
-                    needs_redraw = true;
+                    manager.ingest_from_channel(log);
+                    frames.mark_dirty();
                 }
             }
-            // Timeout: wake up for next frame or periodic tasks
-            _ = tokio::time::sleep(if needs_redraw {
-                // If we need to redraw, sleep until next frame is due
-                min_frame_duration.saturating_sub(time_since_last_draw)
-            } else {
-                // Otherwise, longer sleep for periodic tasks
-                tokio::time::Duration::from_millis(100)
-            }) => {
+            // Timeout: wake up for the next frame, or at least every 100ms for periodic tasks
+            _ = tokio::time::sleep(frames.sleep_for(std::time::Instant::now()).min(Duration::from_millis(100))) => {
                 // Continue to handle IPC, status checks, and pending redraws
             }
         }
@@ -568,6 +865,21 @@ fn color_to_string(color: Color) -> String {
 }
 
 /// Create a StateSnapshot from current App and ProcessManager state for IPC commands
+fn log_line_info(log: &process::LogLine) -> LogLineInfo {
+    LogLineInfo {
+        id: log.id,
+        process: log.source.process_name().to_string(),
+        content: log.line.clone(),
+        timestamp: log.timestamp.to_rfc3339(),
+        batch_id: None, // Batch detection is expensive; skip for now
+        stderr: log.source.is_stderr(),
+        run: log.run,
+        source_type: log.source.source_type().to_string(),
+        source_path: log.source.path().map(|p| p.display().to_string()),
+        arrival_time: Some(log.arrival_time.to_rfc3339()),
+    }
+}
+
 fn create_state_snapshot(app: &App, manager: &ProcessManager, config: &Config) -> StateSnapshot {
     // Build process info list
     let processes: Vec<ProcessInfo> = manager
@@ -580,6 +892,7 @@ fn create_state_snapshot(app: &App, manager: &ProcessManager, config: &Config) -
                 ProcessStatus::Terminating => ("terminating".to_string(), None),
                 ProcessStatus::Restarting => ("restarting".to_string(), None),
                 ProcessStatus::Failed(msg) => ("failed".to_string(), Some(msg.clone())),
+                ProcessStatus::Done => ("done".to_string(), None),
             };
             let (custom_label, custom_color) = handle
                 .get_custom_status()
@@ -594,6 +907,7 @@ fn create_state_snapshot(app: &App, manager: &ProcessManager, config: &Config) -
                 error,
                 custom_label,
                 custom_color,
+                color: app.process_colors.color_name(name),
             }
         })
         .collect();
@@ -609,36 +923,70 @@ fn create_state_snapshot(app: &App, manager: &ProcessManager, config: &Config) -
                 FilterType::Include => "include".to_string(),
                 FilterType::Exclude => "exclude".to_string(),
             },
+            enabled: f.enabled,
         })
         .collect();
 
+    let mut presets: Vec<PresetInfo> = config
+        .presets
+        .iter()
+        .map(|(name, preset)| PresetInfo {
+            name: name.clone(),
+            include: preset.include.clone(),
+            exclude: preset.exclude.clone(),
+        })
+        .collect();
+    presets.sort_by(|a, b| a.name.cmp(&b.name));
+
     // Get buffer stats
     let stats = manager.get_buffer_stats();
     let buffer_stats = BufferStats {
         buffer_bytes: (stats.memory_mb * 1024.0 * 1024.0) as usize,
         max_buffer_bytes: stats.limit_mb * 1024 * 1024,
         usage_percent: stats.percent,
+        ingest_backlog: manager.ingest_backlog(),
     };
 
     // Get recent logs (last 1000 for IPC - callers can use limit/offset)
-    let recent_logs: Vec<LogLineInfo> = manager
-        .get_recent_logs(1000)
+    let recent = manager.get_recent_logs(1000);
+    let mut process_details: std::collections::HashMap<String, ProcessDetail> = manager
+        .get_processes()
         .iter()
-        .map(|log| LogLineInfo {
-            id: log.id,
-            process: log.source.process_name().to_string(),
-            content: log.line.clone(),
-            timestamp: log.timestamp.to_rfc3339(),
-            batch_id: None, // Batch detection is expensive; skip for now
+        .map(|(name, handle)| {
+            let detail = ProcessDetail {
+                command: handle.command.clone(),
+                working_dir: handle.working_dir.as_ref().map(|d| d.display().to_string()),
+                env: handle.spawn_env().into_iter().collect(),
+                exit_code: handle.exit_code(),
+                exit_signal: handle.exit_signal(),
+                dropped_lines: handle.output_pressure().dropped(),
+                final_output: manager.final_output(name).map(|output| FinalOutputInfo {
+                    error: output.error.clone(),
+                    failed_at: output.failed_at.to_rfc3339(),
+                    lines: output.lines.iter().map(log_line_info).collect(),
+                }),
+                ..Default::default()
+            };
+            (name.clone(), detail)
         })
         .collect();
+    for log in recent.iter().rev() {
+        if let Some(detail) = process_details.get_mut(log.source.process_name()) {
+            detail.recent_lines += 1;
+            if detail.last_error.is_none() && app.filters.severity.detect(&log.line) == Some("error") {
+                detail.last_error = Some(log.line.clone());
+            }
+        }
+    }
+
+    let recent_logs: Vec<LogLineInfo> = recent.iter().map(|log| log_line_info(log)).collect();
 
     let total_log_lines = stats.line_count;
 
     StateSnapshot {
         processes,
         log_files: manager.get_standalone_log_file_names(),
-        groups: config.groups.clone(),
+        groups: config.all_groups(),
         filter_count: app.filters.filters.len(),
         active_filters,
         search_pattern: if app.input.search_pattern.is_empty() {
@@ -652,15 +1000,45 @@ fn create_state_snapshot(app: &App, manager: &ProcessManager, config: &Config) -
             trace_filter: app.trace.trace_filter_mode,
             trace_selection: app.trace.trace_selection_mode,
             display_mode: app.display.display_mode.name().to_string(),
+            errors_only: app.filters.errors_only,
+            batch_window_ms: app.batch.batch_window_ms,
+            batch_strategy: app.batch.batch_strategy.name().to_string(),
         },
         auto_scroll: app.navigation.auto_scroll,
         log_count: stats.line_count,
         buffer_stats,
         trace_recording: app.trace.manual_trace_recording,
         active_trace_id: app.trace.active_trace_id.clone(),
+        recording_trace_id: app.trace.manual_trace_id.clone(),
+        uptime_secs: 0,
+        tick: 0,
+        presets,
+        pinned_processes: app.display.pinned_processes.clone(),
+        search_match_ids: operations::search::visible_match_ids(app, manager),
         recent_logs,
         total_log_lines,
         hidden_processes: app.filters.hidden_processes.iter().cloned().collect(),
+        selected_line_id: app.navigation.selected_line_id,
+        process_colors: app.process_colors.name_map(),
+        process_details,
+    }
+}
+
+/// Register a streaming or held connection for `tail_errors` / `wait_ready`.
+/// Returns true when the response must wait for the ready wait to resolve.
+fn subscribe_connection(subscriptions: &mut Subscriptions, conn_id: ipc::server::ConnectionId, action: &IpcAction) -> bool {
+    match action {
+        IpcAction::TailErrors { level } => {
+            subscriptions.add_tail_errors(conn_id, *level);
+            false
+        }
+        IpcAction::WaitReady { names, timeout_ms } => {
+            let timeout = Duration::from_millis(*timeout_ms);
+            let wait = ReadyWait::new(names.clone(), timeout, std::time::Instant::now());
+            subscriptions.add_ready_wait(conn_id, wait);
+            true
+        }
+        _ => false,
     }
 }
 
@@ -670,6 +1048,7 @@ async fn apply_ipc_action(
     config: &mut Config,
     manager: &mut ProcessManager,
     action: IpcAction,
+    response: &mut IpcResponse,
 ) {
     match action {
         IpcAction::SetSearch { pattern } => {
@@ -690,6 +1069,18 @@ async fn apply_ipc_action(
             // Set the selected line - log_viewer will auto-scroll to show it
             app.navigation.selected_line_id = Some(id);
         }
+        IpcAction::GotoRecent { secs } => {
+            // The response already lists the lines; a TUI filter may still hide them all
+            if let Ok(msg) = operations::goto::goto_recent(app, manager, secs) {
+                app.display.set_status_info(msg);
+            }
+        }
+        IpcAction::GotoError { direction, from_id } => {
+            match operations::navigation::select_error(app, manager, direction, from_id) {
+                Ok(id) => response.merge_result(serde_json::json!({"id": id})),
+                Err(msg) => *response = IpcResponse::err(msg),
+            }
+        }
         IpcAction::ScrollUp { lines } => {
             app.navigation.scroll_up(lines);
         }
@@ -701,6 +1092,16 @@ async fn apply_ipc_action(
         IpcAction::ScrollToTop => {
             app.navigation.scroll_to_top();
         }
+        IpcAction::ScrollToPercent { percent } => {
+            let (offset, total) = operations::navigation::scroll_to_percent(app, manager, percent);
+            response.merge_result(serde_json::json!({"offset": offset, "total": total}));
+        }
+        IpcAction::SetErrorsOnly { enabled } => {
+            app.filters.errors_only = enabled;
+        }
+        IpcAction::SetDisplayMode { mode } => {
+            app.display.set_display_mode(mode);
+        }
         IpcAction::SetFrozen { frozen } => {
             if frozen {
                 app.navigation.freeze_display();
@@ -721,6 +1122,17 @@ async fn apply_ipc_action(
         IpcAction::ClearFilters => {
             operations::filter::clear_filters(app, config);
         }
+        IpcAction::ToggleFilter { pattern } => {
+            if let Err(msg) = operations::filter::toggle_filter(app, config, &pattern) {
+                *response = IpcResponse::err(msg);
+            }
+        }
+        IpcAction::ApplyPreset { name } => {
+            match operations::filter::apply_preset(app, config, &name) {
+                Ok(msg) => app.display.set_status_success(msg),
+                Err(msg) => app.display.set_status_error(msg),
+            }
+        }
         IpcAction::HideProcess { name } => {
             // Runtime only - directly modify hidden_processes without saving to config
             app.filters.hidden_processes.insert(name);
@@ -786,6 +1198,121 @@ async fn apply_ipc_action(
                 Err(msg) => app.display.set_status_error(msg),
             }
         }
+        IpcAction::KillAllProcesses => {
+            let outcome = manager.kill_running().await;
+            response.merge_result(outcome.to_json());
+            if outcome.failed.is_empty() {
+                app.display.set_status_success(outcome.summary("Stopped"));
+            } else {
+                app.display.set_status_error(outcome.summary("Stopped"));
+            }
+        }
+        IpcAction::StartAllProcesses => {
+            let outcome = manager.start_stopped().await;
+            response.merge_result(outcome.to_json());
+            if outcome.failed.is_empty() {
+                app.display.set_status_success(outcome.summary("Started"));
+            } else {
+                app.display.set_status_error(outcome.summary("Started"));
+            }
+        }
+        IpcAction::AddProcess { name, command, cwd, env } => {
+            let cwd = cwd.map(std::path::PathBuf::from);
+            match operations::process::add_process(manager, &name, &command, cwd, env).await {
+                Ok(msg) => app.display.set_status_success(msg),
+                Err(msg) => app.display.set_status_error(msg),
+            }
+            operations::coloring::refresh_process_colors(app, manager, config);
+        }
+        IpcAction::AddLogFile { name, path } => {
+            match manager.add_runtime_log_file(&name, std::path::Path::new(&path)).await {
+                Ok(resolved) => {
+                    response.merge_result(serde_json::json!({
+                        "path": resolved.display().to_string(),
+                        "exists": resolved.exists()
+                    }));
+                    app.display.set_status_success(format!("Tailing {} as {}", resolved.display(), name));
+                }
+                Err(e) => *response = IpcResponse::err(e.to_string()),
+            }
+            operations::coloring::refresh_process_colors(app, manager, config);
+        }
+        IpcAction::SetBufferLimit { mb } => {
+            let evicted = manager.set_buffer_limit(mb);
+            response.merge_result(serde_json::json!({"evicted_lines": evicted}));
+            app.display.set_status_success(operations::logs::buffer_limit_message(mb, evicted));
+        }
+        IpcAction::StartTraceRecording { label } => {
+            let id = operations::manual_trace::start_labeled_recording(app, label);
+            response.merge_result(serde_json::json!({"trace_id": id}));
+        }
+        IpcAction::StopTraceRecording => {
+            let result = operations::manual_trace::stop_recording(app, manager);
+            let captured = result.is_ok();
+            let message = match result {
+                Ok(msg) => {
+                    app.display.set_status_success(msg.clone());
+                    msg
+                }
+                Err(msg) => {
+                    app.display.set_status_error(msg.clone());
+                    msg
+                }
+            };
+            response.merge_result(serde_json::json!({"captured": captured, "message": message}));
+        }
+        IpcAction::AnnotateTrace { note } => {
+            match operations::manual_trace::annotate(app, manager, &note) {
+                Ok(msg) => app.display.set_status_info(msg),
+                Err(msg) => app.display.set_status_error(msg),
+            }
+        }
+        IpcAction::SetStatus { message, status_type } => match status_type {
+            StatusType::Info => app.display.set_status_info(message),
+            StatusType::Success => app.display.set_status_success(message),
+            StatusType::Error => app.display.set_status_error(message),
+        },
+        IpcAction::ClearStatus => app.display.clear_status(),
+        IpcAction::CopyVisible { clipboard } => {
+            let logs = operations::navigation::get_display_logs(app, manager);
+            if logs.is_empty() {
+                response.merge_result(serde_json::json!({"text": "", "line_count": 0}));
+                return;
+            }
+            match operations::clipboard::build_visible_text(&logs) {
+                Ok(result) => {
+                    if clipboard {
+                        match operations::clipboard::copy_to_clipboard(&result.text) {
+                            Ok(()) => app.display.set_status_success(result.message),
+                            Err(e) => response.merge_result(serde_json::json!({"clipboard_error": e.to_string()})),
+                        }
+                    }
+                    response.merge_result(serde_json::json!({"text": result.text, "line_count": logs.len()}));
+                }
+                Err(msg) => *response = IpcResponse::err(msg),
+            }
+        }
+        IpcAction::CopyText { text } => match operations::clipboard::copy_to_clipboard(&text) {
+            Ok(()) => {
+                app.display.set_status_success("Agent copied text to clipboard".to_string());
+                response.merge_result(serde_json::json!({"copied": true}));
+            }
+            Err(e) => *response = IpcResponse::err(format!("clipboard unavailable: {}", e)),
+        },
+        IpcAction::ProcessLogs { name, limit, strip_ansi } => {
+            match operations::logs::process_logs(manager, &name, limit) {
+                Ok(logs) => {
+                    let logs: Vec<serde_json::Value> = logs
+                        .iter()
+                        .map(|log| ipc::handler::log_entry(&log_line_info(log), strip_ansi, None))
+                        .collect();
+                    response.merge_result(serde_json::json!({"count": logs.len(), "logs": logs}));
+                }
+                Err(msg) => *response = IpcResponse::err(msg),
+            }
+        }
+        // Registered by the main loop, which knows the requesting connection
+        IpcAction::TailErrors { .. } | IpcAction::WaitReady { .. } => {}
     }
 }
 
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::{Duration, Instant};
+
+/// A process that keeps crashing notifies at most once per this window
+pub const NOTIFY_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// Tells the user a process failed while they may be looking elsewhere
+/// (`notify_on_failure`): a terminal bell and/or a desktop notification.
+pub struct FailureNotifier {
+    bell: bool,
+    desktop: bool,
+    last_notified: HashMap<String, Instant>,
+}
+
+impl FailureNotifier {
+    /// None when `notify_on_failure` is empty
+    pub fn from_config(kinds: &[String]) -> Option<Self> {
+        let bell = kinds.iter().any(|k| k == "bell");
+        let desktop = kinds.iter().any(|k| k == "desktop");
+        (bell || desktop).then(|| Self { bell, desktop, last_notified: HashMap::new() })
+    }
+
+    /// Failures from `check_all_status` that should notify now: each process
+    /// at most once per `NOTIFY_COOLDOWN`, so a crash loop doesn't spam.
+    pub fn due<'a>(&mut self, newly_failed: &'a [(String, String)], now: Instant) -> Vec<&'a (String, String)> {
+        newly_failed
+            .iter()
+            .filter(|(name, _)| {
+                let quiet = self
+                    .last_notified
+                    .get(name)
+                    .is_some_and(|last| now.duration_since(*last) < NOTIFY_COOLDOWN);
+                if !quiet {
+                    self.last_notified.insert(name.clone(), now);
+                }
+                !quiet
+            })
+            .collect()
+    }
+
+    /// Ring the bell and/or post a notification for the failures that are due
+    pub fn notify(&mut self, newly_failed: &[(String, String)], now: Instant) {
+        let due = self.due(newly_failed, now);
+        if due.is_empty() {
+            return;
+        }
+        if self.bell {
+            let mut stdout = std::io::stdout();
+            let _ = stdout.write_all(b"\x07");
+            let _ = stdout.flush();
+        }
+        if self.desktop {
+            for (name, error) in due {
+                post_desktop_notification(&format!("oit: {} failed", name), error);
+            }
+        }
+    }
+}
+
+/// Post a desktop notification with the platform's notifier (`osascript` on
+/// macOS, `notify-send` elsewhere). Failures are ignored: the status bar
+/// already shows the error.
+fn post_desktop_notification(title: &str, body: &str) {
+    let mut cmd = if cfg!(target_os = "macos") {
+        let quote = |s: &str| s.replace('\\', "\\\\").replace('"', "\\\"");
+        let mut cmd = Command::new("osascript");
+        cmd.arg("-e").arg(format!(
+            "display notification \"{}\" with title \"{}\"",
+            quote(body),
+            quote(title)
+        ));
+        cmd
+    } else {
+        let mut cmd = Command::new("notify-send");
+        cmd.arg(title).arg(body);
+        cmd
+    };
+    cmd.stdin(Stdio::null()).stdout(Stdio::null()).stderr(Stdio::null());
+    // Reap the notifier off the main loop
+    std::thread::spawn(move || {
+        let _ = cmd.status();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn failed(names: &[&str]) -> Vec<(String, String)> {
+        names.iter().map(|n| (n.to_string(), "Exited with code 1".to_string())).collect()
+    }
+
+    fn due_names(notifier: &mut FailureNotifier, names: &[&str], now: Instant) -> Vec<String> {
+        notifier.due(&failed(names), now).into_iter().map(|(n, _)| n.clone()).collect()
+    }
+
+    #[test]
+    fn test_from_config_is_off_without_kinds() {
+        assert!(FailureNotifier::from_config(&[]).is_none());
+        assert!(FailureNotifier::from_config(&["bell".to_string()]).is_some());
+    }
+
+    #[test]
+    fn test_due_only_for_new_failures() {
+        let mut notifier = FailureNotifier::from_config(&["bell".to_string()]).unwrap();
+        let start = Instant::now();
+
+        // No transitions this tick: nothing to notify
+        assert!(due_names(&mut notifier, &[], start).is_empty());
+        assert_eq!(due_names(&mut notifier, &["web", "worker"], start), vec!["web", "worker"]);
+    }
+
+    #[test]
+    fn test_due_debounces_a_crash_loop_per_process() {
+        let mut notifier = FailureNotifier::from_config(&["desktop".to_string()]).unwrap();
+        let start = Instant::now();
+
+        assert_eq!(due_names(&mut notifier, &["web"], start), vec!["web"]);
+        // web crashes again after a restart: suppressed, but worker's first failure isn't
+        let later = start + Duration::from_secs(5);
+        assert_eq!(due_names(&mut notifier, &["web", "worker"], later), vec!["worker"]);
+        assert!(due_names(&mut notifier, &["web"], start + NOTIFY_COOLDOWN - Duration::from_millis(1)).is_empty());
+        assert_eq!(due_names(&mut notifier, &["web"], start + NOTIFY_COOLDOWN), vec!["web"]);
+    }
+}
@@ -74,7 +74,7 @@ pub fn focus_batch(app: &mut App, manager: &ProcessManager) -> Result<String, St
     };
 
     let filtered_refs: Vec<&LogLine> = filtered_logs.iter().collect();
-    let batches = ui::detect_batches_from_logs(&filtered_refs, app.batch.batch_window_ms);
+    let batches = ui::detect_batches_from_logs(&filtered_refs, app.batch.batch_window_ms, app.batch.batch_strategy);
 
     let batch_idx = batches
         .iter()
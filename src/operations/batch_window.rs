@@ -1,6 +1,6 @@
 use crate::config::Config;
 use crate::process::ProcessManager;
-use crate::ui::App;
+use crate::ui::{App, BatchStrategy};
 use super::config::save_config_with_error;
 use super::logs::FilteredLogs;
 
@@ -17,7 +17,7 @@ pub fn increase_batch_window(
         app.navigation.scroll_offset = 0;
     }
 
-    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, new_window);
+    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, new_window, app.batch.batch_strategy);
 
     config.batch_window_ms = Some(new_window);
     save_config_with_error(config, app);
@@ -38,7 +38,7 @@ pub fn decrease_batch_window(
         app.navigation.scroll_offset = 0;
     }
 
-    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, new_window);
+    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, new_window, app.batch.batch_strategy);
 
     config.batch_window_ms = Some(new_window);
     save_config_with_error(config, app);
@@ -59,10 +59,31 @@ pub fn set_batch_window(
         app.navigation.scroll_offset = 0;
     }
 
-    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, ms);
+    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, ms, app.batch.batch_strategy);
 
     config.batch_window_ms = Some(ms);
     save_config_with_error(config, app);
 
     filtered.batches.len()
 }
+
+/// Set the batch strategy (persisted to config).
+/// Returns the batch count with the new strategy.
+pub fn set_batch_strategy(
+    app: &mut App,
+    manager: &ProcessManager,
+    config: &mut Config,
+    strategy: BatchStrategy
+) -> usize {
+    app.batch.set_batch_strategy(strategy);
+    if app.batch.batch_view_mode {
+        app.navigation.scroll_offset = 0;
+    }
+
+    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, app.batch.batch_window_ms, strategy);
+
+    config.batch_strategy = Some(strategy.name().to_string());
+    save_config_with_error(config, app);
+
+    filtered.batches.len()
+}
@@ -1,18 +1,40 @@
 use anyhow::Result as AnyhowResult;
 use arboard::Clipboard;
 
-use crate::log::LogLine;
+use crate::log::{format_reference, LogLine};
 use crate::operations::logs::FilteredLogs;
-use crate::operations::navigation::get_selected_log_ids;
+use crate::operations::navigation::{get_display_logs, get_selected_log_ids};
 use crate::process::ProcessManager;
 use crate::ui::App;
 
-fn copy_to_clipboard(text: &str) -> AnyhowResult<()> {
+pub fn copy_to_clipboard(text: &str) -> AnyhowResult<()> {
     let mut clipboard = Clipboard::new()?;
     clipboard.set_text(text)?;
     Ok(())
 }
 
+/// Hand a URL to the platform's opener (`open`, `xdg-open`, `start`) without
+/// waiting for it, so the TUI keeps drawing.
+pub fn open_url(url: &str) -> std::io::Result<()> {
+    use std::process::{Command, Stdio};
+
+    let mut cmd = if cfg!(target_os = "macos") {
+        Command::new("open")
+    } else if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", "start", ""]);
+        cmd
+    } else {
+        Command::new("xdg-open")
+    };
+    cmd.arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
 /// Find a log line by its ID in the given list.
 fn find_log_by_id<'a>(logs: &'a [LogLine], id: u64) -> Option<&'a LogLine> {
     logs.iter().find(|log| log.id == id)
@@ -37,6 +59,9 @@ fn format_logs(logs: &[LogLine]) -> String {
     text
 }
 
+/// Most lines `:copyall` will put on the clipboard at once
+pub const MAX_COPY_ALL_LINES: usize = 50_000;
+
 /// Represents what should be copied and the success message.
 #[derive(Debug)]
 pub struct CopyResult {
@@ -102,6 +127,20 @@ pub fn build_line_text(app: &App, filtered: &FilteredLogs) -> Result<CopyResult,
     })
 }
 
+/// Build the `<process>#<id>` reference for the selected line.
+pub fn build_reference_text(app: &App, filtered: &FilteredLogs) -> Result<CopyResult, String> {
+    let line_id = app.navigation.selected_line_id
+        .ok_or_else(|| "No line selected".to_string())?;
+    let log = find_log_by_id(&filtered.logs, line_id)
+        .ok_or_else(|| "Selected line not found".to_string())?;
+
+    let text = format_reference(log.source.process_name(), log.id);
+    Ok(CopyResult {
+        message: format!("Copied reference {}", text),
+        text,
+    })
+}
+
 /// Build the text for copying multiple selected lines.
 pub fn build_multi_select_text(app: &App, filtered: &FilteredLogs) -> Result<CopyResult, String> {
     // Apply batch view mode filtering if enabled
@@ -278,6 +317,36 @@ pub fn build_batch_text(app: &App, filtered: &FilteredLogs) -> Result<CopyResult
     })
 }
 
+/// Build the text for copying every line in the current view, ANSI-stripped.
+/// `logs` is the view as displayed (filters, errors-only, hidden processes, search, batch).
+pub fn build_visible_text(logs: &[LogLine]) -> Result<CopyResult, String> {
+    if logs.is_empty() {
+        return Err("No visible lines to copy".to_string());
+    }
+    if logs.len() > MAX_COPY_ALL_LINES {
+        return Err(format!(
+            "{} visible lines is too many to copy (limit {}); narrow the view with filters first",
+            logs.len(),
+            MAX_COPY_ALL_LINES
+        ));
+    }
+
+    let mut text = String::new();
+    for log in logs {
+        text.push_str(&format!(
+            "[{}] {}: {}\n",
+            log.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            log.source.process_name(),
+            log.stripped_line()
+        ));
+    }
+
+    Ok(CopyResult {
+        text,
+        message: format!("Copied {} visible lines", logs.len()),
+    })
+}
+
 /// Build the context-aware copy text based on current app state.
 pub fn build_context_text(app: &App, filtered: &FilteredLogs) -> Result<CopyResult, String> {
     match determine_copy_mode(app) {
@@ -291,7 +360,7 @@ pub fn build_context_text(app: &App, filtered: &FilteredLogs) -> Result<CopyResu
 /// If multi-select is active, copies all selected lines.
 /// Returns Ok with success message or Err with error message.
 pub fn copy_line(app: &App, manager: &ProcessManager) -> Result<String, String> {
-    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, app.batch.batch_window_ms);
+    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, app.batch.batch_window_ms, app.batch.batch_strategy);
 
     let result = if app.navigation.has_multi_select() {
         build_multi_select_text(app, &filtered)?
@@ -304,11 +373,22 @@ pub fn copy_line(app: &App, manager: &ProcessManager) -> Result<String, String>
         .map_err(|e| format!("Failed to copy: {}", e))
 }
 
+/// Copy the selected line's `<process>#<id>` reference (Y).
+/// Returns Ok with success message or Err with error message.
+pub fn copy_reference(app: &App, manager: &ProcessManager) -> Result<String, String> {
+    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, app.batch.batch_window_ms, app.batch.batch_strategy);
+    let result = build_reference_text(app, &filtered)?;
+
+    copy_to_clipboard(&result.text)
+        .map(|_| result.message)
+        .map_err(|e| format!("Failed to copy: {}", e))
+}
+
 /// Copy the current context to clipboard (Shift+C).
 /// Context-aware: copies trace, search results, or batch depending on current view.
 /// Returns Ok with success message or Err with error message.
 pub fn copy_context(app: &App, manager: &ProcessManager) -> Result<String, String> {
-    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, app.batch.batch_window_ms);
+    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, app.batch.batch_window_ms, app.batch.batch_strategy);
     let result = build_context_text(app, &filtered)?;
 
     copy_to_clipboard(&result.text)
@@ -316,6 +396,35 @@ pub fn copy_context(app: &App, manager: &ProcessManager) -> Result<String, Strin
         .map_err(|e| format!("Failed to copy: {}", e))
 }
 
+/// Copy every line in the current view to clipboard (`:copyall`).
+/// Returns Ok with success message or Err with error message.
+pub fn copy_visible(app: &App, manager: &ProcessManager) -> Result<String, String> {
+    let result = build_visible_text(&get_display_logs(app, manager))?;
+
+    copy_to_clipboard(&result.text)
+        .map(|_| result.message)
+        .map_err(|e| format!("Failed to copy: {}", e))
+}
+
+/// The first OSC 8 hyperlink on the selected line.
+pub fn selected_link(app: &App, logs: &[LogLine]) -> Result<String, String> {
+    let line_id = app.navigation.selected_line_id
+        .ok_or_else(|| "No line selected".to_string())?;
+    let log = find_log_by_id(logs, line_id)
+        .ok_or_else(|| "Selected line not found".to_string())?;
+    log.links().first().cloned()
+        .ok_or_else(|| "No link on selected line".to_string())
+}
+
+/// Open the selected line's hyperlink in the default handler (o).
+/// Returns Ok with success message or Err with error message.
+pub fn open_link(app: &App, manager: &ProcessManager) -> Result<String, String> {
+    let url = selected_link(app, &get_display_logs(app, manager))?;
+    open_url(&url)
+        .map(|_| format!("Opened {}", url))
+        .map_err(|e| format!("Failed to open link: {}", e))
+}
+
 /// Legacy function for backward compatibility - now calls copy_context.
 pub fn copy_batch(app: &App, manager: &ProcessManager) -> Result<String, String> {
     copy_context(app, manager)
@@ -372,7 +481,7 @@ pub fn copy_time_context(
     manager: &ProcessManager,
     time_window_seconds: f64,
 ) -> Result<String, String> {
-    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, app.batch.batch_window_ms);
+    let filtered = FilteredLogs::from_manager(manager, &app.filters.filters, app.batch.batch_window_ms, app.batch.batch_strategy);
     let result = build_time_context_text(app, &filtered, time_window_seconds)?;
 
     copy_to_clipboard(&result.text)
@@ -384,7 +493,7 @@ pub fn copy_time_context(
 mod tests {
     use super::*;
     use crate::log::LogSource;
-    use chrono::{Duration, Local};
+    use chrono::{Duration, Local, TimeZone};
 
     fn create_test_logs() -> Vec<LogLine> {
         let now = Local::now();
@@ -630,6 +739,29 @@ mod tests {
         assert_eq!(result.message, "Copied line to clipboard");
     }
 
+    #[test]
+    fn test_build_reference_text() {
+        let mut app = App::new();
+
+        let logs = create_test_logs();
+        let id = logs[1].id;
+        app.navigation.selected_line_id = Some(id);
+        let filtered = create_filtered_logs(logs);
+
+        let result = build_reference_text(&app, &filtered).unwrap();
+
+        assert_eq!(result.text, format!("web#{}", id));
+        assert_eq!(result.message, format!("Copied reference web#{}", id));
+    }
+
+    #[test]
+    fn test_build_reference_text_requires_selection() {
+        let app = App::new();
+        let filtered = create_filtered_logs(create_test_logs());
+
+        assert_eq!(build_reference_text(&app, &filtered).unwrap_err(), "No line selected");
+    }
+
     #[test]
     fn test_build_trace_text() {
         let mut app = App::new();
@@ -915,4 +1047,61 @@ mod tests {
         assert!(!result.text.contains("Request completed"));
         assert_eq!(result.message, "Copied 3 lines");
     }
+
+    #[test]
+    fn test_build_visible_text_matches_the_filtered_view() {
+        let time = Local.with_ymd_and_hms(2024, 12, 10, 12, 0, 0).unwrap();
+        let mut manager = ProcessManager::new();
+        for (process, line) in [
+            ("web", "\x1b[31mERROR\x1b[0m: request failed"),
+            ("web", "GET /health 200"),
+            ("worker", "ERROR: job failed"),
+            ("db", "WARN: slow query"),
+        ] {
+            manager.add_test_log(LogLine::new_with_time(
+                LogSource::ProcessStdout(process.to_string()),
+                line.to_string(),
+                time,
+            ));
+        }
+        let mut app = App::new();
+        app.filters.add_exclude_filter("health".to_string());
+        app.filters.errors_only = true;
+        app.filters.hidden_processes.insert("db".to_string());
+
+        let result = build_visible_text(&get_display_logs(&app, &manager)).unwrap();
+        assert_eq!(
+            result.text,
+            "[2024-12-10 12:00:00] web: ERROR: request failed\n[2024-12-10 12:00:00] worker: ERROR: job failed\n"
+        );
+        assert_eq!(result.message, "Copied 2 visible lines");
+    }
+
+    #[test]
+    fn test_build_visible_text_rejects_empty_and_oversized_views() {
+        assert!(build_visible_text(&[]).is_err());
+
+        let log = LogLine::new(LogSource::ProcessStdout("web".to_string()), "x".to_string());
+        let logs = vec![log; MAX_COPY_ALL_LINES + 1];
+        let err = build_visible_text(&logs).unwrap_err();
+        assert!(err.starts_with("50001 visible lines is too many to copy"));
+    }
+
+    #[test]
+    fn test_selected_link_uses_first_osc8_target() {
+        let linked = LogLine::new(
+            LogSource::ProcessStdout("web".to_string()),
+            "\x1b]8;;https://example.com/a\x07a\x1b]8;;\x07 \x1b]8;;https://example.com/b\x07b\x1b]8;;\x07".to_string(),
+        );
+        let plain = LogLine::new(LogSource::ProcessStdout("web".to_string()), "no links".to_string());
+        let logs = vec![linked.clone(), plain.clone()];
+        let mut app = App::new();
+        assert_eq!(selected_link(&app, &logs).unwrap_err(), "No line selected");
+
+        app.navigation.selected_line_id = Some(linked.id);
+        assert_eq!(selected_link(&app, &logs).unwrap(), "https://example.com/a");
+
+        app.navigation.selected_line_id = Some(plain.id);
+        assert_eq!(selected_link(&app, &logs).unwrap_err(), "No link on selected line");
+    }
 }
@@ -14,12 +14,21 @@ pub fn toggle_coloring(app: &mut App, manager: &ProcessManager, config: &mut Con
     }
 }
 
+/// Reassign process colors after the process list changes (no-op when coloring is off).
+pub fn refresh_process_colors(app: &mut App, manager: &ProcessManager, config: &Config) {
+    if app.display.coloring_enabled {
+        let process_names: Vec<String> = manager.get_processes().keys().cloned().collect();
+        let log_file_names = manager.get_standalone_log_file_names();
+        app.init_process_colors(&process_names, &log_file_names, &config.color_overrides());
+    }
+}
+
 /// Enable process coloring.
 /// Returns true to indicate coloring is now enabled.
 fn enable_coloring(app: &mut App, manager: &ProcessManager, config: &mut Config) -> bool {
     let process_names: Vec<String> = manager.get_processes().keys().cloned().collect();
     let log_file_names = manager.get_standalone_log_file_names();
-    app.init_process_colors(&process_names, &log_file_names, &config.colors);
+    app.init_process_colors(&process_names, &log_file_names, &config.color_overrides());
     app.display.coloring_enabled = true;
 
     // Persist to config
@@ -62,14 +71,52 @@ mod tests {
             hidden_processes: Vec::new(),
             ignored_processes: Vec::new(),
             start_processes: Vec::new(),
+            pinned_processes: Vec::new(),
             disable_auto_update: None,
+            background_update_check: None,
             compact_mode: None,
+            condensed_display: None,
             colors: std::collections::HashMap::new(),
             process_coloring: None,
             context_copy_seconds: None,
             groups: std::collections::HashMap::new(),
+            presets: std::collections::HashMap::new(),
+            aliases: std::collections::HashMap::new(),
             theme: None,
+            confirm_destructive: None,
+            confirm_quit: None,
+            json_logs: None,
+            timestamp_format: None,
+            max_ingest_per_tick: None,
+            ansi_mode: None,
+            severity: None,
+            idle_quit_ms: None,
+            working_dir: None,
+            notify_on_failure: Vec::new(),
+            mark_stderr: None,
+            tail_preview: None,
+            stable_process_layout: None,
+            persist_search_history: None,
+            scroll_stick_lines: None,
+            batch_strategy: None,
+            show_date_dividers: None,
+            max_processes: None,
+            max_fps: None,
+            startup_banner: None,
+            align_messages: None,
+            show_last_line: None,
+            sticky_header: None,
+            highlight_syntax: None,
+            volume_histogram: None,
+            watch_config: None,
+            show_commands: None,
+            truncation_suffix: None,
+            line_margin: None,
+            base_port: None,
+            port_step: None,
+            process_list_position: None,
             config_path: None,
+            namespace_groups: HashMap::new(),
         }
     }
 
@@ -1,5 +1,6 @@
 use crate::config::Config;
-use crate::ui::App;
+use crate::process::ProcessManager;
+use crate::ui::{App, BatchStrategy};
 
 /// Save config to file and surface any errors to the status bar.
 /// This consolidates the common pattern of saving config with error handling.
@@ -10,3 +11,243 @@ pub fn save_config_with_error(config: &Config, app: &mut App) {
         }
     }
 }
+
+/// Startup summary (`startup_banner = true`) confirming which config and
+/// Procfile were picked up, e.g. "Loaded 8 processes, 2 log files, 3 filters, buffer 50MB"
+pub fn startup_banner(config: &Config, manager: &ProcessManager) -> String {
+    let count = |n: usize, one: &str, many: &str| format!("{} {}", n, if n == 1 { one } else { many });
+    let filters = config.filters.include.len() + config.filters.exclude.len();
+    format!(
+        "Loaded {}, {}, {}, buffer {}MB",
+        count(manager.get_processes().len(), "process", "processes"),
+        count(manager.get_standalone_log_file_names().len(), "log file", "log files"),
+        count(filters, "filter", "filters"),
+        manager.get_buffer_stats().limit_mb
+    )
+}
+
+/// Re-read the config file after it changed on disk (`watch_config = true`)
+/// and apply it to the running session, reporting the outcome in the status bar.
+/// Saves the TUI makes itself read back unchanged and are ignored.
+pub fn reload_config(app: &mut App, manager: &mut ProcessManager, config: &mut Config) {
+    let Some(path) = config.config_path.clone() else {
+        return;
+    };
+    match Config::from_file(&path.to_string_lossy()) {
+        Ok(new_config) => {
+            if let Some(message) = apply_reloaded_config(app, manager, config, new_config) {
+                app.display.set_status_info(message);
+            }
+        }
+        Err(e) => app.display.set_status_error(format!("Config reload failed: {}", e)),
+    }
+}
+
+/// Apply a freshly read config to the running session: filters, hidden and
+/// pinned processes, colors, batch window and strategy, severity patterns and
+/// each process's status transitions, hooks, `quiet_until`, `strip_prefix`,
+/// `autostart` and startup grace. Settings read only at startup are adopted
+/// into `config` but listed as needing a restart, and Procfile command or env
+/// edits as needing a respawn. Returns the status message, or None if nothing changed.
+pub fn apply_reloaded_config(
+    app: &mut App,
+    manager: &mut ProcessManager,
+    config: &mut Config,
+    mut new_config: Config,
+) -> Option<String> {
+    new_config.config_path = config.config_path.take();
+    new_config.namespace_groups = std::mem::take(&mut config.namespace_groups);
+    let old_config = std::mem::replace(config, new_config);
+    if same_toml(&old_config, config) {
+        return None;
+    }
+
+    app.filters.clear_filters();
+    for pattern in &config.filters.include {
+        app.filters.add_include_filter(pattern.clone());
+    }
+    for pattern in &config.filters.exclude {
+        app.filters.add_exclude_filter(pattern.clone());
+    }
+    for pattern in &config.filters.disabled {
+        app.filters.toggle_filter(pattern);
+    }
+    app.filters.hidden_processes = config.hidden_processes.iter().cloned().collect();
+    app.filters.severity = config.severity_matcher();
+    app.display.pinned_processes = config.pinned_processes.clone();
+
+    app.display.coloring_enabled = config.process_coloring == Some(true);
+    if app.display.coloring_enabled {
+        crate::operations::coloring::refresh_process_colors(app, manager, config);
+    } else {
+        app.init_process_colors(&[], &[], &Default::default());
+    }
+
+    if let Some(ms) = config.batch_window_ms {
+        app.batch.set_batch_window(ms);
+    }
+    if let Some(strategy) = config.batch_strategy.as_deref().and_then(BatchStrategy::parse) {
+        app.batch.set_batch_strategy(strategy);
+    }
+
+    manager.set_severity(config.severity_matcher());
+    let names: Vec<String> = manager.get_processes().keys().cloned().collect();
+    for name in &names {
+        let pc = config.processes.get(name);
+        manager.set_status_config(name, pc.and_then(|pc| pc.status.as_ref()));
+        manager.apply_process_config(name, pc);
+    }
+
+    let mut message = "Config reloaded".to_string();
+    let restart_needed = restart_needed(&old_config, config);
+    if !restart_needed.is_empty() {
+        message.push_str(&format!("; restart oit to apply: {}", restart_needed.join(", ")));
+    }
+    let respawn_needed = manager.procfile_changes();
+    if !respawn_needed.is_empty() {
+        message.push_str(&format!("; restart the processes to apply: {}", respawn_needed.join(", ")));
+    }
+    Some(message)
+}
+
+/// Settings read only at startup or when a process is spawned
+fn restart_needed(old: &Config, new: &Config) -> Vec<String> {
+    let mut changed = Vec::new();
+    let mut check = |name: &str, differs: bool| {
+        if differs {
+            changed.push(name.to_string());
+        }
+    };
+    check("procfile", old.procfile != new.procfile);
+    check("working_dir", old.working_dir != new.working_dir);
+    check("ports", old.base_port != new.base_port || old.port_step != new.port_step);
+    check("ignored_processes", old.ignored_processes != new.ignored_processes);
+    check("log_files", !same_toml(&old.log_files, &new.log_files));
+
+    let mut names: Vec<&String> = old.processes.keys().chain(new.processes.keys()).collect();
+    names.sort();
+    names.dedup();
+    for name in names {
+        let (old_pc, new_pc) = (old.processes.get(name), new.processes.get(name));
+        check(
+            &format!("{}.log_file", name),
+            old_pc.and_then(|pc| pc.log_file.as_ref()) != new_pc.and_then(|pc| pc.log_file.as_ref()),
+        );
+        check(
+            &format!("{}.stdin", name),
+            old_pc.and_then(|pc| pc.stdin.as_ref()) != new_pc.and_then(|pc| pc.stdin.as_ref()),
+        );
+    }
+    changed
+}
+
+/// Compare as TOML values, so map ordering doesn't count as a change
+fn same_toml<T: serde::Serialize>(a: &T, b: &T) -> bool {
+    matches!((toml::Value::try_from(a), toml::Value::try_from(b)), (Ok(a), Ok(b)) if a == b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn startup_banner_summarizes_config_and_procfile() {
+        let config: Config = toml::from_str(
+            "procfile = \"Procfile\"\nmax_log_buffer_mb = 20\n[filters]\ninclude = [\"GET\"]\nexclude = [\"health\", \"ping\"]\n",
+        )
+        .unwrap();
+        let mut manager = ProcessManager::new_with_buffer_limit(20);
+        manager.add_process("web".to_string(), "echo web".to_string(), None, None, None);
+        manager.add_process("worker".to_string(), "echo worker".to_string(), None, None, None);
+        let log = tempfile::NamedTempFile::new().unwrap();
+        manager.add_standalone_log_file("app".to_string(), log.path().to_path_buf()).await.unwrap();
+
+        assert_eq!(
+            startup_banner(&config, &manager),
+            "Loaded 2 processes, 1 log file, 3 filters, buffer 20MB"
+        );
+    }
+
+    fn reload_fixture(toml_text: &str) -> (App, ProcessManager, Config) {
+        let mut config: Config = toml::from_str(toml_text).unwrap();
+        config.config_path = Some("oit.toml".into());
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "echo web".to_string(), None, None, None);
+        let mut app = App::new();
+        for pattern in &config.filters.include {
+            app.filters.add_include_filter(pattern.clone());
+        }
+        (app, manager, config)
+    }
+
+    #[test]
+    fn reload_replaces_filters_and_hidden_processes() {
+        let (mut app, mut manager, mut config) = reload_fixture("procfile = \"Procfile\"\n[filters]\ninclude = [\"GET\"]\n");
+        let new_config: Config = toml::from_str(
+            "procfile = \"Procfile\"\nhidden_processes = [\"web\"]\nbatch_window_ms = 500\n[filters]\ninclude = [\"POST\"]\nexclude = [\"health\"]\n",
+        )
+        .unwrap();
+
+        let message = apply_reloaded_config(&mut app, &mut manager, &mut config, new_config);
+        assert_eq!(message.as_deref(), Some("Config reloaded"));
+        let patterns: Vec<&str> = app.filters.filters.iter().map(|f| f.pattern.as_str()).collect();
+        assert_eq!(patterns, vec!["POST", "health"]);
+        assert!(app.filters.hidden_processes.contains("web"));
+        assert_eq!(app.batch.batch_window_ms, 500);
+        // Runtime-only fields survive the swap
+        assert_eq!(config.config_path.as_deref(), Some(std::path::Path::new("oit.toml")));
+    }
+
+    #[test]
+    fn reload_of_unchanged_config_is_silent() {
+        let text = "procfile = \"Procfile\"\n[colors]\nweb = \"red\"\nworker = \"blue\"\n[filters]\ninclude = [\"GET\"]\n";
+        let (mut app, mut manager, mut config) = reload_fixture(text);
+        let message = apply_reloaded_config(&mut app, &mut manager, &mut config, toml::from_str(text).unwrap());
+        assert_eq!(message, None);
+    }
+
+    #[test]
+    fn reload_reports_settings_that_need_a_restart() {
+        let (mut app, mut manager, mut config) = reload_fixture("procfile = \"Procfile\"\n");
+        let new_config: Config =
+            toml::from_str("procfile = \"Procfile.dev\"\n[processes.web]\nstdin = \"inherit\"\n").unwrap();
+
+        let message = apply_reloaded_config(&mut app, &mut manager, &mut config, new_config);
+        assert_eq!(message.as_deref(), Some("Config reloaded; restart oit to apply: procfile, web.stdin"));
+        assert_eq!(config.procfile, std::path::PathBuf::from("Procfile.dev"));
+    }
+
+    #[test]
+    fn reload_applies_process_settings_to_the_manager() {
+        let (mut app, mut manager, mut config) = reload_fixture("procfile = \"Procfile\"\n");
+        let new_config: Config = toml::from_str(
+            "procfile = \"Procfile\"\n[processes.web]\nautostart = false\non_exit = \"echo bye\"\nquiet_until = \"ready\"\n",
+        )
+        .unwrap();
+
+        let message = apply_reloaded_config(&mut app, &mut manager, &mut config, new_config);
+        assert_eq!(message.as_deref(), Some("Config reloaded"));
+        assert!(manager.autostart_names().is_empty());
+        let web = &manager.get_processes()["web"];
+        assert_eq!(web.hooks.on_exit.as_deref(), Some("echo bye"));
+        assert!(web.hooks.on_start.is_none());
+    }
+
+    #[test]
+    fn reload_reports_procfile_commands_and_env_that_need_a_respawn() {
+        let dir = tempfile::tempdir().unwrap();
+        let procfile = dir.path().join("Procfile.yml");
+        std::fs::write(&procfile, "web:\n  command: echo web --verbose\n  env:\n    DEBUG: \"1\"\n").unwrap();
+        let (mut app, mut manager, mut config) = reload_fixture("procfile = \"Procfile\"\n");
+        manager.set_procfile_path(procfile, dir.path().to_path_buf());
+        let new_config: Config = toml::from_str("procfile = \"Procfile\"\n[filters]\ninclude = [\"GET\"]\n").unwrap();
+
+        let message = apply_reloaded_config(&mut app, &mut manager, &mut config, new_config);
+        assert_eq!(
+            message.as_deref(),
+            Some("Config reloaded; restart the processes to apply: web.command, web.env")
+        );
+        // Reported, not applied
+        assert_eq!(manager.get_processes()["web"].command, "echo web");
+    }
+}
@@ -1,6 +1,8 @@
 use crate::config::Config;
 use crate::operations::config::save_config_with_error;
+use crate::process::ProcessManager;
 use crate::ui::App;
+use crate::ui::display_state::ProcessListPosition;
 
 /// Cycle display mode and persist to config.
 /// Returns the new mode name ("compact", "full", or "wrap").
@@ -12,9 +14,84 @@ pub fn cycle_display_mode(app: &mut App, config: &mut Config) -> String {
     app.display.display_mode.name().to_string()
 }
 
+/// Toggle condensed rendering for full and wrap modes and persist to config.
+/// Returns true if lines are now condensed.
+pub fn toggle_condensed(app: &mut App, config: &mut Config) -> bool {
+    app.display.condensed = !app.display.condensed;
+    config.condensed_display = Some(app.display.condensed);
+    save_config_with_error(config, app);
+    app.display.condensed
+}
+
 /// Cycle timestamp mode: seconds → milliseconds → off → seconds.
 /// Returns the new mode name.
 pub fn cycle_timestamp_mode(app: &mut App) -> String {
     app.display.cycle_timestamp_mode();
     app.display.timestamp_mode.name().to_string()
 }
+
+/// Move the process list (or cycle top → bottom → hidden when `position` is None)
+/// and persist to config. Returns the new position name.
+pub fn set_process_list_position(
+    app: &mut App,
+    config: &mut Config,
+    position: Option<ProcessListPosition>,
+) -> String {
+    let position = position.unwrap_or_else(|| app.display.process_list_position.next());
+    app.display.process_list_position = position;
+    config.process_list_position = Some(position.name().to_string());
+    save_config_with_error(config, app);
+    position.name().to_string()
+}
+
+/// Pin a process or log file to the front of the process list and persist to config.
+/// Returns Ok with success message or Err with error message.
+pub fn pin_process(app: &mut App, manager: &ProcessManager, config: &mut Config, name: &str) -> Result<String, String> {
+    if !manager.has_process(name) && !manager.has_standalone_log_file(name) {
+        return Err(format!("Process or log file not found: {}", name));
+    }
+    if app.display.pinned_processes.iter().any(|pinned| pinned == name) {
+        return Err(format!("{} is already pinned", name));
+    }
+    app.display.pinned_processes.push(name.to_string());
+    config.pinned_processes = app.display.pinned_processes.clone();
+    save_config_with_error(config, app);
+    Ok(format!("Pinned {}", name))
+}
+
+/// Return a pinned process to its alphabetical place and persist to config.
+/// Returns Ok with success message or Err if it wasn't pinned.
+pub fn unpin_process(app: &mut App, config: &mut Config, name: &str) -> Result<String, String> {
+    let before = app.display.pinned_processes.len();
+    app.display.pinned_processes.retain(|pinned| pinned != name);
+    if app.display.pinned_processes.len() == before {
+        return Err(format!("{} is not pinned", name));
+    }
+    config.pinned_processes = app.display.pinned_processes.clone();
+    save_config_with_error(config, app);
+    Ok(format!("Unpinned {}", name))
+}
+
+/// Split the log viewer into side-by-side panes for two processes (or log files).
+/// Returns Ok with success message or Err with error message.
+pub fn split_view(app: &mut App, manager: &ProcessManager, left: &str, right: &str) -> Result<String, String> {
+    if left == right {
+        return Err("Split needs two different processes".to_string());
+    }
+    for name in [left, right] {
+        if !manager.has_process(name) && !manager.has_standalone_log_file(name) {
+            return Err(format!("Process not found: {}", name));
+        }
+    }
+    app.display.split_processes = Some((left.to_string(), right.to_string()));
+    Ok(format!("Split view: {} | {}", left, right))
+}
+
+/// Return from split view to the merged log view.
+/// Returns Ok with success message or Err if not split.
+pub fn unsplit_view(app: &mut App) -> Result<String, String> {
+    match app.display.split_processes.take() {
+        Some(_) => Ok("Merged log view".to_string()),
+        None => Err("Not in split view".to_string()),
+    }
+}
@@ -35,6 +35,96 @@ pub fn remove_filter(app: &mut App, config: &mut Config, pattern: &str) -> bool
     removed
 }
 
+/// Replace the current filters with a saved preset and save to config.
+/// Returns a status message, or an error if no preset has that name.
+pub fn apply_preset(app: &mut App, config: &mut Config, name: &str) -> Result<String, String> {
+    let preset = config
+        .presets
+        .get(name)
+        .cloned()
+        .ok_or_else(|| format!("Unknown preset: {}", name))?;
+    clear_filters(app, config);
+    for pattern in preset.include {
+        add_include_filter(app, config, pattern);
+    }
+    for pattern in preset.exclude {
+        add_exclude_filter(app, config, pattern);
+    }
+    if !preset.disabled.is_empty() {
+        for pattern in &preset.disabled {
+            app.filters.toggle_filter(pattern);
+        }
+        config.update_filters(&app.filters.filters);
+        save_config_with_error(config, app);
+    }
+    Ok(format!("Applied preset '{}' ({} filter(s))", name, app.filters.filter_count()))
+}
+
+/// Switch the `n`th filter (1-based, in `:fl` order) off or back on and save to config.
+pub fn toggle_filter_at(app: &mut App, config: &mut Config, n: usize) -> Result<String, String> {
+    let pattern = n
+        .checked_sub(1)
+        .and_then(|i| app.filters.filters.get(i))
+        .map(|f| f.pattern.clone())
+        .ok_or_else(|| format!("No filter {} ({} active)", n, app.filters.filter_count()))?;
+    let enabled = toggle_filter(app, config, &pattern)?;
+    Ok(format!("Filter {} '{}' {}", n, pattern, if enabled { "on" } else { "off" }))
+}
+
+/// Switch the filter with `pattern` off or back on and save to config. Returns its new state.
+pub fn toggle_filter(app: &mut App, config: &mut Config, pattern: &str) -> Result<bool, String> {
+    let enabled = app
+        .filters
+        .toggle_filter(pattern)
+        .ok_or_else(|| format!("No filter with pattern: {}", pattern))?;
+    config.update_filters(&app.filters.filters);
+    save_config_with_error(config, app);
+    Ok(enabled)
+}
+
+/// Save the current filters as a named preset (replacing one with the same name) and save to config.
+pub fn save_preset(app: &mut App, config: &mut Config, name: &str) -> Result<String, String> {
+    if name == "save" {
+        return Err("'save' cannot be used as a preset name".to_string());
+    }
+    config.update_filters(&app.filters.filters);
+    config.presets.insert(name.to_string(), config.filters.clone());
+    save_config_with_error(config, app);
+    Ok(format!("Saved preset '{}' ({} filter(s))", name, app.filters.filter_count()))
+}
+
+/// Format the list of saved presets for display.
+/// Returns None if there are no presets.
+pub fn list_presets(config: &Config) -> Option<String> {
+    if config.presets.is_empty() {
+        return None;
+    }
+    let mut names: Vec<&String> = config.presets.keys().collect();
+    names.sort();
+    let names: Vec<&str> = names.into_iter().map(String::as_str).collect();
+    Some(format!("Presets: {}", names.join(", ")))
+}
+
+/// Toggle the errors-only quick filter (runtime only, not saved to config).
+/// Returns a status message describing the new state.
+pub fn toggle_errors_only(app: &mut App) -> String {
+    if app.filters.toggle_errors_only() {
+        "Showing errors and warnings only".to_string()
+    } else {
+        "Showing all log levels".to_string()
+    }
+}
+
+/// Toggle collapsing of `quiet_until` boot output (runtime only).
+/// Returns a status message describing the new state.
+pub fn toggle_quiet_startup(app: &mut App) -> String {
+    if app.filters.toggle_quiet_startup() {
+        "Quiet startup on: boot output collapsed until ready".to_string()
+    } else {
+        "Quiet startup off: showing boot output".to_string()
+    }
+}
+
 /// Format the list of current filters for display.
 /// Returns None if there are no filters, otherwise returns a formatted string.
 pub fn list_filters(app: &App) -> Option<String> {
@@ -45,14 +135,94 @@ pub fn list_filters(app: &App) -> Option<String> {
             .filters
             .filters
             .iter()
-            .map(|f| {
+            .enumerate()
+            .map(|(i, f)| {
                 let type_str = match f.filter_type {
                     FilterType::Include => "include",
                     FilterType::Exclude => "exclude",
                 };
-                format!("{}: {}", type_str, f.pattern)
+                let off = if f.enabled { "" } else { " [off]" };
+                format!("{}) {}: {}{}", i + 1, type_str, f.pattern, off)
             })
             .collect();
         Some(format!("Filters: {}", filter_strs.join(", ")))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_at(path: &std::path::Path) -> Config {
+        let mut config: Config = toml::from_str("procfile = \"Procfile\"").unwrap();
+        config.config_path = Some(path.to_path_buf());
+        config
+    }
+
+    #[test]
+    fn saved_preset_round_trips_through_the_config_file() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(".overitall.toml");
+        let mut app = App::new();
+        let mut config = config_at(&path);
+        add_include_filter(&mut app, &mut config, "status=5".to_string());
+        add_exclude_filter(&mut app, &mut config, "/health".to_string());
+
+        assert_eq!(save_preset(&mut app, &mut config, "http_errors").unwrap(), "Saved preset 'http_errors' (2 filter(s))");
+
+        let mut loaded = Config::from_file(path.to_str().unwrap()).unwrap();
+        loaded.config_path = Some(path.clone());
+        let preset = &loaded.presets["http_errors"];
+        assert_eq!(preset.include, vec!["status=5"]);
+        assert_eq!(preset.exclude, vec!["/health"]);
+
+        let mut fresh = App::new();
+        fresh.filters.add_include_filter("unrelated".to_string());
+        apply_preset(&mut fresh, &mut loaded, "http_errors").unwrap();
+        let patterns: Vec<&str> = fresh.filters.filters.iter().map(|f| f.pattern.as_str()).collect();
+        assert_eq!(patterns, vec!["status=5", "/health"]);
+        assert_eq!(loaded.filters.include, vec!["status=5"]);
+    }
+
+    #[test]
+    fn toggled_filter_stays_listed_and_persists() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join(".overitall.toml");
+        let mut app = App::new();
+        let mut config = config_at(&path);
+        add_include_filter(&mut app, &mut config, "status=5".to_string());
+        add_exclude_filter(&mut app, &mut config, "/health".to_string());
+
+        assert_eq!(toggle_filter_at(&mut app, &mut config, 2).unwrap(), "Filter 2 '/health' off");
+        assert_eq!(app.filters.filter_count(), 2);
+        assert_eq!(app.filters.active_filter_count(), 1);
+
+        let loaded = Config::from_file(path.to_str().unwrap()).unwrap();
+        assert_eq!(loaded.filters.exclude, vec!["/health"]);
+        assert_eq!(loaded.filters.disabled, vec!["/health"]);
+
+        assert!(toggle_filter(&mut app, &mut config, "/health").unwrap());
+        assert!(config.filters.disabled.is_empty());
+        assert_eq!(toggle_filter_at(&mut app, &mut config, 3).unwrap_err(), "No filter 3 (2 active)");
+        assert_eq!(toggle_filter(&mut app, &mut config, "nope").unwrap_err(), "No filter with pattern: nope");
+    }
+
+    #[test]
+    fn applying_a_preset_from_toml_replaces_current_filters() {
+        let mut config: Config = toml::from_str(
+            "procfile = \"Procfile\"\n[presets.db_slow]\ninclude = [\"slow query\"]\n",
+        )
+        .unwrap();
+        let mut app = App::new();
+        app.filters.add_exclude_filter("noise".to_string());
+
+        let msg = apply_preset(&mut app, &mut config, "db_slow").unwrap();
+        assert_eq!(msg, "Applied preset 'db_slow' (1 filter(s))");
+        assert_eq!(app.filters.filters.len(), 1);
+        assert!(matches!(app.filters.filters[0].filter_type, FilterType::Include));
+        assert!(config.filters.exclude.is_empty());
+
+        assert_eq!(apply_preset(&mut app, &mut config, "missing").unwrap_err(), "Unknown preset: missing");
+        assert_eq!(list_presets(&config).as_deref(), Some("Presets: db_slow"));
+    }
+}
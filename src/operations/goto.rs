@@ -32,7 +32,7 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
                 let line_text = &log.line;
 
                 // Check exclude filters first (if any match, reject the line)
-                for filter in &app.filters.filters {
+                for filter in app.filters.filters.iter().filter(|f| f.enabled) {
                     if matches!(filter.filter_type, FilterType::Exclude) {
                         if filter.matches(line_text) {
                             return false;
@@ -45,7 +45,7 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
                     .filters
                     .filters
                     .iter()
-                    .filter(|f| matches!(f.filter_type, FilterType::Include))
+                    .filter(|f| f.enabled && matches!(f.filter_type, FilterType::Include))
                     .collect();
 
                 if include_filters.is_empty() {
@@ -57,6 +57,14 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
             .collect()
     };
 
+    // Apply errors-only quick filter (intersects with the filters above)
+    if app.filters.errors_only {
+        filtered_logs.retain(|log| app.filters.severity.detect_lowercase(log.line_lowercase()).is_some());
+    }
+
+    // Collapsed quiet-startup boot output (the viewer draws a placeholder instead)
+    filtered_logs.retain(|log| !app.filters.hides_boot_line(log) && !app.filters.hides_muted_line(log));
+
     // Apply search filter if active
     let active_search_pattern = if app.input.search_mode && !app.input.input.is_empty() {
         &app.input.input
@@ -75,9 +83,10 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
     }
 
     // Apply process visibility filter
-    filtered_logs.retain(|log| {
-        !app.filters.hidden_processes.contains(log.source.process_name())
-    });
+    filtered_logs.retain(|log| !app.filters.hides_process_of(log));
+
+    // Split view: only the two split processes are navigable
+    filtered_logs.retain(|log| app.display.split_shows_line(log));
 
     // Apply trace filter mode if active
     if app.trace.trace_filter_mode {
@@ -101,7 +110,7 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
     }
 
     // Detect batches from filtered logs
-    let batches = detect_batches_from_logs(&filtered_logs, app.batch.batch_window_ms);
+    let batches = detect_batches_from_logs(&filtered_logs, app.batch.batch_window_ms, app.batch.batch_strategy);
 
     // Apply batch view mode filtering if enabled
     let display_logs: Vec<LogLine> = if app.batch.batch_view_mode {
@@ -142,17 +151,8 @@ pub fn goto_timestamp(app: &mut App, manager: &ProcessManager, target: GotoTarge
 
     match target_idx {
         Some(idx) => {
-            // Create snapshot if not already frozen
-            if app.navigation.snapshot.is_none() {
-                let logs = manager.get_all_logs();
-                let filtered = crate::ui::apply_filters(logs, &app.filters.filters);
-                app.navigation.create_snapshot(filtered);
-            }
-
             let log = &display_logs[idx];
-            app.navigation.selected_line_id = Some(log.id);
-            app.navigation.auto_scroll = false;
-            app.navigation.freeze_display();
+            select_and_freeze(app, manager, log.id);
 
             let time_str = log.timestamp.format("%H:%M:%S").to_string();
             Ok(format!("Jumped to {}", time_str))
@@ -161,6 +161,33 @@ pub fn goto_timestamp(app: &mut App, manager: &ProcessManager, target: GotoTarge
     }
 }
 
+/// Select the first line received in the last `secs` seconds (`:recent`).
+/// Uses `arrival_time`, since a line's own timestamp may be stale or missing.
+pub fn goto_recent(app: &mut App, manager: &ProcessManager, secs: u64) -> Result<String, String> {
+    let display_logs = get_display_logs(app, manager);
+    let since = chrono::Local::now() - chrono::Duration::seconds(secs as i64);
+    let Some(start) = display_logs.iter().position(|log| log.arrival_time >= since) else {
+        return Err(format!("No lines in the last {}s", secs));
+    };
+
+    let log = &display_logs[start];
+    select_and_freeze(app, manager, log.id);
+    Ok(format!("Jumped to {} (last {}s)", log.arrival_time.format("%H:%M:%S"), secs))
+}
+
+/// Select a line and freeze the view on it, snapshotting the buffer first
+fn select_and_freeze(app: &mut App, manager: &ProcessManager, id: u64) {
+    if app.navigation.snapshot.is_none() {
+        let logs = manager.get_all_logs();
+        let filtered = crate::ui::apply_filters(logs, &app.filters.filters);
+        app.navigation.create_snapshot(filtered);
+    }
+
+    app.navigation.selected_line_id = Some(id);
+    app.navigation.auto_scroll = false;
+    app.navigation.freeze_display();
+}
+
 /// Find the first log at or after the specified absolute time.
 fn find_by_absolute_time(logs: &[LogLine], hour: u32, minute: u32, second: Option<u32>) -> Option<usize> {
     let target_time = NaiveTime::from_hms_opt(hour, minute, second.unwrap_or(0))?;
@@ -300,4 +327,26 @@ mod tests {
         let result = find_by_absolute_time(&logs, 10, 0, Some(30));
         assert_eq!(result, Some(1));
     }
+
+    #[test]
+    fn test_goto_recent_uses_arrival_time() {
+        let now = Local::now();
+        let mut manager = ProcessManager::new();
+        let mut old = LogLine::new_with_time(LogSource::ProcessStdout("web".to_string()), "old".to_string(), now - chrono::Duration::seconds(60));
+        // A fresh-looking parsed timestamp must not pull an old line into the window
+        old.timestamp = now;
+        let new = LogLine::new_with_time(LogSource::ProcessStdout("web".to_string()), "new".to_string(), now - chrono::Duration::seconds(5));
+        let new_id = new.id;
+        manager.add_test_log(old);
+        manager.add_test_log(new);
+        let mut app = App::new();
+
+        let expected = format!("Jumped to {} (last 30s)", (now - chrono::Duration::seconds(5)).format("%H:%M:%S"));
+        assert_eq!(goto_recent(&mut app, &manager, 30).unwrap(), expected);
+        assert_eq!(app.navigation.selected_line_id, Some(new_id));
+        assert!(!app.navigation.auto_scroll);
+
+        let mut app = App::new();
+        assert_eq!(goto_recent(&mut app, &manager, 1).unwrap_err(), "No lines in the last 1s");
+    }
 }
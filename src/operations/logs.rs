@@ -1,6 +1,6 @@
 use crate::log::LogLine;
 use crate::process::ProcessManager;
-use crate::ui::{self, App, Filter};
+use crate::ui::{self, App, BatchStrategy, Filter};
 
 /// Holds filtered logs and their detected batches.
 /// This struct consolidates the common pattern of:
@@ -14,11 +14,16 @@ pub struct FilteredLogs {
 
 impl FilteredLogs {
     /// Create a new FilteredLogs from a ProcessManager, applying filters and detecting batches.
-    pub fn from_manager(manager: &ProcessManager, filters: &[Filter], batch_window_ms: i64) -> Self {
+    pub fn from_manager(
+        manager: &ProcessManager,
+        filters: &[Filter],
+        batch_window_ms: i64,
+        batch_strategy: BatchStrategy,
+    ) -> Self {
         let logs = manager.get_all_logs();
         let filtered = ui::apply_filters(logs, filters);
         let refs: Vec<&LogLine> = filtered.iter().collect();
-        let batches = ui::detect_batches_from_logs(&refs, batch_window_ms);
+        let batches = ui::detect_batches_from_logs(&refs, batch_window_ms, batch_strategy);
         Self { logs: filtered, batches }
     }
 
@@ -37,3 +42,75 @@ impl FilteredLogs {
         self.logs.len()
     }
 }
+
+/// Change the log buffer's memory limit for this session (`:buffer <MB>`).
+/// Shrinking evicts the oldest lines right away.
+pub fn set_buffer_limit(manager: &mut ProcessManager, mb: usize) -> Result<String, String> {
+    if mb == 0 {
+        return Err("Buffer limit must be at least 1 MB".to_string());
+    }
+    let evicted = manager.set_buffer_limit(mb);
+    Ok(buffer_limit_message(mb, evicted))
+}
+
+/// Status line for a buffer limit change, mentioning evictions when there were any
+pub fn buffer_limit_message(mb: usize, evicted: usize) -> String {
+    if evicted > 0 {
+        format!("Buffer limit set to {} MB (evicted {} lines)", mb, evicted)
+    } else {
+        format!("Buffer limit set to {} MB", mb)
+    }
+}
+
+/// The newest `limit` lines from one process or standalone log file, oldest first.
+/// Returns Err for an unknown name.
+pub fn process_logs<'a>(manager: &'a ProcessManager, name: &str, limit: usize) -> Result<Vec<&'a LogLine>, String> {
+    if !manager.has_process(name) && !manager.has_standalone_log_file(name) {
+        return Err(format!("Process not found: {}", name));
+    }
+    Ok(manager.get_recent_process_logs(name, limit))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::LogSource;
+
+    #[test]
+    fn set_buffer_limit_reports_evictions() {
+        let mut manager = ProcessManager::new_with_buffer_limit(4);
+        let content = "x".repeat(8_000);
+        for i in 0..60 {
+            manager.add_test_log(LogLine::new(LogSource::ProcessStdout("web".into()), format!("{} {}", content, i)));
+        }
+
+        let msg = set_buffer_limit(&mut manager, 1).unwrap();
+        let stats = manager.get_buffer_stats();
+        assert_eq!(stats.limit_mb, 1);
+        assert!(stats.memory_mb <= 1.0);
+        assert_eq!(msg, format!("Buffer limit set to 1 MB (evicted {} lines)", 60 - stats.line_count));
+
+        assert_eq!(set_buffer_limit(&mut manager, 16).unwrap(), "Buffer limit set to 16 MB");
+        assert!(set_buffer_limit(&mut manager, 0).is_err());
+    }
+
+    #[test]
+    fn process_logs_returns_only_that_process_in_order() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "true".to_string(), None, None, None);
+        manager.add_process("worker".to_string(), "true".to_string(), None, None, None);
+        for i in 0..5 {
+            manager.add_test_log(LogLine::new(LogSource::ProcessStdout("web".into()), format!("web {}", i)));
+            manager.add_test_log(LogLine::new(LogSource::ProcessStderr("worker".into()), format!("worker {}", i)));
+        }
+
+        let lines: Vec<&str> = process_logs(&manager, "worker", 3)
+            .unwrap()
+            .iter()
+            .map(|log| log.line.as_str())
+            .collect();
+        assert_eq!(lines, vec!["worker 2", "worker 3", "worker 4"]);
+        assert_eq!(process_logs(&manager, "web", 100).unwrap().len(), 5);
+        assert_eq!(process_logs(&manager, "db", 10).unwrap_err(), "Process not found: db");
+    }
+}
@@ -4,9 +4,39 @@ use crate::ui::App;
 
 /// Start recording a manual trace
 pub fn start_recording(app: &mut App) {
+    start_labeled_recording(app, None);
+}
+
+/// Start recording under an optional label. Returns the new recording's ID.
+pub fn start_labeled_recording(app: &mut App, label: Option<String>) -> String {
+    app.trace.manual_trace_count += 1;
+    let id = format!("rec-{}", app.trace.manual_trace_count);
     app.trace.manual_trace_recording = true;
     app.trace.manual_trace_start = Some(Local::now());
-    app.display.set_status_info("Recording trace... press 's' to stop".to_string());
+    app.trace.manual_trace_id = Some(id.clone());
+    let status = match &label {
+        Some(label) => format!("Recording trace '{}'... press 's' to stop", label),
+        None => "Recording trace... press 's' to stop".to_string(),
+    };
+    app.trace.manual_trace_label = label;
+    app.display.set_status_info(status);
+    id
+}
+
+/// Add a note line to the log so it lands inside the recording's time window
+pub fn annotate(app: &App, manager: &mut ProcessManager, note: &str) -> Result<String, String> {
+    if !app.trace.manual_trace_recording {
+        return Err("No recording in progress".to_string());
+    }
+    manager.add_annotation(format!("[oit] note: {}", note));
+    Ok(format!("Added trace note: {}", note))
+}
+
+fn reset_recording(app: &mut App) {
+    app.trace.manual_trace_recording = false;
+    app.trace.manual_trace_start = None;
+    app.trace.manual_trace_id = None;
+    app.trace.manual_trace_label = None;
 }
 
 /// Stop recording and enter trace filter mode
@@ -27,9 +57,7 @@ pub fn stop_recording(app: &mut App, manager: &ProcessManager) -> Result<String,
     let duration_ms = duration.num_milliseconds();
 
     if filtered.is_empty() {
-        // Reset state
-        app.trace.manual_trace_recording = false;
-        app.trace.manual_trace_start = None;
+        reset_recording(app);
         return Err("No logs captured in time window".to_string());
     }
 
@@ -41,15 +69,12 @@ pub fn stop_recording(app: &mut App, manager: &ProcessManager) -> Result<String,
     app.trace.trace_filter_mode = true;
     app.navigation.freeze_display();
 
-    // Reset recording state
-    app.trace.manual_trace_recording = false;
-    app.trace.manual_trace_start = None;
+    reset_recording(app);
 
     Ok(format!("Captured {} logs in {:.1}s", log_count, duration_ms as f64 / 1000.0))
 }
 
 /// Cancel recording without entering trace mode
 pub fn cancel_recording(app: &mut App) {
-    app.trace.manual_trace_recording = false;
-    app.trace.manual_trace_start = None;
+    reset_recording(app);
 }
@@ -4,7 +4,7 @@ use crate::ui::{App, detect_batches_from_logs, FilterType};
 
 /// Get the list of logs to display based on current view mode.
 /// This matches the filtering logic in log_viewer.rs exactly.
-fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
+pub fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
     // Use snapshot if available (frozen/batch mode), otherwise use live buffer
     let logs_vec: Vec<&LogLine> = if let Some(ref snapshot) = app.navigation.snapshot {
         snapshot.iter().collect()
@@ -30,7 +30,7 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
                 let line_text = &log.line;
 
                 // Check exclude filters first (if any match, reject the line)
-                for filter in &app.filters.filters {
+                for filter in app.filters.filters.iter().filter(|f| f.enabled) {
                     if matches!(filter.filter_type, FilterType::Exclude) {
                         if filter.matches(line_text) {
                             return false;
@@ -42,7 +42,7 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
                 let include_filters: Vec<_> = app
                     .filters.filters
                     .iter()
-                    .filter(|f| matches!(f.filter_type, FilterType::Include))
+                    .filter(|f| f.enabled && matches!(f.filter_type, FilterType::Include))
                     .collect();
 
                 if include_filters.is_empty() {
@@ -54,6 +54,14 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
             .collect()
     };
 
+    // Apply errors-only quick filter (intersects with the filters above)
+    if app.filters.errors_only {
+        filtered_logs.retain(|log| app.filters.severity.detect_lowercase(log.line_lowercase()).is_some());
+    }
+
+    // Collapsed quiet-startup boot output (the viewer draws a placeholder instead)
+    filtered_logs.retain(|log| !app.filters.hides_boot_line(log) && !app.filters.hides_muted_line(log));
+
     // Apply search filter if active
     let active_search_pattern = if app.input.search_mode && !app.input.input.is_empty() {
         &app.input.input
@@ -72,9 +80,10 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
     }
 
     // Apply process visibility filter
-    filtered_logs.retain(|log| {
-        !app.filters.hidden_processes.contains(log.source.process_name())
-    });
+    filtered_logs.retain(|log| !app.filters.hides_process_of(log));
+
+    // Split view: only the two split processes are navigable
+    filtered_logs.retain(|log| app.display.split_shows_line(log));
 
     // Apply trace filter mode if active
     if app.trace.trace_filter_mode {
@@ -98,7 +107,7 @@ fn get_display_logs(app: &App, manager: &ProcessManager) -> Vec<LogLine> {
     }
 
     // Detect batches from filtered logs
-    let batches = detect_batches_from_logs(&filtered_logs, app.batch.batch_window_ms);
+    let batches = detect_batches_from_logs(&filtered_logs, app.batch.batch_window_ms, app.batch.batch_strategy);
 
     // Apply batch view mode filtering if enabled
     let display_logs: Vec<LogLine> = if app.batch.batch_view_mode {
@@ -408,11 +417,159 @@ pub fn page_down(app: &mut App, manager: &ProcessManager) {
     }
 }
 
+/// Scroll so the line `percent` (0-100) of the way through the filtered view
+/// is at the top; the viewer clamps the offset to the last page. Clears the
+/// selection, which would otherwise pin the view. Returns the offset and the
+/// number of lines in view.
+pub fn scroll_to_percent(app: &mut App, manager: &ProcessManager, percent: u64) -> (usize, usize) {
+    let total = get_display_logs(app, manager).len();
+    let last = total.saturating_sub(1);
+    let offset = (last * percent.min(100) as usize + 50) / 100;
+    app.navigation.clear_selection();
+    app.navigation.scroll_offset = offset;
+    app.navigation.auto_scroll = false;
+    (offset, total)
+}
+
+/// Which way `select_error` looks from the current line
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JumpDirection {
+    Next,
+    Prev,
+}
+
+impl JumpDirection {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "next" => Some(Self::Next),
+            "prev" => Some(Self::Prev),
+            _ => None,
+        }
+    }
+}
+
+/// Select the nearest error line after (or before) `from_id`, or the current
+/// selection, wrapping around. With neither, the search starts at the tail, so
+/// Prev finds the most recent error. Errors are classified by the configured
+/// `severity` matcher. Returns the selected line's ID.
+pub fn select_error(
+    app: &mut App,
+    manager: &ProcessManager,
+    direction: JumpDirection,
+    from_id: Option<u64>,
+) -> Result<u64, String> {
+    let display_logs = get_display_logs(app, manager);
+    let errors: Vec<usize> = display_logs
+        .iter()
+        .enumerate()
+        .filter(|(_, log)| app.filters.severity.detect_lowercase(log.line_lowercase()) == Some("error"))
+        .map(|(idx, _)| idx)
+        .collect();
+    if errors.is_empty() {
+        return Err("No error lines in view".to_string());
+    }
+
+    let current = match from_id.or(app.navigation.selected_line_id) {
+        Some(id) => match find_index_by_id(&display_logs, id) {
+            Some(idx) => Some(idx),
+            None if from_id.is_some() => return Err(format!("Line not found: {}", id)),
+            None => None,
+        },
+        None => None,
+    };
+    let target = match (direction, current) {
+        (JumpDirection::Next, Some(cur)) => errors.iter().find(|&&idx| idx > cur).unwrap_or(&errors[0]),
+        (JumpDirection::Next, None) => &errors[0],
+        (JumpDirection::Prev, Some(cur)) => errors.iter().rev().find(|&&idx| idx < cur).unwrap_or(&errors[errors.len() - 1]),
+        (JumpDirection::Prev, None) => &errors[errors.len() - 1],
+    };
+    let id = display_logs[*target].id;
+
+    // Same snapshot/freeze behaviour as arrow-key selection
+    let was_none = app.navigation.selected_line_id.is_none();
+    if was_none && app.navigation.snapshot.is_none() {
+        let logs = manager.get_all_logs();
+        let filtered = crate::ui::apply_filters(logs, &app.filters.filters);
+        app.navigation.create_snapshot(filtered);
+    }
+    app.navigation.selected_line_id = Some(id);
+    app.navigation.auto_scroll = false;
+    if was_none {
+        app.navigation.freeze_display();
+    }
+    Ok(id)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::log::LogSource;
 
+    fn create_manager_with_mixed_levels() -> (ProcessManager, Vec<u64>) {
+        let mut manager = ProcessManager::new();
+        let mut ids = Vec::new();
+        for line in ["boot", "ERROR: db down", "ok", "warning: slow", "job failed", "ok again"] {
+            let log = LogLine::new(LogSource::ProcessStdout("test".to_string()), line.to_string());
+            ids.push(log.id);
+            manager.add_test_log(log);
+        }
+        (manager, ids)
+    }
+
+    #[test]
+    fn test_select_error_moves_between_errors_and_wraps() {
+        let (manager, ids) = create_manager_with_mixed_levels();
+        let mut app = App::new();
+
+        // From the tail, prev finds the most recent error
+        assert_eq!(select_error(&mut app, &manager, JumpDirection::Prev, None), Ok(ids[4]));
+        assert!(app.navigation.frozen);
+        assert_eq!(select_error(&mut app, &manager, JumpDirection::Prev, None), Ok(ids[1]));
+        assert_eq!(select_error(&mut app, &manager, JumpDirection::Prev, None), Ok(ids[4]));
+
+        // Warnings are skipped going forward too
+        assert_eq!(select_error(&mut app, &manager, JumpDirection::Next, None), Ok(ids[1]));
+        assert_eq!(select_error(&mut app, &manager, JumpDirection::Next, None), Ok(ids[4]));
+        assert_eq!(app.navigation.selected_line_id, Some(ids[4]));
+    }
+
+    #[test]
+    fn test_select_error_from_id_and_without_errors() {
+        let (manager, ids) = create_manager_with_mixed_levels();
+        let mut app = App::new();
+
+        assert_eq!(select_error(&mut app, &manager, JumpDirection::Next, Some(ids[2])), Ok(ids[4]));
+        assert_eq!(select_error(&mut app, &manager, JumpDirection::Prev, Some(ids[2])), Ok(ids[1]));
+        assert!(select_error(&mut app, &manager, JumpDirection::Next, Some(999_999)).unwrap_err().contains("Line not found"));
+
+        let (quiet, _) = create_manager_with_logs(3);
+        let mut app = App::new();
+        assert_eq!(
+            select_error(&mut app, &quiet, JumpDirection::Next, None),
+            Err("No error lines in view".to_string())
+        );
+        assert!(app.navigation.selected_line_id.is_none());
+    }
+
+    #[test]
+    fn test_scroll_to_percent_maps_onto_filtered_lines() {
+        let (manager, ids) = create_manager_with_logs(101);
+        let mut app = App::new();
+        app.navigation.selected_line_id = Some(ids[3]);
+
+        assert_eq!(scroll_to_percent(&mut app, &manager, 50), (50, 101));
+        assert!(app.navigation.selected_line_id.is_none());
+        assert!(!app.navigation.auto_scroll);
+        assert_eq!(scroll_to_percent(&mut app, &manager, 100), (100, 101));
+
+        app.filters.add_exclude_filter("Log line 1".to_string());
+        assert_eq!(scroll_to_percent(&mut app, &manager, 0), (0, 89));
+        assert_eq!(scroll_to_percent(&mut app, &manager, 25), (22, 89));
+
+        let empty = ProcessManager::new();
+        assert_eq!(scroll_to_percent(&mut app, &empty, 50), (0, 0));
+    }
+
     fn create_manager_with_logs(count: usize) -> (ProcessManager, Vec<u64>) {
         let mut manager = ProcessManager::new();
         let mut ids = Vec::new();
@@ -427,6 +584,34 @@ mod tests {
         (manager, ids)
     }
 
+    #[test]
+    fn test_errors_only_intersects_with_filters() {
+        let mut manager = ProcessManager::new();
+        for line in ["ERROR: db down", "error in worker", "all good", "warning: slow"] {
+            manager.add_test_log(LogLine::new(LogSource::ProcessStdout("test".to_string()), line.to_string()));
+        }
+        let mut app = App::new();
+        app.filters.errors_only = true;
+        app.filters.add_exclude_filter("worker".to_string());
+
+        let lines: Vec<String> = get_display_logs(&app, &manager).into_iter().map(|l| l.line).collect();
+        assert_eq!(lines, vec!["ERROR: db down", "warning: slow"]);
+    }
+
+    #[test]
+    fn test_errors_only_uses_configured_severity() {
+        let mut manager = ProcessManager::new();
+        for line in ["FATAL: cannot bind", "job failed", "warning: slow"] {
+            manager.add_test_log(LogLine::new(LogSource::ProcessStdout("test".to_string()), line.to_string()));
+        }
+        let mut app = App::new();
+        app.filters.errors_only = true;
+        app.filters.severity = crate::log::SeverityMatcher::new(&["fatal".to_string()], &["^never$".to_string()]).unwrap();
+
+        let lines: Vec<String> = get_display_logs(&app, &manager).into_iter().map(|l| l.line).collect();
+        assert_eq!(lines, vec!["FATAL: cannot bind"]);
+    }
+
     #[test]
     fn test_extend_selection_prev_starts_multi_select() {
         let (manager, ids) = create_manager_with_logs(5);
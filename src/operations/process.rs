@@ -1,4 +1,7 @@
-use crate::process::ProcessManager;
+use crate::process::{ProcessManager, PAUSED_INGEST_LIMIT};
+use crate::ui::App;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 /// Start a stopped process.
 /// Returns Ok with success message or Err with error message.
@@ -17,3 +20,90 @@ pub async fn kill_process(manager: &mut ProcessManager, name: &str) -> Result<St
         Err(e) => Err(format!("Failed to kill {}: {}", name, e)),
     }
 }
+
+/// Add an ad-hoc process (runtime only, not written to the Procfile) and start it.
+/// Returns Ok with success message or Err with error message.
+pub async fn add_process(
+    manager: &mut ProcessManager,
+    name: &str,
+    command: &str,
+    cwd: Option<PathBuf>,
+    env: HashMap<String, String>,
+) -> Result<String, String> {
+    manager
+        .add_runtime_process(name, command, cwd, env)
+        .map_err(|e| format!("Failed to add {}: {}", name, e))?;
+    match manager.start_process(name).await {
+        Ok(_) => Ok(format!("Added process: {}", name)),
+        Err(e) => Err(format!("Added {} but failed to start: {}", name, e)),
+    }
+}
+
+/// Start tailing a standalone log file at runtime (`:tailfile`).
+/// Returns Ok with success message or Err with error message.
+pub async fn add_log_file(manager: &mut ProcessManager, name: &str, path: &str) -> Result<String, String> {
+    let path = manager
+        .add_runtime_log_file(name, Path::new(path))
+        .await
+        .map_err(|e| format!("Failed to add {}: {}", name, e))?;
+    if path.exists() {
+        Ok(format!("Tailing {} as {}", path.display(), name))
+    } else {
+        Ok(format!("Waiting for {} to appear (as {})", path.display(), name))
+    }
+}
+
+/// Re-read a process's (or standalone) log file from the beginning.
+/// Returns Ok with success message or Err with error message.
+pub fn retail_log_file(manager: &mut ProcessManager, name: &str) -> Result<String, String> {
+    match manager.retail_log_file(name) {
+        0 => Err(format!("No log file for: {}", name)),
+        1 => Ok(format!("Re-reading log file: {}", name)),
+        n => Ok(format!("Re-reading {} log files: {}", n, name)),
+    }
+}
+
+/// Open the `:lasterror` overlay for `name`, or the most recently failed process.
+/// Returns Ok with status message or Err if there is no captured output.
+pub fn show_last_output(app: &mut App, manager: &ProcessManager, name: Option<&str>) -> Result<String, String> {
+    let name = match name {
+        Some(name) if !manager.has_process(name) => return Err(format!("Process not found: {}", name)),
+        Some(name) if manager.final_output(name).is_none() => return Err(format!("{} has not failed", name)),
+        Some(name) => name,
+        None => manager.last_failed_process().ok_or("No process has failed")?,
+    };
+    app.last_output = Some(name.to_string());
+    Ok(format!("Final output of {} (Esc to close)", name))
+}
+
+/// The command `oit` launched a process with, and the directory it runs in,
+/// for `:cmd`. Returns Ok with the message or Err if there's no such process.
+pub fn launch_command(manager: &ProcessManager, name: &str) -> Result<String, String> {
+    let handle = manager.get_processes().get(name).ok_or_else(|| format!("Process not found: {}", name))?;
+    Ok(match &handle.working_dir {
+        Some(dir) => format!("{}: {} (in {})", name, handle.command, dir.display()),
+        None => format!("{}: {}", name, handle.command),
+    })
+}
+
+/// Stop buffering a process's (or log file's) new lines until `resume_ingest`.
+/// Returns Ok with success message or Err with error message.
+pub fn pause_ingest(manager: &mut ProcessManager, name: &str) -> Result<String, String> {
+    if !manager.has_process(name) && !manager.has_standalone_log_file(name) {
+        return Err(format!("Process not found: {}", name));
+    }
+    if !manager.pause_ingest(name) {
+        return Err(format!("{} is already paused", name));
+    }
+    Ok(format!("Paused ingest for {} (holding up to {} lines)", name, PAUSED_INGEST_LIMIT))
+}
+
+/// Buffer the lines held while paused and resume normal ingest.
+/// Returns Ok with success message or Err if it wasn't paused.
+pub fn resume_ingest(manager: &mut ProcessManager, name: &str) -> Result<String, String> {
+    match manager.resume_ingest(name) {
+        None => Err(format!("{} is not paused", name)),
+        Some((held, 0)) => Ok(format!("Resumed {}: {} held lines ingested", name, held)),
+        Some((held, dropped)) => Ok(format!("Resumed {}: {} held lines ingested, {} dropped", name, held, dropped)),
+    }
+}
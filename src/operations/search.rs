@@ -1,6 +1,30 @@
+use std::path::Path;
+
 use crate::process::ProcessManager;
 use crate::ui::{App, apply_filters};
 
+/// File (in the working directory) for searches kept across restarts
+/// with `persist_search_history = true`
+pub const SEARCH_HISTORY_FILE: &str = ".oit-search-history";
+
+/// How many of the most recent searches are persisted
+pub const PERSISTED_SEARCHES: usize = 50;
+
+/// Load persisted searches, one per line (missing or unreadable file = none)
+pub fn load_search_history(path: &Path) -> Vec<String> {
+    std::fs::read_to_string(path)
+        .map(|content| content.lines().filter(|l| !l.is_empty()).map(String::from).collect())
+        .unwrap_or_default()
+}
+
+/// Persist the most recent `PERSISTED_SEARCHES` searches
+pub fn save_search_history(path: &Path, history: &[String]) -> std::io::Result<()> {
+    let start = history.len().saturating_sub(PERSISTED_SEARCHES);
+    let mut content = history[start..].join("\n");
+    content.push('\n');
+    std::fs::write(path, content)
+}
+
 /// Execute a search on the filtered logs and set up the selection state.
 /// Returns Ok with the match count on success, or Err with an error message.
 pub fn execute_search(app: &mut App, manager: &ProcessManager, search_text: &str) -> Result<usize, String> {
@@ -10,6 +34,7 @@ pub fn execute_search(app: &mut App, manager: &ProcessManager, search_text: &str
 
     // Save the search pattern
     app.input.perform_search(search_text.to_string());
+    app.input.save_search_to_history(search_text.to_string());
     app.display.expanded_line_view = false;
 
     // Get filtered logs (after persistent filters AND search filter)
@@ -46,6 +71,29 @@ pub fn execute_search(app: &mut App, manager: &ProcessManager, search_text: &str
     Ok(match_count)
 }
 
+/// IDs of the lines the log view shows for the active search pattern, oldest
+/// first: the held snapshot (or the live buffer up to the freeze) through the
+/// view's own filters and split panes. Empty without a search pattern.
+pub fn visible_match_ids(app: &App, manager: &ProcessManager) -> Vec<u64> {
+    if app.input.search_pattern.is_empty() {
+        return Vec::new();
+    }
+    let search_lower = app.input.search_pattern.to_lowercase();
+    let frozen_at = app.navigation.frozen_at.filter(|_| app.navigation.frozen);
+    let logs: Vec<&crate::log::LogLine> = match &app.navigation.snapshot {
+        Some(snapshot) => snapshot.iter().collect(),
+        None => manager
+            .get_all_logs()
+            .into_iter()
+            .filter(|log| frozen_at.is_none_or(|at| log.timestamp <= at))
+            .collect(),
+    };
+    logs.into_iter()
+        .filter(|log| app.filters.shows_line(log, &search_lower) && app.display.split_shows_line(log))
+        .map(|log| log.id)
+        .collect()
+}
+
 /// Show the full context around the currently selected log.
 /// Clears the search pattern and shows all filtered logs with selection preserved.
 /// Returns Ok with success message on success, or Err with an error message.
@@ -85,3 +133,72 @@ pub fn show_context(app: &mut App, manager: &ProcessManager) -> Result<String, S
     // Display is already frozen, keep it that way
     Ok("Showing context around selected log".to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn search_history_round_trips_last_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(SEARCH_HISTORY_FILE);
+        assert!(load_search_history(&path).is_empty());
+
+        let history: Vec<String> = (0..PERSISTED_SEARCHES + 5).map(|i| format!("pattern {}", i)).collect();
+        save_search_history(&path, &history).unwrap();
+
+        let loaded = load_search_history(&path);
+        assert_eq!(loaded.len(), PERSISTED_SEARCHES);
+        assert_eq!(loaded.first().map(String::as_str), Some("pattern 5"));
+        assert_eq!(loaded.last(), history.last());
+    }
+
+    #[test]
+    fn execute_search_records_history() {
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        manager.add_test_log(crate::log::LogLine::new(
+            crate::log::LogSource::ProcessStdout("web".to_string()),
+            "request timeout".to_string(),
+        ));
+
+        execute_search(&mut app, &manager, "timeout").unwrap();
+        app.input.enter_search_mode();
+        execute_search(&mut app, &manager, "timeout").unwrap();
+        // Searches with no matches are still recalled, so they can be edited
+        let _ = execute_search(&mut app, &manager, "missing");
+
+        assert_eq!(app.input.search_history, vec!["timeout", "missing"]);
+    }
+
+    #[test]
+    fn visible_match_ids_use_the_view_filters() {
+        use crate::log::{LogLine, LogSource};
+
+        let mut app = App::new();
+        let mut manager = ProcessManager::new();
+        let mut add = |process: &str, line: &str| {
+            let log = LogLine::new(LogSource::ProcessStdout(process.to_string()), line.to_string());
+            let id = log.id;
+            manager.add_test_log(log);
+            id
+        };
+        let before_mute = add("worker", "timeout before mute");
+        let muted = add("worker", "timeout while muted");
+        let web = add("web", "request timeout");
+        add("db", "query timeout");
+        add("web", "all good");
+
+        app.filters.mute("worker", regex::Regex::new("ready").unwrap(), before_mute);
+        app.display.split_processes = Some(("web".to_string(), "worker".to_string()));
+        app.input.search_pattern = "timeout".to_string();
+
+        // The muted line and the db line outside the split aren't shown
+        let ids = visible_match_ids(&app, &manager);
+        assert_eq!(ids, vec![before_mute, web]);
+        assert!(!ids.contains(&muted));
+
+        app.input.search_pattern.clear();
+        assert!(visible_match_ids(&app, &manager).is_empty());
+    }
+}
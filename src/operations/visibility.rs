@@ -1,4 +1,7 @@
+use regex::Regex;
+
 use crate::config::Config;
+use crate::log::LogLine;
 use crate::operations::config::save_config_with_error;
 use crate::process::ProcessManager;
 use crate::ui::App;
@@ -103,6 +106,37 @@ pub fn only_processes(
     Ok(())
 }
 
+/// Mute a process or log file's new lines until one matches `pattern` (runtime only).
+/// Returns the status message, or an error for an unknown name or bad regex.
+pub fn mute_process(app: &mut App, manager: &ProcessManager, process: &str, pattern: &str) -> Result<String, String> {
+    if !manager.has_process(process) && !manager.has_standalone_log_file(process) {
+        return Err(format!("Process or log file not found: {}", process));
+    }
+    let regex = Regex::new(pattern).map_err(|e| format!("Invalid mute pattern: {}", e))?;
+    app.filters.mute(process, regex, newest_line_id(manager));
+    Ok(format!("{} muted until '{}'", process, pattern))
+}
+
+/// End a mute before its pattern arrives. Lines muted so far stay hidden.
+pub fn unmute_process(app: &mut App, manager: &ProcessManager, process: &str) -> Result<String, String> {
+    if app.filters.unmute(process, newest_line_id(manager) + 1) {
+        Ok(format!("{} un-muted", process))
+    } else {
+        Err(format!("{} is not muted", process))
+    }
+}
+
+/// Un-mute processes whose pattern matched one of the newly ingested lines
+pub fn check_unmutes(app: &mut App, new_logs: &[&LogLine]) {
+    for (process, pattern) in app.filters.unmute_on_match(new_logs) {
+        app.display.set_status_info(format!("{} un-muted (matched '{}')", process, pattern));
+    }
+}
+
+fn newest_line_id(manager: &ProcessManager) -> u64 {
+    manager.get_recent_logs(1).first().map_or(0, |log| log.id)
+}
+
 /// Sync the app's hidden_processes set to the config.
 fn sync_hidden_processes_to_config(app: &App, config: &mut Config) {
     config.hidden_processes = app.filters.hidden_processes.iter().cloned().collect();
@@ -1,8 +1,12 @@
-use anyhow::{Context, Result};
+use anyhow::Result;
 use ratatui::style::Color;
-use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
+use regex::Regex;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::time::Instant;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, Command};
 use tokio::sync::mpsc;
@@ -10,9 +14,10 @@ use tokio::task::JoinHandle;
 
 // Re-export log types for compatibility
 pub use crate::log::{LogLine, LogSource};
-use crate::config::{Config, StatusConfig};
-use crate::log::{LogBuffer, FileReader, LogVelocityTracker};
-use crate::procfile::Procfile;
+use crate::config::{Config, ProcessConfig, StatusConfig};
+use crate::hooks::{self, HookRunner, ProcessHooks};
+use crate::log::{LogBuffer, FileReader, JsonLogFields, LogVelocityTracker, SeverityMatcher, VolumeHistogram};
+use crate::procfile::{self, ProcessOptions, ProcessType, ProcfileSource};
 use crate::status_matcher::StatusMatcher;
 
 /// Status of a managed process
@@ -23,6 +28,101 @@ pub enum ProcessStatus {
     Terminating,
     Restarting,
     Failed(String),
+    /// A `type: oneshot` task that exited 0
+    Done,
+}
+
+/// How far a (re)started process has come, for `restart` with `wait_ready`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Readiness {
+    /// Restarting, before its `quiet_until` line, or not yet at its `ready` status
+    Starting,
+    Ready,
+    /// Failed or exited before becoming ready
+    Down(String),
+}
+
+/// Per-process results of a bulk start/kill
+#[derive(Debug, Default, PartialEq)]
+pub struct BulkOutcome {
+    pub succeeded: Vec<String>,
+    /// (name, error message)
+    pub failed: Vec<(String, String)>,
+}
+
+impl BulkOutcome {
+    /// Status bar summary, e.g. "Stopped: web, worker" or "Started: web; failed: worker (...)"
+    pub fn summary(&self, verb: &str) -> String {
+        let mut parts = Vec::new();
+        if !self.succeeded.is_empty() {
+            parts.push(format!("{}: {}", verb, self.succeeded.join(", ")));
+        }
+        if !self.failed.is_empty() {
+            let failed: Vec<String> = self.failed.iter().map(|(n, e)| format!("{} ({})", n, e)).collect();
+            parts.push(format!("failed: {}", failed.join(", ")));
+        }
+        if parts.is_empty() {
+            format!("{}: nothing to do", verb)
+        } else {
+            parts.join("; ")
+        }
+    }
+
+    /// IPC result fields: `names` that succeeded and `failed: [{name, error}]`
+    pub fn to_json(&self) -> serde_json::Value {
+        let failed: Vec<serde_json::Value> = self
+            .failed
+            .iter()
+            .map(|(name, error)| serde_json::json!({"name": name, "error": error}))
+            .collect();
+        serde_json::json!({"names": self.succeeded, "failed": failed})
+    }
+}
+
+/// Program a Procfile command runs: its first word after any `VAR=value`
+/// assignments and `exec`/`env` wrappers, without surrounding quotes
+pub fn command_program(command: &str) -> Option<&str> {
+    let word = command
+        .split_whitespace()
+        .find(|word| *word != "exec" && *word != "env" && !is_env_assignment(word))?;
+    Some(word.trim_matches(|c| c == '"' || c == '\'')).filter(|program| !program.is_empty())
+}
+
+fn is_env_assignment(word: &str) -> bool {
+    word.split_once('=').is_some_and(|(name, _)| {
+        !name.is_empty() && name.chars().all(|c| c == '_' || c.is_ascii_alphanumeric())
+    })
+}
+
+fn command_not_found_message(command: &str) -> String {
+    format!("command not found: {} (is it installed / on PATH?)", command_program(command).unwrap_or(command))
+}
+
+fn permission_denied_message(command: &str) -> String {
+    format!("permission denied: {} (is it executable?)", command_program(command).unwrap_or(command))
+}
+
+/// Actionable failure message for a shell exit code that means the program
+/// couldn't be run (127: not found, 126: not executable)
+fn launch_failure_message(command: &str, code: i32) -> Option<String> {
+    match code {
+        127 => Some(command_not_found_message(command)),
+        126 => Some(permission_denied_message(command)),
+        _ => None,
+    }
+}
+
+/// Category of a failure message, for the IPC `processes` `error_kind` field
+pub fn failure_kind(message: &str) -> Option<&'static str> {
+    if message.starts_with("command not found: ") {
+        Some("command_not_found")
+    } else if message.starts_with("permission denied: ") {
+        Some("permission_denied")
+    } else if message.starts_with("working directory not found: ") {
+        Some("working_dir_not_found")
+    } else {
+        None
+    }
 }
 
 /// Buffer statistics for UI display
@@ -48,6 +148,44 @@ pub struct ProcessHandle {
     status_matcher: Option<StatusMatcher>,
     stdin_mode: String,
     stdin_handle: Option<tokio::process::ChildStdin>,
+    /// Extra environment variables (from a YAML Procfile)
+    pub env: HashMap<String, String>,
+    /// `PORT` assigned from `base_port`, unless `env` sets its own
+    pub port: Option<u16>,
+    /// Failure from a `marks_failed` status match, not yet reported by check_all_status
+    pending_failure: Option<String>,
+    /// `on_start`/`on_exit`/`on_failure` shell commands
+    pub hooks: ProcessHooks,
+    /// Status as of the last check_all_status, to detect hook transitions
+    observed_status: ProcessStatus,
+    /// Exit code of the last run, if it exited normally
+    exit_code: Option<i32>,
+    /// Signal that terminated the last run, if any
+    exit_signal: Option<i32>,
+    /// `quiet_until` readiness pattern from config
+    quiet_until: Option<Regex>,
+    /// `strip_prefix` pattern from config
+    strip_prefix: Option<Regex>,
+    /// Started with everything else; false waits for an explicit start
+    autostart: bool,
+    /// Still before the readiness line in this run
+    booting: bool,
+    /// Number of times the process has been started; stamped on its lines
+    run: u32,
+    /// Channel pressure from this process's readers
+    pressure: Arc<OutputPressure>,
+    /// `type: oneshot`: a clean exit is Done rather than Stopped
+    oneshot: bool,
+    /// `depends_on` from the Procfile
+    depends_on: Vec<String>,
+    /// `startup_grace_ms`: a nonzero exit this soon after starting is Stopped
+    startup_grace: Option<std::time::Duration>,
+    /// Whether death by a signal inside the grace window still fails
+    grace_crash_fails: bool,
+    /// When the current run was spawned
+    started_at: Option<Instant>,
+    /// Last time the current run was seen alive; it exited after this
+    alive_at: Option<Instant>,
 }
 
 impl ProcessHandle {
@@ -66,7 +204,39 @@ impl ProcessHandle {
             status_matcher,
             stdin_mode: stdin_config.unwrap_or("close").to_string(),
             stdin_handle: None,
+            env: HashMap::new(),
+            port: None,
+            pending_failure: None,
+            hooks: ProcessHooks::default(),
+            observed_status: ProcessStatus::Stopped,
+            exit_code: None,
+            exit_signal: None,
+            quiet_until: None,
+            strip_prefix: None,
+            autostart: true,
+            booting: false,
+            run: 0,
+            pressure: Arc::default(),
+            oneshot: false,
+            depends_on: Vec::new(),
+            startup_grace: None,
+            grace_crash_fails: true,
+            started_at: None,
+            alive_at: None,
+        }
+    }
+
+    /// Environment the process is started with: `env` plus the assigned `PORT`
+    pub fn spawn_env(&self) -> HashMap<String, String> {
+        let mut env = self.env.clone();
+        if let Some(port) = self.port {
+            env.entry("PORT".to_string()).or_insert_with(|| port.to_string());
         }
+        env
+    }
+
+    pub fn output_pressure(&self) -> &OutputPressure {
+        &self.pressure
     }
 
     /// Root pid of the process group (the `sh -c` leader), if the managed
@@ -83,18 +253,83 @@ impl ProcessHandle {
         }
     }
 
+    /// Exit code of the last run, if it exited normally
+    pub fn exit_code(&self) -> Option<i32> {
+        self.exit_code
+    }
+
+    /// Signal that terminated the last run, if any
+    pub fn exit_signal(&self) -> Option<i32> {
+        self.exit_signal
+    }
+
     /// Get custom display status if configured
     pub fn get_custom_status(&self) -> Option<(&str, Option<Color>)> {
         self.status_matcher.as_ref().and_then(|m| m.get_display_status())
     }
 
+    /// Whether the process is up: running, past its `quiet_until` line and at
+    /// its `ready` custom status when those are configured
+    pub fn readiness(&self) -> Readiness {
+        match &self.status {
+            ProcessStatus::Running if self.booting || self.status_matcher.as_ref().is_some_and(|m| !m.is_ready()) => {
+                Readiness::Starting
+            }
+            ProcessStatus::Running | ProcessStatus::Done => Readiness::Ready,
+            ProcessStatus::Restarting | ProcessStatus::Terminating => Readiness::Starting,
+            ProcessStatus::Stopped => Readiness::Down("exited".to_string()),
+            ProcessStatus::Failed(msg) => Readiness::Down(msg.clone()),
+        }
+    }
+
     /// Check log line against status patterns. Returns true if status changed.
+    /// A `marks_failed` match moves a running process to Failed (with the line
+    /// as the message); a later non-failing match brings it back to Running.
     pub fn check_log_line(&mut self, line: &str) -> bool {
-        self.status_matcher.as_mut().map(|m| m.check_line(line)).unwrap_or(false)
+        let Some(matcher) = self.status_matcher.as_mut() else {
+            return false;
+        };
+        let changed = matcher.check_line(line);
+        if changed {
+            let failed = matcher.is_failed();
+            match self.status {
+                ProcessStatus::Running if failed => {
+                    self.status = ProcessStatus::Failed(line.to_string());
+                    self.pending_failure = Some(line.to_string());
+                }
+                ProcessStatus::Failed(_) if !failed && self.child.is_some() => {
+                    self.status = ProcessStatus::Running;
+                    self.pending_failure = None;
+                }
+                _ => {}
+            }
+        }
+        changed
+    }
+
+    /// Failed by a `marks_failed` match while its child is still running
+    fn is_soft_failed(&self) -> bool {
+        matches!(self.status, ProcessStatus::Failed(_)) && self.child.is_some()
+    }
+
+    /// Whether a line is startup boilerplate: logged before the first
+    /// `quiet_until` match and not an error or warning (per `severity`).
+    /// The match ends the phase.
+    pub fn is_quiet_line(&mut self, line: &str, severity: &SeverityMatcher) -> bool {
+        let Some(pattern) = self.quiet_until.as_ref().filter(|_| self.booting) else {
+            return false;
+        };
+        if pattern.is_match(line) {
+            self.booting = false;
+            return false;
+        }
+        severity.detect(line).is_none()
     }
 
     /// Reset status matcher to default (call on restart)
     pub fn reset_status(&mut self) {
+        self.pending_failure = None;
+        self.booting = self.quiet_until.is_some();
         if let Some(m) = &mut self.status_matcher {
             m.reset();
         }
@@ -106,12 +341,20 @@ impl ProcessHandle {
             return Ok(());
         }
 
+        // Marked failed by a status pattern but still alive: stop the old instance first
+        if matches!(self.status, ProcessStatus::Failed(_)) && self.child.is_some() {
+            self.kill().await?;
+        }
+
         // Apply default status label when starting
         self.reset_status();
+        self.exit_code = None;
+        self.exit_signal = None;
 
         // Execute command through shell (handles quotes, spaces, variables, pipes, etc.)
         let mut cmd = Command::new("sh");
         cmd.args(&["-c", &self.command]);
+        cmd.envs(self.spawn_env());
 
         // Set working directory if specified
         if let Some(ref working_dir) = self.working_dir {
@@ -138,15 +381,7 @@ impl ProcessHandle {
             .stderr(Stdio::piped())
             .kill_on_drop(true)
             .spawn()
-            .with_context(|| format!(
-                "Failed to spawn process '{}': command='{}'{}",
-                self.name,
-                self.command,
-                self.working_dir
-                    .as_ref()
-                    .map(|d| format!(", working_dir='{}'", d.display()))
-                    .unwrap_or_default()
-            ))?;
+            .map_err(|e| self.spawn_error(e))?;
 
         // Store the process group ID
         // With process_group(0), the child's PID becomes the PGID
@@ -160,14 +395,16 @@ impl ProcessHandle {
         };
 
         // Capture stdout
+        let run = self.run + 1;
         let stdout = child.stdout.take().unwrap();
         let name = self.name.clone();
         let tx = log_tx.clone();
+        let pressure = self.pressure.clone();
         let stdout_task = tokio::spawn(async move {
             let reader = BufReader::new(stdout);
             let mut lines = reader.lines();
             while let Ok(Some(line)) = lines.next_line().await {
-                let _ = tx.send(LogLine::new(LogSource::ProcessStdout(name.clone()), line));
+                forward_line(&tx, &pressure, LogSource::ProcessStdout(name.clone()), line, run);
             }
         });
 
@@ -175,11 +412,12 @@ impl ProcessHandle {
         let stderr = child.stderr.take().unwrap();
         let name = self.name.clone();
         let tx = log_tx;
+        let pressure = self.pressure.clone();
         let stderr_task = tokio::spawn(async move {
             let reader = BufReader::new(stderr);
             let mut lines = reader.lines();
             while let Ok(Some(line)) = lines.next_line().await {
-                let _ = tx.send(LogLine::new(LogSource::ProcessStderr(name.clone()), line));
+                forward_line(&tx, &pressure, LogSource::ProcessStderr(name.clone()), line, run);
             }
         });
 
@@ -187,6 +425,9 @@ impl ProcessHandle {
         self.stdout_task = Some(stdout_task);
         self.stderr_task = Some(stderr_task);
         self.status = ProcessStatus::Running;
+        self.run = run;
+        self.started_at = Some(Instant::now());
+        self.alive_at = self.started_at;
 
         Ok(())
     }
@@ -234,6 +475,12 @@ impl ProcessHandle {
         Ok(())
     }
 
+    /// Both readers have hit EOF and none of their lines are still queued
+    fn output_drained(&self) -> bool {
+        let finished = |task: &Option<JoinHandle<()>>| task.as_ref().is_none_or(|t| t.is_finished());
+        finished(&self.stdout_task) && finished(&self.stderr_task) && self.pressure.queued() == 0
+    }
+
     /// Check if the process has actually exited after being killed
     pub async fn is_terminated(&mut self) -> bool {
         if let Some(child) = &mut self.child {
@@ -265,16 +512,43 @@ impl ProcessHandle {
         }
     }
 
+    /// Turn a spawn error into the message shown for the failed process
+    fn spawn_error(&self, error: std::io::Error) -> anyhow::Error {
+        match error.kind() {
+            std::io::ErrorKind::NotFound => match &self.working_dir {
+                Some(dir) if !dir.is_dir() => {
+                    anyhow::anyhow!("working directory not found: {}", dir.display())
+                }
+                _ => anyhow::anyhow!(command_not_found_message(&self.command)),
+            },
+            std::io::ErrorKind::PermissionDenied => anyhow::anyhow!(permission_denied_message(&self.command)),
+            _ => anyhow::Error::new(error).context(format!(
+                "Failed to spawn process '{}': command='{}'{}",
+                self.name,
+                self.command,
+                self.working_dir
+                    .as_ref()
+                    .map(|d| format!(", working_dir='{}'", d.display()))
+                    .unwrap_or_default()
+            )),
+        }
+    }
+
     /// Check if process has exited
     pub async fn check_status(&mut self) -> ProcessStatus {
         if let Some(child) = &mut self.child {
             match child.try_wait() {
                 Ok(Some(status)) => {
+                    self.exit_code = status.code();
+                    self.exit_signal = std::os::unix::process::ExitStatusExt::signal(&status);
                     if status.success() {
+                        self.status = if self.oneshot { ProcessStatus::Done } else { ProcessStatus::Stopped };
+                    } else if self.within_startup_grace(status.code()) {
                         self.status = ProcessStatus::Stopped;
                     } else {
                         let msg = match status.code() {
-                            Some(code) => format!("Exit code: {}", code),
+                            Some(code) => launch_failure_message(&self.command, code)
+                                .unwrap_or_else(|| format!("Exit code: {}", code)),
                             None => "Terminated by signal".to_string(),
                         };
                         self.status = ProcessStatus::Failed(msg);
@@ -285,6 +559,7 @@ impl ProcessHandle {
                 }
                 Ok(None) => {
                     // Still running
+                    self.alive_at = Some(Instant::now());
                 }
                 Err(e) => {
                     self.status = ProcessStatus::Failed(e.to_string());
@@ -297,6 +572,21 @@ impl ProcessHandle {
         self.status.clone()
     }
 
+    /// Whether an unsuccessful exit (`code` None = signal) falls inside
+    /// `startup_grace_ms` and counts as a stop. Launch failures always fail.
+    /// The exit is timed by the last check that saw the run alive, not by when
+    /// it was noticed, so a late status check doesn't push it past the window.
+    fn within_startup_grace(&self, code: Option<i32>) -> bool {
+        let (Some(grace), Some(started_at), Some(alive_at)) = (self.startup_grace, self.started_at, self.alive_at) else {
+            return false;
+        };
+        let forgiven = match code {
+            Some(code) => launch_failure_message(&self.command, code).is_none(),
+            None => !self.grace_crash_fails,
+        };
+        forgiven && alive_at.duration_since(started_at) < grace
+    }
+
     /// Restart the process (kill then start)
     pub async fn restart(&mut self, log_tx: mpsc::UnboundedSender<LogLine>) -> Result<()> {
         self.reset_status();
@@ -321,6 +611,8 @@ impl ProcessHandle {
         self.stderr_task = Some(result.stderr_task);
         self.stdin_handle = result.stdin_handle;
         self.status = ProcessStatus::Running;
+        self.started_at = Some(Instant::now());
+        self.alive_at = self.started_at;
     }
 }
 
@@ -331,7 +623,11 @@ struct RestartData {
     working_dir: Option<PathBuf>,
     old_pgid: Option<i32>,
     log_tx: mpsc::UnboundedSender<LogLine>,
+    pressure: Arc<OutputPressure>,
     stdin_mode: String,
+    env: HashMap<String, String>,
+    /// Run number for the new instance's lines
+    run: u32,
 }
 
 /// Successful restart result containing new process handles
@@ -373,6 +669,7 @@ async fn perform_restart(data: RestartData) -> RestartResult {
     let spawn_result = async {
         let mut cmd = Command::new("sh");
         cmd.args(&["-c", &data.command]);
+        cmd.envs(&data.env);
 
         if let Some(ref working_dir) = data.working_dir {
             cmd.current_dir(working_dir);
@@ -412,14 +709,16 @@ async fn perform_restart(data: RestartData) -> RestartResult {
         };
 
         // Capture stdout
+        let run = data.run;
         let stdout = child.stdout.take().unwrap();
         let name = data.name.clone();
         let tx = data.log_tx.clone();
+        let pressure = data.pressure.clone();
         let stdout_task = tokio::spawn(async move {
             let reader = BufReader::new(stdout);
             let mut lines = reader.lines();
             while let Ok(Some(line)) = lines.next_line().await {
-                let _ = tx.send(LogLine::new(LogSource::ProcessStdout(name.clone()), line));
+                forward_line(&tx, &pressure, LogSource::ProcessStdout(name.clone()), line, run);
             }
         });
 
@@ -427,11 +726,12 @@ async fn perform_restart(data: RestartData) -> RestartResult {
         let stderr = child.stderr.take().unwrap();
         let name = data.name.clone();
         let tx = data.log_tx;
+        let pressure = data.pressure;
         let stderr_task = tokio::spawn(async move {
             let reader = BufReader::new(stderr);
             let mut lines = reader.lines();
             while let Ok(Some(line)) = lines.next_line().await {
-                let _ = tx.send(LogLine::new(LogSource::ProcessStderr(name.clone()), line));
+                forward_line(&tx, &pressure, LogSource::ProcessStderr(name.clone()), line, run);
             }
         });
 
@@ -484,6 +784,171 @@ impl ProcfileReloadResult {
     }
 }
 
+/// Default cap on log lines moved from the channel into the buffer per tick
+pub const DEFAULT_MAX_INGEST_PER_TICK: usize = 5000;
+
+/// Lines held per process while its ingest is paused (`:pause`); older held
+/// lines are dropped past this so a paused firehose can't grow memory forever
+pub const PAUSED_INGEST_LIMIT: usize = 10_000;
+
+/// Lines held back from the log buffer while a process's ingest is paused
+#[derive(Debug, Default)]
+struct PausedIngest {
+    held: VecDeque<LogLine>,
+    /// Oldest held lines dropped because `held` reached `PAUSED_INGEST_LIMIT`
+    dropped: usize,
+}
+
+impl PausedIngest {
+    fn hold(&mut self, log: LogLine) {
+        if self.held.len() >= PAUSED_INGEST_LIMIT {
+            self.held.pop_front();
+            self.dropped += 1;
+        }
+        self.held.push_back(log);
+    }
+}
+
+/// Most lines one process may have waiting in the shared ingest channel. Past
+/// this its reader drops new lines instead of blocking: a blocked reader fills
+/// the pipe and stalls the child's own writes. Drops are counted
+/// (`describe`'s `dropped_lines`) and marked in the log once lines fit again.
+pub const PROCESS_QUEUE_LIMIT: usize = 50_000;
+
+/// Queued lines at which a process shows "output lagging" in the status bar
+const LAGGING_QUEUE_LEN: usize = PROCESS_QUEUE_LIMIT * 4 / 5;
+
+/// A process's share of the ingest channel, shared by its reader tasks (which
+/// reserve a slot per line) and the manager (which releases it on drain)
+#[derive(Debug, Default)]
+pub struct OutputPressure {
+    queued: AtomicUsize,
+    dropped: AtomicU64,
+    /// Drops not yet reported by a marker line
+    unreported: AtomicU64,
+}
+
+impl OutputPressure {
+    /// Claim a queue slot for one line, or count the line as dropped
+    pub fn try_reserve(&self) -> bool {
+        if self.queued.load(Ordering::Relaxed) >= PROCESS_QUEUE_LIMIT {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            self.unreported.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        self.queued.fetch_add(1, Ordering::Relaxed);
+        true
+    }
+
+    /// Drops since the last marker; claims a slot for the marker when nonzero
+    fn take_unreported(&self) -> u64 {
+        let count = self.unreported.swap(0, Ordering::Relaxed);
+        if count > 0 {
+            self.queued.fetch_add(1, Ordering::Relaxed);
+        }
+        count
+    }
+
+    /// A line left the channel
+    pub fn release(&self) {
+        let _ = self.queued.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |n| n.checked_sub(1));
+    }
+
+    pub fn queued(&self) -> usize {
+        self.queued.load(Ordering::Relaxed)
+    }
+
+    /// Lines dropped since the process was added
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    pub fn is_lagging(&self) -> bool {
+        self.queued() >= LAGGING_QUEUE_LEN
+    }
+}
+
+/// Send one line from a reader task, subject to `PROCESS_QUEUE_LIMIT`. The
+/// first line through after a run of drops is preceded by a marker counting them.
+fn forward_line(
+    tx: &mpsc::UnboundedSender<LogLine>,
+    pressure: &OutputPressure,
+    source: LogSource,
+    line: String,
+    run: u32,
+) {
+    if !pressure.try_reserve() {
+        return;
+    }
+    let dropped = pressure.take_unreported();
+    if dropped > 0 {
+        let marker = format!("[oit] output lagging: dropped {} lines", dropped);
+        let _ = tx.send(LogLine::new(source.clone(), marker).with_run(run));
+    }
+    let _ = tx.send(LogLine::new(source, line).with_run(run));
+}
+
+/// Default `max_processes`: starting more than this at once needs `--force`
+pub const DEFAULT_MAX_PROCESSES: usize = 50;
+
+/// Which processes to launch at startup when starting everything, keeping a
+/// huge generated Procfile from spawning hundreds of processes at once
+#[derive(Debug, PartialEq)]
+pub struct StartupPlan {
+    pub start: Vec<String>,
+    /// Left stopped because of the cap (start them with `:s`)
+    pub deferred: Vec<String>,
+}
+
+impl StartupPlan {
+    /// Start the first `max` names in sorted order, or all of them with `force`
+    pub fn new(mut names: Vec<String>, max: usize, force: bool) -> Self {
+        names.sort();
+        let deferred = if force || names.len() <= max { Vec::new() } else { names.split_off(max) };
+        Self { start: names, deferred }
+    }
+
+    /// Warning explaining the cap, when anything was deferred
+    pub fn warning(&self, max: usize) -> Option<String> {
+        if self.deferred.is_empty() {
+            return None;
+        }
+        Some(format!(
+            "Started {} of {} processes (max_processes = {}); start the rest with :s <name> or rerun with --force",
+            self.start.len(),
+            self.start.len() + self.deferred.len(),
+            max
+        ))
+    }
+}
+
+/// Oldest restart markers are dropped past this many
+const MAX_RESTART_MARKERS: usize = 1000;
+
+/// When a process restart was initiated, drawn as a separator in the log view
+#[derive(Debug, Clone, PartialEq)]
+pub struct RestartMarker {
+    pub process: String,
+    pub time: chrono::DateTime<chrono::Local>,
+}
+
+/// Lines of a failed process's output kept for `:lasterror` and IPC `last_output`
+pub const FINAL_OUTPUT_LINES: usize = 30;
+
+/// A process's last lines, captured when it failed so they survive other
+/// processes' output scrolling past (and buffer eviction)
+#[derive(Debug, Clone)]
+pub struct FinalOutput {
+    /// Failure message, as shown in the status bar
+    pub error: String,
+    pub failed_at: chrono::DateTime<chrono::Local>,
+    /// Oldest first, at most `FINAL_OUTPUT_LINES`
+    pub lines: Vec<LogLine>,
+}
+
+/// Status shown when a process control command is used during `oit replay`
+pub const REPLAY_MODE_MESSAGE: &str = "Replay mode: process control is disabled";
+
 /// Manages multiple processes
 pub struct ProcessManager {
     processes: HashMap<String, ProcessHandle>,
@@ -496,8 +961,39 @@ pub struct ProcessManager {
     restart_rx: mpsc::UnboundedReceiver<RestartResult>,
     restart_tx: mpsc::UnboundedSender<RestartResult>,
     restarts_in_flight: HashSet<String>,
-    procfile_path: Option<PathBuf>,
-    procfile_dir: Option<PathBuf>,
+    /// Procfiles the processes were loaded from (re-read on reload)
+    procfiles: Vec<ProcfileSource>,
+    /// Most recently killed process (for `:s` without arguments)
+    last_killed: Option<String>,
+    /// Field names for structured JSON log extraction (None = disabled)
+    json_logs: Option<JsonLogFields>,
+    /// Processes added at runtime (not in the Procfile, never persisted)
+    runtime_processes: HashSet<String>,
+    /// Custom chrono format for precomputed timestamps (None = default `%H:%M:%S`)
+    timestamp_format: Option<String>,
+    /// Error/warning classifier (`[severity]` config); such lines are never quiet boot output
+    severity: SeverityMatcher,
+    /// Maximum log lines ingested per tick, so a flood drains over several frames
+    max_ingest_per_tick: usize,
+    /// Lines still queued in the channel after the last drain (0 = caught up)
+    ingest_backlog: usize,
+    /// Logs were loaded from an export (`oit replay`); nothing is spawned
+    replay_mode: bool,
+    /// Restart initiation times, oldest first (display only, not in the log buffer)
+    restart_markers: Vec<RestartMarker>,
+    /// Lifecycle hooks waiting to run or still running
+    hook_runner: HookRunner,
+    /// Processes whose lines are held instead of buffered (`:pause`)
+    paused_ingest: HashMap<String, PausedIngest>,
+    /// Each process's output from its most recent failure
+    final_outputs: HashMap<String, FinalOutput>,
+    /// Failures not yet captured into `final_outputs`: (error, failed_at, the
+    /// run that exited, whose output must drain first)
+    pending_final_outputs: HashMap<String, (String, chrono::DateTime<chrono::Local>, Option<u32>)>,
+    /// Processes held until their oneshot dependencies are done
+    waiting: BTreeSet<String>,
+    /// Lines buffered since the last `take_new_line_count`, by any ingest path
+    new_lines: usize,
 }
 
 impl ProcessManager {
@@ -520,14 +1016,136 @@ impl ProcessManager {
             restart_rx,
             restart_tx,
             restarts_in_flight: HashSet::new(),
-            procfile_path: None,
-            procfile_dir: None,
+            procfiles: Vec::new(),
+            last_killed: None,
+            json_logs: None,
+            runtime_processes: HashSet::new(),
+            timestamp_format: None,
+            severity: SeverityMatcher::default(),
+            max_ingest_per_tick: DEFAULT_MAX_INGEST_PER_TICK,
+            ingest_backlog: 0,
+            replay_mode: false,
+            restart_markers: Vec::new(),
+            hook_runner: HookRunner::default(),
+            paused_ingest: HashMap::new(),
+            final_outputs: HashMap::new(),
+            pending_final_outputs: HashMap::new(),
+            waiting: BTreeSet::new(),
+            new_lines: 0,
         }
     }
 
+    /// Enable or disable JSON log extraction for incoming lines
+    pub fn set_json_logs(&mut self, fields: Option<JsonLogFields>) {
+        self.json_logs = fields;
+    }
+
+    /// Set the error/warning classifier used to keep boot errors visible
+    pub fn set_severity(&mut self, severity: SeverityMatcher) {
+        self.severity = severity;
+    }
+
+    /// Set the per-tick ingest cap (at least 1)
+    pub fn set_max_ingest_per_tick(&mut self, max: usize) {
+        self.max_ingest_per_tick = max.max(1);
+    }
+
+    /// Processes whose queued output is near `PROCESS_QUEUE_LIMIT`, sorted
+    pub fn lagging_processes(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .processes
+            .iter()
+            .filter(|(_, p)| p.pressure.is_lagging())
+            .map(|(name, _)| name.as_str())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Number of lines still waiting in the channel after the last drain
+    pub fn ingest_backlog(&self) -> usize {
+        self.ingest_backlog
+    }
+
+    /// Whether the last drain hit the cap and left lines queued
+    pub fn is_catching_up(&self) -> bool {
+        self.ingest_backlog > 0
+    }
+
+    /// Set a custom chrono format for incoming lines' timestamps
+    pub fn set_timestamp_format(&mut self, format: Option<String>) {
+        self.timestamp_format = format;
+    }
+
+    /// Load exported logs for `oit replay`. Each process seen in the logs gets a
+    /// stopped placeholder so the process list, coloring and visibility work.
+    pub fn load_replay(&mut self, logs: Vec<LogLine>) {
+        self.replay_mode = true;
+        for mut log in logs {
+            if let Some(format) = &self.timestamp_format {
+                log.set_timestamp_format(format);
+            }
+            let name = log.source.process_name();
+            if !self.processes.contains_key(name) {
+                self.add_process(name.to_string(), String::new(), None, None, None);
+            }
+            self.log_buffer.push(log);
+        }
+    }
+
+    /// Whether every process is stopped or failed (nothing left running). A
+    /// process soft-failed by a `marks_failed` match is still running.
+    pub fn all_exited(&self) -> bool {
+        self.processes.values().all(|p| {
+            matches!(p.status, ProcessStatus::Stopped | ProcessStatus::Failed(_) | ProcessStatus::Done)
+                && !p.is_soft_failed()
+        })
+    }
+
+    /// Whether logs came from `oit replay` (process control is disabled)
+    pub fn is_replay_mode(&self) -> bool {
+        self.replay_mode
+    }
+
+    #[allow(dead_code)]
     pub fn set_procfile_path(&mut self, path: PathBuf, dir: PathBuf) {
-        self.procfile_path = Some(path);
-        self.procfile_dir = Some(dir);
+        self.procfiles = vec![ProcfileSource { path, dir, namespace: None }];
+    }
+
+    /// Procfiles the process list was loaded from
+    pub fn procfiles(&self) -> &[ProcfileSource] {
+        &self.procfiles
+    }
+
+    /// Set the Procfiles to re-read on reload (several = namespaced names)
+    pub fn set_procfiles(&mut self, sources: Vec<ProcfileSource>) {
+        self.procfiles = sources;
+    }
+
+    /// Processes whose Procfile command or env differs from what they were
+    /// loaded with, as sorted `name.command` / `name.env` entries. A restart
+    /// re-reads the Procfile and applies them. Empty if it can't be re-read.
+    pub fn procfile_changes(&self) -> Vec<String> {
+        if self.procfiles.is_empty() || self.procfiles.iter().any(|s| s.path.as_path() == Path::new("-")) {
+            return Vec::new();
+        }
+        let Ok(entries) = procfile::load_entries(&self.procfiles) else {
+            return Vec::new();
+        };
+        let mut changes = Vec::new();
+        for (name, process) in &self.processes {
+            let Some(entry) = entries.get(name) else {
+                continue;
+            };
+            if entry.command != process.command {
+                changes.push(format!("{}.command", name));
+            }
+            if entry.options.env != process.env {
+                changes.push(format!("{}.env", name));
+            }
+        }
+        changes.sort();
+        changes
     }
 
     /// Add a process definition (doesn't start it)
@@ -535,30 +1153,96 @@ impl ProcessManager {
         self.processes.insert(name.clone(), ProcessHandle::new(name, command, working_dir, status_config, stdin_config));
     }
 
+    /// Apply a process's `[processes.<name>]` settings: hooks, `quiet_until`,
+    /// `strip_prefix`, `autostart` and startup grace (None = the defaults)
+    pub fn apply_process_config(&mut self, name: &str, pc: Option<&ProcessConfig>) {
+        self.set_hooks(name, pc.map(ProcessHooks::from_config).unwrap_or_default());
+        self.set_quiet_until(name, pc.and_then(|pc| pc.quiet_until_regex()));
+        self.set_strip_prefix(name, pc.and_then(|pc| pc.strip_prefix_regex()));
+        self.set_autostart(name, pc.and_then(|pc| pc.autostart).unwrap_or(true));
+        self.set_startup_grace(
+            name,
+            pc.and_then(|pc| pc.startup_grace()),
+            pc.and_then(|pc| pc.startup_grace_crash_fails).unwrap_or(true),
+        );
+    }
+
+    /// Apply Procfile options (env and cwd) to a process.
+    /// `cwd` is resolved relative to `base_dir`.
+    pub fn apply_process_options(&mut self, name: &str, options: &ProcessOptions, base_dir: Option<&Path>) {
+        if let Some(process) = self.processes.get_mut(name) {
+            process.env = options.env.clone();
+            process.oneshot = options.process_type == ProcessType::Oneshot;
+            process.depends_on = options.depends_on.clone();
+            if let Some(ref cwd) = options.cwd {
+                process.working_dir = Some(match base_dir {
+                    Some(dir) => dir.join(cwd),
+                    None => cwd.clone(),
+                });
+            }
+        }
+    }
+
+    /// Add an ad-hoc process that isn't in the Procfile (doesn't start it).
+    /// Relative `cwd` values resolve against the Procfile directory.
+    pub fn add_runtime_process(
+        &mut self,
+        name: &str,
+        command: &str,
+        cwd: Option<PathBuf>,
+        env: HashMap<String, String>,
+    ) -> Result<()> {
+        if self.processes.contains_key(name) {
+            anyhow::bail!("Process '{}' already exists", name);
+        }
+        if self.has_standalone_log_file(name) {
+            anyhow::bail!("A log file named '{}' already exists", name);
+        }
+
+        let base_dir = self.procfiles.first().map(|s| &s.dir);
+        let working_dir = match (cwd, base_dir) {
+            (Some(cwd), Some(dir)) => Some(dir.join(cwd)),
+            (Some(cwd), None) => Some(cwd),
+            (None, dir) => dir.cloned(),
+        };
+        self.add_process(name.to_string(), command.to_string(), working_dir, None, None);
+        if let Some(process) = self.processes.get_mut(name) {
+            process.env = env;
+        }
+        self.runtime_processes.insert(name.to_string());
+        Ok(())
+    }
+
     /// Reload the Procfile and update process definitions.
     /// - Updated commands are applied to existing processes (takes effect on next restart)
     /// - New processes are added (in Stopped state)
     /// - Removed processes are put into Failed state with a message
     /// Returns a summary of changes, or an error if the Procfile can't be read.
     pub fn reload_procfile(&mut self, config: &Config) -> Result<ProcfileReloadResult> {
-        let procfile_path = self.procfile_path.as_ref()
-            .ok_or_else(|| anyhow::anyhow!("No Procfile path configured"))?;
-        let procfile_dir = self.procfile_dir.clone();
+        if self.procfiles.is_empty() {
+            anyhow::bail!("No Procfile path configured");
+        }
+        if self.procfiles.iter().any(|s| s.path.as_path() == Path::new("-")) {
+            anyhow::bail!("Cannot reload a Procfile read from stdin");
+        }
 
-        let procfile = Procfile::from_file(procfile_path)?;
+        let entries = procfile::load_entries(&self.procfiles)?;
         let mut result = ProcfileReloadResult::default();
+        let entry_names: Vec<String> = entries.keys().cloned().collect();
+        let ports = config.assigned_ports(&entry_names);
 
         // Check existing processes against new Procfile
         let existing_names: Vec<String> = self.processes.keys().cloned().collect();
         for name in &existing_names {
-            if config.ignored_processes.contains(name) {
+            if config.ignored_processes.contains(name) || self.runtime_processes.contains(name) {
                 continue;
             }
-            match procfile.processes.get(name) {
-                Some(new_command) => {
+            match entries.get(name) {
+                Some(entry) => {
+                    self.apply_process_options(name, &entry.options, Some(&entry.dir));
                     let process = self.processes.get_mut(name).unwrap();
-                    if process.command != *new_command {
-                        process.command = new_command.clone();
+                    if process.command != entry.command {
+                        process.command = entry.command.clone();
                         result.updated.push(name.clone());
                     } else {
                         result.unchanged.push(name.clone());
@@ -574,7 +1258,7 @@ impl ProcessManager {
         }
 
         // Check for new processes in the Procfile
-        for (name, command) in &procfile.processes {
+        for (name, entry) in &entries {
             if config.ignored_processes.contains(name) {
                 continue;
             }
@@ -585,14 +1269,19 @@ impl ProcessManager {
                     .and_then(|pc| pc.stdin.as_deref());
                 self.add_process(
                     name.clone(),
-                    command.clone(),
-                    procfile_dir.clone(),
+                    entry.command.clone(),
+                    Some(entry.dir.clone()),
                     status_config,
                     stdin_config,
                 );
+                self.apply_process_options(name, &entry.options, Some(&entry.dir));
+                self.apply_process_config(name, config.processes.get(name));
                 result.added.push(name.clone());
             }
         }
+        if !ports.is_empty() {
+            self.set_ports(&ports);
+        }
 
         Ok(result)
     }
@@ -603,12 +1292,46 @@ impl ProcessManager {
         process.start(self.log_tx.clone()).await
     }
 
-    /// Start all processes, continuing even if some fail.
+    /// Start all processes except those with `autostart = false`, continuing even if some fail.
     /// Returns a list of (name, error_message) for any processes that failed to start.
+    #[allow(dead_code)]
     pub async fn start_all(&mut self) -> Vec<(String, String)> {
-        let names: Vec<String> = self.processes.keys().cloned().collect();
+        let names = self.autostart_names();
+        self.start_specific(&names).await
+    }
+
+    /// Processes that start with everything else (all but `autostart = false`)
+    pub fn autostart_names(&self) -> Vec<String> {
+        self.processes
+            .iter()
+            .filter(|(_, handle)| handle.autostart)
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// Start only the specified processes, continuing even if some fail.
+    /// A process with an unfinished oneshot dependency waits for `start_waiting`,
+    /// and any such dependency that isn't running is started too.
+    /// Returns a list of (name, error_message) for any processes that failed to start.
+    pub async fn start_specific(&mut self, names: &[String]) -> Vec<(String, String)> {
         let mut failures = Vec::new();
-        for name in names {
+        let mut queue: VecDeque<String> = names.iter().cloned().collect();
+        let mut seen = HashSet::new();
+        while let Some(name) = queue.pop_front() {
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+            let pending = self.pending_oneshots(&name);
+            if !pending.is_empty() {
+                queue.extend(
+                    pending
+                        .iter()
+                        .filter(|dep| matches!(dep.status, ProcessStatus::Stopped | ProcessStatus::Failed(_)))
+                        .map(|dep| dep.name.clone()),
+                );
+                self.waiting.insert(name);
+                continue;
+            }
             if let Err(e) = self.start_process(&name).await {
                 // Set the process status to Failed
                 if let Some(process) = self.processes.get_mut(&name) {
@@ -620,26 +1343,124 @@ impl ProcessManager {
         failures
     }
 
-    /// Start only the specified processes, continuing even if some fail.
-    /// Returns a list of (name, error_message) for any processes that failed to start.
-    pub async fn start_specific(&mut self, names: &[String]) -> Vec<(String, String)> {
-        let mut failures = Vec::new();
-        for name in names {
-            if let Err(e) = self.start_process(name).await {
-                // Set the process status to Failed
-                if let Some(process) = self.processes.get_mut(name) {
-                    process.status = ProcessStatus::Failed(e.to_string());
-                }
-                failures.push((name.clone(), e.to_string()));
+    /// Oneshot dependencies of `name` that haven't finished successfully
+    fn pending_oneshots(&self, name: &str) -> Vec<&ProcessHandle> {
+        let Some(process) = self.processes.get(name) else {
+            return Vec::new();
+        };
+        process
+            .depends_on
+            .iter()
+            .filter_map(|dep| self.processes.get(dep))
+            .filter(|dep| dep.oneshot && dep.status != ProcessStatus::Done)
+            .collect()
+    }
+
+    /// Start waiting processes whose oneshot dependencies are done. A
+    /// dependency that ended without finishing (failed, killed, or stopped
+    /// inside its startup grace) blocks its dependents: they leave the queue,
+    /// stay stopped and are returned as (name, reason).
+    pub async fn start_waiting(&mut self) -> Vec<(String, String)> {
+        let mut ready = Vec::new();
+        let mut blocked = Vec::new();
+        for name in &self.waiting {
+            let pending = self.pending_oneshots(name);
+            // A dependency still waiting on its own oneshots (a chain) is pending, not ended
+            let ended = |dep: &ProcessHandle| {
+                !matches!(dep.status, ProcessStatus::Running | ProcessStatus::Restarting)
+                    && !self.waiting.contains(&dep.name)
+            };
+            if let Some(dep) = pending.iter().find(|dep| ended(dep)) {
+                blocked.push((name.clone(), format!("blocked: {} {}", dep.name, hooks::status_name(&dep.status))));
+            } else if pending.is_empty() {
+                ready.push(name.clone());
             }
         }
-        failures
+        for (name, _) in &blocked {
+            self.waiting.remove(name);
+        }
+        for name in &ready {
+            self.waiting.remove(name);
+        }
+        blocked.extend(self.start_specific(&ready).await);
+        blocked
+    }
+
+    /// Whether `name` is waiting on a oneshot dependency
+    pub fn is_waiting(&self, name: &str) -> bool {
+        self.waiting.contains(name)
     }
 
     pub async fn kill_process(&mut self, name: &str) -> Result<()> {
+        self.waiting.remove(name);
         let process = self.processes.get_mut(name)
             .ok_or_else(|| anyhow::anyhow!("Process '{}' not found", name))?;
-        process.kill().await
+        process.kill().await?;
+        self.last_killed = Some(name.to_string());
+        Ok(())
+    }
+
+    /// Kill every running (or restarting, or soft-failed but alive) process,
+    /// leaving them stopped so they can be started again. Unlike `kill_all`,
+    /// this is not a shutdown.
+    pub async fn kill_running(&mut self) -> BulkOutcome {
+        let names = self.names_where(|p| {
+            matches!(p.status, ProcessStatus::Running | ProcessStatus::Restarting) || p.is_soft_failed()
+        });
+        let mut outcome = BulkOutcome::default();
+        for name in names {
+            match self.kill_process(&name).await {
+                Ok(()) => outcome.succeeded.push(name),
+                Err(e) => outcome.failed.push((name, e.to_string())),
+            }
+        }
+        outcome
+    }
+
+    /// Start every stopped or failed process (finished oneshot tasks stay done)
+    pub async fn start_stopped(&mut self) -> BulkOutcome {
+        let names = self.names_where(|p| {
+            matches!(p.status, ProcessStatus::Stopped | ProcessStatus::Failed(_)) && !p.is_soft_failed()
+        });
+        let mut outcome = BulkOutcome::default();
+        for name in names {
+            match self.start_process(&name).await {
+                Ok(()) => outcome.succeeded.push(name),
+                Err(e) => {
+                    if let Some(process) = self.processes.get_mut(&name) {
+                        process.status = ProcessStatus::Failed(e.to_string());
+                    }
+                    outcome.failed.push((name, e.to_string()));
+                }
+            }
+        }
+        outcome
+    }
+
+    /// Number of processes with a live child (whatever their status says, e.g.
+    /// soft-failed or terminating) or a restart in flight
+    pub fn running_count(&self) -> usize {
+        self.processes
+            .values()
+            .filter(|p| p.child.is_some() || p.status == ProcessStatus::Restarting)
+            .count()
+    }
+
+    /// Sorted names of processes that match
+    fn names_where(&self, pred: impl Fn(&ProcessHandle) -> bool) -> Vec<String> {
+        let mut names: Vec<String> = self
+            .processes
+            .iter()
+            .filter(|(_, p)| pred(p))
+            .map(|(name, _)| name.clone())
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Name of the most recently killed process, if any
+    pub fn last_killed(&self) -> Option<&str> {
+        self.last_killed.as_deref()
     }
 
     #[allow(dead_code)]
@@ -654,6 +1475,7 @@ impl ProcessManager {
     pub fn set_restarting(&mut self, name: &str) -> bool {
         if let Some(process) = self.processes.get_mut(name) {
             process.status = ProcessStatus::Restarting;
+            self.record_restart(name, chrono::Local::now());
             true
         } else {
             false
@@ -663,11 +1485,31 @@ impl ProcessManager {
     /// Set all running processes to Restarting status (fast, non-blocking)
     /// Only restarts processes that are currently Running - stopped processes stay stopped
     pub fn set_all_restarting(&mut self) {
-        for (_name, process) in self.processes.iter_mut() {
+        let now = chrono::Local::now();
+        let mut restarted = Vec::new();
+        for (name, process) in self.processes.iter_mut() {
             if process.status == ProcessStatus::Running {
                 process.status = ProcessStatus::Restarting;
+                restarted.push(name.clone());
             }
         }
+        restarted.sort();
+        for name in restarted {
+            self.record_restart(&name, now);
+        }
+    }
+
+    /// Remember that a restart of `name` was initiated at `time`
+    pub fn record_restart(&mut self, name: &str, time: chrono::DateTime<chrono::Local>) {
+        if self.restart_markers.len() >= MAX_RESTART_MARKERS {
+            self.restart_markers.remove(0);
+        }
+        self.restart_markers.push(RestartMarker { process: name.to_string(), time });
+    }
+
+    /// Restart markers, oldest first
+    pub fn restart_markers(&self) -> &[RestartMarker] {
+        &self.restart_markers
     }
 
     /// Get the names of processes that are currently in Restarting status
@@ -692,6 +1534,7 @@ impl ProcessManager {
             }
 
             if let Some(process) = self.processes.get_mut(&name) {
+                process.run += 1;
                 // Collect data needed for restart
                 let restart_data = RestartData {
                     name: name.clone(),
@@ -699,7 +1542,10 @@ impl ProcessManager {
                     working_dir: process.working_dir.clone(),
                     old_pgid: process.pgid.take(),
                     log_tx: self.log_tx.clone(),
+                    pressure: process.pressure.clone(),
                     stdin_mode: process.stdin_mode.clone(),
+                    env: process.spawn_env(),
+                    run: process.run,
                 };
 
                 // Abort old output capture tasks
@@ -826,18 +1672,140 @@ impl ProcessManager {
     pub async fn check_all_status(&mut self) -> Vec<(String, String)> {
         let mut newly_failed = Vec::new();
         for (name, process) in self.processes.iter_mut() {
+            if let Some(msg) = process.pending_failure.take() {
+                // The matched line is already buffered and the process is still running
+                self.pending_final_outputs.insert(name.clone(), (msg.clone(), chrono::Local::now(), None));
+                newly_failed.push((name.clone(), msg));
+            }
             let was_running = process.status == ProcessStatus::Running;
             let new_status = process.check_status().await;
             // Detect transitions to Failed status
             if was_running {
                 if let ProcessStatus::Failed(ref msg) = new_status {
+                    // Its last lines may still be in the pipes or the channel
+                    self.pending_final_outputs
+                        .insert(name.clone(), (msg.clone(), chrono::Local::now(), Some(process.run)));
                     newly_failed.push((name.clone(), msg.clone()));
                 }
             }
+
+            let previous = std::mem::replace(&mut process.observed_status, new_status.clone());
+            if process.hooks.is_empty() {
+                continue;
+            }
+            for event in hooks::transition_events(&previous, &new_status, process.child.is_none()) {
+                if let Some(invocation) = hooks::invocation(
+                    name,
+                    &process.hooks,
+                    event,
+                    hooks::status_name(&new_status),
+                    process.exit_code,
+                    process.working_dir.clone(),
+                ) {
+                    self.hook_runner.enqueue(invocation);
+                }
+            }
         }
+        self.capture_final_outputs();
         newly_failed
     }
 
+    /// Save the output of failures whose readers have hit EOF and whose lines
+    /// have all left the channel, so the last lines before exit are included
+    fn capture_final_outputs(&mut self) {
+        let ready: Vec<String> = self
+            .pending_final_outputs
+            .iter()
+            .filter(|(name, (_, _, exited_run))| match (exited_run, self.processes.get(name.as_str())) {
+                (Some(run), Some(p)) => p.run != *run || p.output_drained(),
+                _ => true,
+            })
+            .map(|(name, _)| name.clone())
+            .collect();
+        for name in ready {
+            let Some((error, failed_at, _)) = self.pending_final_outputs.remove(&name) else {
+                continue;
+            };
+            let lines = self.get_recent_process_logs(&name, FINAL_OUTPUT_LINES).into_iter().cloned().collect();
+            self.final_outputs.insert(name, FinalOutput { error, failed_at, lines });
+        }
+    }
+
+    /// Output captured when `name` last failed
+    pub fn final_output(&self, name: &str) -> Option<&FinalOutput> {
+        self.final_outputs.get(name)
+    }
+
+    /// The process that failed most recently, if any has
+    pub fn last_failed_process(&self) -> Option<&str> {
+        self.final_outputs
+            .iter()
+            .max_by_key(|(_, output)| output.failed_at)
+            .map(|(name, _)| name.as_str())
+    }
+
+    /// Set a process's `quiet_until` readiness pattern
+    pub fn set_quiet_until(&mut self, name: &str, pattern: Option<Regex>) {
+        if let Some(process) = self.processes.get_mut(name) {
+            process.quiet_until = pattern;
+        }
+    }
+
+    /// Set each process's `PORT` from `Config::assigned_ports`; others get none
+    pub fn set_ports(&mut self, ports: &HashMap<String, u16>) {
+        for (name, process) in self.processes.iter_mut() {
+            process.port = ports.get(name).copied();
+        }
+    }
+
+    /// Set whether a process starts with everything else (`autostart`)
+    pub fn set_autostart(&mut self, name: &str, autostart: bool) {
+        if let Some(process) = self.processes.get_mut(name) {
+            process.autostart = autostart;
+        }
+    }
+
+    /// Set a process's `startup_grace_ms` window and whether crashes inside it still fail
+    pub fn set_startup_grace(&mut self, name: &str, grace: Option<std::time::Duration>, crash_fails: bool) {
+        if let Some(process) = self.processes.get_mut(name) {
+            process.startup_grace = grace;
+            process.grace_crash_fails = crash_fails;
+        }
+    }
+
+    /// Set a process's `strip_prefix` pattern
+    pub fn set_strip_prefix(&mut self, name: &str, pattern: Option<Regex>) {
+        if let Some(process) = self.processes.get_mut(name) {
+            process.strip_prefix = pattern;
+        }
+    }
+
+    /// Replace a process's status transitions (config reload); the status
+    /// label starts over from the new config's default
+    pub fn set_status_config(&mut self, name: &str, status_config: Option<&StatusConfig>) {
+        if let Some(process) = self.processes.get_mut(name) {
+            process.status_matcher = status_config.and_then(|c| StatusMatcher::new(c).ok());
+        }
+    }
+
+    /// Set a process's lifecycle hooks
+    pub fn set_hooks(&mut self, name: &str, process_hooks: ProcessHooks) {
+        if let Some(process) = self.processes.get_mut(name) {
+            process.hooks = process_hooks;
+        }
+    }
+
+    /// Hooks queued by check_all_status and not yet spawned
+    #[cfg(test)]
+    pub fn pending_hooks(&self) -> &[hooks::HookInvocation] {
+        self.hook_runner.pending()
+    }
+
+    /// Spawn queued hooks (detached). Returns spawn errors for the status bar.
+    pub fn fire_hooks(&mut self) -> Vec<String> {
+        self.hook_runner.fire(Instant::now())
+    }
+
     #[allow(dead_code)]
     pub fn get_status(&self, name: &str) -> Option<ProcessStatus> {
         self.processes.get(name).map(|p| p.status.clone())
@@ -889,6 +1857,24 @@ impl ProcessManager {
         Ok(())
     }
 
+    /// Tail a standalone log file added while running (`:tailfile`, IPC
+    /// `add_log_file`). Relative paths resolve against the Procfile directory,
+    /// like configured log files; the file may not exist yet. Returns the resolved path.
+    pub async fn add_runtime_log_file(&mut self, name: &str, path: &Path) -> Result<PathBuf> {
+        if self.processes.contains_key(name) {
+            anyhow::bail!("A process named '{}' already exists", name);
+        }
+        if self.has_standalone_log_file(name) {
+            anyhow::bail!("Log file '{}' already exists", name);
+        }
+        let path = match self.procfiles.first() {
+            Some(source) => crate::config::resolve_path(path, &source.dir),
+            None => path.to_path_buf(),
+        };
+        self.add_standalone_log_file(name.to_string(), path.clone()).await?;
+        Ok(path)
+    }
+
     /// Get names of all standalone log files
     pub fn get_standalone_log_file_names(&self) -> Vec<String> {
         self.standalone_log_files.iter().map(|r| r.name().to_string()).collect()
@@ -899,29 +1885,110 @@ impl ProcessManager {
         self.standalone_log_files.iter().any(|r| r.name() == name)
     }
 
+    /// Re-read the log files for `name` (a process or standalone log file) from the top.
+    /// Returns how many files were restarted.
+    pub fn retail_log_file(&mut self, name: &str) -> usize {
+        self.log_sources
+            .iter_mut()
+            .chain(self.standalone_log_files.iter_mut())
+            .filter(|r| r.name() == name)
+            .map(|r| r.retail())
+            .filter(|&restarted| restarted)
+            .count()
+    }
+
     /// Process incoming logs from the channel into the buffer
     /// Also checks each log line against status patterns for the corresponding process
     /// Note: This only works if the receiver hasn't been taken via take_log_receiver()
-    pub fn process_logs(&mut self) {
-        // Collect logs first to avoid borrow issues
-        let logs: Vec<LogLine> = if let Some(ref mut log_rx) = self.log_rx {
-            std::iter::from_fn(|| log_rx.try_recv().ok()).collect()
-        } else {
-            Vec::new()
-        };
-        for log in logs {
-            self.process_single_log(log);
+    pub fn process_logs(&mut self) -> usize {
+        match self.log_rx.take() {
+            Some(mut log_rx) => {
+                let count = self.drain_logs(&mut log_rx);
+                self.log_rx = Some(log_rx);
+                count
+            }
+            None => 0,
         }
     }
 
+    /// Move up to `max_ingest_per_tick` lines from the receiver into the buffer.
+    /// Anything beyond the cap stays queued for the next tick (see `is_catching_up`).
+    /// Returns the number of lines buffered (lines held by `:pause` don't count).
+    pub fn drain_logs(&mut self, log_rx: &mut mpsc::UnboundedReceiver<LogLine>) -> usize {
+        let buffered_before = self.new_lines;
+        let mut count = 0;
+        while count < self.max_ingest_per_tick {
+            match log_rx.try_recv() {
+                Ok(log) => {
+                    self.ingest_from_channel(log);
+                    count += 1;
+                }
+                Err(_) => break,
+            }
+        }
+        self.ingest_backlog = log_rx.len();
+        self.new_lines - buffered_before
+    }
+
+    /// Ingest a line taken off the log channel, by `drain_logs` or the main
+    /// loop's select, and release the queue slot its reader reserved
+    pub fn ingest_from_channel(&mut self, log: LogLine) {
+        // Only reader lines reserved a slot; log file lines don't
+        if matches!(log.source, LogSource::ProcessStdout(_) | LogSource::ProcessStderr(_))
+            && let Some(process) = self.processes.get(log.source.process_name())
+        {
+            process.pressure.release();
+        }
+        self.process_single_log(log);
+    }
+
     pub fn get_recent_logs(&self, n: usize) -> Vec<&LogLine> {
         self.log_buffer.get_last(n)
     }
 
+    pub fn get_recent_process_logs(&self, name: &str, n: usize) -> Vec<&LogLine> {
+        self.log_buffer.get_last_from(name, n)
+    }
+
+    /// The newest buffered line from each of `names` (processes or log files)
+    pub fn last_lines(&self, names: &[&str]) -> HashMap<&str, &LogLine> {
+        self.log_buffer.last_per_process(names)
+    }
+
+    /// Log volume per bucket over the newest lines (see `VolumeHistogram::compute`)
+    pub fn volume_histogram(&self, bucket_secs: i64, buckets: usize, per_process: bool) -> VolumeHistogram {
+        let arrivals = self
+            .log_buffer
+            .newest_first()
+            .filter(|log| !log.source.is_annotation())
+            .map(|log| (log.source.process_name(), log.arrival_time));
+        VolumeHistogram::compute(arrivals, chrono::Local::now(), bucket_secs, buckets, per_process)
+    }
+
+    /// ID of the newest buffered line
+    pub fn newest_log_id(&self) -> Option<u64> {
+        self.log_buffer.newest_first().next().map(|log| log.id)
+    }
+
     pub fn get_all_logs(&self) -> Vec<&LogLine> {
         self.log_buffer.get_all()
     }
 
+    /// Buffered lines per process or log file name
+    pub fn line_counts(&self) -> HashMap<&str, usize> {
+        let mut counts = HashMap::new();
+        for log in self.log_buffer.get_all().into_iter().filter(|log| !log.source.is_annotation()) {
+            *counts.entry(log.source.process_name()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Change the log buffer's memory cap live, evicting the oldest lines if it
+    /// shrank. Returns the number of lines evicted.
+    pub fn set_buffer_limit(&mut self, max_log_buffer_mb: usize) -> usize {
+        self.log_buffer.set_memory_limit(max_log_buffer_mb)
+    }
+
     /// Get buffer statistics for UI display
     pub fn get_buffer_stats(&self) -> BufferStats {
         BufferStats {
@@ -960,14 +2027,82 @@ impl ProcessManager {
         self.log_rx.take().expect("log receiver already taken")
     }
 
-    /// Process a single log line (for use with external receiver)
-    pub fn process_single_log(&mut self, log: LogLine) {
-        self.velocity_tracker.record(log.arrival_time);
+    /// Hold a process's new lines instead of buffering them, so a flood can't
+    /// evict older history. The channel is shared by every process, so it keeps
+    /// draining; held lines wait in a per-process queue of up to
+    /// `PAUSED_INGEST_LIMIT` lines, dropping the oldest beyond that.
+    /// Returns false if it was already paused.
+    pub fn pause_ingest(&mut self, name: &str) -> bool {
+        if self.paused_ingest.contains_key(name) {
+            return false;
+        }
+        self.paused_ingest.insert(name.to_string(), PausedIngest::default());
+        true
+    }
+
+    /// Buffer a paused process's held lines and go back to normal ingest.
+    /// Returns (lines ingested, lines dropped while paused), or None if it wasn't paused.
+    pub fn resume_ingest(&mut self, name: &str) -> Option<(usize, usize)> {
+        let paused = self.paused_ingest.remove(name)?;
+        let ingested = paused.held.len();
+        for mut log in paused.held {
+            // Buffered now, after lines that arrived while it was held
+            log.restamp();
+            self.buffer_log(log);
+        }
+        Some((ingested, paused.dropped))
+    }
+
+    pub fn is_ingest_paused(&self, name: &str) -> bool {
+        self.paused_ingest.contains_key(name)
+    }
+
+    /// Process a single log line (for use with external receiver). Status
+    /// patterns see every line, including ones a `:pause` holds back.
+    pub fn process_single_log(&mut self, mut log: LogLine) {
         let process_name = log.source.process_name();
         if let Some(handle) = self.processes.get_mut(process_name) {
             handle.check_log_line(&log.line);
+            log.quiet = handle.is_quiet_line(&log.line, &self.severity);
+            if let Some(pattern) = &handle.strip_prefix {
+                log.strip_prefix(pattern);
+            }
+        }
+        if let Some(fields) = &self.json_logs {
+            log.apply_json_fields(fields);
+        }
+        if let Some(format) = &self.timestamp_format {
+            log.set_timestamp_format(format);
         }
+        if let Some(paused) = self.paused_ingest.get_mut(log.source.process_name()) {
+            paused.hold(log);
+            return;
+        }
+        self.buffer_log(log);
+    }
+
+    /// Add a line of oit's own (e.g. a trace note). It skips per-process handling
+    /// and log volume, but is new to subscribers like any other line.
+    pub fn add_annotation(&mut self, text: String) {
+        let mut log = LogLine::new(LogSource::Annotation, text);
+        if let Some(format) = &self.timestamp_format {
+            log.set_timestamp_format(format);
+        }
+        self.log_buffer.push(log);
+        self.new_lines += 1;
+    }
+
+    /// Add a processed line to the buffer and count it as new
+    fn buffer_log(&mut self, log: LogLine) {
+        self.velocity_tracker.record(log.arrival_time);
         self.log_buffer.push(log);
+        self.new_lines += 1;
+    }
+
+    /// Number of lines buffered since the last call, whichever path brought
+    /// them in; the newest that many lines are the new ones
+    pub fn take_new_line_count(&mut self) -> usize {
+        std::mem::take(&mut self.new_lines)
     }
 
     /// Add a log line directly to the buffer (for testing)
@@ -991,6 +2126,39 @@ impl Drop for ProcessManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hooks::HookEvent;
+
+    fn names(count: usize) -> Vec<String> {
+        (0..count).map(|i| format!("proc{:03}", i)).rev().collect()
+    }
+
+    #[test]
+    fn startup_plan_starts_everything_under_the_cap() {
+        let plan = StartupPlan::new(names(3), 50, false);
+        assert_eq!(plan.start, vec!["proc000", "proc001", "proc002"]);
+        assert!(plan.deferred.is_empty());
+        assert_eq!(plan.warning(50), None);
+    }
+
+    #[test]
+    fn startup_plan_defers_processes_over_the_cap() {
+        let plan = StartupPlan::new(names(200), 50, false);
+        assert_eq!(plan.start.len(), 50);
+        assert_eq!(plan.start.first().map(String::as_str), Some("proc000"));
+        assert_eq!(plan.deferred.len(), 150);
+        assert_eq!(plan.deferred.first().map(String::as_str), Some("proc050"));
+        assert_eq!(
+            plan.warning(50).as_deref(),
+            Some("Started 50 of 200 processes (max_processes = 50); start the rest with :s <name> or rerun with --force")
+        );
+    }
+
+    #[test]
+    fn startup_plan_force_ignores_the_cap() {
+        let plan = StartupPlan::new(names(200), 50, true);
+        assert_eq!(plan.start.len(), 200);
+        assert!(plan.deferred.is_empty());
+    }
 
     #[tokio::test]
     async fn test_process_start_stop() {
@@ -1004,6 +2172,7 @@ mod tests {
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
         manager.kill_process("test").await.unwrap();
+        assert_eq!(manager.last_killed(), Some("test"));
 
         // Wait for the process to actually terminate
         while !manager.check_termination_status().await {
@@ -1013,6 +2182,243 @@ mod tests {
         assert_eq!(manager.get_status("test"), Some(ProcessStatus::Stopped));
     }
 
+    #[tokio::test]
+    async fn runtime_log_file_waits_for_the_file_and_rejects_duplicates() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jobs.log");
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "true".to_string(), None, None, None);
+
+        let resolved = manager.add_runtime_log_file("jobs", &path).await.unwrap();
+        assert_eq!(resolved, path);
+        assert!(manager.add_runtime_log_file("jobs", &path).await.unwrap_err().to_string().contains("already exists"));
+        assert!(manager.add_runtime_log_file("web", &path).await.is_err());
+
+        std::fs::write(&path, "first\nsecond\n").unwrap();
+        for _ in 0..100 {
+            manager.process_logs();
+            if manager.get_all_logs().len() >= 2 {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+        }
+        let lines: Vec<_> = manager.get_all_logs().iter().map(|l| (l.source.process_name(), l.line.clone())).collect();
+        assert_eq!(lines, vec![("jobs", "first".to_string()), ("jobs", "second".to_string())]);
+    }
+
+    async fn wait_for_exit(manager: &mut ProcessManager, name: &str) -> Option<ProcessStatus> {
+        for _ in 0..100 {
+            manager.check_all_status().await;
+            if manager.get_status(name) != Some(ProcessStatus::Running) {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+        }
+        manager.get_status(name)
+    }
+
+    /// `migrate` (oneshot, running `migrate_cmd`) with `web` depending on it
+    fn manager_with_oneshot(migrate_cmd: &str) -> ProcessManager {
+        let mut manager = ProcessManager::new();
+        manager.add_process("migrate".to_string(), migrate_cmd.to_string(), None, None, None);
+        manager.add_process("web".to_string(), "sleep 5".to_string(), None, None, None);
+        let oneshot = ProcessOptions { process_type: ProcessType::Oneshot, ..Default::default() };
+        manager.apply_process_options("migrate", &oneshot, None);
+        let dependent = ProcessOptions { depends_on: vec!["migrate".to_string()], ..Default::default() };
+        manager.apply_process_options("web", &dependent, None);
+        manager
+    }
+
+    #[tokio::test]
+    async fn oneshot_success_is_done_and_starts_dependents() {
+        let mut manager = manager_with_oneshot("true");
+        let failures = manager.start_specific(&["web".to_string(), "migrate".to_string()]).await;
+        assert!(failures.is_empty());
+        assert!(manager.is_waiting("web"));
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Stopped));
+
+        assert_eq!(wait_for_exit(&mut manager, "migrate").await, Some(ProcessStatus::Done));
+        assert!(manager.start_waiting().await.is_empty());
+        assert!(!manager.is_waiting("web"));
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Running));
+
+        manager.kill_process("web").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn starting_a_dependent_starts_its_oneshot() {
+        let mut manager = manager_with_oneshot("true");
+        manager.start_specific(&["web".to_string()]).await;
+        assert!(manager.is_waiting("web"));
+        assert_ne!(manager.get_status("migrate"), Some(ProcessStatus::Stopped));
+
+        assert_eq!(wait_for_exit(&mut manager, "migrate").await, Some(ProcessStatus::Done));
+        assert!(manager.start_waiting().await.is_empty());
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Running));
+
+        manager.kill_process("web").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn chained_oneshots_start_in_order() {
+        let mut manager = manager_with_oneshot("true");
+        manager.add_process("setup".to_string(), "sleep 0.2".to_string(), None, None, None);
+        let oneshot = ProcessOptions { process_type: ProcessType::Oneshot, ..Default::default() };
+        manager.apply_process_options("setup", &oneshot, None);
+        let chained = ProcessOptions { depends_on: vec!["setup".to_string()], ..oneshot };
+        manager.apply_process_options("migrate", &chained, None);
+
+        manager.start_specific(&["web".to_string()]).await;
+        assert!(manager.is_waiting("web"));
+        assert!(manager.is_waiting("migrate"));
+        // migrate is still waiting on setup, so web keeps waiting too
+        assert!(manager.start_waiting().await.is_empty());
+        assert!(manager.is_waiting("web"));
+
+        assert_eq!(wait_for_exit(&mut manager, "setup").await, Some(ProcessStatus::Done));
+        assert!(manager.start_waiting().await.is_empty());
+        assert_eq!(wait_for_exit(&mut manager, "migrate").await, Some(ProcessStatus::Done));
+        assert!(manager.start_waiting().await.is_empty());
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Running));
+
+        manager.kill_process("web").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn killed_oneshot_blocks_dependents() {
+        let mut manager = manager_with_oneshot("sleep 5");
+        manager.start_specific(&["web".to_string()]).await;
+        manager.kill_process("migrate").await.unwrap();
+        while !manager.check_termination_status().await {
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+
+        let blocked = manager.start_waiting().await;
+        assert_eq!(blocked, vec![("web".to_string(), "blocked: migrate stopped".to_string())]);
+        assert!(!manager.is_waiting("web"));
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Stopped));
+    }
+
+    #[tokio::test]
+    async fn oneshot_stopped_inside_startup_grace_blocks_dependents() {
+        let mut manager = manager_with_oneshot("exit 3");
+        manager.set_startup_grace("migrate", Some(std::time::Duration::from_secs(5)), true);
+        manager.start_specific(&["web".to_string()]).await;
+
+        assert_eq!(wait_for_exit(&mut manager, "migrate").await, Some(ProcessStatus::Stopped));
+        let blocked = manager.start_waiting().await;
+        assert_eq!(blocked, vec![("web".to_string(), "blocked: migrate stopped".to_string())]);
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Stopped));
+    }
+
+    #[tokio::test]
+    async fn oneshot_failure_blocks_dependents() {
+        let mut manager = manager_with_oneshot("false");
+        manager.start_specific(&["migrate".to_string(), "web".to_string()]).await;
+        assert!(manager.is_waiting("web"));
+
+        assert!(matches!(wait_for_exit(&mut manager, "migrate").await, Some(ProcessStatus::Failed(_))));
+        let blocked = manager.start_waiting().await;
+        assert_eq!(blocked, vec![("web".to_string(), "blocked: migrate failed".to_string())]);
+        assert!(!manager.is_waiting("web"));
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Stopped));
+    }
+
+    #[tokio::test]
+    async fn exit_inside_startup_grace_is_stopped_not_failed() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("bootstrap".to_string(), "exit 3".to_string(), None, None, None);
+        manager.add_process("web".to_string(), "sleep 0.3; exit 3".to_string(), None, None, None);
+        manager.add_process("missing".to_string(), "oit-no-such-binary".to_string(), None, None, None);
+        for name in ["bootstrap", "web", "missing"] {
+            manager.set_startup_grace(name, Some(std::time::Duration::from_secs(5)), true);
+        }
+        manager.set_startup_grace("web", Some(std::time::Duration::from_millis(100)), true);
+        manager.start_specific(&["bootstrap".to_string(), "web".to_string(), "missing".to_string()]).await;
+
+        assert_eq!(wait_for_exit(&mut manager, "bootstrap").await, Some(ProcessStatus::Stopped));
+        assert_eq!(
+            wait_for_exit(&mut manager, "web").await,
+            Some(ProcessStatus::Failed("Exit code: 3".to_string()))
+        );
+        // A launch failure isn't a quick re-exec
+        assert!(matches!(wait_for_exit(&mut manager, "missing").await, Some(ProcessStatus::Failed(_))));
+    }
+
+    #[tokio::test]
+    async fn exit_inside_startup_grace_noticed_late_is_still_stopped() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "exit 3".to_string(), None, None, None);
+        manager.set_startup_grace("web", Some(std::time::Duration::from_millis(200)), true);
+        manager.start_process("web").await.unwrap();
+
+        // The exit happens right away but isn't checked until the window has passed
+        tokio::time::sleep(tokio::time::Duration::from_millis(400)).await;
+        manager.check_all_status().await;
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Stopped));
+    }
+
+    #[tokio::test]
+    async fn missing_binary_fails_with_command_not_found() {
+        let mut manager = ProcessManager::new();
+        manager.add_process(
+            "web".to_string(),
+            "PORT=3000 oit-no-such-binary --port 3000".to_string(),
+            None,
+            None,
+            None,
+        );
+        manager.start_process("web").await.unwrap();
+
+        let status = wait_for_exit(&mut manager, "web").await;
+        assert_eq!(
+            status,
+            Some(ProcessStatus::Failed(
+                "command not found: oit-no-such-binary (is it installed / on PATH?)".to_string()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn non_executable_binary_fails_with_permission_denied() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("server"), "#!/bin/sh\necho hi\n").unwrap();
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "./server".to_string(), Some(dir.path().to_path_buf()), None, None);
+        manager.start_process("web").await.unwrap();
+
+        let status = wait_for_exit(&mut manager, "web").await;
+        assert_eq!(
+            status,
+            Some(ProcessStatus::Failed("permission denied: ./server (is it executable?)".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn missing_working_dir_fails_at_spawn() {
+        let mut manager = ProcessManager::new();
+        let dir = PathBuf::from("/nonexistent/oit-test-dir");
+        manager.add_process("web".to_string(), "echo hi".to_string(), Some(dir), None, None);
+
+        let failures = manager.start_all().await;
+        assert_eq!(
+            failures,
+            vec![("web".to_string(), "working directory not found: /nonexistent/oit-test-dir".to_string())]
+        );
+        let status = manager.get_status("web").unwrap();
+        let ProcessStatus::Failed(msg) = status else { panic!("expected failed, got {:?}", status) };
+        assert_eq!(failure_kind(&msg), Some("working_dir_not_found"));
+    }
+
+    #[test]
+    fn command_program_skips_env_assignments_and_exec() {
+        assert_eq!(command_program("bundle exec rails s"), Some("bundle"));
+        assert_eq!(command_program("RAILS_ENV=dev PORT=3000 exec rails s"), Some("rails"));
+        assert_eq!(command_program("env PORT=3000 \"node\" server.js"), Some("node"));
+        assert_eq!(command_program("./bin/dev --flag=x"), Some("./bin/dev"));
+        assert_eq!(command_program("PORT=3000"), None);
+    }
+
     #[tokio::test]
     async fn test_set_all_terminating() {
         let mut manager = ProcessManager::new();
@@ -1125,6 +2531,23 @@ mod tests {
         manager.kill_all().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn test_start_all_skips_processes_without_autostart() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.add_process("migrate".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.set_autostart("migrate", false);
+
+        assert!(manager.start_all().await.is_empty());
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Running));
+        assert_eq!(manager.get_status("migrate"), Some(ProcessStatus::Stopped));
+
+        manager.start_process("migrate").await.unwrap();
+        assert_eq!(manager.get_status("migrate"), Some(ProcessStatus::Running));
+
+        manager.kill_all().await.unwrap();
+    }
+
     #[tokio::test]
     async fn test_check_all_status_detects_failed_processes() {
         let mut manager = ProcessManager::new();
@@ -1262,6 +2685,27 @@ mod tests {
         manager.kill_all().await.unwrap();
     }
 
+    #[tokio::test]
+    async fn lines_carry_the_run_that_produced_them() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("worker".to_string(), "echo booted; sleep 10".to_string(), None, None, None);
+        manager.start_process("worker").await.unwrap();
+        tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
+        manager.process_logs();
+
+        manager.set_restarting("worker");
+        manager.spawn_pending_restarts();
+        tokio::time::sleep(tokio::time::Duration::from_millis(700)).await;
+        let (succeeded, _) = manager.poll_restart_completions();
+        assert_eq!(succeeded, vec!["worker".to_string()]);
+        manager.process_logs();
+
+        let runs: Vec<Option<u32>> = manager.get_all_logs().iter().map(|log| log.run).collect();
+        assert_eq!(runs, vec![Some(1), Some(2)]);
+
+        manager.kill_all().await.unwrap();
+    }
+
     // StatusMatcher integration tests
 
     #[test]
@@ -1310,11 +2754,13 @@ mod tests {
         let config = StatusConfig {
             default: Some("Starting".to_string()),
             color: None,
+            ready: None,
             transitions: vec![
                 StatusTransition {
                     pattern: "Ready".to_string(),
                     label: "Ready".to_string(),
                     color: Some("green".to_string()),
+                    marks_failed: false,
                 },
             ],
         };
@@ -1337,11 +2783,13 @@ mod tests {
         let config = StatusConfig {
             default: Some("Starting".to_string()),
             color: None,
+            ready: None,
             transitions: vec![
                 StatusTransition {
                     pattern: "Ready".to_string(),
                     label: "Ready".to_string(),
                     color: Some("green".to_string()),
+                    marks_failed: false,
                 },
             ],
         };
@@ -1371,11 +2819,13 @@ mod tests {
         let config = StatusConfig {
             default: Some("Starting".to_string()),
             color: None,
+            ready: None,
             transitions: vec![
                 StatusTransition {
                     pattern: "Server ready".to_string(),
                     label: "Ready".to_string(),
                     color: Some("green".to_string()),
+                    marks_failed: false,
                 },
             ],
         };
@@ -1399,6 +2849,115 @@ mod tests {
         assert_eq!(status.1, Some(Color::Green));
     }
 
+    #[tokio::test]
+    async fn test_marks_failed_transition_fails_running_process_until_recovered() {
+        use crate::config::{StatusConfig, StatusTransition};
+
+        let config = StatusConfig {
+            default: None,
+            color: None,
+            ready: None,
+            transitions: vec![
+                StatusTransition {
+                    pattern: "FATAL".to_string(),
+                    label: "Dead".to_string(),
+                    color: Some("red".to_string()),
+                    marks_failed: true,
+                },
+                StatusTransition {
+                    pattern: "Listening".to_string(),
+                    label: "Ready".to_string(),
+                    color: None,
+                    marks_failed: false,
+                },
+            ],
+        };
+
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "sleep 5".to_string(), None, Some(&config), None);
+        manager.start_process("web").await.unwrap();
+
+        let fatal = "FATAL: cannot bind to port 3000";
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), fatal.to_string()));
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Failed(fatal.to_string())));
+
+        // Reported once, like a real crash
+        let newly_failed = manager.check_all_status().await;
+        assert_eq!(newly_failed, vec![("web".to_string(), fatal.to_string())]);
+        assert!(manager.check_all_status().await.is_empty());
+
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), "Listening on 3001".to_string()));
+        assert_eq!(manager.get_status("web"), Some(ProcessStatus::Running));
+
+        manager.kill_all().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_failure_captures_the_final_output() {
+        use crate::config::{StatusConfig, StatusTransition};
+
+        let config = StatusConfig {
+            default: None,
+            color: None,
+            ready: None,
+            transitions: vec![StatusTransition {
+                pattern: "FATAL".to_string(),
+                label: "Dead".to_string(),
+                color: None,
+                marks_failed: true,
+            }],
+        };
+        let mut manager = ProcessManager::new();
+        manager.add_process("worker".to_string(), "sleep 5".to_string(), None, Some(&config), None);
+        manager.add_process("web".to_string(), "sleep 5".to_string(), None, None, None);
+        manager.start_process("worker").await.unwrap();
+        assert!(manager.last_failed_process().is_none());
+
+        for i in 1..=40 {
+            manager.process_single_log(LogLine::new(LogSource::ProcessStdout("worker".to_string()), format!("trace {}", i)));
+            manager.process_single_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), format!("GET /{}", i)));
+        }
+        manager.process_single_log(LogLine::new(LogSource::ProcessStderr("worker".to_string()), "FATAL: boom".to_string()));
+        manager.check_all_status().await;
+
+        // Later output from other processes doesn't disturb the capture
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), "GET /late".to_string()));
+
+        assert_eq!(manager.last_failed_process(), Some("worker"));
+        let output = manager.final_output("worker").unwrap();
+        assert_eq!(output.error, "FATAL: boom");
+        let lines: Vec<&str> = output.lines.iter().map(|l| l.line.as_str()).collect();
+        let mut expected: Vec<String> = (12..=40).map(|i| format!("trace {}", i)).collect();
+        expected.push("FATAL: boom".to_string());
+        assert_eq!(lines, expected);
+        assert!(manager.final_output("web").is_none());
+
+        manager.kill_all().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_final_output_waits_for_lines_still_queued_at_exit() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("worker".to_string(), "echo one; echo two; exit 3".to_string(), None, None, None);
+        manager.start_process("worker").await.unwrap();
+
+        // Nothing has been drained yet, so both lines are still in the channel
+        assert!(matches!(wait_for_exit(&mut manager, "worker").await, Some(ProcessStatus::Failed(_))));
+        assert!(manager.final_output("worker").is_none());
+
+        for _ in 0..100 {
+            manager.process_logs();
+            manager.check_all_status().await;
+            if manager.final_output("worker").is_some() {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+        }
+        let output = manager.final_output("worker").unwrap();
+        let lines: Vec<&str> = output.lines.iter().map(|l| l.line.as_str()).collect();
+        assert_eq!(lines, vec!["one", "two"]);
+    }
+
     #[test]
     fn test_process_handle_check_log_line_no_match() {
         use crate::config::{StatusConfig, StatusTransition};
@@ -1406,11 +2965,13 @@ mod tests {
         let config = StatusConfig {
             default: Some("Starting".to_string()),
             color: None,
+            ready: None,
             transitions: vec![
                 StatusTransition {
                     pattern: "Server ready".to_string(),
                     label: "Ready".to_string(),
                     color: None,
+                    marks_failed: false,
                 },
             ],
         };
@@ -1430,6 +2991,47 @@ mod tests {
         assert_eq!(handle.get_custom_status().unwrap().0, "Starting");
     }
 
+    #[test]
+    fn test_quiet_until_marks_boot_lines_until_ready() {
+        let mut handle = ProcessHandle::new("web".to_string(), "rails s".to_string(), None, None, None);
+        handle.quiet_until = Some(Regex::new("Listening on").unwrap());
+        handle.reset_status(); // Simulates process start
+        let severity = SeverityMatcher::default();
+
+        assert!(handle.is_quiet_line("=> Booting Puma", &severity));
+        assert!(!handle.is_quiet_line("ERROR: missing secret_key_base", &severity), "errors are never quiet");
+        assert!(handle.is_quiet_line("* Min threads: 5", &severity));
+        assert!(!handle.is_quiet_line("Listening on http://127.0.0.1:3000", &severity));
+        assert!(!handle.is_quiet_line("=> more output after ready", &severity));
+
+        // A restart boots quietly again
+        handle.reset_status();
+        assert!(handle.is_quiet_line("=> Booting Puma", &severity));
+    }
+
+    #[test]
+    fn test_quiet_until_keeps_lines_the_severity_config_flags() {
+        let mut handle = ProcessHandle::new("web".to_string(), "rails s".to_string(), None, None, None);
+        handle.quiet_until = Some(Regex::new("Listening on").unwrap());
+        handle.reset_status();
+        let severity = SeverityMatcher::new(&[], &["deprecat".to_string()]).unwrap();
+
+        assert!(!handle.is_quiet_line("DEPRECATION: config.foo is going away", &severity));
+        assert!(handle.is_quiet_line("=> Booting Puma", &severity));
+    }
+
+    #[test]
+    fn test_spawn_env_adds_assigned_port_unless_env_sets_one() {
+        let mut handle = ProcessHandle::new("web".to_string(), "rails s".to_string(), None, None, None);
+        assert!(!handle.spawn_env().contains_key("PORT"));
+
+        handle.port = Some(5100);
+        assert_eq!(handle.spawn_env()["PORT"], "5100");
+
+        handle.env.insert("PORT".to_string(), "3000".to_string());
+        assert_eq!(handle.spawn_env()["PORT"], "3000");
+    }
+
     #[test]
     fn test_process_handle_reset_status() {
         use crate::config::{StatusConfig, StatusTransition};
@@ -1437,11 +3039,13 @@ mod tests {
         let config = StatusConfig {
             default: Some("Starting".to_string()),
             color: None,
+            ready: None,
             transitions: vec![
                 StatusTransition {
                     pattern: "Ready".to_string(),
                     label: "Ready".to_string(),
                     color: Some("green".to_string()),
+                    marks_failed: false,
                 },
             ],
         };
@@ -1499,11 +3103,13 @@ mod tests {
         let config = StatusConfig {
             default: Some("Starting".to_string()),
             color: None,
+            ready: None,
             transitions: vec![
                 StatusTransition {
                     pattern: "Server ready".to_string(),
                     label: "Ready".to_string(),
                     color: Some("green".to_string()),
+                    marks_failed: false,
                 },
             ],
         };
@@ -1556,6 +3162,293 @@ mod tests {
         // And no crash occurred - graceful handling
     }
 
+    #[test]
+    fn test_process_single_log_applies_json_extraction_when_enabled() {
+        let raw = r#"{"level":"info","msg":"started"}"#;
+        let mut manager = ProcessManager::new();
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), raw.to_string()));
+        assert_eq!(manager.get_all_logs()[0].line, raw);
+
+        manager.set_json_logs(Some(JsonLogFields::default()));
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), raw.to_string()));
+        let logs = manager.get_all_logs();
+        assert_eq!(logs[1].line, raw);
+        assert_eq!(logs[1].display_line(), "INFO started");
+        assert_eq!(logs[1].raw_json(), Some(raw));
+    }
+
+    #[test]
+    fn test_process_single_log_strips_configured_prefix() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("rails".to_string(), "true".to_string(), None, None, None);
+        manager.set_strip_prefix("rails", Some(Regex::new(r"^\[[^\]]*\] \w+ ").unwrap()));
+
+        let raw = "[2024-12-10 12:00:00] INFO foo";
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("rails".to_string()), raw.to_string()));
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), raw.to_string()));
+
+        let logs = manager.get_all_logs();
+        assert_eq!(logs[0].line, "foo");
+        assert_eq!(logs[0].raw_line(), Some(raw));
+        assert_eq!(logs[1].line, raw);
+    }
+
+    #[test]
+    fn test_process_logs_caps_ingest_per_tick() {
+        let mut manager = ProcessManager::new();
+        manager.set_max_ingest_per_tick(100);
+        for i in 0..250 {
+            let _ = manager.log_tx.send(LogLine::new(LogSource::ProcessStdout("web".to_string()), format!("line {}", i)));
+        }
+
+        assert_eq!(manager.process_logs(), 100);
+        assert!(manager.is_catching_up());
+        assert_eq!(manager.ingest_backlog(), 150);
+
+        assert_eq!(manager.process_logs(), 100);
+        assert_eq!(manager.process_logs(), 50);
+        assert!(!manager.is_catching_up());
+        assert_eq!(manager.get_all_logs().len(), 250);
+        assert_eq!(manager.get_all_logs()[249].line, "line 249");
+    }
+
+    #[test]
+    fn test_full_output_queue_drops_and_marks_lines() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "true".to_string(), None, None, None);
+        manager.set_max_ingest_per_tick(PROCESS_QUEUE_LIMIT + 10);
+        let pressure = manager.processes["web"].pressure.clone();
+        let tx = manager.log_tx.clone();
+        let send = |text: &str| forward_line(&tx, &pressure, LogSource::ProcessStdout("web".to_string()), text.to_string(), 1);
+
+        for _ in 0..PROCESS_QUEUE_LIMIT {
+            send("flood");
+        }
+        assert_eq!(manager.lagging_processes(), vec!["web"]);
+        send("lost 1");
+        send("lost 2");
+        assert_eq!(pressure.dropped(), 2);
+        assert_eq!(pressure.queued(), PROCESS_QUEUE_LIMIT);
+
+        // Draining frees the queue; the next line is preceded by a drop marker
+        assert_eq!(manager.process_logs(), PROCESS_QUEUE_LIMIT);
+        assert_eq!(pressure.queued(), 0);
+        assert!(manager.lagging_processes().is_empty());
+        send("after");
+        manager.process_logs();
+        let logs = manager.get_recent_logs(2);
+        assert_eq!(logs[0].line, "[oit] output lagging: dropped 2 lines");
+        assert_eq!(logs[1].line, "after");
+        assert_eq!(pressure.dropped(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_lines_received_outside_drain_release_their_queue_slot() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "true".to_string(), None, None, None);
+        let pressure = manager.processes["web"].pressure.clone();
+        let tx = manager.log_tx.clone();
+        let mut log_rx = manager.take_log_receiver();
+
+        // The main loop's select takes lines one at a time with recv()
+        for i in 0..PROCESS_QUEUE_LIMIT + 10 {
+            forward_line(&tx, &pressure, LogSource::ProcessStdout("web".to_string()), format!("line {}", i), 1);
+            let log = log_rx.recv().await.unwrap();
+            manager.ingest_from_channel(log);
+        }
+        assert_eq!(pressure.queued(), 0);
+        assert_eq!(pressure.dropped(), 0);
+        assert!(manager.lagging_processes().is_empty());
+    }
+
+    #[test]
+    fn test_new_line_count_covers_every_ingest_path() {
+        let mut manager = ProcessManager::new();
+        let line = |text: &str| LogLine::new(LogSource::ProcessStdout("web".to_string()), text.to_string());
+
+        let _ = manager.log_tx.send(line("drained"));
+        manager.process_logs();
+        manager.ingest_from_channel(line("selected"));
+        assert_eq!(manager.take_new_line_count(), 2);
+        assert_eq!(manager.take_new_line_count(), 0);
+        let lines: Vec<&str> = manager.get_recent_logs(2).iter().map(|log| log.line.as_str()).collect();
+        assert_eq!(lines, vec!["drained", "selected"]);
+    }
+
+    #[test]
+    fn test_paused_ingest_holds_lines_until_resumed() {
+        let mut manager = ProcessManager::new();
+        let line = |process: &str, text: &str| LogLine::new(LogSource::ProcessStdout(process.to_string()), text.to_string());
+
+        assert!(manager.pause_ingest("web"));
+        assert!(!manager.pause_ingest("web"));
+        let _ = manager.log_tx.send(line("web", "held 1"));
+        let _ = manager.log_tx.send(line("worker", "job"));
+        let _ = manager.log_tx.send(line("web", "held 2"));
+        manager.process_logs();
+
+        let lines: Vec<&str> = manager.get_all_logs().iter().map(|log| log.line.as_str()).collect();
+        assert_eq!(lines, vec!["job"]);
+        assert!(manager.is_ingest_paused("web"));
+
+        assert_eq!(manager.resume_ingest("web"), Some((2, 0)));
+        assert_eq!(manager.resume_ingest("web"), None);
+        let _ = manager.log_tx.send(line("web", "live"));
+        manager.process_logs();
+
+        let lines: Vec<&str> = manager.get_all_logs().iter().map(|log| log.line.as_str()).collect();
+        assert_eq!(lines, vec!["job", "held 1", "held 2", "live"]);
+    }
+
+    #[tokio::test]
+    async fn test_paused_ingest_checks_status_and_buffers_resumed_lines_as_new() {
+        use crate::config::{StatusConfig, StatusTransition};
+
+        let config = StatusConfig {
+            default: None,
+            color: None,
+            ready: None,
+            transitions: vec![StatusTransition {
+                pattern: "FATAL".to_string(),
+                label: "Dead".to_string(),
+                color: None,
+                marks_failed: true,
+            }],
+        };
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "sleep 5".to_string(), None, Some(&config), None);
+        manager.start_process("web").await.unwrap();
+        let line = |process: &str, text: &str| LogLine::new(LogSource::ProcessStdout(process.to_string()), text.to_string());
+
+        manager.pause_ingest("web");
+        let _ = manager.log_tx.send(line("web", "FATAL: out of memory"));
+        let _ = manager.log_tx.send(line("worker", "job"));
+        assert_eq!(manager.process_logs(), 1, "held lines aren't counted as buffered");
+        assert!(matches!(manager.get_status("web"), Some(ProcessStatus::Failed(_))));
+        assert_eq!(manager.take_new_line_count(), 1);
+
+        manager.resume_ingest("web");
+        assert_eq!(manager.take_new_line_count(), 1);
+        let logs = manager.get_all_logs();
+        assert_eq!(logs[1].line, "FATAL: out of memory");
+        assert!(logs[1].id > logs[0].id && logs[1].arrival_time >= logs[0].arrival_time);
+
+        manager.kill_process("web").await.unwrap();
+    }
+
+    #[test]
+    fn test_paused_ingest_drops_oldest_past_the_limit() {
+        let mut manager = ProcessManager::new();
+        manager.pause_ingest("web");
+        for i in 0..PAUSED_INGEST_LIMIT + 5 {
+            manager.process_single_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), format!("line {}", i)));
+        }
+
+        assert_eq!(manager.resume_ingest("web"), Some((PAUSED_INGEST_LIMIT, 5)));
+        assert_eq!(manager.get_all_logs()[0].line, "line 5");
+    }
+
+    #[test]
+    fn test_set_restarting_records_markers() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "true".to_string(), None, None, None);
+        manager.add_process("worker".to_string(), "true".to_string(), None, None, None);
+        manager.set_process_status_for_testing("worker", ProcessStatus::Running);
+
+        assert!(manager.set_restarting("web"));
+        assert!(!manager.set_restarting("missing"));
+        manager.set_all_restarting();
+
+        let names: Vec<&str> = manager.restart_markers().iter().map(|m| m.process.as_str()).collect();
+        assert_eq!(names, vec!["web", "worker"]);
+    }
+
+    #[test]
+    fn test_all_exited_ignores_stopped_and_failed() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "true".to_string(), None, None, None);
+        manager.add_process("worker".to_string(), "true".to_string(), None, None, None);
+        assert!(manager.all_exited());
+
+        manager.set_process_status_for_testing("web", ProcessStatus::Failed("exit 1".to_string()));
+        assert!(manager.all_exited());
+
+        manager.set_process_status_for_testing("worker", ProcessStatus::Running);
+        assert!(!manager.all_exited());
+    }
+
+    #[tokio::test]
+    async fn test_all_exited_counts_soft_failed_live_process_as_running() {
+        use crate::config::{StatusConfig, StatusTransition};
+
+        let config = StatusConfig {
+            default: None,
+            color: None,
+            ready: None,
+            transitions: vec![StatusTransition {
+                pattern: "FATAL".to_string(),
+                label: "Dead".to_string(),
+                color: None,
+                marks_failed: true,
+            }],
+        };
+        let mut manager = ProcessManager::new();
+        manager.add_process("worker".to_string(), "sleep 10".to_string(), None, Some(&config), None);
+        manager.start_process("worker").await.unwrap();
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("worker".to_string()), "FATAL: boom".to_string()));
+        assert!(matches!(manager.get_status("worker"), Some(ProcessStatus::Failed(_))));
+
+        assert!(!manager.all_exited());
+
+        manager.kill_all().await.unwrap();
+        assert!(manager.all_exited());
+    }
+
+    #[tokio::test]
+    async fn test_running_count_counts_live_children() {
+        use crate::config::{StatusConfig, StatusTransition};
+
+        let config = StatusConfig {
+            default: None,
+            color: None,
+            ready: None,
+            transitions: vec![StatusTransition {
+                pattern: "FATAL".to_string(),
+                label: "Dead".to_string(),
+                color: None,
+                marks_failed: true,
+            }],
+        };
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.add_process("worker".to_string(), "sleep 10".to_string(), None, Some(&config), None);
+        manager.add_process("idle".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.start_process("web").await.unwrap();
+        manager.start_process("worker").await.unwrap();
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("worker".to_string()), "FATAL: boom".to_string()));
+
+        // The soft-failed worker is still alive
+        assert_eq!(manager.running_count(), 2);
+
+        manager.kill_all().await.unwrap();
+        assert_eq!(manager.running_count(), 0);
+    }
+
+    #[test]
+    fn test_load_replay_registers_stopped_processes() {
+        let mut manager = ProcessManager::new();
+        manager.load_replay(vec![
+            LogLine::new(LogSource::ProcessStdout("web".to_string()), "one".to_string()),
+            LogLine::new(LogSource::ProcessStdout("worker".to_string()), "two".to_string()),
+            LogLine::new(LogSource::ProcessStdout("web".to_string()), "three".to_string()),
+        ]);
+
+        assert!(manager.is_replay_mode());
+        assert_eq!(manager.get_all_logs().len(), 3);
+        assert_eq!(manager.get_processes().len(), 2);
+        assert!(matches!(manager.get_processes()["web"].status, ProcessStatus::Stopped));
+    }
+
     #[test]
     fn test_process_logs_handles_file_logs_without_matching_process() {
         use std::path::PathBuf;
@@ -1584,16 +3477,19 @@ mod tests {
         let config = StatusConfig {
             default: Some("Starting".to_string()),
             color: None,
+            ready: None,
             transitions: vec![
                 StatusTransition {
                     pattern: "Listening".to_string(),
                     label: "Listening".to_string(),
                     color: Some("yellow".to_string()),
+                    marks_failed: false,
                 },
                 StatusTransition {
                     pattern: "Ready".to_string(),
                     label: "Ready".to_string(),
                     color: Some("green".to_string()),
+                    marks_failed: false,
                 },
             ],
         };
@@ -1688,14 +3584,52 @@ mod tests {
             hidden_processes: Vec::new(),
             ignored_processes: Vec::new(),
             start_processes: Vec::new(),
+            pinned_processes: Vec::new(),
             disable_auto_update: None,
+            background_update_check: None,
             compact_mode: None,
+            condensed_display: None,
             colors: HashMap::new(),
             process_coloring: None,
             context_copy_seconds: None,
             groups: HashMap::new(),
+            presets: HashMap::new(),
+            aliases: HashMap::new(),
             theme: None,
+            confirm_destructive: None,
+            confirm_quit: None,
+            json_logs: None,
+            timestamp_format: None,
+            max_ingest_per_tick: None,
+            ansi_mode: None,
+            severity: None,
+            idle_quit_ms: None,
+            working_dir: None,
+            notify_on_failure: Vec::new(),
+            mark_stderr: None,
+            tail_preview: None,
+            stable_process_layout: None,
+            persist_search_history: None,
+            scroll_stick_lines: None,
+            batch_strategy: None,
+            show_date_dividers: None,
+            max_processes: None,
+            max_fps: None,
+            startup_banner: None,
+            align_messages: None,
+            show_last_line: None,
+            sticky_header: None,
+            highlight_syntax: None,
+            volume_histogram: None,
+            watch_config: None,
+            show_commands: None,
+            truncation_suffix: None,
+            line_margin: None,
+            base_port: None,
+            port_step: None,
+            process_list_position: None,
             config_path: None,
+            namespace_groups: HashMap::new(),
         }
     }
 
@@ -1733,6 +3667,38 @@ mod tests {
         assert_eq!(process.command, "new_command");
     }
 
+    #[test]
+    fn test_reload_yaml_procfile_applies_env_and_cwd() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("procs.yaml");
+        std::fs::write(
+            &path,
+            "services:\n  web:\n    command: rails server\n    cwd: backend\n    env:\n      PORT: \"3000\"\n  worker:\n    command: sidekiq\n    env:\n      QUEUE: default\n",
+        )
+        .unwrap();
+
+        let mut manager = ProcessManager::new();
+        manager.set_procfile_path(path, dir.path().to_path_buf());
+        let result = manager.reload_procfile(&test_config()).unwrap();
+        assert_eq!(result.added.len(), 2);
+
+        let web = manager.processes.get("web").unwrap();
+        assert_eq!(web.working_dir, Some(dir.path().join("backend")));
+        assert_eq!(web.env.get("PORT").map(|s| s.as_str()), Some("3000"));
+
+        let worker = manager.processes.get("worker").unwrap();
+        assert_eq!(worker.working_dir, Some(dir.path().to_path_buf()));
+        assert_eq!(worker.env.get("QUEUE").map(|s| s.as_str()), Some("default"));
+    }
+
+    #[test]
+    fn test_reload_procfile_from_stdin_fails() {
+        let mut manager = ProcessManager::new();
+        manager.set_procfile_path(PathBuf::from("-"), PathBuf::from("."));
+        let err = manager.reload_procfile(&test_config()).unwrap_err();
+        assert!(err.to_string().contains("stdin"));
+    }
+
     #[test]
     fn test_reload_procfile_detects_new_process() {
         let (mut manager, tmp) = create_manager_with_procfile("web: rails server\n");
@@ -1752,6 +3718,30 @@ mod tests {
         assert_eq!(manager.get_status("worker"), Some(ProcessStatus::Stopped));
     }
 
+    #[test]
+    fn test_reload_several_procfiles_keeps_namespaces_and_dirs() {
+        let root = tempfile::tempdir().unwrap();
+        let frontend = root.path().join("frontend");
+        let backend = root.path().join("backend");
+        std::fs::create_dir_all(&frontend).unwrap();
+        std::fs::create_dir_all(&backend).unwrap();
+        std::fs::write(frontend.join("Procfile"), "web: npm run dev\n").unwrap();
+        std::fs::write(backend.join("Procfile"), "web: rails server\n").unwrap();
+
+        let sources = ProcfileSource::for_paths(&[frontend.join("Procfile"), backend.join("Procfile")]).unwrap();
+        let mut manager = ProcessManager::new();
+        manager.set_procfiles(sources);
+        manager.add_process("frontend/web".to_string(), "npm run dev".to_string(), Some(frontend.clone()), None, None);
+        manager.add_process("backend/web".to_string(), "rails server".to_string(), Some(backend.clone()), None, None);
+
+        std::fs::write(backend.join("Procfile"), "web: rails server\nworker: sidekiq\n").unwrap();
+        let result = manager.reload_procfile(&test_config()).unwrap();
+
+        assert_eq!(result.added, vec!["backend/worker"]);
+        assert!(result.removed.is_empty());
+        assert_eq!(manager.processes["backend/worker"].working_dir, Some(backend));
+    }
+
     #[test]
     fn test_reload_procfile_removes_process() {
         let (mut manager, tmp) = create_manager_with_procfile("web: rails server\nworker: sidekiq\n");
@@ -1773,6 +3763,43 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_reload_procfile_keeps_runtime_processes() {
+        let (mut manager, _tmp) = create_manager_with_procfile("web: rails server\n");
+        let config = test_config();
+
+        manager.add_process("web".to_string(), "rails server".to_string(), None, None, None);
+        manager.add_runtime_process("tailer", "tail -f log", None, HashMap::new()).unwrap();
+
+        let result = manager.reload_procfile(&config).unwrap();
+        assert!(result.removed.is_empty());
+        assert_eq!(manager.get_status("tailer"), Some(ProcessStatus::Stopped));
+    }
+
+    #[test]
+    fn test_add_runtime_process_rejects_duplicates() {
+        let (mut manager, _tmp) = create_manager_with_procfile("web: rails server\n");
+        manager.add_process("web".to_string(), "rails server".to_string(), None, None, None);
+
+        let err = manager.add_runtime_process("web", "echo hi", None, HashMap::new()).unwrap_err();
+        assert_eq!(err.to_string(), "Process 'web' already exists");
+        assert!(!manager.runtime_processes.contains("web"));
+    }
+
+    #[test]
+    fn test_add_runtime_process_resolves_cwd_and_env() {
+        let (mut manager, tmp) = create_manager_with_procfile("web: rails server\n");
+        let dir = tmp.path().parent().unwrap().to_path_buf();
+        let env = HashMap::from([("DEBUG".to_string(), "1".to_string())]);
+
+        manager.add_runtime_process("debug", "./debug.sh", Some(PathBuf::from("scripts")), env.clone()).unwrap();
+
+        let handle = manager.get_processes().get("debug").unwrap();
+        assert_eq!(handle.working_dir, Some(dir.join("scripts")));
+        assert_eq!(handle.env, env);
+        assert!(manager.runtime_processes.contains("debug"));
+    }
+
     #[test]
     fn test_reload_procfile_no_changes() {
         let (mut manager, _tmp) = create_manager_with_procfile("web: rails server\n");
@@ -1833,4 +3860,109 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("No Procfile path configured"));
     }
+
+    #[tokio::test]
+    async fn test_kill_running_then_start_stopped() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("a".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.add_process("b".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.add_process("idle".to_string(), "sleep 10".to_string(), None, None, None);
+        manager.start_process("a").await.unwrap();
+        manager.start_process("b").await.unwrap();
+
+        let killed = manager.kill_running().await;
+        assert_eq!(killed.succeeded, vec!["a", "b"]);
+        assert!(killed.failed.is_empty());
+        while !manager.check_termination_status().await {
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+        assert!(manager.all_exited());
+
+        let started = manager.start_stopped().await;
+        assert_eq!(started.succeeded, vec!["a", "b", "idle"]);
+        assert_eq!(manager.get_status("idle"), Some(ProcessStatus::Running));
+
+        manager.kill_all().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_kill_running_includes_soft_failed_live_processes() {
+        use crate::config::{StatusConfig, StatusTransition};
+
+        let config = StatusConfig {
+            default: None,
+            color: None,
+            ready: None,
+            transitions: vec![StatusTransition {
+                pattern: "FATAL".to_string(),
+                label: "Dead".to_string(),
+                color: None,
+                marks_failed: true,
+            }],
+        };
+        let mut manager = ProcessManager::new();
+        manager.add_process("worker".to_string(), "sleep 10".to_string(), None, Some(&config), None);
+        manager.start_process("worker").await.unwrap();
+        manager.process_single_log(LogLine::new(LogSource::ProcessStdout("worker".to_string()), "FATAL: boom".to_string()));
+        assert!(matches!(manager.get_status("worker"), Some(ProcessStatus::Failed(_))));
+
+        // Still alive, so start_all leaves it alone and kill_all stops it
+        assert!(manager.start_stopped().await.succeeded.is_empty());
+        assert_eq!(manager.kill_running().await.succeeded, vec!["worker"]);
+        while !manager.check_termination_status().await {
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+    }
+
+    #[test]
+    fn test_bulk_outcome_summary() {
+        let outcome = BulkOutcome {
+            succeeded: vec!["web".to_string()],
+            failed: vec![("worker".to_string(), "boom".to_string())],
+        };
+        assert_eq!(outcome.summary("Started"), "Started: web; failed: worker (boom)");
+        assert_eq!(BulkOutcome::default().summary("Stopped"), "Stopped: nothing to do");
+        assert_eq!(
+            outcome.to_json(),
+            serde_json::json!({"names": ["web"], "failed": [{"name": "worker", "error": "boom"}]})
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_transitions_enqueue_hooks() {
+        let mut manager = ProcessManager::new();
+        manager.add_process("web".to_string(), "sleep 0.2; exit 3".to_string(), None, None, None);
+        manager.set_hooks("web", ProcessHooks {
+            on_start: Some("echo started".to_string()),
+            on_exit: None,
+            on_failure: Some("notify-send crashed".to_string()),
+        });
+
+        manager.start_process("web").await.unwrap();
+        manager.check_all_status().await;
+        assert_eq!(manager.pending_hooks().len(), 1);
+        assert_eq!(manager.pending_hooks()[0].event, HookEvent::Start);
+        assert_eq!(manager.pending_hooks()[0].command, "echo started");
+
+        while manager.get_status("web") == Some(ProcessStatus::Running) {
+            tokio::time::sleep(tokio::time::Duration::from_millis(20)).await;
+            manager.check_all_status().await;
+        }
+
+        // No on_exit configured, so only the failure hook is queued
+        let hooks = manager.pending_hooks();
+        assert_eq!(hooks.len(), 2);
+        let failure = &hooks[1];
+        assert_eq!(failure.event, HookEvent::Failure);
+        assert_eq!(failure.command, "notify-send crashed");
+        assert_eq!(failure.env, vec![
+            ("OIT_PROCESS".to_string(), "web".to_string()),
+            ("OIT_STATUS".to_string(), "failed".to_string()),
+            ("OIT_EXIT_CODE".to_string(), "3".to_string()),
+        ]);
+
+        // Further checks don't re-enqueue
+        manager.check_all_status().await;
+        assert_eq!(manager.pending_hooks().len(), 2);
+    }
 }
@@ -1,21 +1,151 @@
 use anyhow::{Context, Result};
+use serde::Deserialize;
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
 /// Represents a parsed Procfile containing process definitions
 #[derive(Debug, Clone)]
 pub struct Procfile {
     /// Map of process names to their commands
     pub processes: HashMap<String, String>,
+    /// Per-process options (env, cwd, dependencies). Classic Procfiles
+    /// produce default options for every process.
+    pub options: HashMap<String, ProcessOptions>,
+}
+
+/// Extra process settings only expressible in the YAML format
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ProcessOptions {
+    /// Extra environment variables for the process
+    #[serde(deserialize_with = "deserialize_env")]
+    pub env: HashMap<String, String>,
+    /// Working directory, relative to the Procfile's directory
+    pub cwd: Option<PathBuf>,
+    /// Processes this one depends on. A `oneshot` dependency holds this
+    /// process back until it has exited successfully.
+    pub depends_on: Vec<String>,
+    /// `service` (default) runs until stopped; `oneshot` runs to completion
+    #[serde(rename = "type")]
+    pub process_type: ProcessType,
+}
+
+/// How a process's clean exit is treated
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessType {
+    /// Long-running; a clean exit leaves it stopped
+    #[default]
+    Service,
+    /// A setup task (migrations, asset builds); a clean exit marks it done
+    Oneshot,
+}
+
+/// Env values as written in YAML: `PORT: 3000` and `DEBUG: true` are kept as
+/// their text, like the quoted forms
+fn deserialize_env<'de, D>(deserializer: D) -> Result<HashMap<String, String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+    use serde_norway::Value;
+
+    HashMap::<String, Value>::deserialize(deserializer)?
+        .into_iter()
+        .map(|(key, value)| match value {
+            Value::String(s) => Ok((key, s)),
+            Value::Number(n) => Ok((key, n.to_string())),
+            Value::Bool(b) => Ok((key, b.to_string())),
+            _ => Err(D::Error::custom(format!("env {} must be a string, number or boolean", key))),
+        })
+        .collect()
+}
+
+/// A YAML service written as a full entry rather than a bare command string
+#[derive(Debug, Deserialize)]
+struct YamlEntry {
+    command: String,
+    #[serde(flatten)]
+    options: ProcessOptions,
+}
+
+/// Top-level YAML document, with or without a `services:` wrapper. Services
+/// are parsed one by one so an error can name the service.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum YamlProcfile {
+    Wrapped { services: HashMap<String, serde_norway::Value> },
+    Bare(HashMap<String, serde_norway::Value>),
 }
 
 impl Procfile {
+    /// Load a Procfile. `-` reads the classic format from stdin, and
+    /// `.yml`/`.yaml` files are parsed as YAML.
     pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self> {
-        let content = fs::read_to_string(&path)
-            .with_context(|| format!("Failed to read Procfile at {:?}", path.as_ref()))?;
+        let path = path.as_ref();
+
+        if path == Path::new("-") {
+            let mut content = String::new();
+            std::io::stdin()
+                .read_to_string(&mut content)
+                .context("Failed to read Procfile from stdin")?;
+            return Self::from_string(&content);
+        }
 
-        Self::from_string(&content)
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read Procfile at {:?}", path))?;
+
+        if is_yaml_path(path) {
+            Self::from_yaml(&content)
+        } else {
+            Self::from_string(&content)
+        }
+    }
+
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        let parsed: YamlProcfile =
+            serde_norway::from_str(content).context("Invalid YAML Procfile")?;
+        let services = match parsed {
+            YamlProcfile::Wrapped { services } => services,
+            YamlProcfile::Bare(services) => services,
+        };
+
+        let mut processes = HashMap::new();
+        let mut options = HashMap::new();
+
+        for (name, service) in services {
+            let (command, opts) = match service {
+                serde_norway::Value::String(command) => (command, ProcessOptions::default()),
+                entry => {
+                    let entry: YamlEntry = serde_norway::from_value(entry)
+                        .with_context(|| format!("Invalid definition for process '{}'", name))?;
+                    (entry.command, entry.options)
+                }
+            };
+
+            if command.trim().is_empty() {
+                anyhow::bail!("Empty command for process '{}'", name);
+            }
+
+            processes.insert(name.clone(), command.trim().to_string());
+            options.insert(name, opts);
+        }
+
+        if processes.is_empty() {
+            anyhow::bail!("Procfile contains no process definitions");
+        }
+
+        for (name, opts) in &options {
+            for dep in &opts.depends_on {
+                if !processes.contains_key(dep) {
+                    anyhow::bail!("Process '{}' depends on unknown process '{}'", name, dep);
+                }
+            }
+        }
+
+        Ok(Procfile { processes, options })
     }
 
     pub fn from_string(content: &str) -> Result<Self> {
@@ -59,7 +189,12 @@ impl Procfile {
             anyhow::bail!("Procfile contains no process definitions");
         }
 
-        Ok(Procfile { processes })
+        let options = processes
+            .keys()
+            .map(|name| (name.clone(), ProcessOptions::default()))
+            .collect();
+
+        Ok(Procfile { processes, options })
     }
 
     pub fn process_names(&self) -> Vec<&str> {
@@ -68,10 +203,158 @@ impl Procfile {
         names
     }
 
+    /// Options for a process (defaults if the process has none)
+    pub fn options_for(&self, name: &str) -> ProcessOptions {
+        self.options.get(name).cloned().unwrap_or_default()
+    }
+
     #[allow(dead_code)]
     pub fn get_command(&self, name: &str) -> Option<&str> {
         self.processes.get(name).map(|s| s.as_str())
     }
+
+    /// Prefix every process name (and dependency) with `<namespace>/`
+    pub fn namespaced(self, namespace: &str) -> Self {
+        let prefix = |name: &str| format!("{}/{}", namespace, name);
+        Procfile {
+            processes: self.processes.into_iter().map(|(name, cmd)| (prefix(&name), cmd)).collect(),
+            options: self
+                .options
+                .into_iter()
+                .map(|(name, mut options)| {
+                    options.depends_on = options.depends_on.iter().map(|d| prefix(d)).collect();
+                    (prefix(&name), options)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A Procfile to load and the directory its processes run in. When several
+/// Procfiles are loaded, each gets a namespace and its processes are named
+/// `<namespace>/<name>`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProcfileSource {
+    pub path: PathBuf,
+    pub dir: PathBuf,
+    pub namespace: Option<String>,
+}
+
+/// One process definition and the Procfile it came from
+#[derive(Debug, Clone)]
+pub struct ProcfileEntry {
+    pub command: String,
+    pub options: ProcessOptions,
+    /// Directory of the Procfile (working directory for the process)
+    pub dir: PathBuf,
+    pub namespace: Option<String>,
+}
+
+impl ProcfileSource {
+    /// A single Procfile whose processes keep their own names
+    pub fn new(path: PathBuf) -> Self {
+        // "Procfile" (no directory) has an empty parent: use the current directory
+        let dir = path
+            .parent()
+            .filter(|p| !p.as_os_str().is_empty())
+            .map(|p| p.to_path_buf())
+            .unwrap_or_else(|| std::env::current_dir().unwrap_or_else(|_| PathBuf::from(".")));
+        Self { path, dir, namespace: None }
+    }
+
+    /// Sources for the given Procfiles. With more than one, each is namespaced
+    /// by the name of its directory, which must be unique.
+    pub fn for_paths(paths: &[PathBuf]) -> Result<Vec<Self>> {
+        if let [path] = paths {
+            return Ok(vec![Self::new(path.clone())]);
+        }
+
+        let mut sources: Vec<Self> = Vec::new();
+        for path in paths {
+            if path.as_path() == Path::new("-") {
+                anyhow::bail!("Cannot read a Procfile from stdin when loading several Procfiles");
+            }
+            let mut source = Self::new(path.clone());
+            let dir = source.dir.canonicalize().unwrap_or_else(|_| source.dir.clone());
+            let namespace = dir
+                .file_name()
+                .map(|n| n.to_string_lossy().into_owned())
+                .ok_or_else(|| anyhow::anyhow!("Cannot derive a namespace for Procfile '{}'", path.display()))?;
+            if let Some(other) = sources.iter().find(|s| s.namespace.as_deref() == Some(namespace.as_str())) {
+                anyhow::bail!(
+                    "Procfiles '{}' and '{}' are both in a directory named '{}'; namespaces must be unique",
+                    other.path.display(),
+                    path.display(),
+                    namespace
+                );
+            }
+            source.namespace = Some(namespace);
+            sources.push(source);
+        }
+        Ok(sources)
+    }
+
+    /// Parse the Procfile, applying the namespace to process names
+    pub fn load(&self) -> Result<Procfile> {
+        let procfile = Procfile::from_file(&self.path)?;
+        Ok(match &self.namespace {
+            Some(namespace) => procfile.namespaced(namespace),
+            None => procfile,
+        })
+    }
+}
+
+/// Run processes and resolve log files from `dir` instead of each Procfile's
+/// directory (`--working-dir` / `working_dir`)
+pub fn override_working_dir(sources: &mut [ProcfileSource], dir: &Path) -> Result<()> {
+    if !dir.is_dir() {
+        anyhow::bail!("Working directory '{}' does not exist", dir.display());
+    }
+    for source in sources {
+        source.dir = dir.to_path_buf();
+    }
+    Ok(())
+}
+
+/// Load every source into one map of process name to definition
+pub fn load_entries(sources: &[ProcfileSource]) -> Result<HashMap<String, ProcfileEntry>> {
+    let mut entries = HashMap::new();
+    for source in sources {
+        let procfile = source.load()?;
+        for (name, command) in &procfile.processes {
+            entries.insert(
+                name.clone(),
+                ProcfileEntry {
+                    command: command.clone(),
+                    options: procfile.options_for(name),
+                    dir: source.dir.clone(),
+                    namespace: source.namespace.clone(),
+                },
+            );
+        }
+    }
+    Ok(entries)
+}
+
+/// One group per namespace, holding that Procfile's processes (sorted)
+pub fn namespace_groups(entries: &HashMap<String, ProcfileEntry>) -> HashMap<String, Vec<String>> {
+    let mut groups: HashMap<String, Vec<String>> = HashMap::new();
+    for (name, entry) in entries {
+        if let Some(namespace) = &entry.namespace {
+            groups.entry(namespace.clone()).or_default().push(name.clone());
+        }
+    }
+    for members in groups.values_mut() {
+        members.sort();
+    }
+    groups
+}
+
+fn is_yaml_path(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|e| e.to_str()),
+        Some("yml") | Some("yaml")
+    )
 }
 
 #[cfg(test)]
@@ -137,4 +420,165 @@ middle: command 3
         let procfile = Procfile::from_string(content).unwrap();
         assert_eq!(procfile.process_names(), vec!["alpha", "middle", "zebra"]);
     }
+
+    #[test]
+    fn test_parse_yaml_services() {
+        let content = r#"
+services:
+  web:
+    command: bundle exec rails server -p 3000
+    cwd: backend
+    env:
+      PORT: "3000"
+      RAILS_ENV: development
+  worker:
+    command: bundle exec sidekiq
+    depends_on: [web]
+"#;
+        let procfile = Procfile::from_yaml(content).unwrap();
+        assert_eq!(procfile.process_names(), vec!["web", "worker"]);
+        assert_eq!(
+            procfile.get_command("web"),
+            Some("bundle exec rails server -p 3000")
+        );
+
+        let web = procfile.options_for("web");
+        assert_eq!(web.cwd, Some(PathBuf::from("backend")));
+        assert_eq!(web.env.get("PORT").map(|s| s.as_str()), Some("3000"));
+        assert_eq!(web.env.get("RAILS_ENV").map(|s| s.as_str()), Some("development"));
+
+        let worker = procfile.options_for("worker");
+        assert!(worker.env.is_empty());
+        assert_eq!(worker.cwd, None);
+        assert_eq!(worker.depends_on, vec!["web".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_yaml_without_services_key() {
+        let content = "web: rails server\nworker:\n  command: sidekiq\n";
+        let procfile = Procfile::from_yaml(content).unwrap();
+        assert_eq!(procfile.get_command("web"), Some("rails server"));
+        assert_eq!(procfile.get_command("worker"), Some("sidekiq"));
+    }
+
+    #[test]
+    fn test_parse_yaml_oneshot_type() {
+        let content = "migrate:\n  command: rake db:migrate\n  type: oneshot\nweb: rails server\n";
+        let procfile = Procfile::from_yaml(content).unwrap();
+        assert_eq!(procfile.options_for("migrate").process_type, ProcessType::Oneshot);
+        assert_eq!(procfile.options_for("web").process_type, ProcessType::Service);
+        assert!(Procfile::from_yaml("web:\n  command: x\n  type: cron\n").is_err());
+    }
+
+    #[test]
+    fn test_parse_yaml_scalar_env_values() {
+        let content = "web:\n  command: rails server\n  env:\n    PORT: 3000\n    DEBUG: true\n    RATIO: 1.5\n";
+        let env = Procfile::from_yaml(content).unwrap().options_for("web").env;
+        assert_eq!(env.get("PORT").map(|s| s.as_str()), Some("3000"));
+        assert_eq!(env.get("DEBUG").map(|s| s.as_str()), Some("true"));
+        assert_eq!(env.get("RATIO").map(|s| s.as_str()), Some("1.5"));
+
+        let err = Procfile::from_yaml("web:\n  command: x\n  env:\n    LIST: [1, 2]\n").unwrap_err();
+        let message = format!("{:#}", err);
+        assert!(message.contains("'web'"), "{}", message);
+        assert!(message.contains("LIST"), "{}", message);
+    }
+
+    #[test]
+    fn test_yaml_unknown_dependency_fails() {
+        let content = "web:\n  command: rails server\n  depends_on: [db]\n";
+        assert!(Procfile::from_yaml(content).is_err());
+    }
+
+    #[test]
+    fn test_from_file_selects_yaml_by_extension() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("procs.yml");
+        fs::write(&path, "web:\n  command: rails server\n  env:\n    PORT: \"3000\"\n").unwrap();
+
+        let procfile = Procfile::from_file(&path).unwrap();
+        assert_eq!(procfile.get_command("web"), Some("rails server"));
+        assert_eq!(procfile.options_for("web").env.get("PORT").map(|s| s.as_str()), Some("3000"));
+    }
+
+    #[test]
+    fn test_classic_procfile_has_default_options() {
+        let procfile = Procfile::from_string("web: rails server").unwrap();
+        assert_eq!(procfile.options_for("web"), ProcessOptions::default());
+    }
+
+    #[test]
+    fn test_two_procfiles_are_namespaced_by_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let frontend = root.path().join("frontend");
+        let backend = root.path().join("backend");
+        std::fs::create_dir_all(&frontend).unwrap();
+        std::fs::create_dir_all(&backend).unwrap();
+        std::fs::write(frontend.join("Procfile"), "web: npm run dev\n").unwrap();
+        std::fs::write(backend.join("Procfile"), "web: rails server\nworker: sidekiq\n").unwrap();
+
+        let sources =
+            ProcfileSource::for_paths(&[frontend.join("Procfile"), backend.join("Procfile")]).unwrap();
+        let entries = load_entries(&sources).unwrap();
+
+        let mut names: Vec<&str> = entries.keys().map(|s| s.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["backend/web", "backend/worker", "frontend/web"]);
+        assert_eq!(entries["frontend/web"].command, "npm run dev");
+        assert_eq!(entries["frontend/web"].dir, frontend);
+        assert_eq!(entries["backend/worker"].dir, backend);
+
+        let groups = namespace_groups(&entries);
+        assert_eq!(groups["backend"], vec!["backend/web", "backend/worker"]);
+        assert_eq!(groups["frontend"], vec!["frontend/web"]);
+    }
+
+    #[test]
+    fn test_working_dir_override_replaces_the_procfile_directory() {
+        let root = tempfile::tempdir().unwrap();
+        let config_dir = root.path().join("config");
+        std::fs::create_dir_all(&config_dir).unwrap();
+        std::fs::write(config_dir.join("Procfile"), "web: rails server\n").unwrap();
+
+        let mut sources = ProcfileSource::for_paths(&[config_dir.join("Procfile")]).unwrap();
+        assert_eq!(load_entries(&sources).unwrap()["web"].dir, config_dir);
+
+        override_working_dir(&mut sources, root.path()).unwrap();
+        assert_eq!(load_entries(&sources).unwrap()["web"].dir, root.path());
+
+        let err = override_working_dir(&mut sources, &root.path().join("missing")).unwrap_err();
+        assert!(err.to_string().contains("does not exist"));
+    }
+
+    #[test]
+    fn test_single_procfile_is_not_namespaced() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("Procfile");
+        std::fs::write(&path, "web: rails server\n").unwrap();
+
+        let sources = ProcfileSource::for_paths(std::slice::from_ref(&path)).unwrap();
+        assert_eq!(sources[0].namespace, None);
+        let entries = load_entries(&sources).unwrap();
+        assert!(entries.contains_key("web"));
+        assert!(namespace_groups(&entries).is_empty());
+    }
+
+    #[test]
+    fn test_procfiles_in_same_directory_name_are_rejected() {
+        let root = tempfile::tempdir().unwrap();
+        let a = root.path().join("a/app");
+        let b = root.path().join("b/app");
+        std::fs::create_dir_all(&a).unwrap();
+        std::fs::create_dir_all(&b).unwrap();
+
+        let err = ProcfileSource::for_paths(&[a.join("Procfile"), b.join("Procfile")]).unwrap_err();
+        assert!(err.to_string().contains("namespaces must be unique"));
+    }
+
+    #[test]
+    fn test_namespaced_renames_dependencies() {
+        let yaml = "services:\n  web:\n    command: rails s\n    depends_on: [db]\n  db: postgres\n";
+        let procfile = Procfile::from_yaml(yaml).unwrap().namespaced("api");
+        assert_eq!(procfile.options_for("api/web").depends_on, vec!["api/db"]);
+    }
 }
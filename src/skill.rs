@@ -68,13 +68,27 @@ All commands require the oit TUI to be running in another terminal.
 ## Status & Info
 
 ### `oit ping`
-Check if TUI is running. Returns "pong" if connected.
+Check if TUI is running. Returns "pong" if connected, plus `uptime_secs`, `server_time` (RFC 3339) and `tick` (main-loop iteration count). A `tick` that stops advancing between pings means the TUI is wedged.
 
 ### `oit status`
 Get TUI status including frozen state, process count, log count.
 
-### `oit processes`
-List all processes with their status (running/stopped/failed).
+### `oit info`
+Where the running instance lives: `version`, `working_dir`, absolute `config_path`, `procfiles` and `socket_path`. Use it to find the files behind the processes you are looking at.
+
+### `oit processes [--status <status> | --failed]`
+List all processes with their status (running/stopped/failed/done) and assigned display `color`. Failed processes include `error`; launch failures also set `error_kind` (`command_not_found`, `permission_denied`, `working_dir_not_found`).
+- `--status` - Only processes in that status: `running`, `stopped`, `failed`, `done`, `restarting`, `terminating`, or `unhealthy` (failed or stopped). An empty list means none match, so polling `--failed` is a cheap health check
+- `--failed` - Same as `--status failed`
+
+### `oit describe <name>`
+Everything about one process in a single call: status with `exit_code`/`exit_signal`, the resolved `command`, `cwd`, `env` overrides, `recent_lines` (its share of the last 1000 lines), `last_error`, `dropped_lines` (output lost while its ingest queue was full), custom label/color, and whether it is `hidden`. Errors for unknown names.
+
+### `oit last-output <name>`
+The last 30 lines a process emitted before its most recent failure, captured when it failed (so they survive other processes' output and buffer eviction), plus the failure `error` and `failed_at`. Reach for this first when a process shows as failed. `--strip-ansi` removes color codes. Errors if the process hasn't failed.
+
+### `oit colors`
+Get the display color assigned to each process and log file (empty when coloring is off).
 
 ### `oit commands`
 List all available IPC commands.
@@ -83,21 +97,52 @@ List all available IPC commands.
 
 **Important:** `logs`, `search`, and `errors` are separate commands. Do not combine their options.
 
-### `oit logs [--limit N] [--offset N]`
-Get recent log lines (no filtering). Each line includes an ID for reference.
+### `oit logs [--limit N] [--offset N] [--strip-ansi] [--stream stdout|stderr|both] [--with-batches]`
+Get recent log lines (no filtering). Each line includes an ID for reference. Lines from managed processes also carry `run`, which start of the process produced them (1 = first run, incremented on every restart). `source_type` is `stdout`, `stderr`, `file` (a process's `log_file`), `standalone` (a `[[log_files]]` entry) or `annotation` (a note oit added, such as `trace-annotate`); file-sourced lines also carry `source_path`.
 - `--limit N` - Number of lines (default 100)
 - `--offset N` - Skip first N lines
+- `--strip-ansi` - Remove ANSI color codes from the content
+- `--stream stderr` - Only stderr lines (`stdout` drops them; default `both`)
+- `--with-batches` - Fill in `batch_id` (the ID of the batch's first returned line), detected among the returned lines only; pass it to `oit batch`
 
 Example: `oit logs --limit 50`
 
-### `oit search <pattern> [--limit N] [--case-sensitive]`
+### `oit process-logs <name> [--limit N] [--strip-ansi]`
+The newest lines from one process or log file only, oldest first (default 100). Unlike `logs`, this searches the whole buffer, so a quiet service's lines aren't crowded out by chatty ones. Returns `logs` and `count`; errors for an unknown name.
+
+Example: `oit process-logs worker --limit 20`
+
+### `oit recent <secs> [--limit N] [--strip-ansi]`
+"What happened in the last N seconds": lines oit received in that window (by arrival time, not the line's own timestamp), oldest first, keeping the newest `--limit` (default 100). Returns `logs`, `count` and `since`, and scrolls the TUI to the start of the window.
+
+Example: `oit recent 30`
+
+### `oit histogram [--bucket-secs N] [--buckets N] [--per-process]`
+Log volume over time: how many lines arrived in each of the last `--buckets` (default 30, at most 360) windows of `--bucket-secs` (default 10, at most 86400), oldest first, as `buckets: [{start, count}]` plus `total` and `since`. Counted over the newest 1000 lines by arrival time, so a burst that pushed older lines out shows up as empty early buckets. `--per-process` adds `per_process: {name: [counts]}` over the same buckets. Useful for spotting a log flood or a process that went quiet.
+
+Example: `oit histogram --bucket-secs 60 --buckets 10`
+
+### `oit copy-visible [--clipboard]`
+Get what the user is looking at: every line in the TUI's current view (include/exclude filters, errors-only, hidden processes, search and batch focus applied) as ANSI-free `[time] process: message` text, plus `line_count`. Views over 50,000 lines return an error instead.
+- `--clipboard` - Also copy the text to the clipboard on the machine running the TUI
+
+### `oit clipboard-copy <text> | --id N`
+Put something on the clipboard of the machine running the TUI for the user to paste: either `<text>` or the content of log line `N` (ANSI codes removed). Returns `bytes` and `copied: true`; errors if the line isn't among the newest 1000, the text is over 1 MiB, or the clipboard can't be reached (common on headless machines).
+
+Example: `oit clipboard-copy "bin/rails db:migrate:redo VERSION=20240101"`
+
+### `oit search <pattern> [--limit N] [--case-sensitive] [--strip-ansi]`
 Search logs for a text pattern. Also highlights matches in TUI.
 - `<pattern>` - Required text to search for
 - `--limit N` - Max results
 - `--case-sensitive` - Case sensitive matching
+- `--strip-ansi` - Match and return content without ANSI color codes
 
 Example: `oit search "error connecting"` or `oit search "timeout" --limit 20`
 
+### `oit search-next` / `oit search-prev`
+Move the TUI selection to the next/previous line matching the active search pattern (wraps around). Returns the match `index`, `total` and the line.
+
 ### `oit errors [--limit N] [--level L] [--process P]`
 Get error and warning logs (searches for error patterns automatically).
 - `--limit N` - Max results
@@ -106,10 +151,16 @@ Get error and warning logs (searches for error patterns automatically).
 
 Example: `oit errors --limit 10` or `oit errors --process web`
 
+### `oit tail-errors [--level L]`
+Stay connected and print each new error/warning line as it arrives, one JSON frame per line (`event`, `id`, `process`, `content`, `timestamp`, `level`). Runs until interrupted or the TUI exits.
+- `--level L` - error/warning/error_or_warning (default: error)
+
+Example: `oit tail-errors --level error_or_warning`
+
 ## Navigation
 
 ### `oit select <id>`
-Select and expand a specific log line by ID.
+Select and expand a specific log line by ID. Returns its `process` and `source_type`, plus `source_path` for lines read from a log file.
 
 ### `oit context <id> [--before N] [--after N]`
 Get log lines surrounding a specific line by ID.
@@ -121,20 +172,45 @@ Example: `oit context 12345 --before 10 --after 10`
 
 ### `oit goto <id>`
 Scroll TUI view to a specific log line.
+- `--ref web#4821` - Jump by a shared line reference instead of an ID
+
+### `oit goto-error [next|prev] [--from-id N]`
+Select the nearest error line after (`next`, default) or before (`prev`) the TUI's selected line, wrapping around; with nothing selected, `prev` finds the most recent error. Returns the line's `id` (use it with `oit context`). Errors if no error lines are in view.
+
+### `oit reference <id>`
+Get a shareable `<process>#<id>` reference for a log line (what the `Y` key copies).
 
 ### `oit scroll <direction> [--lines N]`
 Scroll the TUI view.
 - `direction` - up/down/top/bottom
 - `--lines N` - Number of lines for up/down
 
+### `oit scroll-percent <percent>`
+Scroll the TUI so the line `percent` (0-100) of the way through the filtered log is at the top; 0 is the oldest line, 100 the newest. Values above 100 are clamped. Clears the selection, turns auto-scroll off, and returns `offset` and `total` (lines in view).
+
 ### `oit freeze [mode]`
 Pause/resume log updates in TUI.
 - `mode` - on/off/toggle (default: toggle)
 
+### `oit errors-only [mode]`
+Show only error and warning lines in the TUI (combines with filters).
+- `mode` - on/off/toggle (default: toggle)
+
+### `oit set-view [--display-mode M] [--wrap BOOL] [--auto-scroll BOOL]`
+Set several view controls at once and print the resulting view mode.
+- `--display-mode` - compact/full/wrap
+- `--wrap` - true/false (false turns wrap back into full)
+- `--auto-scroll` - true/false
+
 ### `oit batch <id> [--scroll]`
-Get all log lines from the same batch as the given line.
+Get all log lines from the same batch as the given line. Takes a `batch_id` from `oit logs --with-batches`.
 - `--scroll` - Also scroll TUI to the batch
 
+### `oit pipeline <command>...`
+Run several commands in one round-trip against the same state. Each argument is a command name or a JSON object with `command` and `args`. Returns `{"responses": [...]}` in order.
+
+Example: `oit pipeline status processes '{"command": "errors", "args": {"limit": 10}}'`
+
 ## Filters
 
 ### `oit filters`
@@ -147,9 +223,18 @@ Add a filter. Persists to config.
 ### `oit filter-remove <pattern>`
 Remove a filter by pattern. Persists to config.
 
+### `oit filter-toggle <pattern>`
+Switch a filter off or back on without removing it. Disabled filters stay in `oit filters` with `enabled: false` and don't affect output. Returns the new `enabled` state; errors if no filter has that pattern. Persists to config.
+
 ### `oit filter-clear`
 Remove all filters. Persists to config.
 
+### `oit presets`
+List saved filter presets (`[presets.<name>]` in config) with their include/exclude patterns.
+
+### `oit preset-apply <name>`
+Replace all filters with a saved preset. Persists to config.
+
 ## Process Visibility
 
 ### `oit visibility`
@@ -163,15 +248,41 @@ Show logs from a hidden process (runtime only).
 
 ## Process Control
 
-### `oit restart [name]`
+### `oit restart [name] [--dry-run] [--wait-ready [--timeout-ms N]]`
 Restart a process, or all processes if no name given. Re-reads the Procfile on each restart to pick up command changes, new processes, and removed processes.
+- `--dry-run` - Return the processes that would be restarted (with their status) without restarting
+- `--wait-ready` - Don't respond until every restarted process is running, past its `quiet_until` line and at its `status.ready` label (when those are configured). Returns `ready` and `waited_ms`. If a process fails or exits first, or `--timeout-ms` (default 30000) passes, returns an error naming the process with its `last_output`
 
-### `oit kill <name>`
+### `oit kill <name> [--dry-run]`
 Kill (stop) a specific process.
+- `--dry-run` - Return the processes that would be killed without killing them
 
 ### `oit start <name>`
 Start a stopped process.
 
+### `oit kill-all` / `oit start-all`
+Stop every running process without quitting `oit`, then start everything that's stopped or failed again (e.g. to pause services around a deploy step). Returns `names` (the processes that were actually stopped or started), `failed` (`[{name, error}]`) and `skipped`.
+
+### `oit process-add [--cwd DIR] [--env KEY=VALUE]... <name> <command>...`
+Add and start an ad-hoc process that isn't in the Procfile (runtime only, not saved). It behaves like any other process for kill/restart/hide. Options must come before the name. Fails if the name is already taken.
+
+### `oit add-log-file <name> <path>`
+Start tailing a log file as a standalone source without restarting (runtime only). Relative paths resolve against the Procfile directory. The file may not exist yet; it is read from the top once created. Returns the resolved `path` and whether it `exists`. Fails if the name is already taken.
+
+### `oit set-buffer <mb>`
+Change the log buffer memory limit for the running session (not saved to config). Shrinking evicts the oldest lines right away. Returns `max_buffer_mb`, `previous_mb` and `evicted_lines`.
+
+## Traces
+
+### `oit trace-start [--label L]` / `oit trace-stop`
+Bracket a reproduction: start a manual trace recording (returns its `trace_id`, e.g. `rec-1`), trigger the behaviour, then stop. Stopping opens the captured time window in the TUI and returns `captured` and `message`. Starting while a recording is in progress is an error.
+
+### `oit trace-annotate <note>...`
+During a recording, add an `[oit] note: ...` line to the log so the step shows up inside the captured window.
+
+### `oit set-status [--type info|success|error] <message>...` / `oit clear-status`
+Leave a note in the TUI status bar for the person watching it (e.g. `oit set-status Agent investigating worker timeout`). It stays until replaced or cleared.
+
 ## AI-Optimized
 
 ### `oit summary`
@@ -183,6 +294,14 @@ Get comprehensive state summary including:
 - View state
 
 Best command to use first when investigating issues.
+
+### `oit snapshot [--recent-logs N]`
+Get everything the TUI knows in one JSON object: processes, per-process details, groups, filters, search pattern, view mode, buffer stats, hidden and pinned processes, selection and trace state. `recent_logs` holds the newest N lines (default: 20). Use it instead of stitching together `status`, `processes`, `filters` and `visibility`.
+
+## Setup
+
+### `oit doctor`
+Does not need the TUI. Loads the config and Procfile without starting anything and prints a checklist (`✓` pass, `!` warning, `✗` failure): patterns compile, names don't collide, each command's program is on PATH, log files are readable. Exits 1 if any check fails. Run it first when the TUI won't start.
 "#;
 
 /// Check if a directory looks like it might benefit from skill installation
@@ -325,15 +444,32 @@ mod tests {
 
     #[test]
     fn test_commands_md_has_key_commands() {
+        assert!(COMMANDS_MD.contains("oit filter-toggle"));
+        assert!(COMMANDS_MD.contains("oit add-log-file"));
+        assert!(COMMANDS_MD.contains("oit recent"));
+        assert!(COMMANDS_MD.contains("oit goto-error"));
+        assert!(COMMANDS_MD.contains("oit set-status"));
+        assert!(COMMANDS_MD.contains("oit doctor"));
+        assert!(COMMANDS_MD.contains("oit process-logs"));
+        assert!(COMMANDS_MD.contains("oit preset-apply"));
+        assert!(COMMANDS_MD.contains("oit copy-visible"));
         assert!(COMMANDS_MD.contains("oit ping"));
         assert!(COMMANDS_MD.contains("oit status"));
         assert!(COMMANDS_MD.contains("oit processes"));
         assert!(COMMANDS_MD.contains("oit logs"));
         assert!(COMMANDS_MD.contains("oit search"));
         assert!(COMMANDS_MD.contains("oit errors"));
+        assert!(COMMANDS_MD.contains("oit tail-errors"));
         assert!(COMMANDS_MD.contains("oit restart"));
         assert!(COMMANDS_MD.contains("oit kill"));
         assert!(COMMANDS_MD.contains("oit start"));
+        assert!(COMMANDS_MD.contains("oit kill-all"));
+        assert!(COMMANDS_MD.contains("oit process-add"));
+        assert!(COMMANDS_MD.contains("oit set-buffer"));
+        assert!(COMMANDS_MD.contains("oit snapshot"));
+        assert!(COMMANDS_MD.contains("oit trace-start"));
+        assert!(COMMANDS_MD.contains("oit reference"));
+        assert!(COMMANDS_MD.contains("oit pipeline"));
         assert!(COMMANDS_MD.contains("oit summary"));
     }
 
@@ -6,14 +6,17 @@ pub struct CompiledTransition {
     pub regex: Regex,
     pub label: String,
     pub color: Option<Color>,
+    pub marks_failed: bool,
 }
 
 pub struct StatusMatcher {
     default: Option<String>,
     default_color: Option<Color>,
+    ready: Option<String>,
     transitions: Vec<CompiledTransition>,
     current_label: Option<String>,
     current_color: Option<Color>,
+    current_failed: bool,
 }
 
 fn parse_color(s: &str) -> Option<Color> {
@@ -42,6 +45,7 @@ impl StatusMatcher {
                 regex,
                 label: t.label.clone(),
                 color,
+                marks_failed: t.marks_failed,
             });
         }
 
@@ -50,9 +54,11 @@ impl StatusMatcher {
         Ok(StatusMatcher {
             default: config.default.clone(),
             default_color,
+            ready: config.ready.clone(),
             transitions,
             current_label: None,
             current_color: None,
+            current_failed: false,
         })
     }
 
@@ -63,6 +69,7 @@ impl StatusMatcher {
                 let changed = self.current_label.as_ref() != Some(&t.label);
                 self.current_label = Some(t.label.clone());
                 self.current_color = t.color;
+                self.current_failed = t.marks_failed;
                 return changed;
             }
         }
@@ -76,10 +83,22 @@ impl StatusMatcher {
             .map(|l| (l.as_str(), self.current_color))
     }
 
+    /// Whether the most recent matching transition has `marks_failed` set.
+    pub fn is_failed(&self) -> bool {
+        self.current_failed
+    }
+
+    /// Whether the current label is the configured `ready` label (always true
+    /// when none is configured).
+    pub fn is_ready(&self) -> bool {
+        self.ready.as_ref().is_none_or(|ready| self.current_label.as_ref() == Some(ready))
+    }
+
     /// Reset to default (call when process restarts).
     pub fn reset(&mut self) {
         self.current_label = self.default.clone();
         self.current_color = self.default_color;
+        self.current_failed = false;
     }
 }
 
@@ -103,12 +122,14 @@ mod tests {
         StatusConfig {
             default: default.map(|s| s.to_string()),
             color: color.map(|s| s.to_string()),
+            ready: None,
             transitions: transitions
                 .into_iter()
                 .map(|(pattern, label, color)| StatusTransition {
                     pattern: pattern.to_string(),
                     label: label.to_string(),
                     color: color.map(|c| c.to_string()),
+                    marks_failed: false,
                 })
                 .collect(),
         }
@@ -187,6 +208,25 @@ mod tests {
         assert_eq!(status.1, Some(Color::Green));
     }
 
+    #[test]
+    fn test_marks_failed_tracks_latest_match() {
+        let mut config = make_config(None, vec![("FATAL", "Dead", Some("red")), ("Ready", "Ready", None)]);
+        config.transitions[0].marks_failed = true;
+
+        let mut matcher = StatusMatcher::new(&config).unwrap();
+        assert!(!matcher.is_failed());
+
+        matcher.check_line("FATAL: cannot bind");
+        assert!(matcher.is_failed());
+
+        matcher.check_line("Ready");
+        assert!(!matcher.is_failed());
+
+        matcher.check_line("FATAL again");
+        matcher.reset();
+        assert!(!matcher.is_failed());
+    }
+
     #[test]
     fn test_first_match_wins() {
         let config = make_config(
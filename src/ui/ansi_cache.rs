@@ -1,20 +1,40 @@
 use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span};
 use std::collections::HashMap;
+use unicode_width::UnicodeWidthStr;
 
 use crate::ui::display_state::TimestampMode;
-use crate::ui::utils::{parse_ansi_to_spans, truncate_spans};
+use crate::ui::utils::{parse_ansi_to_spans, skip_spans, truncate_spans};
+
+/// Marks a line scrolled horizontally past its start
+const SCROLL_LEFT: &str = "«";
+/// Marks a scrolled line that continues past the right edge
+const SCROLL_RIGHT: &str = "»";
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct AnsiCacheKey {
     log_id: u64,
     compact_mode: bool,
     timestamp_mode: TimestampMode,
+    /// Width process names are padded to (`align_messages`), 0 when off
+    name_width: usize,
+    /// Content spans come from the syntax highlighter (`highlight_syntax`)
+    highlighted: bool,
 }
 
 impl AnsiCacheKey {
     pub fn new(log_id: u64, compact_mode: bool, timestamp_mode: TimestampMode) -> Self {
-        Self { log_id, compact_mode, timestamp_mode }
+        Self { log_id, compact_mode, timestamp_mode, name_width: 0, highlighted: false }
+    }
+
+    pub fn with_name_width(mut self, name_width: usize) -> Self {
+        self.name_width = name_width;
+        self
+    }
+
+    pub fn with_highlight(mut self, highlighted: bool) -> Self {
+        self.highlighted = highlighted;
+        self
     }
 }
 
@@ -44,6 +64,15 @@ impl AnsiCache {
         &mut self,
         key: AnsiCacheKey,
         text: &str,
+    ) -> &CachedSpans {
+        self.get_or_insert_with(key, || parse_ansi_to_spans(text))
+    }
+
+    /// Cached spans for `key`, built by `build` on a miss
+    pub fn get_or_insert_with(
+        &mut self,
+        key: AnsiCacheKey,
+        build: impl FnOnce() -> Vec<(String, Style)>,
     ) -> &CachedSpans {
         if self.cache.contains_key(&key) {
             self.hits += 1;
@@ -60,8 +89,8 @@ impl AnsiCache {
             }
         }
 
-        // Parse and cache
-        let spans = parse_ansi_to_spans(text);
+        // Build and cache
+        let spans = build();
         self.cache.insert(key.clone(), CachedSpans { spans });
         self.cache.get(&key).unwrap()
     }
@@ -134,6 +163,41 @@ impl AnsiCache {
 
         Line::from(spans)
     }
+
+    /// Create a line scrolled `offset` columns to the right and fitted to
+    /// `max_width`, with `«` / `»` marking content cut off on either side.
+    pub fn to_scrolled_line(
+        cached: &CachedSpans,
+        offset: usize,
+        max_width: usize,
+        bg_color: Option<Color>,
+        fg_override: Option<Color>,
+        indicator_style: Style,
+    ) -> Line<'static> {
+        let visible = skip_spans(&cached.spans, offset);
+        let visible_width: usize = visible.iter().map(|(content, _)| content.width()).sum();
+        let cut_right = visible_width > max_width.saturating_sub(SCROLL_LEFT.width());
+        let content_width = max_width
+            .saturating_sub(SCROLL_LEFT.width())
+            .saturating_sub(if cut_right { SCROLL_RIGHT.width() } else { 0 });
+
+        let indicator_style = bg_color.map_or(indicator_style, |bg| indicator_style.bg(bg));
+        let mut spans = vec![Span::styled(SCROLL_LEFT, indicator_style)];
+        spans.extend(truncate_spans(&visible, content_width).into_iter().map(|(content, style)| {
+            let mut new_style = style;
+            if let Some(bg) = bg_color {
+                new_style = new_style.bg(bg);
+            }
+            if let Some(fg) = fg_override {
+                new_style = new_style.fg(fg);
+            }
+            Span::styled(content, new_style)
+        }));
+        if cut_right {
+            spans.push(Span::styled(SCROLL_RIGHT, indicator_style));
+        }
+        Line::from(spans)
+    }
 }
 
 #[cfg(test)]
@@ -6,9 +6,12 @@ use super::display_state::DisplayState;
 use super::filter_state::FilterState;
 use super::input_state::InputState;
 use super::navigation_state::NavigationState;
+use super::palette_state::PaletteState;
+use super::process_picker_state::ProcessPickerState;
 use super::process_colors::ProcessColors;
 use super::render_cache::RenderCache;
 use super::theme::Theme;
+use super::types::PendingConfirmation;
 use super::trace_state::TraceState;
 
 /// Display mode for log lines
@@ -41,6 +44,16 @@ impl DisplayMode {
             DisplayMode::Wrap => "wrap",
         }
     }
+
+    /// Parse a mode from its name (inverse of `name`)
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "compact" => Some(DisplayMode::Compact),
+            "full" => Some(DisplayMode::Full),
+            "wrap" => Some(DisplayMode::Wrap),
+            _ => None,
+        }
+    }
 }
 
 /// Application state for the TUI
@@ -70,6 +83,14 @@ pub struct App {
     pub should_quit: bool,
     /// Whether we're in the process of shutting down
     pub shutting_down: bool,
+    /// Destructive action waiting for `y` confirmation
+    pub pending_confirm: Option<PendingConfirmation>,
+    /// `Ctrl+P` command palette
+    pub palette: PaletteState,
+    /// `m` process picker for restart/kill/start
+    pub process_picker: ProcessPickerState,
+    /// Process whose final output the `:lasterror` overlay shows
+    pub last_output: Option<String>,
 }
 
 impl App {
@@ -89,9 +110,24 @@ impl App {
             theme,
             should_quit: false,
             shutting_down: false,
+            pending_confirm: None,
+            palette: PaletteState::new(),
+            process_picker: ProcessPickerState::new(),
+            last_output: None,
         }
     }
 
+    /// Whether an overlay or the process tree hides the log view
+    pub fn logs_covered(&self) -> bool {
+        self.display.show_help
+            || self.display.expanded_line_view
+            || self.display.is_process_tree()
+            || self.trace.trace_selection_mode
+            || self.last_output.is_some()
+            || self.process_picker.open
+            || self.palette.open
+    }
+
     /// Replace the active theme. Callers must follow up with `init_process_colors`
     /// for the new palette to take effect on existing processes.
     pub fn set_theme(&mut self, theme: Theme) {
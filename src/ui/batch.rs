@@ -1,8 +1,47 @@
+use chrono::{DateTime, Local};
+
 use crate::log::LogLine;
 
+/// How log lines are split into batches (`batch_strategy` config, `:bs`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum BatchStrategy {
+    /// A batch ends when a line arrives more than the window after the batch start
+    #[default]
+    TimeWindow,
+    /// Time window, and a batch also ends whenever the process changes
+    Hybrid,
+}
+
+impl BatchStrategy {
+    pub const ALL: [BatchStrategy; 2] = [Self::TimeWindow, Self::Hybrid];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::TimeWindow => "time_window",
+            Self::Hybrid => "hybrid",
+        }
+    }
+
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|s| s.name() == name)
+    }
+}
+
 /// Detect batches from a slice of LogLine references
 /// Returns a vector of (start_index, end_index) tuples for each batch
-pub fn detect_batches_from_logs(logs: &[&LogLine], window_ms: i64) -> Vec<(usize, usize)> {
+pub fn detect_batches_from_logs(logs: &[&LogLine], window_ms: i64, strategy: BatchStrategy) -> Vec<(usize, usize)> {
+    detect_batches_by(logs, window_ms, strategy, |log| log.source.process_name(), |log| log.arrival_time)
+}
+
+/// Detect batches over any sequence of lines, given each line's process name
+/// and arrival time (IPC snapshots carry lines as `LogLineInfo`)
+pub fn detect_batches_by<T>(
+    logs: &[T],
+    window_ms: i64,
+    strategy: BatchStrategy,
+    process: impl Fn(&T) -> &str,
+    arrival: impl Fn(&T) -> DateTime<Local>,
+) -> Vec<(usize, usize)> {
     if logs.is_empty() {
         return vec![];
     }
@@ -11,14 +50,23 @@ pub fn detect_batches_from_logs(logs: &[&LogLine], window_ms: i64) -> Vec<(usize
         return vec![(0, 0)];
     }
 
+    let exceeds = |from: DateTime<Local>, to: DateTime<Local>| (to - from).num_milliseconds() > window_ms;
+
     let mut batches = Vec::new();
     let mut batch_start = 0;
 
     for i in 1..logs.len() {
-        // Compare to the start of the current batch, not the previous log
-        // This prevents "chaining" where logs slowly drift apart over time
-        let time_diff = logs[i].arrival_time - logs[batch_start].arrival_time;
-        if time_diff.num_milliseconds() > window_ms {
+        let log = &logs[i];
+        let breaks = match strategy {
+            // Compare to the start of the current batch, not the previous log
+            // This prevents "chaining" where logs slowly drift apart over time
+            BatchStrategy::TimeWindow => exceeds(arrival(&logs[batch_start]), arrival(log)),
+            BatchStrategy::Hybrid => {
+                process(log) != process(&logs[i - 1])
+                    || exceeds(arrival(&logs[batch_start]), arrival(log))
+            }
+        };
+        if breaks {
             batches.push((batch_start, i - 1));
             batch_start = i;
         }
@@ -1,10 +1,11 @@
 use crate::log::LogLine;
-use crate::ui::batch::detect_batches_from_logs;
+use crate::ui::batch::{BatchStrategy, detect_batches_from_logs};
 
 #[derive(Clone, PartialEq, Eq)]
 pub struct BatchCacheKey {
     log_signature: (usize, Option<u64>, Option<u64>),
     batch_window_ms: i64,
+    batch_strategy: BatchStrategy,
     filter_count: usize,
     search_pattern: String,
     hidden_count: usize,
@@ -28,6 +29,7 @@ impl BatchCacheKey {
         Self {
             log_signature: (logs.len(), first_id, last_id),
             batch_window_ms,
+            batch_strategy: BatchStrategy::default(),
             filter_count,
             search_pattern,
             hidden_count,
@@ -35,6 +37,12 @@ impl BatchCacheKey {
             using_snapshot,
         }
     }
+
+    /// Key for a non-default batch strategy
+    pub fn with_strategy(mut self, strategy: BatchStrategy) -> Self {
+        self.batch_strategy = strategy;
+        self
+    }
 }
 
 pub struct BatchCache {
@@ -61,7 +69,7 @@ impl BatchCache {
         current_key: BatchCacheKey,
     ) -> &Vec<(usize, usize)> {
         if self.key.as_ref() != Some(&current_key) {
-            self.batches = detect_batches_from_logs(logs, window_ms);
+            self.batches = detect_batches_from_logs(logs, window_ms, current_key.batch_strategy);
             self.key = Some(current_key);
             self.misses += 1;
         } else {
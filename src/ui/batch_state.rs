@@ -1,8 +1,12 @@
+use super::batch::BatchStrategy;
+
 /// Batch mode state for grouping log lines by time window
 #[derive(Debug)]
 pub struct BatchState {
     /// Time window for batch detection in milliseconds
     pub batch_window_ms: i64,
+    /// How lines are split into batches
+    pub batch_strategy: BatchStrategy,
     /// If true, show only the current batch
     pub batch_view_mode: bool,
     /// Index of currently viewed batch
@@ -13,6 +17,7 @@ impl Default for BatchState {
     fn default() -> Self {
         Self {
             batch_window_ms: 100,
+            batch_strategy: BatchStrategy::default(),
             batch_view_mode: false,
             current_batch: None,
         }
@@ -57,4 +62,11 @@ impl BatchState {
             self.current_batch = Some(0);
         }
     }
+
+    pub fn set_batch_strategy(&mut self, strategy: BatchStrategy) {
+        self.batch_strategy = strategy;
+        if self.batch_view_mode {
+            self.current_batch = Some(0);
+        }
+    }
 }
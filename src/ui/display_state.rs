@@ -1,3 +1,5 @@
+use chrono::{DateTime, Local};
+
 use super::app::DisplayMode;
 use super::types::StatusType;
 
@@ -14,6 +16,18 @@ pub enum ProcessPanelViewMode {
 }
 
 impl ProcessPanelViewMode {
+    /// Above this many processes the Normal grid is unreadable, so startup uses Summary
+    pub const SUMMARY_ABOVE: usize = 30;
+
+    /// Starting view mode for a session managing `process_count` processes
+    pub fn initial(process_count: usize) -> Self {
+        if process_count > Self::SUMMARY_ABOVE {
+            ProcessPanelViewMode::Summary
+        } else {
+            ProcessPanelViewMode::Normal
+        }
+    }
+
     /// Cycle to the next view mode
     pub fn next(self) -> Self {
         match self {
@@ -75,6 +89,49 @@ impl TimestampMode {
     }
 }
 
+/// Where the process list sits relative to the log viewer (`process_list_position`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProcessListPosition {
+    /// Above the log viewer (default)
+    #[default]
+    Top,
+    /// Between the log viewer and the status bar
+    Bottom,
+    /// Not drawn; the log viewer takes its rows
+    Hidden,
+}
+
+impl ProcessListPosition {
+    pub const ALL: [ProcessListPosition; 3] = [Self::Top, Self::Bottom, Self::Hidden];
+
+    /// Cycle top → bottom → hidden → top
+    pub fn next(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Hidden,
+            Self::Hidden => Self::Top,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Top => "top",
+            Self::Bottom => "bottom",
+            Self::Hidden => "hidden",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|position| position.name() == s)
+    }
+}
+
+/// Suffix marking a log line cut at the viewer edge
+pub const DEFAULT_TRUNCATION_SUFFIX: &str = "… ↵";
+
+/// Columns one ←/→ (h/l) press scrolls the log view
+pub const HORIZONTAL_SCROLL_STEP: usize = 8;
+
 /// Display state for UI modes and status
 #[derive(Debug)]
 pub struct DisplayState {
@@ -82,8 +139,14 @@ pub struct DisplayState {
     pub display_mode: DisplayMode,
     /// Current timestamp display mode
     pub timestamp_mode: TimestampMode,
+    /// When set (`timestamp_format = "relative"`), seconds mode shows elapsed time since this instant
+    pub relative_timestamp_origin: Option<DateTime<Local>>,
     /// Current process panel view mode
     pub process_panel_mode: ProcessPanelViewMode,
+    /// Where the process list is drawn, or whether it is hidden
+    pub process_list_position: ProcessListPosition,
+    /// Drawn first in the process list, in this order (`pinned_processes`, `:pin`)
+    pub pinned_processes: Vec<String>,
     /// What the main content area renders (logs or process tree)
     pub content_view: ContentView,
     /// Scroll offset (in lines) for the process tree viewer
@@ -94,12 +157,51 @@ pub struct DisplayState {
     pub show_help: bool,
     /// Scroll offset for help overlay
     pub help_scroll_offset: u16,
+    /// Keyword typed after `/` in the help overlay (matches are highlighted)
+    pub help_query: String,
+    /// Whether the help overlay's `/` prompt is taking input
+    pub help_query_editing: bool,
     /// Whether to show expanded line view
     pub expanded_line_view: bool,
     /// Status message to show to the user (message, type)
     pub status_message: Option<(String, StatusType)>,
+    /// Clear `status_message` on the next keypress (the startup banner)
+    pub status_dismiss_on_key: bool,
     /// Whether process coloring is enabled
     pub coloring_enabled: bool,
+    /// Processes shown side by side in split view (`:split a b`), None when merged
+    pub split_processes: Option<(String, String)>,
+    /// Render log content without its ANSI escape codes (`ansi_mode = "strip"`)
+    pub strip_ansi: bool,
+    /// Prefix stderr lines with a red `!` gutter (`mark_stderr = true`)
+    pub mark_stderr: bool,
+    /// Keep the newest line visible at the bottom while scrolled up (`tail_preview = true`)
+    pub tail_preview: bool,
+    /// Give every process-list cell a fixed-width label slot (`stable_process_layout = true`)
+    pub stable_process_layout: bool,
+    /// Draw a date divider where displayed lines cross midnight (`show_date_dividers`, default on)
+    pub show_date_dividers: bool,
+    /// Pad process names to the longest visible one so messages line up (`align_messages = true`)
+    pub align_messages: bool,
+    /// Show each process on its own header row with its newest line (`show_last_line = true`)
+    pub show_last_line: bool,
+    /// In full mode, list one process per row with its command dimmed beneath (`show_commands = true`)
+    pub show_commands: bool,
+    /// While scrolled, name the batch, date and process of the top line (`sticky_header = true`)
+    pub sticky_header: bool,
+    /// Color HTTP status codes, `key=` keys and quoted strings in lines without
+    /// their own ANSI colors (`highlight_syntax = true`)
+    pub highlight_syntax: bool,
+    /// Show the last five minutes of log volume in the status bar (`volume_histogram = true`)
+    pub volume_histogram: bool,
+    /// Appended to log lines cut at the viewer edge (`truncation_suffix`, default `… ↵`)
+    pub truncation_suffix: String,
+    /// Columns kept free at the right edge of the log viewer (`line_margin`, default 0)
+    pub line_margin: usize,
+    /// Collapse `[key:value]` metadata in full and wrap modes too (`:condense`, `condensed_display`)
+    pub condensed: bool,
+    /// Columns the log view is scrolled right by (←/→, h/l); not used in wrap mode
+    pub horizontal_offset: usize,
 }
 
 impl Default for DisplayState {
@@ -107,15 +209,37 @@ impl Default for DisplayState {
         Self {
             display_mode: DisplayMode::Compact,
             timestamp_mode: TimestampMode::Seconds,
+            relative_timestamp_origin: None,
             process_panel_mode: ProcessPanelViewMode::Normal,
+            process_list_position: ProcessListPosition::Top,
+            pinned_processes: Vec::new(),
             content_view: ContentView::Logs,
             process_tree_scroll: 0,
             process_tree_viewport: 0,
             show_help: false,
             help_scroll_offset: 0,
+            help_query: String::new(),
+            help_query_editing: false,
             expanded_line_view: false,
             status_message: None,
+            status_dismiss_on_key: false,
             coloring_enabled: false,
+            split_processes: None,
+            strip_ansi: false,
+            mark_stderr: false,
+            tail_preview: false,
+            stable_process_layout: false,
+            show_date_dividers: true,
+            align_messages: false,
+            show_last_line: false,
+            show_commands: false,
+            sticky_header: false,
+            highlight_syntax: false,
+            volume_histogram: false,
+            truncation_suffix: DEFAULT_TRUNCATION_SUFFIX.to_string(),
+            line_margin: 0,
+            condensed: false,
+            horizontal_offset: 0,
         }
     }
 }
@@ -126,7 +250,24 @@ impl DisplayState {
     }
 
     pub fn cycle_display_mode(&mut self) {
-        self.display_mode = self.display_mode.next();
+        self.set_display_mode(self.display_mode.next());
+    }
+
+    /// Switch display mode; horizontal scrolling starts over in the new mode
+    pub fn set_display_mode(&mut self, mode: DisplayMode) {
+        self.display_mode = mode;
+        self.horizontal_offset = 0;
+    }
+
+    /// Shift the log view `columns` right (wrap mode has nothing to scroll)
+    pub fn scroll_right(&mut self, columns: usize) {
+        if !self.is_wrap() {
+            self.horizontal_offset += columns;
+        }
+    }
+
+    pub fn scroll_left(&mut self, columns: usize) {
+        self.horizontal_offset = self.horizontal_offset.saturating_sub(columns);
     }
 
     pub fn is_compact(&self) -> bool {
@@ -137,6 +278,11 @@ impl DisplayState {
         self.display_mode == DisplayMode::Wrap
     }
 
+    /// Whether the log viewer shows condensed lines (compact mode always does)
+    pub fn condenses(&self) -> bool {
+        self.condensed || self.is_compact()
+    }
+
     pub fn cycle_timestamp_mode(&mut self) {
         self.timestamp_mode = self.timestamp_mode.next();
     }
@@ -167,6 +313,21 @@ impl DisplayState {
         self.content_view == ContentView::ProcessTree
     }
 
+    /// Whether a process's lines are visible under the current split view
+    /// (always true when not split)
+    pub fn split_shows(&self, process: &str) -> bool {
+        match &self.split_processes {
+            Some((left, right)) => process == left || process == right,
+            None => true,
+        }
+    }
+
+    /// Whether a line is visible under the current split view; oit's own
+    /// annotations show in both panes
+    pub fn split_shows_line(&self, log: &crate::log::LogLine) -> bool {
+        log.source.is_annotation() || self.split_shows(log.source.process_name())
+    }
+
     /// Scroll the process tree up by `n` lines.
     pub fn process_tree_scroll_up(&mut self, n: u16) {
         self.process_tree_scroll = self.process_tree_scroll.saturating_sub(n);
@@ -198,9 +359,26 @@ impl DisplayState {
         self.show_help = !self.show_help;
         if self.show_help {
             self.help_scroll_offset = 0;
+            self.help_query.clear();
+            self.help_query_editing = false;
         }
     }
 
+    /// Open the help overlay's `/` prompt with an empty keyword
+    pub fn start_help_query(&mut self) {
+        self.help_query.clear();
+        self.help_query_editing = true;
+    }
+
+    /// Update the help keyword and jump to its first match
+    pub fn set_help_query(&mut self, query: String) {
+        let lines = super::keybindings::help_lines();
+        if let Some(idx) = super::keybindings::find_help_line(&lines, &query) {
+            self.help_scroll_offset = idx as u16;
+        }
+        self.help_query = query;
+    }
+
     pub fn scroll_help_up(&mut self) {
         self.help_scroll_offset = self.help_scroll_offset.saturating_sub(1);
     }
@@ -219,18 +397,46 @@ impl DisplayState {
 
     pub fn set_status_success(&mut self, message: String) {
         self.status_message = Some((message, StatusType::Success));
+        self.status_dismiss_on_key = false;
     }
 
     pub fn set_status_error(&mut self, message: String) {
         self.status_message = Some((message, StatusType::Error));
+        self.status_dismiss_on_key = false;
     }
 
     pub fn set_status_info(&mut self, message: String) {
         self.status_message = Some((message, StatusType::Info));
+        self.status_dismiss_on_key = false;
+    }
+
+    /// Info message that goes away on the next keypress
+    pub fn set_status_transient(&mut self, message: String) {
+        self.set_status_info(message);
+        self.status_dismiss_on_key = true;
     }
 
-    #[allow(dead_code)]
     pub fn clear_status(&mut self) {
         self.status_message = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn large_sessions_start_in_summary_layout() {
+        assert_eq!(ProcessPanelViewMode::initial(3), ProcessPanelViewMode::Normal);
+        assert_eq!(ProcessPanelViewMode::initial(ProcessPanelViewMode::SUMMARY_ABOVE), ProcessPanelViewMode::Normal);
+        assert_eq!(ProcessPanelViewMode::initial(200), ProcessPanelViewMode::Summary);
+    }
+
+    #[test]
+    fn process_list_position_cycles_and_parses() {
+        assert_eq!(ProcessListPosition::default().next(), ProcessListPosition::Bottom);
+        assert_eq!(ProcessListPosition::Hidden.next(), ProcessListPosition::Top);
+        assert_eq!(ProcessListPosition::parse("hidden"), Some(ProcessListPosition::Hidden));
+        assert_eq!(ProcessListPosition::parse("left"), None);
+    }
+}
@@ -1,12 +1,13 @@
 use ratatui::{
-    layout::{Constraint, Direction, Layout},
+    layout::{Constraint, Direction, Layout, Rect},
     Frame,
 };
 
 use crate::process::ProcessManager;
 use super::app::App;
-use super::overlays::{draw_help_overlay, draw_expanded_line_overlay, draw_expanded_line_panel, draw_trace_selection_overlay};
-use super::widgets::{draw_process_list, draw_process_tree, draw_log_viewer, draw_status_bar, draw_command_input, calculate_process_list_height};
+use super::display_state::ProcessListPosition;
+use super::overlays::{draw_command_palette, draw_help_overlay, draw_expanded_line_overlay, draw_expanded_line_panel, draw_last_output, draw_process_picker, draw_trace_selection_overlay};
+use super::widgets::{draw_process_list, draw_process_tree, draw_log_viewer, draw_split_log_viewer, draw_status_bar, draw_command_input, calculate_process_list_height};
 
 /// Width threshold for split-screen view (below this, use overlay)
 const SPLIT_VIEW_THRESHOLD: u16 = 160;
@@ -17,30 +18,54 @@ pub fn draw(f: &mut Frame, app: &mut App, manager: &ProcessManager) {
     let use_split_view = f.area().width >= SPLIT_VIEW_THRESHOLD && app.display.expanded_line_view;
 
     // Calculate dynamic height for process list based on number of processes
-    let process_list_height = calculate_process_list_height(manager, app, f.area().width);
+    let position = app.display.process_list_position;
+    let process_list_height = match position {
+        ProcessListPosition::Hidden => 0,
+        _ => calculate_process_list_height(manager, app, f.area().width),
+    };
 
-    // Create the main layout: process list, log viewer, status bar, command input
+    // Create the main layout: process list and log viewer (in the configured
+    // order), status bar, command input
+    let process_list = Constraint::Length(process_list_height); // Process list (dynamic height)
+    let log_viewer = Constraint::Min(0); // Log viewer (takes remaining space)
+    let (process_idx, log_idx, constraints) = match position {
+        ProcessListPosition::Bottom => (1, 0, [log_viewer, process_list]),
+        _ => (0, 1, [process_list, log_viewer]),
+    };
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
-            Constraint::Length(process_list_height), // Process list (dynamic height)
-            Constraint::Min(0),         // Log viewer (takes remaining space)
+            constraints[0],
+            constraints[1],
             Constraint::Length(1),      // Status bar
             Constraint::Length(1),      // Command input (exactly 1 line)
         ])
         .split(f.area());
+    let content_area = chunks[log_idx];
+
+    // Remember what the user has seen, for the "── new ──" marker
+    let tailing = app.navigation.auto_scroll
+        && app.navigation.selected_line_id.is_none()
+        && !app.batch.batch_view_mode
+        && !app.trace.trace_filter_mode;
+    let covered = app.logs_covered();
+    app.navigation.unread.update(tailing, covered, manager.newest_log_id());
 
     // Store layout areas for mouse click detection
-    app.regions.process_list_area = Some(chunks[0]);
     app.regions.status_bar_area = Some(chunks[2]);
 
     // Draw process list
-    draw_process_list(f, chunks[0], manager, app);
+    if position == ProcessListPosition::Hidden {
+        app.regions.process_list_area = None;
+    } else {
+        app.regions.process_list_area = Some(chunks[process_idx]);
+        draw_process_list(f, chunks[process_idx], manager, app);
+    }
 
     // Draw the content area: process tree viewer or log viewer
     if app.display.is_process_tree() {
-        app.regions.log_viewer_area = Some(chunks[1]);
-        draw_process_tree(f, chunks[1], manager, app);
+        app.regions.log_viewer_area = Some(content_area);
+        draw_process_tree(f, content_area, manager, app);
     } else if use_split_view {
         // Split horizontally: 60% log viewer, 40% detail panel
         let log_area_chunks = Layout::default()
@@ -49,14 +74,14 @@ pub fn draw(f: &mut Frame, app: &mut App, manager: &ProcessManager) {
                 Constraint::Percentage(60),
                 Constraint::Percentage(40),
             ])
-            .split(chunks[1]);
+            .split(content_area);
 
         app.regions.log_viewer_area = Some(log_area_chunks[0]);
-        draw_log_viewer(f, log_area_chunks[0], manager, app);
+        draw_logs(f, log_area_chunks[0], manager, app);
         draw_expanded_line_panel(f, log_area_chunks[1], manager, app);
     } else {
-        app.regions.log_viewer_area = Some(chunks[1]);
-        draw_log_viewer(f, chunks[1], manager, app);
+        app.regions.log_viewer_area = Some(content_area);
+        draw_logs(f, content_area, manager, app);
     }
 
     // Draw status bar
@@ -67,7 +92,7 @@ pub fn draw(f: &mut Frame, app: &mut App, manager: &ProcessManager) {
 
     // Draw help overlay if show_help is true (must be last so it's on top)
     if app.display.show_help {
-        draw_help_overlay(f, app.display.help_scroll_offset);
+        draw_help_overlay(f, &mut app.display);
     }
 
     // Draw expanded line view overlay if enabled and NOT in split view mode
@@ -79,4 +104,25 @@ pub fn draw(f: &mut Frame, app: &mut App, manager: &ProcessManager) {
     if app.trace.trace_selection_mode {
         draw_trace_selection_overlay(f, &app.trace.trace_candidates, app.trace.selected_trace_index);
     }
+
+    if let Some(name) = &app.last_output {
+        draw_last_output(f, manager, name);
+    }
+
+    if app.process_picker.open {
+        draw_process_picker(f, manager, &app.process_picker, &app.display.pinned_processes);
+    }
+
+    if app.palette.open {
+        draw_command_palette(f, &app.palette);
+    }
+}
+
+/// Draw the log viewer, split into per-process panes when `:split` is active
+fn draw_logs(f: &mut Frame, area: Rect, manager: &ProcessManager, app: &mut App) {
+    if app.display.split_processes.is_some() {
+        draw_split_log_viewer(f, area, manager, app);
+    } else {
+        draw_log_viewer(f, area, manager, app);
+    }
 }
@@ -15,6 +15,8 @@ pub struct Filter {
     pattern_lowercase: String,
     pub filter_type: FilterType,
     pub is_regex: bool, // For future: support both plain text and regex
+    /// Disabled filters (`:ft`) stay listed but are skipped when filtering
+    pub enabled: bool,
 }
 
 impl Filter {
@@ -26,6 +28,7 @@ impl Filter {
             pattern_lowercase,
             filter_type,
             is_regex: false, // Start with plain text, add regex support later
+            enabled: true,
         }
     }
 
@@ -48,6 +51,7 @@ impl Filter {
 
 /// Apply filters to a vector of log references, returning owned logs that pass all filters
 pub fn apply_filters(logs: Vec<&LogLine>, filters: &[Filter]) -> Vec<LogLine> {
+    let filters: Vec<&Filter> = filters.iter().filter(|f| f.enabled).collect();
     if filters.is_empty() {
         return logs.into_iter().map(|log| (*log).clone()).collect();
     }
@@ -56,7 +60,7 @@ pub fn apply_filters(logs: Vec<&LogLine>, filters: &[Filter]) -> Vec<LogLine> {
         .filter(|log| {
             let line_lower = log.line_lowercase();
             // First, check exclude filters - if any match, exclude the log
-            for filter in filters {
+            for filter in &filters {
                 if matches!(filter.filter_type, FilterType::Exclude) {
                     if filter.matches_lowercase(line_lower) {
                         return false;
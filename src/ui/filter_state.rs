@@ -1,6 +1,27 @@
 use std::collections::HashSet;
 
+use regex::Regex;
+
 use super::filter::{Filter, FilterType};
+use crate::log::{LogLine, SeverityMatcher};
+
+/// A process muted until one of its lines matches (`:mute <proc> until <pattern>`).
+/// Lines logged while muted stay hidden after it un-mutes.
+#[derive(Debug, Clone)]
+pub struct Mute {
+    pub process: String,
+    pub pattern: Regex,
+    /// Newest buffered line when the mute started; later lines are muted
+    pub after_id: u64,
+    /// Line that ended the mute, None while still muted
+    pub until_id: Option<u64>,
+}
+
+impl Mute {
+    fn hides(&self, log: &LogLine) -> bool {
+        log.source.process_name() == self.process && log.id > self.after_id && self.until_id.is_none_or(|until| log.id < until)
+    }
+}
 
 /// Filter state for log filtering
 #[derive(Debug, Default)]
@@ -9,6 +30,14 @@ pub struct FilterState {
     pub filters: Vec<Filter>,
     /// Set of process names whose output should be hidden
     pub hidden_processes: HashSet<String>,
+    /// Only show lines detected as errors or warnings
+    pub errors_only: bool,
+    /// Error/warning classifier used by the errors-only view (`[severity]` config)
+    pub severity: SeverityMatcher,
+    /// Show `quiet_until` boot output instead of collapsing it (`:quiet` toggles)
+    pub show_boot_output: bool,
+    /// Current and ended mutes; ended ones keep their lines hidden
+    pub mutes: Vec<Mute>,
 }
 
 impl FilterState {
@@ -34,7 +63,113 @@ impl FilterState {
         self.filters.len() < original_len
     }
 
+    pub fn toggle_errors_only(&mut self) -> bool {
+        self.errors_only = !self.errors_only;
+        self.errors_only
+    }
+
+    /// Toggle quiet startup; returns true when boot output is collapsed again
+    pub fn toggle_quiet_startup(&mut self) -> bool {
+        self.show_boot_output = !self.show_boot_output;
+        !self.show_boot_output
+    }
+
+    /// Whether a line passes the view's filters: include/exclude, errors-only,
+    /// quiet boot output, mutes, search (`search_lower`, empty for none) and
+    /// hidden processes
+    pub fn shows_line(&self, log: &LogLine, search_lower: &str) -> bool {
+        let line_lower = log.line_lowercase();
+        let filters = self.filters.iter().filter(|f| f.enabled);
+
+        // Any matching exclude filter rejects; if include filters exist, one must match
+        if filters.clone().any(|f| matches!(f.filter_type, FilterType::Exclude) && f.matches_lowercase(line_lower)) {
+            return false;
+        }
+        let mut includes = filters.filter(|f| matches!(f.filter_type, FilterType::Include)).peekable();
+        if includes.peek().is_some() && !includes.any(|f| f.matches_lowercase(line_lower)) {
+            return false;
+        }
+
+        if self.errors_only && self.severity.detect_lowercase(line_lower).is_none() {
+            return false;
+        }
+        if self.hides_boot_line(log) || self.hides_muted_line(log) {
+            return false;
+        }
+        if !search_lower.is_empty() && !line_lower.contains(search_lower) {
+            return false;
+        }
+        !self.hides_process_of(log)
+    }
+
+    /// Whether a line belongs to a hidden process (oit's own annotations never do)
+    pub fn hides_process_of(&self, log: &LogLine) -> bool {
+        !log.source.is_annotation() && self.hidden_processes.contains(log.source.process_name())
+    }
+
+    /// Whether a line is boot output currently collapsed by quiet startup
+    pub fn hides_boot_line(&self, log: &LogLine) -> bool {
+        log.quiet && !self.show_boot_output
+    }
+
+    /// Mute `process` from after line `after_id` until a line matches `pattern`
+    /// (replaces the pattern of an active mute)
+    pub fn mute(&mut self, process: &str, pattern: Regex, after_id: u64) {
+        match self.mutes.iter_mut().find(|m| m.process == process && m.until_id.is_none()) {
+            Some(mute) => mute.pattern = pattern,
+            None => self.mutes.push(Mute { process: process.to_string(), pattern, after_id, until_id: None }),
+        }
+    }
+
+    /// End an active mute at line `until_id`; returns false if the process wasn't muted
+    pub fn unmute(&mut self, process: &str, until_id: u64) -> bool {
+        match self.mutes.iter_mut().find(|m| m.process == process && m.until_id.is_none()) {
+            Some(mute) => {
+                mute.until_id = Some(until_id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn has_active_mutes(&self) -> bool {
+        self.mutes.iter().any(|m| m.until_id.is_none())
+    }
+
+    /// Whether a line was logged while its process was muted
+    pub fn hides_muted_line(&self, log: &LogLine) -> bool {
+        self.mutes.iter().any(|m| m.hides(log))
+    }
+
+    /// End the mutes whose pattern one of `new_logs` matches (the matching
+    /// line is shown). Returns (process, pattern) for each un-muted process.
+    pub fn unmute_on_match(&mut self, new_logs: &[&LogLine]) -> Vec<(String, String)> {
+        let mut unmuted = Vec::new();
+        for mute in self.mutes.iter_mut().filter(|m| m.until_id.is_none()) {
+            let matched = new_logs.iter().find(|log| {
+                log.id > mute.after_id && log.source.process_name() == mute.process && mute.pattern.is_match(log.stripped_line())
+            });
+            if let Some(log) = matched {
+                mute.until_id = Some(log.id);
+                unmuted.push((mute.process.clone(), mute.pattern.as_str().to_string()));
+            }
+        }
+        unmuted
+    }
+
     pub fn filter_count(&self) -> usize {
         self.filters.len()
     }
+
+    /// Filters currently applied (not switched off with `:ft`)
+    pub fn active_filter_count(&self) -> usize {
+        self.filters.iter().filter(|f| f.enabled).count()
+    }
+
+    /// Flip the filter with `pattern` on or off; returns its new state, or None if there is none
+    pub fn toggle_filter(&mut self, pattern: &str) -> Option<bool> {
+        let filter = self.filters.iter_mut().find(|f| f.pattern == pattern)?;
+        filter.enabled = !filter.enabled;
+        Some(filter.enabled)
+    }
 }
@@ -0,0 +1,138 @@
+//! Light syntax highlighting for log lines without their own ANSI colors
+//! (`highlight_syntax = true`): HTTP status codes, `key=value` keys and
+//! quoted strings. Spans are cached with the rest of the line.
+
+use ratatui::style::Style;
+
+use crate::ui::theme::Theme;
+
+const HTTP_METHODS: &[&str] = &["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"];
+
+/// Keys whose value is an HTTP status even on lines that don't look like HTTP
+const STATUS_KEYS: &[&str] = &["status", "status_code", "code"];
+
+fn is_word_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || matches!(b, b'_' | b'.' | b'-')
+}
+
+/// Request lines (`GET /users`, `HTTP/1.1`) and Rails' `Completed 200 OK`
+fn looks_like_http(text: &str) -> bool {
+    text.contains("HTTP/")
+        || text.split_whitespace().any(|word| {
+            let word = word.trim_matches(|c: char| !c.is_ascii_alphabetic());
+            word == "Completed" || HTTP_METHODS.contains(&word)
+        })
+}
+
+/// Style for a status code by class: 2xx success, 3xx info, 4xx/5xx error
+fn status_style(word: &str, theme: &Theme) -> Option<Style> {
+    let bytes = word.as_bytes();
+    if bytes.len() != 3 || !bytes.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    let color = match bytes[0] {
+        b'2' => theme.success,
+        b'3' => theme.info,
+        b'4' | b'5' => theme.error,
+        _ => return None,
+    };
+    Some(Style::default().fg(color))
+}
+
+/// Split `text` into styled spans. Only the first status code on a line is
+/// highlighted, so byte counts and durations after it stay plain.
+pub fn highlight_spans(text: &str, theme: &Theme) -> Vec<(String, Style)> {
+    let http = looks_like_http(text);
+    let bytes = text.as_bytes();
+    let mut spans = Vec::new();
+    let mut plain_start = 0;
+    let mut last_key: Option<&str> = None;
+    let mut status_seen = false;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let (end, style) = if bytes[i] == b'"' {
+            match text[i + 1..].find('"') {
+                Some(len) => (i + len + 2, Some(Style::default().fg(theme.info))),
+                None => (i + 1, None),
+            }
+        } else if is_word_byte(bytes[i]) && (i == 0 || !is_word_byte(bytes[i - 1])) {
+            let end = text[i..].bytes().position(|b| !is_word_byte(b)).map_or(bytes.len(), |len| i + len);
+            let word = &text[i..end];
+            let after_status_key = i > 0 && bytes[i - 1] == b'=' && last_key.is_some_and(|k| STATUS_KEYS.contains(&k));
+            if bytes.get(end) == Some(&b'=') {
+                last_key = Some(word);
+                (end, Some(Style::default().fg(theme.muted)))
+            } else if !status_seen && (http || after_status_key) && let Some(style) = status_style(word, theme) {
+                status_seen = true;
+                (end, Some(style))
+            } else {
+                (end, None)
+            }
+        } else {
+            (i + 1, None)
+        };
+
+        if let Some(style) = style {
+            if plain_start < i {
+                spans.push((text[plain_start..i].to_string(), Style::default()));
+            }
+            spans.push((text[i..end].to_string(), style));
+            plain_start = end;
+        }
+        i = end;
+    }
+
+    if plain_start < text.len() {
+        spans.push((text[plain_start..].to_string(), Style::default()));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn styled<'a>(spans: &'a [(String, Style)], theme: &Theme) -> Vec<(&'a str, &'static str)> {
+        spans
+            .iter()
+            .filter_map(|(text, style)| {
+                let class = match style.fg {
+                    Some(c) if c == theme.success => "success",
+                    Some(c) if c == theme.error => "error",
+                    Some(c) if c == theme.info => "info",
+                    Some(c) if c == theme.muted => "key",
+                    _ => return None,
+                };
+                Some((text.as_str(), class))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_status_code_needs_http_context_or_status_key() {
+        let theme = Theme::dark();
+        let spans = highlight_spans("Completed 404 Not Found in 12ms", &theme);
+        assert_eq!(styled(&spans, &theme), vec![("404", "error")]);
+
+        assert!(styled(&highlight_spans("retrying 200 jobs", &theme), &theme).is_empty());
+        let spans = highlight_spans("status=503 duration=12", &theme);
+        assert_eq!(styled(&spans, &theme), vec![("status", "key"), ("503", "error"), ("duration", "key")]);
+    }
+
+    #[test]
+    fn test_only_the_first_status_code_is_highlighted() {
+        let theme = Theme::dark();
+        let spans = highlight_spans("\"GET /users HTTP/1.1\" 200 404", &theme);
+        assert_eq!(styled(&spans, &theme), vec![("\"GET /users HTTP/1.1\"", "info"), ("200", "success")]);
+    }
+
+    #[test]
+    fn test_spans_cover_the_whole_line() {
+        let theme = Theme::dark();
+        let text = "user=\"ana\" → GET /x 302 \"unterminated";
+        let spans = highlight_spans(text, &theme);
+        assert_eq!(spans.iter().map(|(t, _)| t.as_str()).collect::<String>(), text);
+        assert_eq!(styled(&spans, &theme), vec![("user", "key"), ("\"ana\"", "info"), ("302", "info")]);
+    }
+}
@@ -1,3 +1,6 @@
+/// Searches kept in memory for Up/Down recall
+pub const MAX_SEARCH_HISTORY: usize = 100;
+
 /// Input and command state for the TUI
 #[derive(Debug, Default)]
 pub struct InputState {
@@ -11,8 +14,12 @@ pub struct InputState {
     pub search_pattern: String,
     /// Command history for Up/Down navigation
     pub command_history: Vec<String>,
-    /// Current position in history (None = not navigating)
+    /// Completed searches for Up/Down navigation in search mode, oldest first
+    pub search_history: Vec<String>,
+    /// Current position in the active mode's history (None = not navigating)
     pub history_index: Option<usize>,
+    /// Cursor as a byte offset into `input`; read it through `cursor()`
+    cursor: usize,
 }
 
 impl InputState {
@@ -20,42 +27,83 @@ impl InputState {
         Self::default()
     }
 
-    /// Add a character to the command or search input
+    /// Cursor byte offset, clamped to a char boundary of the current input
+    /// (the input is also written directly, e.g. cleared after a search).
+    pub fn cursor(&self) -> usize {
+        let mut cursor = self.cursor.min(self.input.len());
+        while !self.input.is_char_boundary(cursor) {
+            cursor -= 1;
+        }
+        cursor
+    }
+
+    /// Replace the input text and put the cursor at the end
+    pub fn set_input(&mut self, text: String) {
+        self.cursor = text.len();
+        self.input = text;
+    }
+
+    /// Insert a character at the cursor in the command or search input
     pub fn add_char(&mut self, c: char) {
-        if self.command_mode {
+        if self.command_mode || self.search_mode {
             self.reset_history_nav();
-            self.input.push(c);
-        } else if self.search_mode {
-            self.input.push(c);
+            let cursor = self.cursor();
+            self.input.insert(cursor, c);
+            self.cursor = cursor + c.len_utf8();
         }
     }
 
-    /// Delete the last character from the command or search input
+    /// Delete the character before the cursor from the command or search input
     pub fn delete_char(&mut self) {
         if self.command_mode || self.search_mode {
-            self.input.pop();
+            let cursor = self.cursor();
+            if let Some((start, _)) = self.input[..cursor].char_indices().next_back() {
+                self.input.remove(start);
+                self.cursor = start;
+            }
         }
     }
 
+    /// Move the cursor one character left (Left arrow)
+    pub fn cursor_left(&mut self) {
+        let cursor = self.cursor();
+        self.cursor = self.input[..cursor].char_indices().next_back().map_or(0, |(i, _)| i);
+    }
+
+    /// Move the cursor one character right (Right arrow)
+    pub fn cursor_right(&mut self) {
+        let cursor = self.cursor();
+        self.cursor = self.input[cursor..].chars().next().map_or(cursor, |c| cursor + c.len_utf8());
+    }
+
+    pub fn cursor_home(&mut self) {
+        self.cursor = 0;
+    }
+
+    pub fn cursor_end(&mut self) {
+        self.cursor = self.input.len();
+    }
+
     pub fn enter_command_mode(&mut self) {
         self.command_mode = true;
-        self.input.clear();
+        self.set_input(String::new());
         self.history_index = None;
     }
 
     pub fn exit_command_mode(&mut self) {
         self.command_mode = false;
-        self.input.clear();
+        self.set_input(String::new());
     }
 
     pub fn enter_search_mode(&mut self) {
         self.search_mode = true;
-        self.input.clear();
+        self.set_input(String::new());
+        self.history_index = None;
     }
 
     pub fn exit_search_mode(&mut self) {
         self.search_mode = false;
-        self.input.clear();
+        self.set_input(String::new());
         self.search_pattern.clear();
     }
 
@@ -73,38 +121,60 @@ impl InputState {
         }
     }
 
+    /// Record a completed search, skipping a repeat of the previous one
+    pub fn save_search_to_history(&mut self, pattern: String) {
+        if pattern.is_empty() || self.search_history.last() == Some(&pattern) {
+            return;
+        }
+        self.search_history.push(pattern);
+        if self.search_history.len() > MAX_SEARCH_HISTORY {
+            self.search_history.remove(0);
+        }
+    }
+
+    /// History for the active mode: searches in search mode, commands otherwise
+    fn active_history(&self) -> &[String] {
+        if self.search_mode {
+            &self.search_history
+        } else {
+            &self.command_history
+        }
+    }
+
     /// Navigate backward in history (Up arrow)
     pub fn history_prev(&mut self) {
-        if self.command_history.is_empty() {
+        let history = self.active_history();
+        if history.is_empty() {
             return;
         }
 
         let new_index = match self.history_index {
-            None => self.command_history.len() - 1,
+            None => history.len() - 1,
             Some(0) => 0,
             Some(i) => i - 1,
         };
 
+        self.set_input(history[new_index].clone());
         self.history_index = Some(new_index);
-        self.input = self.command_history[new_index].clone();
     }
 
     /// Navigate forward in history (Down arrow)
     pub fn history_next(&mut self) {
-        if self.command_history.is_empty() {
+        let history = self.active_history();
+        if history.is_empty() {
             return;
         }
 
         match self.history_index {
             None => {}
-            Some(i) if i >= self.command_history.len() - 1 => {
+            Some(i) if i >= history.len() - 1 => {
                 self.history_index = None;
-                self.input.clear();
+                self.set_input(String::new());
             }
             Some(i) => {
                 let new_index = i + 1;
+                self.set_input(history[new_index].clone());
                 self.history_index = Some(new_index);
-                self.input = self.command_history[new_index].clone();
             }
         }
     }
@@ -114,3 +184,118 @@ impl InputState {
         self.history_index = None;
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn searching_with_history(patterns: &[&str]) -> InputState {
+        let mut input = InputState::new();
+        for p in patterns {
+            input.save_search_to_history(p.to_string());
+        }
+        input.enter_search_mode();
+        input
+    }
+
+    #[test]
+    fn search_history_recalls_newest_first() {
+        let mut input = searching_with_history(&["timeout", "500", "worker"]);
+
+        input.history_prev();
+        assert_eq!(input.input, "worker");
+        input.history_prev();
+        assert_eq!(input.input, "500");
+        input.history_next();
+        assert_eq!(input.input, "worker");
+        input.history_next();
+        assert_eq!(input.input, "");
+    }
+
+    #[test]
+    fn search_history_skips_consecutive_duplicates() {
+        let input = searching_with_history(&["error", "error", "warn", "error", ""]);
+        assert_eq!(input.search_history, vec!["error", "warn", "error"]);
+    }
+
+    #[test]
+    fn search_and_command_histories_are_separate() {
+        let mut input = searching_with_history(&["needle"]);
+        input.save_to_history("r web".to_string());
+
+        input.history_prev();
+        assert_eq!(input.input, "needle");
+
+        input.exit_search_mode();
+        input.enter_command_mode();
+        input.history_prev();
+        assert_eq!(input.input, "r web");
+    }
+
+    #[test]
+    fn typing_resets_search_history_navigation() {
+        let mut input = searching_with_history(&["one", "two"]);
+        input.history_prev();
+        input.history_prev();
+        input.add_char('x');
+        assert_eq!(input.history_index, None);
+        input.history_prev();
+        assert_eq!(input.input, "two");
+    }
+
+    #[test]
+    fn delete_removes_whole_multibyte_char() {
+        let mut input = InputState::new();
+        input.enter_command_mode();
+        for c in "café 🚀".chars() {
+            input.add_char(c);
+        }
+
+        input.delete_char();
+        assert_eq!(input.input, "café ");
+        input.delete_char();
+        input.delete_char();
+        assert_eq!(input.input, "caf");
+        assert_eq!(input.cursor(), 3);
+    }
+
+    #[test]
+    fn insert_and_delete_mid_string_at_cursor() {
+        let mut input = InputState::new();
+        input.enter_search_mode();
+        for c in "héllo".chars() {
+            input.add_char(c);
+        }
+
+        input.cursor_left();
+        input.cursor_left();
+        input.add_char('🙂');
+        assert_eq!(input.input, "hél🙂lo");
+
+        input.delete_char();
+        input.delete_char();
+        assert_eq!(input.input, "hélo");
+
+        input.cursor_home();
+        input.add_char('>');
+        input.cursor_end();
+        input.add_char('!');
+        assert_eq!(input.input, ">hélo!");
+    }
+
+    #[test]
+    fn cursor_is_clamped_when_input_is_replaced() {
+        let mut input = InputState::new();
+        input.enter_command_mode();
+        for c in "restart".chars() {
+            input.add_char(c);
+        }
+        input.input.clear();
+        assert_eq!(input.cursor(), 0);
+
+        input.input.push('é');
+        input.cursor_right();
+        input.cursor_right();
+        assert_eq!(input.cursor(), 2);
+    }
+}
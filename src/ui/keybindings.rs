@@ -0,0 +1,244 @@
+//! Keybinding map: the keys and `:` commands the TUI understands, grouped the
+//! way the help overlay shows them. The overlay is generated from this table
+//! plus the IPC command list, so update it alongside `event_handler.rs` and
+//! `command.rs`.
+
+pub struct Binding {
+    pub keys: &'static str,
+    pub description: &'static str,
+}
+
+pub struct Section {
+    pub title: &'static str,
+    pub bindings: &'static [Binding],
+}
+
+const fn bind(keys: &'static str, description: &'static str) -> Binding {
+    Binding { keys, description }
+}
+
+pub const KEYBINDINGS: &[Section] = &[
+    Section {
+        title: "Navigation",
+        bindings: &[
+            bind("↑/↓", "Select previous/next log line"),
+            bind("Shift+↑/↓", "Extend selection (multi-select)"),
+            bind("Ctrl+B/F", "Page up/down (Vim-style)"),
+            bind("Enter", "Expand selected line (show full content)"),
+            bind("Esc", "Jump to latest logs (reset view)"),
+            bind("q", "Close overlay or batch view, else quit (q q with confirm_quit)"),
+            bind("s", "Start/stop manual trace capture"),
+            bind("e", "Toggle errors-only view (errors and warnings)"),
+            bind("E / W", "Select the next / previous error line (wraps)"),
+            bind("w", "Cycle display: compact → full → wrap"),
+            bind("←/→ or h/l", "Scroll long lines sideways (compact/full; « » mark cut-off text)"),
+            bind("t", "Cycle timestamps: seconds → ms → off"),
+            bind("p", "Cycle process panel: normal → summary → minimal"),
+            bind("P", "Toggle process tree view (P/Esc returns to logs)"),
+            bind("m", "Process picker: ↑/↓ select, r/k/s restart/kill/start"),
+            bind("tree", "Scroll with ↑/↓, PgUp/PgDn, Home/End, mouse wheel"),
+        ],
+    },
+    Section {
+        title: "Commands",
+        bindings: &[
+            bind(":", "Enter command mode"),
+            bind("Ctrl+P", "Command palette: fuzzy-find a : command, Enter to run"),
+            bind("←/→, Home/End", "Move the cursor while typing a command or search"),
+            bind(":s <proc|group>", "Start process or group"),
+            bind(":s", "Start the most recently killed process"),
+            bind(":r [proc|group]", "Restart process/group (or all if no arg)"),
+            bind(":k <proc|group>", "Kill process or group"),
+            bind(":add <name> <cmd>", "Add an ad-hoc process (not saved)"),
+            bind(":tailfile <name> <path>", "Tail a log file at runtime, even one not created yet"),
+            bind(":retail <name>", "Re-read a log file from the top"),
+            bind(":pause <proc>", "Hold a process's new lines out of the buffer"),
+            bind(":resume <proc>", "Ingest held lines and resume"),
+            bind(":lasterror [proc]", "Show a failed process's final output"),
+            bind(":cmd <proc>", "Show the command a process was launched with"),
+            bind("y", "Confirm :k / :r (with confirm_destructive)"),
+            bind(":q/:quit/:exit", "Quit"),
+        ],
+    },
+    Section {
+        title: "Filtering",
+        bindings: &[
+            bind(":f <pat>", "Include filter (show only matching lines)"),
+            bind(":fn <pat>", "Exclude filter (hide matching lines)"),
+            bind(":fc", "Clear all filters"),
+            bind(":fl", "List filters (switched-off ones marked [off])"),
+            bind(":ft <n>", "Switch the nth filter off or on"),
+            bind(":preset <name>", "Replace filters with a saved preset (no name: list presets)"),
+            bind(":preset save <name>", "Save current filters as a preset"),
+            bind(":errors", "Toggle errors-only view"),
+            bind(":quiet", "Toggle collapsing boot output (quiet_until)"),
+        ],
+    },
+    Section {
+        title: "Search",
+        bindings: &[
+            bind("/", "Start search (filters as you type)"),
+            bind("↑/↓", "In search mode: recall previous searches"),
+            bind("Enter", "In search mode: enter selection mode"),
+            bind("Enter", "In expanded view: show context around log"),
+            bind("Esc", "Step back (selection→typing→exit)"),
+        ],
+    },
+    Section {
+        title: "Batch Navigation",
+        bindings: &[
+            bind("[", "Previous batch"),
+            bind("]", "Next batch"),
+            bind(":pb", "Previous batch (same as [)"),
+            bind(":nb", "Next batch (same as ])"),
+            bind(":sb", "Toggle batch view mode"),
+            bind(":bw", "Show current batch window"),
+            bind(":bw <ms>", "Set batch window (milliseconds)"),
+            bind(":bw fast/medium/slow", "Presets: 100ms/1000ms/5000ms"),
+            bind(":buffer [MB]", "Show or change the log buffer limit for this session"),
+            bind(":bs [strategy]", "Show or set batch strategy: time_window/hybrid"),
+            bind("+/-", "Increase/decrease batch window by 100ms"),
+            bind(":g/:goto <time>", "Jump to time (HH:MM, -5m, +30s)"),
+            bind(":recent <secs>", "Jump to the first line received in the last N seconds"),
+        ],
+    },
+    Section {
+        title: "Clipboard & Batch",
+        bindings: &[
+            bind("c", "Copy selected line(s) (also in expanded view)"),
+            bind("Shift+C", "Copy entire batch to clipboard"),
+            bind("x", "Contextual copy (same process ±1s)"),
+            bind("Y", "Copy line reference (process#id)"),
+            bind("o", "Open the selected line's hyperlink (OSC 8)"),
+            bind(":copyall", "Copy every line in the current view"),
+            bind("b", "Focus on batch containing selected line"),
+        ],
+    },
+    Section {
+        title: "Process Visibility",
+        bindings: &[
+            bind(":hide <proc|group>", "Hide logs from process or group"),
+            bind(":show <proc|group>", "Show logs from process or group"),
+            bind(":hide all", "Hide all process logs"),
+            bind(":show all", "Show all process logs"),
+            bind(":only <proc|group>", "Show only process or group"),
+            bind(":mute <proc> until <pat>", "Hide a process's new lines until one matches (regex)"),
+            bind(":unmute <proc>", "End a mute early (muted lines stay hidden)"),
+        ],
+    },
+    Section {
+        title: "Display",
+        bindings: &[
+            bind(":color", "Toggle process coloring on/off"),
+            bind(":condense", "Collapse [key:value] metadata in full/wrap modes"),
+            bind(":split <a> <b>", "Two processes side by side"),
+            bind(":unsplit", "Back to the merged log view"),
+            bind(":proclist [top|bottom|hidden]", "Move or hide the process list (cycles)"),
+            bind(":pin <proc>", "Keep a process first in the process list"),
+            bind(":unpin <proc>", "Return a pinned process to its usual place"),
+        ],
+    },
+    Section {
+        title: "Trace Detection",
+        bindings: &[
+            bind(":traces", "Detect correlation IDs (UUIDs, etc.)"),
+            bind("[ ]", "Expand trace view (before/after)"),
+        ],
+    },
+];
+
+/// One line of help overlay content
+#[derive(Debug, Clone, PartialEq)]
+pub enum HelpLine {
+    Title(String),
+    Entry { keys: String, description: String },
+    Blank,
+}
+
+impl HelpLine {
+    fn matches(&self, query_lower: &str) -> bool {
+        match self {
+            HelpLine::Title(title) => title.to_lowercase().contains(query_lower),
+            HelpLine::Entry { keys, description } => {
+                keys.to_lowercase().contains(query_lower) || description.to_lowercase().contains(query_lower)
+            }
+            HelpLine::Blank => false,
+        }
+    }
+}
+
+/// Help overlay content: the keybinding map followed by the IPC commands
+pub fn help_lines() -> Vec<HelpLine> {
+    let mut lines = vec![HelpLine::Title("Overitall Help".to_string()), HelpLine::Blank];
+
+    for section in KEYBINDINGS {
+        lines.push(HelpLine::Title(format!("{}:", section.title)));
+        lines.extend(section.bindings.iter().map(|b| HelpLine::Entry {
+            keys: b.keys.to_string(),
+            description: b.description.to_string(),
+        }));
+        lines.push(HelpLine::Blank);
+    }
+
+    lines.push(HelpLine::Title("IPC Commands (oit <command>):".to_string()));
+    if let Some(commands) = crate::ipc::handler::command_list().as_array() {
+        lines.extend(commands.iter().map(|c| HelpLine::Entry {
+            keys: c["name"].as_str().unwrap_or_default().replace('_', "-"),
+            description: c["description"].as_str().unwrap_or_default().to_string(),
+        }));
+    }
+
+    lines
+}
+
+/// Index of the first help line matching `query` (case-insensitive)
+pub fn find_help_line(lines: &[HelpLine], query: &str) -> Option<usize> {
+    if query.is_empty() {
+        return None;
+    }
+    let query_lower = query.to_lowercase();
+    lines.iter().position(|line| line.matches(&query_lower))
+}
+
+/// Whether a help line matches `query` (for highlighting)
+pub fn help_line_matches(line: &HelpLine, query: &str) -> bool {
+    !query.is_empty() && line.matches(&query.to_lowercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::{parse_command, Command};
+
+    #[test]
+    fn every_colon_command_in_the_map_parses() {
+        for section in KEYBINDINGS {
+            for binding in section.bindings {
+                for alternative in binding.keys.split('/') {
+                    let Some(word) = alternative.strip_prefix(':').and_then(|s| s.split_whitespace().next()) else {
+                        continue;
+                    };
+                    if let Command::Unknown(msg) = parse_command(word) {
+                        assert!(!msg.starts_with("Unknown command"), "{} is listed in the help but {}", word, msg);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn help_lines_include_ipc_commands() {
+        let lines = help_lines();
+        assert!(lines.contains(&HelpLine::Title("IPC Commands (oit <command>):".to_string())));
+        assert!(lines.iter().any(|l| matches!(l, HelpLine::Entry { keys, .. } if keys == "kill-all")));
+    }
+
+    #[test]
+    fn find_help_line_is_case_insensitive() {
+        let lines = help_lines();
+        let idx = find_help_line(&lines, "RETAIL").unwrap();
+        assert!(matches!(&lines[idx], HelpLine::Entry { keys, .. } if keys.starts_with(":retail")));
+        assert_eq!(find_help_line(&lines, ""), None);
+        assert_eq!(find_help_line(&lines, "no such keyword"), None);
+    }
+}
@@ -8,9 +8,13 @@ pub mod display_state;
 mod draw;
 mod filter;
 mod filter_state;
+pub mod highlight;
 mod input_state;
+pub mod keybindings;
 mod navigation_state;
 mod overlays;
+mod palette_state;
+mod process_picker_state;
 pub mod process_colors;
 mod render_cache;
 pub mod theme;
@@ -21,9 +25,11 @@ mod widgets;
 
 // Public API
 pub use app::{App, DisplayMode};
-pub use batch::detect_batches_from_logs;
+pub use batch::{BatchStrategy, detect_batches_by, detect_batches_from_logs};
 #[allow(unused_imports)]
 pub use batch_cache::{BatchCache, BatchCacheKey};
 pub use draw::draw;
 pub use filter::{apply_filters, Filter, FilterType};
+pub use palette_state::PaletteInvocation;
 pub use theme::Theme;
+pub use types::{PendingAction, PendingConfirmation, StatusType};
@@ -21,6 +21,93 @@ pub struct NavigationState {
     pub selection_anchor: Option<u64>,
     /// Current end of multi-select range
     pub selection_end: Option<u64>,
+    /// Re-engages auto-scroll after a short peek up (`scroll_stick_lines`)
+    pub stick: ScrollStick,
+    /// Where lines that arrived while the user was away begin ("── new ──")
+    pub unread: UnreadMarker,
+}
+
+/// Tracks the newest line the user has seen, so lines that arrive while the
+/// view is scrolled away or covered by an overlay get a "── new ──" marker
+/// before them. Scrolling back to the bottom clears it.
+#[derive(Debug, Default)]
+pub struct UnreadMarker {
+    /// Newest buffered line the last time the view was tailing with nothing over it
+    last_seen: Option<u64>,
+    /// The marker goes before the first line with a greater ID
+    after: Option<u64>,
+    /// Whether the view was tailing uncovered on the last update
+    was_active: bool,
+    /// Whether the view was scrolled away on the last update
+    was_scrolled: bool,
+}
+
+impl UnreadMarker {
+    /// Record one frame. `tailing` means following the newest line; `covered`
+    /// means an overlay or another view hides the logs; `newest` is the newest
+    /// buffered line's ID.
+    pub fn update(&mut self, tailing: bool, covered: bool, newest: Option<u64>) {
+        if tailing && self.was_scrolled {
+            // Scrolled back down: everything has been seen
+            self.after = None;
+        }
+        let active = tailing && !covered;
+        if active {
+            // Back from an overlay with nothing new to show
+            if self.after.is_some_and(|after| newest.is_none_or(|id| id <= after)) {
+                self.after = None;
+            }
+            self.last_seen = newest.or(self.last_seen);
+        } else if self.was_active {
+            self.after = self.last_seen;
+        }
+        self.was_active = active;
+        self.was_scrolled = !tailing;
+    }
+
+    /// ID after which the marker is drawn, if any lines are unread
+    pub fn after(&self) -> Option<u64> {
+        self.after
+    }
+
+    pub fn clear(&mut self) {
+        self.after = None;
+    }
+}
+
+/// Auto-scroll stickiness: a manual scroll that stays within `lines` of the
+/// bottom keeps auto-scroll armed, and it re-engages once new lines arrive.
+#[derive(Debug, Default)]
+pub struct ScrollStick {
+    /// How close to the bottom a scroll stays armed (0 = off)
+    pub lines: usize,
+    /// Bottom offset seen on the last check while armed
+    armed_bottom: Option<usize>,
+}
+
+impl ScrollStick {
+    /// Check a manually scrolled view. `bottom_offset` is the scroll offset that
+    /// shows the newest line. Returns true when auto-scroll should re-engage.
+    pub fn settle(&mut self, scroll_offset: usize, bottom_offset: usize) -> bool {
+        if self.lines == 0 {
+            return false;
+        }
+        if let Some(prev) = self.armed_bottom
+            && bottom_offset > prev
+            && prev.saturating_sub(scroll_offset) <= self.lines
+        {
+            self.armed_bottom = None;
+            return true;
+        }
+        let distance = bottom_offset.saturating_sub(scroll_offset);
+        self.armed_bottom = (distance <= self.lines).then_some(bottom_offset);
+        false
+    }
+
+    /// Forget the armed position (auto-scroll or selection took over)
+    pub fn disarm(&mut self) {
+        self.armed_bottom = None;
+    }
 }
 
 impl NavigationState {
@@ -51,6 +138,7 @@ impl NavigationState {
     pub fn scroll_to_bottom(&mut self) {
         self.auto_scroll = true;
         self.scroll_offset = 0;
+        self.unread.clear();
     }
 
     pub fn freeze_display(&mut self) {
@@ -78,7 +166,6 @@ impl NavigationState {
         self.selected_line_id = id;
     }
 
-    #[allow(dead_code)]
     pub fn clear_selection(&mut self) {
         self.selected_line_id = None;
     }
@@ -189,6 +276,38 @@ mod tests {
             .collect()
     }
 
+    #[test]
+    fn test_unread_marker_set_while_scrolled_away_and_cleared_at_bottom() {
+        let mut unread = UnreadMarker::default();
+        unread.update(true, false, Some(10));
+        assert_eq!(unread.after(), None);
+
+        // Scrolled up while lines 11.. arrive
+        unread.update(false, false, Some(10));
+        unread.update(false, false, Some(14));
+        assert_eq!(unread.after(), Some(10));
+
+        // Back at the bottom
+        unread.update(true, false, Some(14));
+        assert_eq!(unread.after(), None);
+    }
+
+    #[test]
+    fn test_unread_marker_survives_closing_an_overlay() {
+        let mut unread = UnreadMarker::default();
+        unread.update(true, false, Some(10));
+        unread.update(true, true, Some(12));
+        unread.update(true, false, Some(12));
+        assert_eq!(unread.after(), Some(10));
+        unread.clear();
+        assert_eq!(unread.after(), None);
+
+        // An overlay opened and closed with nothing new leaves no marker
+        unread.update(true, true, Some(12));
+        unread.update(true, false, Some(12));
+        assert_eq!(unread.after(), None);
+    }
+
     #[test]
     fn test_is_in_selection_no_anchor() {
         let nav = NavigationState::new();
@@ -285,4 +404,47 @@ mod tests {
         assert_eq!(nav.selection_end, Some(5));
         assert_eq!(nav.selected_line_id, Some(5)); // Also updates cursor
     }
+
+    /// Scroll up from a bottom offset of 100, then report whether 3 new lines re-engage
+    fn peek_then_new_lines(stick_lines: usize, up: usize) -> bool {
+        let mut nav = NavigationState::new();
+        nav.stick.lines = stick_lines;
+        nav.scroll_offset = 100;
+        nav.scroll_up(up);
+        assert!(!nav.auto_scroll);
+        assert!(!nav.stick.settle(nav.scroll_offset, 100));
+        nav.stick.settle(nav.scroll_offset, 103)
+    }
+
+    #[test]
+    fn test_scroll_stick_reengages_within_threshold() {
+        assert!(peek_then_new_lines(5, 1));
+        assert!(peek_then_new_lines(5, 5));
+    }
+
+    #[test]
+    fn test_scroll_stick_stays_off_past_threshold() {
+        assert!(!peek_then_new_lines(5, 6));
+    }
+
+    #[test]
+    fn test_scroll_stick_disabled_by_default() {
+        assert!(!peek_then_new_lines(0, 1));
+    }
+
+    #[test]
+    fn test_scroll_stick_disarms_when_scrolling_further_up() {
+        let mut nav = NavigationState::new();
+        nav.stick.lines = 5;
+        nav.scroll_offset = 100;
+        nav.scroll_up(2);
+        assert!(!nav.stick.settle(nav.scroll_offset, 100));
+        nav.scroll_up(10);
+        assert!(!nav.stick.settle(nav.scroll_offset, 103));
+        // Scrolling back down into the zone re-arms
+        nav.scroll_down(12, 103);
+        assert!(!nav.auto_scroll);
+        assert!(!nav.stick.settle(nav.scroll_offset, 103));
+        assert!(nav.stick.settle(nav.scroll_offset, 104));
+    }
 }
@@ -6,7 +6,7 @@ use ratatui::{
     Frame,
 };
 
-use crate::log::LogLine;
+use crate::log::{pretty_print_json, LogLine};
 use crate::process::ProcessManager;
 use crate::ui::app::App;
 use crate::ui::batch::detect_batches_from_logs;
@@ -19,6 +19,7 @@ struct ExpandedLineContext<'a> {
     selected_idx: usize,
     total_logs: usize,
     batch_num: Option<usize>,
+    strip_ansi: bool,
 }
 
 /// Get the selected log with context for rendering
@@ -50,7 +51,7 @@ fn get_selected_log_context<'a>(
             .filter(|log| {
                 let line_text = &log.line;
 
-                for filter in &app.filters.filters {
+                for filter in app.filters.filters.iter().filter(|f| f.enabled) {
                     if matches!(filter.filter_type, FilterType::Exclude) && filter.matches(line_text)
                     {
                         return false;
@@ -61,7 +62,7 @@ fn get_selected_log_context<'a>(
                     .filters
                     .filters
                     .iter()
-                    .filter(|f| matches!(f.filter_type, FilterType::Include))
+                    .filter(|f| f.enabled && matches!(f.filter_type, FilterType::Include))
                     .collect();
 
                 if include_filters.is_empty() {
@@ -73,6 +74,14 @@ fn get_selected_log_context<'a>(
             .collect()
     };
 
+    // Apply errors-only quick filter (intersects with the filters above)
+    if app.filters.errors_only {
+        filtered_logs.retain(|log| app.filters.severity.detect_lowercase(log.line_lowercase()).is_some());
+    }
+
+    // Collapsed quiet-startup boot output (the viewer draws a placeholder instead)
+    filtered_logs.retain(|log| !app.filters.hides_boot_line(log) && !app.filters.hides_muted_line(log));
+
     // Apply search filter if active
     let active_search_pattern = if app.input.search_mode && !app.input.input.is_empty() {
         &app.input.input
@@ -91,7 +100,8 @@ fn get_selected_log_context<'a>(
     }
 
     // Apply process visibility filter
-    filtered_logs.retain(|log| !app.filters.hidden_processes.contains(log.source.process_name()));
+    filtered_logs.retain(|log| !app.filters.hides_process_of(log));
+    filtered_logs.retain(|log| app.display.split_shows_line(log));
 
     // Apply trace filter mode if active
     if app.trace.trace_filter_mode {
@@ -119,7 +129,7 @@ fn get_selected_log_context<'a>(
     }
 
     // Detect batches
-    let batches = detect_batches_from_logs(&filtered_logs, app.batch.batch_window_ms);
+    let batches = detect_batches_from_logs(&filtered_logs, app.batch.batch_window_ms, app.batch.batch_strategy);
 
     // Apply batch view mode filtering if enabled
     let display_logs: Vec<&LogLine> = if app.batch.batch_view_mode {
@@ -159,6 +169,7 @@ fn get_selected_log_context<'a>(
         selected_idx,
         total_logs: display_logs.len(),
         batch_num,
+        strip_ansi: app.display.strip_ansi,
     })
 }
 
@@ -192,6 +203,20 @@ fn build_expanded_line_content(ctx: &ExpandedLineContext, for_panel: bool) -> Ve
         ),
     ]));
 
+    if let Some(path) = ctx.log.source.path() {
+        content.push(Line::from(vec![
+            Span::styled("Source: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(path.display().to_string(), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+
+    if let Some(run) = ctx.log.run {
+        content.push(Line::from(vec![
+            Span::styled("Run: ", Style::default().add_modifier(Modifier::BOLD)),
+            Span::styled(format!("#{}", run), Style::default().fg(Color::Green)),
+        ]));
+    }
+
     if let Some(batch_num) = ctx.batch_num {
         content.push(Line::from(vec![
             Span::styled("Batch: ", Style::default().add_modifier(Modifier::BOLD)),
@@ -214,13 +239,38 @@ fn build_expanded_line_content(ctx: &ExpandedLineContext, for_panel: bool) -> Ve
     )]));
     content.push(Line::from(""));
 
-    let parsed_spans = parse_ansi_to_spans(&ctx.log.line);
+    let message = if ctx.strip_ansi { ctx.log.display_stripped_line() } else { ctx.log.display_line() };
+    let parsed_spans = parse_ansi_to_spans(message);
     let spans: Vec<Span> = parsed_spans
         .into_iter()
         .map(|(text, style)| Span::styled(text, style))
         .collect();
     content.push(Line::from(spans));
 
+    if let Some(raw) = ctx.log.raw_line() {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            "Raw:",
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        content.push(Line::from(""));
+        let spans: Vec<Span> = parse_ansi_to_spans(raw)
+            .into_iter()
+            .map(|(text, style)| Span::styled(text, style))
+            .collect();
+        content.push(Line::from(spans));
+    }
+
+    if let Some(pretty) = ctx.log.raw_json().and_then(pretty_print_json) {
+        content.push(Line::from(""));
+        content.push(Line::from(vec![Span::styled(
+            "JSON:",
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+        content.push(Line::from(""));
+        content.extend(pretty.lines().map(|line| Line::from(line.to_string())));
+    }
+
     content.push(Line::from(""));
 
     if for_panel {
@@ -277,7 +327,7 @@ pub fn draw_expanded_line_panel(f: &mut Frame, area: Rect, manager: &ProcessMana
 
     let paragraph = Paragraph::new(content)
         .block(block)
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap { trim: false });
 
     f.render_widget(paragraph, area);
 }
@@ -298,10 +348,85 @@ pub fn draw_expanded_line_overlay(f: &mut Frame, manager: &ProcessManager, app:
 
     let paragraph = Paragraph::new(content)
         .block(block)
-        .wrap(Wrap { trim: true });
+        .wrap(Wrap { trim: false });
 
     let area = centered_rect(80, 60, f.area());
 
     f.render_widget(Clear, area);
     f.render_widget(paragraph, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::log::{JsonLogFields, LogSource};
+
+    fn content_text(log: &LogLine) -> Vec<String> {
+        let ctx = ExpandedLineContext {
+            log,
+            selected_idx: 0,
+            total_logs: 1,
+            batch_num: None,
+            strip_ansi: false,
+        };
+        build_expanded_line_content(&ctx, false)
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn test_expanded_content_pretty_prints_json() {
+        let mut log = LogLine::new(
+            LogSource::ProcessStdout("api".to_string()),
+            r#"{"msg":"hello","request_id":"abc"}"#.to_string(),
+        );
+        log.apply_json_fields(&JsonLogFields::default());
+
+        let text = content_text(&log);
+        assert!(text.contains(&"hello".to_string()));
+        assert!(text.contains(&"JSON:".to_string()));
+        assert!(text.contains(&"  \"request_id\": \"abc\"".to_string()));
+    }
+
+    #[test]
+    fn test_expanded_content_omits_json_section_for_plain_lines() {
+        let log = LogLine::new(LogSource::ProcessStdout("api".to_string()), "plain".to_string());
+        assert!(!content_text(&log).contains(&"JSON:".to_string()));
+    }
+
+    #[test]
+    fn test_expanded_content_shows_source_file() {
+        let log = LogLine::new(
+            LogSource::File { process_name: "rails".to_string(), path: "/var/log/rails.log".into() },
+            "Started GET /".to_string(),
+        );
+        assert!(content_text(&log).contains(&"Source: /var/log/rails.log".to_string()));
+
+        let stdout = LogLine::new(LogSource::ProcessStdout("rails".to_string()), "Started GET /".to_string());
+        assert!(!content_text(&stdout).iter().any(|line| line.starts_with("Source:")));
+    }
+
+    #[test]
+    fn test_expanded_content_shows_process_run() {
+        let log = LogLine::new(LogSource::ProcessStdout("worker".to_string()), "job done".to_string()).with_run(2);
+        assert!(content_text(&log).contains(&"Run: #2".to_string()));
+
+        let untagged = LogLine::new(LogSource::ProcessStdout("worker".to_string()), "job done".to_string());
+        assert!(!content_text(&untagged).iter().any(|line| line.starts_with("Run:")));
+    }
+
+    #[test]
+    fn test_expanded_content_shows_raw_line_before_prefix_strip() {
+        let raw = "[2024-12-10 12:00:00] INFO foo";
+        let mut log = LogLine::new(LogSource::ProcessStdout("rails".to_string()), raw.to_string());
+        log.strip_prefix(&regex::Regex::new(r"^\[[^\]]*\] \w+ ").unwrap());
+
+        let text = content_text(&log);
+        assert!(text.contains(&"Raw:".to_string()));
+        assert!(text.contains(&raw.to_string()));
+
+        let plain = LogLine::new(LogSource::ProcessStdout("rails".to_string()), "foo".to_string());
+        assert!(!content_text(&plain).contains(&"Raw:".to_string()));
+    }
+}
@@ -0,0 +1,69 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::process::ProcessManager;
+use crate::ui::utils::centered_rect;
+
+/// Draw the `:lasterror` overlay: the lines `name` emitted just before its most
+/// recent failure, newest at the bottom
+pub fn draw_last_output(f: &mut Frame, manager: &ProcessManager, name: &str) {
+    let Some(output) = manager.final_output(name) else {
+        return;
+    };
+
+    let block = Block::default()
+        .title(format!(" {} final output ", name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red));
+
+    let area = centered_rect(80, 70, f.area());
+    let inner = block.inner(area);
+    let [header, body, footer] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(0), Constraint::Length(1)])
+        .areas(inner);
+
+    let header_line = Line::from(vec![
+        Span::styled(
+            format!("Failed at {}: ", output.failed_at.format("%H:%M:%S")),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(output.error.clone()),
+    ]);
+
+    // Keep the newest lines: the end of a stack trace matters most
+    let visible = body.height as usize;
+    let lines: Vec<Line> = if output.lines.is_empty() {
+        vec![Line::from(Span::styled("  No output before the failure", Style::default().fg(Color::DarkGray)))]
+    } else {
+        output
+            .lines
+            .iter()
+            .skip(output.lines.len().saturating_sub(visible))
+            .map(|log| {
+                let style = if log.source.is_stderr() { Style::default().fg(Color::LightRed) } else { Style::default() };
+                Line::from(vec![
+                    Span::styled(format!("[{}] ", log.timestamp.format("%H:%M:%S")), Style::default().fg(Color::DarkGray)),
+                    Span::styled(log.stripped_line().to_string(), style),
+                ])
+            })
+            .collect()
+    };
+
+    let footer_line = Line::from(vec![
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(" close"),
+    ]);
+
+    // Clear the area behind the popup
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(header_line), header);
+    f.render_widget(Paragraph::new(lines), body);
+    f.render_widget(Paragraph::new(footer_line), footer);
+}
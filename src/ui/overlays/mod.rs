@@ -1,7 +1,13 @@
 mod help;
+mod palette;
 mod expanded_line;
 mod trace_selection;
+mod process_picker;
+mod last_output;
 
 pub use help::draw_help_overlay;
+pub use palette::draw_command_palette;
 pub use expanded_line::{draw_expanded_line_overlay, draw_expanded_line_panel};
 pub use trace_selection::draw_trace_selection_overlay;
+pub use process_picker::draw_process_picker;
+pub use last_output::draw_last_output;
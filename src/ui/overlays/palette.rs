@@ -0,0 +1,89 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::ui::palette_state::PaletteState;
+use crate::ui::utils::centered_rect;
+
+/// Widest command column before descriptions stop lining up
+const MAX_KEYS_WIDTH: usize = 22;
+
+/// Draw the `Ctrl+P` command palette: query prompt, matching `:` commands, key hints
+pub fn draw_command_palette(f: &mut Frame, palette: &PaletteState) {
+    let matches = palette.matches();
+
+    let block = Block::default()
+        .title(" Commands ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let area = centered_rect(70, 60, f.area());
+    let inner = block.inner(area);
+    let [prompt, list, footer] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0), Constraint::Length(1)])
+        .areas(inner);
+
+    let prompt_line = Line::from(vec![
+        Span::styled("> ", Style::default().fg(Color::Cyan)),
+        Span::raw(palette.query.clone()),
+        Span::styled("_", Style::default().fg(Color::Cyan)),
+    ]);
+
+    let keys_width = matches
+        .iter()
+        .map(|binding| binding.keys.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(MAX_KEYS_WIDTH);
+
+    // Keep the selected entry on screen
+    let visible = list.height as usize;
+    let offset = (palette.selected + 1).saturating_sub(visible);
+
+    let lines: Vec<Line> = if matches.is_empty() {
+        vec![Line::from(Span::styled("  No matching commands", Style::default().fg(Color::DarkGray)))]
+    } else {
+        matches
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .map(|(idx, binding)| {
+                let selected = idx == palette.selected;
+                let line = Line::from(vec![
+                    Span::styled(
+                        format!("{}{:<keys_width$}", if selected { "> " } else { "  " }, binding.keys),
+                        Style::default().fg(Color::Yellow),
+                    ),
+                    Span::raw(format!("  {}", binding.description)),
+                ]);
+                if selected {
+                    line.style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    };
+
+    let footer_line = Line::from(vec![
+        Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
+        Span::raw(" select | "),
+        Span::styled("Enter", Style::default().fg(Color::Yellow)),
+        Span::raw(" run | "),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(" close"),
+    ]);
+
+    // Clear the area behind the popup
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(prompt_line), prompt);
+    f.render_widget(Paragraph::new(lines), list);
+    f.render_widget(Paragraph::new(footer_line), footer);
+}
@@ -0,0 +1,94 @@
+use ratatui::{
+    layout::{Constraint, Direction, Layout},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::process::ProcessManager;
+use crate::ui::process_picker_state::{picker_processes, ProcessPickerState};
+use crate::ui::utils::centered_rect;
+use crate::ui::widgets::{status_color, status_label};
+
+/// Draw the `m` process picker: every managed process with its status, and the
+/// keys that act on the highlighted one
+pub fn draw_process_picker(f: &mut Frame, manager: &ProcessManager, picker: &ProcessPickerState, pinned: &[String]) {
+    let names = picker_processes(manager, pinned);
+
+    let block = Block::default()
+        .title(" Processes ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan));
+
+    let area = centered_rect(60, 60, f.area());
+    let inner = block.inner(area);
+    let [list, footer] = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .areas(inner);
+
+    let name_width = names.iter().map(|name| name.chars().count()).max().unwrap_or(0);
+    let status_width = names
+        .iter()
+        .filter_map(|name| manager.get_status(name))
+        .map(|status| status_label(&status).chars().count())
+        .max()
+        .unwrap_or(0);
+
+    // Keep the selected entry on screen
+    let visible = list.height as usize;
+    let offset = (picker.selected + 1).saturating_sub(visible);
+
+    let lines: Vec<Line> = if names.is_empty() {
+        vec![Line::from(Span::styled("  No processes", Style::default().fg(Color::DarkGray)))]
+    } else {
+        names
+            .iter()
+            .enumerate()
+            .skip(offset)
+            .take(visible)
+            .map(|(idx, name)| {
+                let selected = idx == picker.selected;
+                let status = manager.get_status(name);
+                let status_text = status.as_ref().map(status_label).unwrap_or_default();
+                let mut spans = vec![
+                    Span::raw(format!("{}{:<name_width$}", if selected { "> " } else { "  " }, name)),
+                    Span::styled(
+                        format!("  {:<status_width$}", status_text),
+                        Style::default().fg(status.as_ref().map(status_color).unwrap_or(Color::DarkGray)),
+                    ),
+                ];
+                // What was launched, so Procfile mistakes are visible before restarting
+                if let Some(handle) = manager.get_processes().get(name.as_str()) {
+                    spans.push(Span::styled(format!("  {}", handle.command), Style::default().fg(Color::DarkGray)));
+                }
+                let line = Line::from(spans);
+                if selected {
+                    line.style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    };
+
+    let footer_line = Line::from(vec![
+        Span::styled("↑/↓", Style::default().fg(Color::Yellow)),
+        Span::raw(" select | "),
+        Span::styled("r", Style::default().fg(Color::Yellow)),
+        Span::raw(" restart | "),
+        Span::styled("k", Style::default().fg(Color::Yellow)),
+        Span::raw(" kill | "),
+        Span::styled("s", Style::default().fg(Color::Yellow)),
+        Span::raw(" start | "),
+        Span::styled("Esc", Style::default().fg(Color::Yellow)),
+        Span::raw(" close"),
+    ]);
+
+    // Clear the area behind the popup
+    f.render_widget(Clear, area);
+    f.render_widget(block, area);
+    f.render_widget(Paragraph::new(lines), list);
+    f.render_widget(Paragraph::new(footer_line), footer);
+}
@@ -0,0 +1,169 @@
+use super::keybindings::{Binding, KEYBINDINGS};
+
+/// What choosing a palette entry does
+#[derive(Debug, Clone, PartialEq)]
+pub enum PaletteInvocation {
+    /// Run this command text right away
+    Run(String),
+    /// Open command mode with this text, waiting for an argument
+    Prefill(String),
+}
+
+/// State of the `Ctrl+P` command palette
+#[derive(Debug, Default)]
+pub struct PaletteState {
+    pub open: bool,
+    /// Fuzzy filter typed into the palette
+    pub query: String,
+    /// Index into `matches()`
+    pub selected: usize,
+}
+
+impl PaletteState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self) {
+        let count = self.matches().len();
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    /// `:` commands from the keybinding map matching the query, best first
+    pub fn matches(&self) -> Vec<&'static Binding> {
+        palette_matches(&self.query)
+    }
+
+    /// What the highlighted entry does, if anything matches
+    pub fn selected_invocation(&self) -> Option<PaletteInvocation> {
+        self.matches().get(self.selected).map(|binding| invocation(binding.keys))
+    }
+}
+
+/// Every `:` command in the keybinding map, in help-overlay order
+fn palette_commands() -> impl Iterator<Item = &'static Binding> {
+    KEYBINDINGS
+        .iter()
+        .flat_map(|section| section.bindings.iter())
+        .filter(|binding| binding.keys.starts_with(':') && binding.keys.len() > 1)
+}
+
+/// Commands matching `query`: substring matches on the command, then on the
+/// description, then fuzzy (in-order subsequence) matches on either
+pub fn palette_matches(query: &str) -> Vec<&'static Binding> {
+    let query = query.to_lowercase();
+    let mut scored: Vec<(u8, &'static Binding)> = palette_commands()
+        .filter_map(|binding| match_rank(binding, &query).map(|rank| (rank, binding)))
+        .collect();
+    scored.sort_by_key(|(rank, _)| *rank);
+    scored.into_iter().map(|(_, binding)| binding).collect()
+}
+
+fn match_rank(binding: &Binding, query: &str) -> Option<u8> {
+    let keys = binding.keys.to_lowercase();
+    let description = binding.description.to_lowercase();
+    if keys.contains(query) {
+        Some(0)
+    } else if description.contains(query) {
+        Some(1)
+    } else if is_subsequence(query, &keys) || is_subsequence(query, &description) {
+        Some(2)
+    } else {
+        None
+    }
+}
+
+fn is_subsequence(needle: &str, haystack: &str) -> bool {
+    let mut haystack = haystack.chars();
+    needle.chars().all(|c| haystack.any(|h| h == c))
+}
+
+/// Turn a keybinding like `:hide <proc|group>` into what the palette does with it:
+/// a placeholder or a list of choices prefills command mode up to that point,
+/// an optional `[arg]` runs the bare command
+pub fn invocation(keys: &str) -> PaletteInvocation {
+    let mut words = keys.trim_start_matches(':').split_whitespace();
+    let command = words.next().unwrap_or_default();
+    let mut text = command.split('/').next().unwrap_or(command).to_string();
+    for word in words {
+        if word.starts_with('[') {
+            break;
+        }
+        if word.starts_with('<') || word.contains('/') {
+            text.push(' ');
+            return PaletteInvocation::Prefill(text);
+        }
+        text.push(' ');
+        text.push_str(word);
+    }
+    PaletteInvocation::Run(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arguments_prefill_and_optional_arguments_run() {
+        assert_eq!(invocation(":hide <proc|group>"), PaletteInvocation::Prefill("hide ".to_string()));
+        assert_eq!(invocation(":g/:goto <time>"), PaletteInvocation::Prefill("g ".to_string()));
+        assert_eq!(invocation(":bw fast/medium/slow"), PaletteInvocation::Prefill("bw ".to_string()));
+        assert_eq!(invocation(":r [proc|group]"), PaletteInvocation::Run("r".to_string()));
+        assert_eq!(invocation(":hide all"), PaletteInvocation::Run("hide all".to_string()));
+        assert_eq!(invocation(":q/:quit/:exit"), PaletteInvocation::Run("q".to_string()));
+    }
+
+    #[test]
+    fn exact_matches_rank_before_fuzzy_ones() {
+        let keys: Vec<&str> = palette_matches("fc").iter().map(|b| b.keys).collect();
+        assert_eq!(keys.first(), Some(&":fc"));
+        assert!(keys.len() > 1, "fuzzy matches follow: {:?}", keys);
+    }
+
+    #[test]
+    fn only_colon_commands_are_listed() {
+        assert!(palette_matches("").iter().all(|b| b.keys.starts_with(':') && b.keys.len() > 1));
+        assert!(palette_matches("zzzz").is_empty());
+    }
+
+    #[test]
+    fn selection_stays_within_matches() {
+        let mut palette = PaletteState::new();
+        palette.open();
+        for c in "unsplit".chars() {
+            palette.push_char(c);
+        }
+        palette.select_next();
+        palette.select_next();
+        assert_eq!(palette.matches().len(), 1);
+        assert_eq!(palette.selected_invocation(), Some(PaletteInvocation::Run("unsplit".to_string())));
+    }
+}
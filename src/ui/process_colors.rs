@@ -28,18 +28,33 @@ impl ProcessColors {
         all_names.sort();
         all_names.dedup();
 
-        let palette = theme.process_palette;
         let fallback = theme.fallback_process;
-        let palette_len = palette.len().max(1);
-        for (idx, name) in all_names.iter().enumerate() {
-            let default_color = palette
-                .get(idx % palette_len)
-                .copied()
-                .unwrap_or(fallback);
-            let color = if let Some(color_name) = config_colors.get(*name) {
-                parse_color_name(color_name).unwrap_or(default_color)
-            } else {
-                default_color
+        let overrides: HashMap<&String, Color> = all_names
+            .iter()
+            .filter_map(|name| Some((*name, parse_color_name(config_colors.get(*name)?)?)))
+            .collect();
+
+        // The rest take palette colors in order, skipping ones claimed by overrides
+        // (unless every palette color is claimed)
+        let mut palette: Vec<Color> = theme
+            .process_palette
+            .iter()
+            .copied()
+            .filter(|color| !overrides.values().any(|claimed| claimed == color))
+            .collect();
+        if palette.is_empty() {
+            palette = theme.process_palette.to_vec();
+        }
+        let mut auto_assigned = 0;
+
+        for name in &all_names {
+            let color = match overrides.get(*name) {
+                Some(color) => *color,
+                None => {
+                    let color = palette.get(auto_assigned % palette.len().max(1)).copied().unwrap_or(fallback);
+                    auto_assigned += 1;
+                    color
+                }
             };
             assignments.insert(
                 (*name).clone(),
@@ -63,6 +78,20 @@ impl ProcessColors {
             .unwrap_or(self.fallback)
     }
 
+    /// Name of the color assigned to a process (`None` if it has no assignment,
+    /// e.g. when coloring is off). Uses config spellings like `light_blue`, or `#rrggbb`.
+    pub fn color_name(&self, name: &str) -> Option<String> {
+        self.assignments.get(name).map(|a| color_to_name(a.color))
+    }
+
+    /// Every assigned process/log file color by name, for IPC clients
+    pub fn name_map(&self) -> HashMap<String, String> {
+        self.assignments
+            .iter()
+            .map(|(name, a)| (name.clone(), color_to_name(a.color)))
+            .collect()
+    }
+
     /// Get ANSI escape sequence for the process color.
     /// Returns (start_code, reset_code) to wrap text with color.
     pub fn get_ansi(&self, name: &str) -> (&str, &'static str) {
@@ -98,7 +127,33 @@ fn color_to_ansi_start(color: Color) -> String {
     }
 }
 
-fn parse_color_name(name: &str) -> Option<Color> {
+/// Convert a ratatui Color to the name `parse_color_name` accepts (hex for RGB).
+fn color_to_name(color: Color) -> String {
+    match color {
+        Color::Red => "red".to_string(),
+        Color::Green => "green".to_string(),
+        Color::Yellow => "yellow".to_string(),
+        Color::Blue => "blue".to_string(),
+        Color::Magenta => "magenta".to_string(),
+        Color::Cyan => "cyan".to_string(),
+        Color::White => "white".to_string(),
+        Color::Gray => "gray".to_string(),
+        Color::DarkGray => "dark_gray".to_string(),
+        Color::LightRed => "light_red".to_string(),
+        Color::LightGreen => "light_green".to_string(),
+        Color::LightYellow => "light_yellow".to_string(),
+        Color::LightBlue => "light_blue".to_string(),
+        Color::LightMagenta => "light_magenta".to_string(),
+        Color::LightCyan => "light_cyan".to_string(),
+        Color::Black => "black".to_string(),
+        Color::Rgb(r, g, b) => format!("#{:02x}{:02x}{:02x}", r, g, b),
+        Color::Indexed(i) => format!("indexed:{}", i),
+        _ => "default".to_string(),
+    }
+}
+
+/// Parse a config color name (`blue`, `light_green`, `dark_gray`, ...)
+pub fn parse_color_name(name: &str) -> Option<Color> {
     match name.to_lowercase().as_str() {
         "red" => Some(Color::Red),
         "green" => Some(Color::Green),
@@ -174,7 +229,25 @@ mod tests {
         );
 
         assert_eq!(colors.get("api"), Color::Magenta);
-        assert_eq!(colors.get("web"), Color::Yellow); // default palette, second position
+        assert_eq!(colors.get("web"), Color::Green); // first auto-assigned name takes the first palette color
+    }
+
+    #[test]
+    fn test_configured_colors_are_not_reused_by_auto_assignment() {
+        let process_names: Vec<String> = ["api", "db", "web", "worker"].iter().map(|s| s.to_string()).collect();
+        let mut config_colors = HashMap::new();
+        config_colors.insert("db".to_string(), "blue".to_string());
+        config_colors.insert("worker".to_string(), "Green".to_string());
+
+        let colors = ProcessColors::new(&process_names, &[], &config_colors, &Theme::dark());
+
+        assert_eq!(colors.get("db"), Color::Blue);
+        assert_eq!(colors.get("worker"), Color::Green);
+        // The others skip green and blue
+        assert_eq!(colors.get("api"), Color::Yellow);
+        assert_eq!(colors.get("web"), Color::Cyan);
+        let assigned: std::collections::HashSet<Color> = process_names.iter().map(|name| colors.get(name)).collect();
+        assert_eq!(assigned.len(), 4, "no two processes share a color");
     }
 
     #[test]
@@ -274,6 +347,34 @@ mod tests {
         assert_eq!(colors1.get("rails"), colors2.get("rails"));
     }
 
+    #[test]
+    fn test_color_names_are_stable_across_restarts() {
+        // Same name set in a different order (as HashMap iteration might give) yields the same map
+        let first = ProcessColors::new(
+            &["worker".to_string(), "web".to_string(), "api".to_string()],
+            &["rails".to_string()],
+            &HashMap::new(),
+            &Theme::dark(),
+        );
+        let second = ProcessColors::new(
+            &["api".to_string(), "web".to_string(), "worker".to_string()],
+            &["rails".to_string()],
+            &HashMap::new(),
+            &Theme::dark(),
+        );
+
+        assert_eq!(first.name_map(), second.name_map());
+        assert_eq!(first.color_name("api").as_deref(), Some("green"));
+        assert_eq!(first.color_name("rails").as_deref(), Some("yellow"));
+        assert_eq!(first.color_name("unknown"), None);
+    }
+
+    #[test]
+    fn test_rgb_color_name_is_hex() {
+        let colors = ProcessColors::new(&["api".to_string()], &[], &HashMap::new(), &Theme::light());
+        assert_eq!(colors.color_name("api").as_deref(), Some("#076678"));
+    }
+
     #[test]
     fn test_invalid_config_color_uses_default() {
         let process_names = vec!["api".to_string()];
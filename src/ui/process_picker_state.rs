@@ -0,0 +1,73 @@
+use crate::process::ProcessManager;
+
+/// State of the `m` process picker overlay
+#[derive(Debug, Default)]
+pub struct ProcessPickerState {
+    pub open: bool,
+    /// Index into `picker_processes()`
+    pub selected: usize,
+}
+
+impl ProcessPickerState {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn open(&mut self) {
+        self.open = true;
+        self.selected = 0;
+    }
+
+    pub fn close(&mut self) {
+        self.open = false;
+        self.selected = 0;
+    }
+
+    pub fn select_prev(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    pub fn select_next(&mut self, count: usize) {
+        if self.selected + 1 < count {
+            self.selected += 1;
+        }
+    }
+
+    /// Name of the highlighted process, if there are any
+    pub fn selected_process(&self, manager: &ProcessManager, pinned: &[String]) -> Option<String> {
+        picker_processes(manager, pinned).into_iter().nth(self.selected)
+    }
+}
+
+/// Managed processes in process-list order: pinned first (in pin order), then
+/// alphabetical. Log files are left out since they can't be controlled.
+pub fn picker_processes(manager: &ProcessManager, pinned: &[String]) -> Vec<String> {
+    let mut names: Vec<String> = manager.get_processes().keys().cloned().collect();
+    names.sort();
+    names.sort_by_key(|name| pinned.iter().position(|p| p == name).unwrap_or(usize::MAX));
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pinned_processes_come_first_and_selection_stays_in_range() {
+        let mut manager = ProcessManager::new();
+        for name in ["web", "worker", "api"] {
+            manager.add_process(name.to_string(), "sleep 10".to_string(), None, None, None);
+        }
+        let pinned = vec!["worker".to_string()];
+        assert_eq!(picker_processes(&manager, &pinned), vec!["worker", "api", "web"]);
+
+        let mut picker = ProcessPickerState::new();
+        picker.open();
+        picker.select_prev();
+        assert_eq!(picker.selected_process(&manager, &pinned).as_deref(), Some("worker"));
+        for _ in 0..5 {
+            picker.select_next(3);
+        }
+        assert_eq!(picker.selected_process(&manager, &pinned).as_deref(), Some("web"));
+    }
+}
@@ -1,4 +1,7 @@
-use ratatui::style::Color;
+use ratatui::style::{Color, Modifier};
+
+/// Built-in themes, as accepted by `--theme` and the `theme` config value
+pub const THEMES: &[&str] = &["dark", "light", "high-contrast"];
 
 const DARK_PROCESS_PALETTE: &[Color] = &[
     Color::Green,
@@ -25,6 +28,16 @@ const LIGHT_PROCESS_PALETTE: &[Color] = &[
     Color::Rgb(0x66, 0x5c, 0x54),  // brown / fg3
 ];
 
+// Bright ANSI colors only, so every name reads against a black background.
+const HIGH_CONTRAST_PROCESS_PALETTE: &[Color] = &[
+    Color::LightGreen,
+    Color::LightYellow,
+    Color::LightCyan,
+    Color::LightMagenta,
+    Color::LightBlue,
+    Color::White,
+];
+
 #[derive(Debug, Clone, Copy)]
 pub struct Theme {
     pub footer_bg: Color,
@@ -38,6 +51,10 @@ pub struct Theme {
     pub cursor_bg: Color,
     pub cursor_fg: Color,
     pub truncation_hint_fg: Color,
+    /// Rules between log sections and process-list cells
+    pub separator: Color,
+    /// Added to all footer text (bold in `high_contrast`)
+    pub footer_modifier: Modifier,
     pub process_palette: &'static [Color],
     pub fallback_process: Color,
 }
@@ -56,6 +73,8 @@ impl Theme {
             cursor_bg: Color::Blue,
             cursor_fg: Color::White,
             truncation_hint_fg: Color::Cyan,
+            separator: Color::DarkGray,
+            footer_modifier: Modifier::empty(),
             process_palette: DARK_PROCESS_PALETTE,
             fallback_process: Color::White,
         }
@@ -76,14 +95,39 @@ impl Theme {
             cursor_bg: Color::Rgb(0x07, 0x66, 0x78),       // faded blue
             cursor_fg: Color::Rgb(0xfb, 0xf1, 0xc7),       // bg0
             truncation_hint_fg: Color::Black,
+            separator: Color::Rgb(0x92, 0x83, 0x74),       // gray
+            footer_modifier: Modifier::empty(),
             process_palette: LIGHT_PROCESS_PALETTE,
             fallback_process: Color::Rgb(0x3c, 0x38, 0x36),
         }
     }
 
+    // Black and white with bright accents and bold footer text, for
+    // accessibility; works on dark and light terminals alike.
+    pub fn high_contrast() -> Self {
+        Self {
+            footer_bg: Color::Black,
+            footer_fg: Color::White,
+            muted: Color::White,
+            accent: Color::LightYellow,
+            success: Color::LightGreen,
+            error: Color::LightRed,
+            info: Color::LightCyan,
+            selection_bg: Color::Blue,
+            cursor_bg: Color::Yellow,
+            cursor_fg: Color::Black,
+            truncation_hint_fg: Color::LightCyan,
+            separator: Color::White,
+            footer_modifier: Modifier::BOLD,
+            process_palette: HIGH_CONTRAST_PROCESS_PALETTE,
+            fallback_process: Color::White,
+        }
+    }
+
     pub fn from_config(value: Option<&str>) -> Self {
         match value.map(|s| s.to_lowercase()).as_deref() {
             Some("light") => Self::light(),
+            Some("high-contrast" | "high_contrast") => Self::high_contrast(),
             _ => Self::dark(),
         }
     }
@@ -132,6 +176,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_config_parses_high_contrast() {
+        for name in ["high-contrast", "high_contrast"] {
+            let theme = Theme::from_config(Some(name));
+            assert_eq!(theme.footer_bg, Color::Black);
+            assert_eq!(theme.footer_modifier, Modifier::BOLD);
+        }
+    }
+
     #[test]
     fn light_palette_first_color_is_gruvbox_blue() {
         assert_eq!(
@@ -32,6 +32,12 @@ pub struct TraceState {
     pub manual_trace_recording: bool,
     /// When manual trace recording started
     pub manual_trace_start: Option<DateTime<Local>>,
+    /// ID of the recording in progress (e.g. "rec-3")
+    pub manual_trace_id: Option<String>,
+    /// Optional label given when the recording was started over IPC
+    pub manual_trace_label: Option<String>,
+    /// Recordings started this session, used to number their IDs
+    pub manual_trace_count: usize,
 }
 
 impl TraceState {
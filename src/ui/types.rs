@@ -1,7 +1,73 @@
+use std::time::{Duration, Instant};
+
 /// Type of status message
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum StatusType {
     Success,
     Error,
     Info,
 }
+
+impl StatusType {
+    /// Parse `info`, `success` or `error` (the `set_status` IPC `type`)
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "info" => Some(Self::Info),
+            "success" => Some(Self::Success),
+            "error" => Some(Self::Error),
+            _ => None,
+        }
+    }
+}
+
+/// How long a destructive action waits for confirmation
+pub const CONFIRM_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// A destructive action that needs confirmation (when `confirm_destructive`
+/// or, for a bare `q`, `confirm_quit` is set)
+#[derive(Debug, Clone, PartialEq)]
+pub enum PendingAction {
+    Kill(String),
+    RestartAll,
+    /// Quit, killing this many running processes
+    Quit(usize),
+}
+
+impl PendingAction {
+    /// Short description for status messages, e.g. "kill web"
+    pub fn describe(&self) -> String {
+        match self {
+            PendingAction::Kill(name) => format!("kill {}", name),
+            PendingAction::RestartAll => "restart all".to_string(),
+            PendingAction::Quit(running) => format!("quit and kill {} processes", running),
+        }
+    }
+
+    /// The key that confirms: `q` again for quit, `y` otherwise
+    pub fn confirm_key(&self) -> char {
+        match self {
+            PendingAction::Quit(_) => 'q',
+            _ => 'y',
+        }
+    }
+}
+
+/// A pending action and when it was requested
+#[derive(Debug, Clone)]
+pub struct PendingConfirmation {
+    pub action: PendingAction,
+    pub requested_at: Instant,
+}
+
+impl PendingConfirmation {
+    pub fn new(action: PendingAction) -> Self {
+        Self {
+            action,
+            requested_at: Instant::now(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.requested_at.elapsed() > CONFIRM_TIMEOUT
+    }
+}
@@ -46,19 +46,19 @@ pub fn parse_ansi_to_spans(text: &str) -> Vec<(String, Style)> {
 
 /// Truncate spans to fit within a target display width, preserving styles.
 /// Returns the truncated spans - caller should append any suffix (like "… ↵").
+/// Stops at the first character that doesn't fit, so a wide character at the
+/// edge leaves a gap rather than letting later, narrower text through.
 pub fn truncate_spans(spans: &[(String, Style)], target_width: usize) -> Vec<(String, Style)> {
     let mut result = Vec::new();
     let mut current_width = 0;
 
     for (content, style) in spans {
-        if current_width >= target_width {
-            break;
-        }
-
         let mut span_text = String::new();
+        let mut full = false;
         for ch in content.chars() {
             let char_width = UnicodeWidthChar::width(ch).unwrap_or(0);
             if current_width + char_width > target_width {
+                full = true;
                 break;
             }
             span_text.push(ch);
@@ -69,7 +69,7 @@ pub fn truncate_spans(spans: &[(String, Style)], target_width: usize) -> Vec<(St
             result.push((span_text, *style));
         }
 
-        if current_width >= target_width {
+        if full {
             break;
         }
     }
@@ -77,6 +77,38 @@ pub fn truncate_spans(spans: &[(String, Style)], target_width: usize) -> Vec<(St
     result
 }
 
+/// Drop the first `columns` display columns from spans, preserving styles.
+/// A wide character cut by the edge becomes a space for its visible half so
+/// later text stays in its column.
+pub fn skip_spans(spans: &[(String, Style)], columns: usize) -> Vec<(String, Style)> {
+    let mut result = Vec::new();
+    let mut skipped = 0;
+
+    for (content, style) in spans {
+        if skipped >= columns {
+            result.push((content.clone(), *style));
+            continue;
+        }
+        let mut span_text = String::new();
+        for ch in content.chars() {
+            if skipped >= columns {
+                span_text.push(ch);
+                continue;
+            }
+            let char_width = UnicodeWidthChar::width(ch).unwrap_or(0);
+            skipped += char_width;
+            if skipped > columns {
+                span_text.push_str(&" ".repeat(skipped - columns));
+            }
+        }
+        if !span_text.is_empty() {
+            result.push((span_text, *style));
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use crate::log::condense_log_line;
@@ -229,4 +261,35 @@ mod tests {
         let result = truncate_spans(&spans, 0);
         assert!(result.is_empty());
     }
+
+    #[test]
+    fn test_truncate_spans_stops_at_wide_char_that_does_not_fit() {
+        use super::truncate_spans;
+        use ratatui::style::Style;
+
+        // "日" needs 2 columns but only 1 is left; "x" must not slip in after it
+        let spans = vec![("abc日".to_string(), Style::default()), ("x".to_string(), Style::default())];
+        let result = truncate_spans(&spans, 4);
+        assert_eq!(result, vec![("abc".to_string(), Style::default())]);
+    }
+
+    #[test]
+    fn test_skip_spans_keeps_styles_after_the_offset() {
+        use super::skip_spans;
+        use ratatui::style::{Color, Style};
+
+        let red = Style::default().fg(Color::Red);
+        let spans = vec![("abc".to_string(), Style::default()), ("def".to_string(), red)];
+        assert_eq!(skip_spans(&spans, 4), vec![("ef".to_string(), red)]);
+        assert!(skip_spans(&spans, 10).is_empty());
+    }
+
+    #[test]
+    fn test_skip_spans_pads_a_cut_wide_char() {
+        use super::skip_spans;
+        use ratatui::style::Style;
+
+        let spans = vec![("a日x".to_string(), Style::default())];
+        assert_eq!(skip_spans(&spans, 2), vec![(" x".to_string(), Style::default())]);
+    }
 }
@@ -6,9 +6,55 @@ use ratatui::{
     Frame,
 };
 
+use unicode_width::UnicodeWidthStr;
+
 use crate::ui::app::App;
 use crate::ui::types::StatusType;
 
+/// Slice `input` into (before cursor, char under cursor, after cursor) so the
+/// cursor cell stays visible within `width` columns. Text before the cursor is
+/// dropped from the left as needed; the cursor at the end takes one cell.
+fn visible_input(input: &str, cursor: usize, width: usize) -> (&str, &str, &str) {
+    let cursor_len = input[cursor..].chars().next().map_or(0, char::len_utf8);
+    let under = &input[cursor..cursor + cursor_len];
+    let cursor_width = under.width().max(1);
+
+    let mut start = 0;
+    while start < cursor && input[start..cursor].width() + cursor_width > width {
+        start += input[start..].chars().next().map_or(1, char::len_utf8);
+    }
+
+    let mut room = width.saturating_sub(input[start..cursor].width() + cursor_width);
+    let rest = &input[cursor + cursor_len..];
+    let mut end = 0;
+    for c in rest.chars() {
+        let w = c.to_string().width();
+        if w > room {
+            break;
+        }
+        room -= w;
+        end += c.len_utf8();
+    }
+
+    (&input[start..cursor], under, &rest[..end])
+}
+
+/// Prompt, input and cursor spans, scrolled horizontally to fit `width`
+fn input_spans<'a>(prompt: &'a str, input: &'a str, cursor: usize, width: usize, color: Color, text_fg: Color) -> Vec<Span<'a>> {
+    let (before, under, after) = visible_input(input, cursor, width.saturating_sub(prompt.width()));
+    let cursor_span = if under.is_empty() {
+        Span::styled("_", Style::default().fg(color))
+    } else {
+        Span::styled(under, Style::default().fg(text_fg).add_modifier(Modifier::REVERSED))
+    };
+    vec![
+        Span::styled(prompt, Style::default().fg(color)),
+        Span::styled(before, Style::default().fg(text_fg)),
+        cursor_span,
+        Span::styled(after, Style::default().fg(text_fg)),
+    ]
+}
+
 /// Draw the command input at the bottom of the screen
 pub fn draw_command_input(f: &mut Frame, area: Rect, app: &App) {
     let muted = app.theme.muted;
@@ -16,25 +62,30 @@ pub fn draw_command_input(f: &mut Frame, area: Rect, app: &App) {
     let footer_fg = app.theme.footer_fg;
     let prompt = app.theme.success;
 
+    let width = area.width as usize;
+    let input = app.input.input.as_str();
+    let cursor = app.input.cursor();
+
     let text = if app.input.search_mode {
-        // Show search input with a cursor and help text
-        Line::from(vec![
-            Span::styled("/", Style::default().fg(Color::Cyan)),
-            Span::styled(app.input.input.as_str(), Style::default().fg(footer_fg)),
-            Span::styled("_", Style::default().fg(Color::Cyan)),
+        // Show search input with a cursor, plus help text while it fits
+        let help = vec![
             Span::styled("  (", Style::default().fg(muted)),
             Span::styled("Enter", Style::default().fg(accent)),
             Span::styled(" to select | ", Style::default().fg(muted)),
             Span::styled("Esc", Style::default().fg(accent)),
             Span::styled(" to cancel)", Style::default().fg(muted)),
-        ])
+        ];
+        let help_width: usize = help.iter().map(|span| span.width()).sum();
+        if 1 + input.width() + 1 + help_width <= width {
+            let mut spans = input_spans("/", input, cursor, width, Color::Cyan, footer_fg);
+            spans.extend(help);
+            Line::from(spans)
+        } else {
+            Line::from(input_spans("/", input, cursor, width, Color::Cyan, footer_fg))
+        }
     } else if app.input.command_mode {
         // Show the input with a cursor
-        Line::from(vec![
-            Span::styled(":", Style::default().fg(prompt)),
-            Span::styled(app.input.input.as_str(), Style::default().fg(footer_fg)),
-            Span::styled("_", Style::default().fg(prompt)),
-        ])
+        Line::from(input_spans(":", input, cursor, width, prompt, footer_fg))
     } else if let Some((message, status_type)) = &app.display.status_message {
         // Show color-coded status message
         let color = match status_type {
@@ -67,7 +118,34 @@ pub fn draw_command_input(f: &mut Frame, area: Rect, app: &App) {
 
     let paragraph = Paragraph::new(text)
         .block(Block::default().borders(Borders::NONE))
-        .style(Style::default().bg(app.theme.footer_bg).fg(footer_fg));
+        .style(Style::default().bg(app.theme.footer_bg).fg(footer_fg).add_modifier(app.theme.footer_modifier));
 
     f.render_widget(paragraph, area);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn visible_input_fits_without_scrolling() {
+        assert_eq!(visible_input("restart web", 11, 20), ("restart web", "", ""));
+        assert_eq!(visible_input("restart web", 3, 20), ("res", "t", "art web"));
+    }
+
+    #[test]
+    fn visible_input_scrolls_to_keep_cursor_in_view() {
+        // Cursor at the end: keep the tail plus one cell for the cursor
+        assert_eq!(visible_input("abcdefghij", 10, 5), ("ghij", "", ""));
+        // Cursor at the start: show the head
+        assert_eq!(visible_input("abcdefghij", 0, 5), ("", "a", "bcde"));
+    }
+
+    #[test]
+    fn visible_input_counts_wide_chars_by_display_width() {
+        // Each emoji is two columns wide
+        let input = "🚀🚀🚀";
+        assert_eq!(visible_input(input, input.len(), 5), ("🚀🚀", "", ""));
+        assert_eq!(visible_input(input, 4, 4), ("🚀", "🚀", ""));
+    }
+}
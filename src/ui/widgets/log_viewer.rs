@@ -1,5 +1,8 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Local};
 use ratatui::{
-    layout::Rect,
+    layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Wrap},
@@ -7,29 +10,195 @@ use ratatui::{
 };
 use unicode_width::UnicodeWidthStr;
 
-use crate::log::LogLine;
-use crate::process::ProcessManager;
+use crate::log::{LogLine, SeverityMatcher};
+use crate::process::{ProcessManager, RestartMarker};
 use crate::ui::Theme;
 use crate::ui::ansi_cache::{AnsiCache, AnsiCacheKey};
 use crate::ui::app::App;
+use crate::ui::batch::detect_batches_from_logs;
 use crate::ui::batch_cache::BatchCacheKey;
-use crate::ui::display_state::TimestampMode;
-use crate::ui::filter::FilterType;
+use crate::ui::display_state::{DisplayState, TimestampMode};
+use crate::ui::highlight::highlight_spans;
+use crate::ui::process_colors::ProcessColors;
+use crate::ui::utils::parse_ansi_to_spans;
+
+/// Rows reserved by the tail preview: a divider plus the newest line
+const TAIL_PREVIEW_ROWS: usize = 2;
+
+/// Center `text` in a `width`-column rule of `─`, as used for batch and restart separators
+fn separator_line(text: &str, width: usize) -> String {
+    let padding_needed = width.saturating_sub(text.width());
+    let left_padding = padding_needed / 2;
+    let right_padding = padding_needed - left_padding;
+    format!("{}{}{}", "─".repeat(left_padding), text, "─".repeat(right_padding))
+}
 
-/// Calculate the display width of a log line (without ANSI codes)
-fn calculate_line_width(log: &LogLine, timestamp_mode: TimestampMode, is_compact: bool) -> usize {
-    let timestamp_part = match timestamp_mode {
-        TimestampMode::Seconds => format!("[{}] ", log.formatted_timestamp()),
+/// A separator drawn between log lines at a point in time: a restart, or a
+/// run of collapsed quiet-startup boot output
+struct ViewMarker {
+    time: DateTime<Local>,
+    text: String,
+    style: Style,
+}
+
+impl ViewMarker {
+    fn restart(marker: &RestartMarker) -> Self {
+        Self {
+            time: marker.time,
+            text: format!(" {} restarting ", marker.process),
+            style: Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        }
+    }
+
+    fn booting(process: &str, time: DateTime<Local>, lines: usize) -> Self {
+        Self {
+            time,
+            text: format!(" {} booting… ({} lines) ", process, lines),
+            style: Style::default().fg(Color::DarkGray),
+        }
+    }
+
+    fn line(&self, width: usize) -> Line<'static> {
+        Line::from(Span::styled(separator_line(&self.text, width), self.style))
+    }
+}
+
+/// Date divider label when `curr` is on a different calendar day than `prev`
+fn date_divider(prev: &LogLine, curr: &LogLine) -> Option<String> {
+    let day = curr.timestamp.date_naive();
+    (prev.timestamp.date_naive() != day).then(|| format!(" {} ", day.format("%Y-%m-%d")))
+}
+
+/// Sticky header text for the top visible line: "Batch 4 · 2024-12-10 · web"
+fn sticky_context(log: &LogLine, batch: Option<usize>) -> String {
+    let mut parts = Vec::with_capacity(3);
+    if let Some(batch) = batch {
+        parts.push(format!("Batch {}", batch + 1));
+    }
+    parts.push(log.timestamp.format("%Y-%m-%d").to_string());
+    parts.push(log.source.process_name().to_string());
+    format!(" {} ", parts.join(" · "))
+}
+
+/// Markers placed after `after` (or from the start if None) and no later than `until` (or ever)
+fn markers_between(
+    markers: &[ViewMarker],
+    after: Option<DateTime<Local>>,
+    until: Option<DateTime<Local>>,
+) -> impl Iterator<Item = &ViewMarker> {
+    markers.iter().filter(move |m| {
+        after.is_none_or(|a| m.time > a) && until.is_none_or(|u| m.time <= u)
+    })
+}
+
+/// Markers follow process visibility and the split pane
+fn marker_visible(app: &App, pane: Option<&str>, process: &str) -> bool {
+    !app.filters.hidden_processes.contains(process) && pane.is_none_or(|p| p == process)
+}
+
+/// One "booting…" marker per boot, at its first collapsed line. Errors and
+/// warnings (per `severity`) shown mid-boot don't end the run; the readiness line does.
+fn boot_markers<'a>(
+    logs: impl Iterator<Item = &'a LogLine>,
+    severity: &SeverityMatcher,
+    visible: impl Fn(&str) -> bool,
+) -> Vec<ViewMarker> {
+    let mut runs: Vec<(&str, DateTime<Local>, usize)> = Vec::new();
+    let mut open: HashMap<&str, usize> = HashMap::new();
+    for log in logs {
+        let process = log.source.process_name();
+        if !log.quiet {
+            if !open.is_empty() && severity.detect_lowercase(log.line_lowercase()).is_none() {
+                open.remove(process);
+            }
+        } else if visible(process) {
+            match open.get(process) {
+                Some(&run) => runs[run].2 += 1,
+                None => {
+                    open.insert(process, runs.len());
+                    runs.push((process, log.arrival_time, 1));
+                }
+            }
+        }
+    }
+    runs.into_iter()
+        .map(|(process, time, lines)| ViewMarker::booting(process, time, lines))
+        .collect()
+}
+
+/// Format elapsed time for relative timestamps: "+1.2s", "+3m05s", "+2h10m"
+fn format_elapsed(elapsed: chrono::Duration) -> String {
+    let millis = elapsed.num_milliseconds().max(0);
+    let secs = millis / 1000;
+    if secs < 60 {
+        format!("+{}.{}s", secs, (millis % 1000) / 100)
+    } else if secs < 3600 {
+        format!("+{}m{:02}s", secs / 60, secs % 60)
+    } else {
+        format!("+{}h{:02}m", secs / 3600, (secs % 3600) / 60)
+    }
+}
+
+/// Timestamp prefix ("[..] ") for a log line under the current timestamp settings.
+/// Empty when timestamps are off or `timestamp_format` is empty.
+fn timestamp_prefix(log: &LogLine, display: &DisplayState) -> String {
+    match display.timestamp_mode {
+        TimestampMode::Seconds => match display.relative_timestamp_origin {
+            Some(origin) => format!("[{}] ", format_elapsed(log.arrival_time - origin)),
+            None if log.formatted_timestamp().is_empty() => String::new(),
+            None => format!("[{}] ", log.formatted_timestamp()),
+        },
         TimestampMode::Milliseconds => format!("[{}] ", log.arrival_time.format("%H:%M:%S%.3f")),
         TimestampMode::Off => String::new(),
-    };
-    let process_part = format!("{}: ", log.source.process_name());
-    let content = if is_compact {
+    }
+}
+
+/// Gutter in front of each line when `mark_stderr` is on: a red `!` for stderr
+/// lines and blank padding for everything else, so columns stay aligned.
+/// Returns (with ANSI codes, plain).
+fn stderr_gutter(log: &LogLine, display: &DisplayState) -> (&'static str, &'static str) {
+    match (display.mark_stderr, log.source.is_stderr()) {
+        (false, _) => ("", ""),
+        (true, true) => ("\x1b[31m!\x1b[0m ", "! "),
+        (true, false) => ("  ", "  "),
+    }
+}
+
+/// Blank padding after "name: " so messages start at the same column when
+/// names are aligned to `name_width` (0 = no alignment)
+fn name_padding(name: &str, name_width: usize) -> String {
+    " ".repeat(name_width.saturating_sub(name.width()))
+}
+
+/// Width of the longest visible process or log file name when `align_messages`
+/// is on, else 0. Hidden processes and the other split pane don't count.
+fn aligned_name_width(app: &App, manager: &ProcessManager, pane: Option<&str>) -> usize {
+    if !app.display.align_messages {
+        return 0;
+    }
+    manager
+        .get_processes()
+        .keys()
+        .map(String::as_str)
+        .chain(manager.get_standalone_log_file_names().iter().map(String::as_str))
+        .filter(|name| marker_visible(app, pane, name))
+        .map(|name| name.width())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Calculate the display width of a log line (without ANSI codes)
+fn calculate_line_width(log: &LogLine, display: &DisplayState, condensed: bool, name_width: usize) -> usize {
+    let gutter = stderr_gutter(log, display).1;
+    let timestamp_part = timestamp_prefix(log, display);
+    let process_name = log.source.process_name();
+    let process_part = format!("{}: {}", process_name, name_padding(process_name, name_width));
+    let content = if condensed {
         log.condensed_stripped_line()
     } else {
-        log.stripped_line()
+        log.display_stripped_line()
     };
-    format!("{}{}{}", timestamp_part, process_part, content).width()
+    format!("{}{}{}{}", gutter, timestamp_part, process_part, content).width()
 }
 
 /// Calculate the number of visual lines a log entry will take when wrapped
@@ -66,6 +235,114 @@ fn truncation_hint_style(theme: &Theme, is_cursor: bool, is_multi_selected: bool
     }
 }
 
+/// Whether a line passes the view's filters (see `FilterState::shows_line`)
+/// and belongs in the split pane
+fn passes_view_filters(app: &App, log: &LogLine, search_lower: &str, pane: Option<&str>) -> bool {
+    // oit's own annotations show in every pane
+    app.filters.shows_line(log, search_lower)
+        && (log.source.is_annotation() || pane.is_none_or(|p| p == log.source.process_name()))
+}
+
+/// What rendering a log line needs from `App`, borrowed field by field so the
+/// caller can keep holding the navigation snapshot
+struct LineStyle<'a> {
+    display: &'a DisplayState,
+    theme: &'a Theme,
+    colors: &'a ProcessColors,
+    max_line_width: usize,
+    /// Pad names to this width (`aligned_name_width`)
+    name_width: usize,
+}
+
+impl LineStyle<'_> {
+    /// Render one log line. `batch_view` shows full (uncondensed) content; `wrap`
+    /// leaves long lines for the paragraph to wrap instead of truncating them.
+    fn render(
+        &self,
+        ansi_cache: &mut AnsiCache,
+        log: &LogLine,
+        batch_view: bool,
+        wrap: bool,
+        is_cursor: bool,
+        is_multi_selected: bool,
+    ) -> Line<'static> {
+        let process_name = log.source.process_name();
+
+        // Format timestamp based on mode
+        let timestamp_part = timestamp_prefix(log, self.display);
+
+        // Get color ANSI codes for this process/log file name
+        let (color_start, color_reset) = self.colors.get_ansi(process_name);
+
+        // Process part with ANSI color codes for cached rendering paths
+        let padding = name_padding(process_name, self.name_width);
+        let process_part_colored = format!("{}{}{}: {}", color_start, process_name, color_reset, padding);
+        // Process part without color for width calculations
+        let process_part_plain = format!("{}: {}", process_name, padding);
+
+        // Apply condensing in compact mode or with `:condense` (but not in batch view mode,
+        // which shows full content). Use cached condensed line
+        let condensed = self.display.condenses() && !batch_view;
+        let (log_content, log_content_stripped): (&str, &str) = if condensed {
+            (log.condensed_line(), log.condensed_stripped_line())
+        } else {
+            (log.display_line(), log.display_stripped_line())
+        };
+        // Strip mode drops the process's own colors before ANSI parsing
+        let log_content = if self.display.strip_ansi { log_content_stripped } else { log_content };
+
+        let (gutter_colored, gutter_plain) = stderr_gutter(log, self.display);
+
+        // Build the full line with ANSI codes preserved (includes colored process name)
+        let full_line_with_ansi = format!("{}{}{}{}", gutter_colored, timestamp_part, process_part_colored, log_content);
+
+        // For width calculations, use cached stripped content (no ANSI codes)
+        let full_line_clean = format!("{}{}{}{}", gutter_plain, timestamp_part, process_part_plain, log_content_stripped);
+
+        let (bg_color, fg_override) = line_selection_overrides(self.theme, is_cursor, is_multi_selected);
+
+        // Syntax highlighting only for lines that bring no colors of their own
+        let highlighted = self.display.highlight_syntax && log.display_stripped_line().len() == log.display_line().len();
+        let cache_key = AnsiCacheKey::new(log.id, condensed, self.display.timestamp_mode)
+            .with_name_width(self.name_width)
+            .with_highlight(highlighted);
+        let cached = if highlighted {
+            ansi_cache.get_or_insert_with(cache_key, || {
+                let prefix = format!("{}{}{}", gutter_colored, timestamp_part, process_part_colored);
+                let mut spans = parse_ansi_to_spans(&prefix);
+                spans.extend(highlight_spans(log_content, self.theme));
+                spans
+            })
+        } else {
+            ansi_cache.get_or_parse(cache_key, &full_line_with_ansi)
+        };
+
+        // Determine if we need to truncate and render accordingly
+        if wrap {
+            // In batch view mode or wrap mode: show full content
+            // Paragraph wrapping is applied at the widget level
+            AnsiCache::to_line_with_overrides(cached, bg_color, fg_override)
+        } else if self.display.horizontal_offset > 0 {
+            // Scrolled right: the line starts at the offset column, cut-off sides are marked
+            let hint_style = truncation_hint_style(self.theme, is_cursor, is_multi_selected);
+            AnsiCache::to_scrolled_line(cached, self.display.horizontal_offset, self.max_line_width, bg_color, fg_override, hint_style)
+        } else if full_line_clean.width() > self.max_line_width {
+            // Truncate with ANSI color preservation
+            let suffix = self.display.truncation_suffix.as_str();
+            let suffix_width = suffix.width();
+            let target_width = self.max_line_width.saturating_sub(suffix_width);
+
+            let hint_style = truncation_hint_style(self.theme, is_cursor, is_multi_selected);
+
+            // Truncate the cached spans
+            AnsiCache::to_truncated_line(cached, target_width, bg_color, fg_override, suffix, hint_style)
+        } else {
+            // Full line fits; the cache key includes condensing and timestamp mode since content may differ
+            AnsiCache::to_line_with_overrides(cached, bg_color, fg_override)
+        }
+    }
+}
+
 /// Draw the log viewer in the middle of the screen
 pub fn draw_log_viewer(
     f: &mut Frame,
@@ -73,57 +350,69 @@ pub fn draw_log_viewer(
     manager: &ProcessManager,
     app: &mut App,
 ) {
-    // Use snapshot if available (frozen/batch mode), otherwise use live buffer
-    let logs_vec: Vec<&LogLine> = if let Some(ref snapshot) = app.navigation.snapshot {
-        snapshot.iter().collect()
-    } else {
-        let mut logs = manager.get_all_logs();
-
-        // If display is frozen (without snapshot), only show logs up to the frozen timestamp
-        if app.navigation.frozen {
-            if let Some(frozen_at) = app.navigation.frozen_at {
-                logs.retain(|log| log.timestamp <= frozen_at);
-            }
-        }
+    draw_log_pane(f, area, manager, app, None, None);
+}
 
-        logs
+/// Draw the log viewer as two side-by-side panes, one per process in
+/// `app.display.split_processes`. The pane holding the selected line leads;
+/// the other pane scrolls to the leader's first visible timestamp.
+pub fn draw_split_log_viewer(
+    f: &mut Frame,
+    area: Rect,
+    manager: &ProcessManager,
+    app: &mut App,
+) {
+    let Some((left, right)) = app.display.split_processes.clone() else {
+        draw_log_viewer(f, area, manager, app);
+        return;
     };
 
-    // Apply filters to logs
-    let mut filtered_logs: Vec<&LogLine> = if app.filters.filters.is_empty() {
-        // No filters, show all logs
-        logs_vec
-    } else {
-        logs_vec.into_iter()
-            .filter(|log| {
-                let line_lower = log.line_lowercase();
-
-                // Check exclude filters first (if any match, reject the line)
-                for filter in &app.filters.filters {
-                    if matches!(filter.filter_type, FilterType::Exclude) {
-                        if filter.matches_lowercase(line_lower) {
-                            return false; // Excluded
-                        }
-                    }
-                }
+    let panes = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
 
-                // Check include filters (if any exist, at least one must match)
-                let include_filters: Vec<_> = app
-                    .filters
-                    .filters
-                    .iter()
-                    .filter(|f| matches!(f.filter_type, FilterType::Include))
-                    .collect();
+    let right_leads = app.navigation.selected_line_id.is_some_and(|id| {
+        manager
+            .get_all_logs()
+            .iter()
+            .find(|log| log.id == id)
+            .is_some_and(|log| log.source.process_name() == right)
+    });
 
-                if include_filters.is_empty() {
-                    return true; // No include filters, line passes
-                }
+    if right_leads {
+        let anchor = draw_log_pane(f, panes[1], manager, app, Some(&right), None);
+        draw_log_pane(f, panes[0], manager, app, Some(&left), anchor);
+    } else {
+        let anchor = draw_log_pane(f, panes[0], manager, app, Some(&left), None);
+        draw_log_pane(f, panes[1], manager, app, Some(&right), anchor);
+    }
+}
 
-                // At least one include filter must match
-                include_filters.iter().any(|filter| filter.matches_lowercase(line_lower))
-            })
-            .collect()
+/// Draw one log pane. `pane` restricts it to a single process (split view);
+/// `sync_to` scrolls it to the first line at or after that time.
+/// Returns the arrival time of the first visible line when the pane isn't tailing,
+/// for the other split pane to sync to.
+fn draw_log_pane(
+    f: &mut Frame,
+    area: Rect,
+    manager: &ProcessManager,
+    app: &mut App,
+    pane: Option<&str>,
+    sync_to: Option<DateTime<Local>>,
+) -> Option<DateTime<Local>> {
+    // Collected once per render: the view and the tail preview both read it
+    let live_logs = manager.get_all_logs();
+
+    // Use snapshot if available (frozen/batch mode), otherwise use live buffer
+    let held_logs: Option<Vec<&LogLine>> = match &app.navigation.snapshot {
+        Some(snapshot) => Some(snapshot.iter().collect()),
+        // If display is frozen (without snapshot), only show logs up to the frozen timestamp
+        None => app.navigation.frozen_at.filter(|_| app.navigation.frozen).map(|frozen_at| {
+            live_logs.iter().copied().filter(|log| log.timestamp <= frozen_at).collect()
+        }),
     };
+    let logs_vec: &[&LogLine] = held_logs.as_deref().unwrap_or(&live_logs);
 
     // Apply search filter if active (temporary filter)
     // Skip search filter in batch view mode - batch view shows raw batch content
@@ -137,19 +426,21 @@ pub fn draw_log_viewer(
     } else {
         ""
     };
+    // Owned, so lines can be rendered with `app` borrowed mutably
+    let active_search_pattern = active_search_pattern.to_string();
+    let search_lower = active_search_pattern.to_lowercase();
 
-    if !active_search_pattern.is_empty() {
-        let pattern_lower = active_search_pattern.to_lowercase();
-        filtered_logs = filtered_logs
-            .into_iter()
-            .filter(|log| log.line_lowercase().contains(&pattern_lower))
-            .collect();
-    }
-
-    // Apply process visibility filter
-    filtered_logs.retain(|log| {
-        !app.filters.hidden_processes.contains(log.source.process_name())
-    });
+    // Boot markers stand in for the collapsed lines filtered out below
+    let boot = if app.filters.show_boot_output {
+        Vec::new()
+    } else {
+        boot_markers(logs_vec.iter().copied(), &app.filters.severity, |process| marker_visible(app, pane, process))
+    };
+    let mut filtered_logs: Vec<&LogLine> = logs_vec
+        .iter()
+        .copied()
+        .filter(|log| passes_view_filters(app, log, &search_lower, pane))
+        .collect();
 
     // Apply trace filter mode if active
     if app.trace.trace_filter_mode {
@@ -184,16 +475,28 @@ pub fn draw_log_viewer(
     let cache_key = BatchCacheKey::from_context(
         &filtered_logs,
         app.batch.batch_window_ms,
-        app.filters.filters.len(),
+        // errors_only counts as a filter so toggling it invalidates the cache
+        app.filters.active_filter_count() + usize::from(app.filters.errors_only),
         active_search_pattern.to_string(),
         app.filters.hidden_processes.len(),
         app.trace.trace_filter_mode,
         app.navigation.snapshot.is_some(),
-    );
-    let batches = app.cache.batch_cache.get_or_compute(&filtered_logs, app.batch.batch_window_ms, cache_key).clone();
+    )
+    .with_strategy(app.batch.batch_strategy);
+    // Split panes bypass the shared cache so they don't evict each other every frame
+    let batches = if pane.is_some() {
+        detect_batches_from_logs(&filtered_logs, app.batch.batch_window_ms, app.batch.batch_strategy)
+    } else {
+        app.cache.batch_cache.get_or_compute(&filtered_logs, app.batch.batch_window_ms, cache_key).clone()
+    };
+
+    // Only the leading pane feeds the status bar's batch stats
+    let updates_status = sync_to.is_none();
 
     // Update cached batch count for status bar (avoids duplicate batch detection)
-    app.cache.cached_batch_count = batches.len();
+    if updates_status {
+        app.cache.cached_batch_count = batches.len();
+    }
 
     // Build a map from each log index to its batch number (before consuming filtered_logs)
     let filtered_log_to_batch: Vec<Option<usize>> = if !batches.is_empty() {
@@ -241,25 +544,113 @@ pub fn draw_log_viewer(
             let (start, end) = batches[batch_idx];
             let line_count = end - start + 1;
             // Update cached batch info for status bar
-            app.cache.cached_batch_info = Some((batch_idx, batches.len(), line_count));
+            if updates_status {
+                app.cache.cached_batch_info = Some((batch_idx, batches.len(), line_count));
+            }
             (filtered_logs[start..=end].to_vec(), start)
         } else {
-            app.cache.cached_batch_info = None;
+            if updates_status {
+                app.cache.cached_batch_info = None;
+            }
             (filtered_logs, 0)
         }
     } else {
-        app.cache.cached_batch_info = None;
+        if updates_status {
+            app.cache.cached_batch_info = None;
+        }
         (filtered_logs, 0)
     };
 
+    // Restart and boot markers are interleaved by time (not in batch view, which shows raw batch content)
+    let restart_markers: Vec<ViewMarker> = if current_batch_validated.is_none() {
+        let mut markers: Vec<ViewMarker> = manager
+            .restart_markers()
+            .iter()
+            .filter(|m| marker_visible(app, pane, &m.process))
+            .map(ViewMarker::restart)
+            .collect();
+        if !boot.is_empty() {
+            markers.extend(boot);
+            markers.sort_by_key(|m| m.time);
+        }
+        markers
+    } else {
+        Vec::new()
+    };
+    let marker_time_before = |idx: usize| idx.checked_sub(1).map(|i| display_logs_source[i].arrival_time);
+
+    // "── new ──" goes before the first line that arrived while the user was away
+    let unread_idx: Option<usize> = app
+        .navigation
+        .unread
+        .after()
+        .filter(|_| current_batch_validated.is_none())
+        .map(|after| display_logs_source.partition_point(|log| log.id <= after))
+        .filter(|&idx| idx < display_logs_source.len());
+
+    // Find the selected line index by ID (if any line is selected)
+    let selected_line_index: Option<usize> = app.navigation.selected_line_id.and_then(|id| {
+        display_logs_source.iter().position(|log| log.id == id)
+    });
+
+    // Scroll stickiness: a short peek up snaps back to the bottom once new lines arrive
+    if pane.is_none() && !app.navigation.auto_scroll && selected_line_index.is_none() && current_batch_validated.is_none() {
+        let bottom_offset = display_logs_source.len().saturating_sub((area.height as usize).saturating_sub(1));
+        if app.navigation.stick.settle(app.navigation.scroll_offset, bottom_offset) {
+            app.navigation.auto_scroll = true;
+            app.navigation.scroll_offset = 0;
+        }
+    } else {
+        app.navigation.stick.disarm();
+    }
+
+    let tailing = app.navigation.auto_scroll && selected_line_index.is_none();
+
+    // Tail preview: while scrolled up, the newest live line that passes the filters
+    // is pinned below a divider (the view itself may be a frozen snapshot)
+    let preview_log: Option<&LogLine> = if app.display.tail_preview
+        && !tailing
+        && current_batch_validated.is_none()
+        && !app.trace.trace_filter_mode
+        && area.height as usize > TAIL_PREVIEW_ROWS + 1
+    {
+        live_logs
+            .iter()
+            .rev()
+            .copied()
+            .find(|log| passes_view_filters(app, log, &search_lower, pane))
+    } else {
+        None
+    };
+    let (area, preview_area) = if preview_log.is_some() {
+        let [main, preview] = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(0), Constraint::Length(TAIL_PREVIEW_ROWS as u16)])
+            .areas(area);
+        (main, Some(preview))
+    } else {
+        (area, None)
+    };
+
+    // Sticky header: while scrolled, the first row names the top line's batch, day and process
+    let sticky_header = app.display.sticky_header
+        && !tailing
+        && current_batch_validated.is_none()
+        && !display_logs_source.is_empty()
+        && area.height > 3;
+
     // Calculate visible lines
     // Subtract 1 for the title line (Block title takes 1 line even with Borders::NONE)
-    let visible_lines = (area.height as usize).saturating_sub(1);
+    let visible_lines = (area.height as usize).saturating_sub(1 + usize::from(sticky_header));
     let total_logs = display_logs_source.len();
 
-    // Calculate max line width for wrap mode height calculations
-    // Account for borders: 2 chars, plus 1 for safety
-    let max_line_width = (area.width as usize).saturating_sub(3);
+    // The viewer has no borders: unwrapped lines may use every column but the margin
+    let max_line_width = (area.width as usize).saturating_sub(app.display.line_margin);
+    // Word wrapping breaks before the edge, so estimate wrapped heights a little narrow
+    let wrap_width = (area.width as usize).saturating_sub(3);
+    let name_width = aligned_name_width(app, manager, pane);
+    // Separators span the pane (it has no borders)
+    let separator_width = area.width as usize;
 
     // Check if we're in wrap mode (affects scroll calculations)
     let is_wrap_mode = current_batch_validated.is_some() || app.display.is_wrap();
@@ -270,26 +661,32 @@ pub fn draw_log_viewer(
         display_logs_source
             .iter()
             .map(|log| {
-                // In batch/wrap mode, we show full content (not condensed)
-                let line_width = calculate_line_width(log, app.display.timestamp_mode, false);
-                calculate_wrapped_height(line_width, max_line_width)
+                // Batch view shows full content; wrap mode condenses only with `:condense`
+                let condensed = app.display.condenses() && current_batch_validated.is_none();
+                let line_width = calculate_line_width(log, &app.display, condensed, name_width);
+                calculate_wrapped_height(line_width, wrap_width)
             })
             .collect()
     } else {
         vec![]
     };
 
-    // Find the selected line index by ID (if any line is selected)
-    let selected_line_index: Option<usize> = app.navigation.selected_line_id.and_then(|id| {
-        display_logs_source.iter().position(|log| log.id == id)
-    });
-
     // Determine which logs to display based on scroll state
-    let (display_logs, scroll_indicator, display_start) = if app.navigation.auto_scroll && selected_line_index.is_none() {
+    let (display_logs, scroll_indicator, display_start) = if let Some(anchor) = sync_to.filter(|_| selected_line_index.is_none()) {
+        // Follower split pane: start at the leader's first visible timestamp
+        let start = display_logs_source
+            .partition_point(|log| log.arrival_time < anchor)
+            .min(total_logs.saturating_sub(visible_lines));
+        let end = (start + visible_lines).min(total_logs);
+        (&display_logs_source[start..end], String::new(), start)
+    } else if tailing {
         // Auto-scroll mode: show the last N logs (only when not selecting lines)
         // Account for batch separators: work backwards from the end to find how many logs fit
         let mut start = total_logs;
-        let mut lines_used = 0;
+        // Markers for restarts newer than the last line sit at the bottom
+        let mut lines_used = markers_between(&restart_markers, marker_time_before(total_logs), None)
+            .count()
+            .min(visible_lines);
 
         while start > 0 && lines_used < visible_lines {
             start -= 1;
@@ -326,6 +723,44 @@ pub fn draw_log_viewer(
                     }
                 }
             }
+
+            // Date divider when this log starts a new day
+            if start > 0
+                && app.display.show_date_dividers
+                && date_divider(display_logs_source[start - 1], display_logs_source[start]).is_some()
+            {
+                if lines_used < visible_lines {
+                    lines_used += 1;
+                } else {
+                    start += 1;
+                    break;
+                }
+            }
+
+            if unread_idx == Some(start) {
+                if lines_used < visible_lines {
+                    lines_used += 1;
+                } else {
+                    start += 1;
+                    break;
+                }
+            }
+
+            // Restart markers drawn just before this log
+            let marker_lines = markers_between(
+                &restart_markers,
+                marker_time_before(start),
+                Some(display_logs_source[start].arrival_time),
+            )
+            .count();
+            if marker_lines > 0 {
+                if lines_used + marker_lines <= visible_lines {
+                    lines_used += marker_lines;
+                } else {
+                    start += 1;
+                    break;
+                }
+            }
         }
 
         let display = &display_logs_source[start..];
@@ -436,7 +871,32 @@ pub fn draw_log_viewer(
     // When not in batch view mode, add separators between batches
     let mut log_lines: Vec<Line> = Vec::new();
 
+    if sticky_header && let Some(top) = display_logs.first() {
+        let batch = filtered_log_to_batch.get(display_start_in_filtered + display_start).and_then(|b| *b);
+        log_lines.push(Line::from(Span::styled(
+            separator_line(&sticky_context(top, batch), separator_width),
+            Style::default().fg(app.theme.separator).add_modifier(Modifier::BOLD),
+        )));
+    }
+
     for (display_idx, log) in display_logs.iter().enumerate() {
+        // Restart markers go before the first line logged after the restart
+        let source_idx = display_start + display_idx;
+        for marker in markers_between(&restart_markers, marker_time_before(source_idx), Some(log.arrival_time)) {
+            log_lines.push(marker.line(separator_width));
+        }
+
+        // Date divider where consecutive lines cross midnight
+        if display_idx > 0
+            && app.display.show_date_dividers
+            && let Some(label) = date_divider(display_logs[display_idx - 1], log)
+        {
+            log_lines.push(Line::from(Span::styled(
+                separator_line(&label, separator_width),
+                Style::default().fg(app.theme.separator),
+            )));
+        }
+
         // Insert batch separator if we're starting a new batch
         // Only show separators when not in batch view mode
         if current_batch_validated.is_none() && display_idx > 0 && !filtered_log_to_batch.is_empty() {
@@ -461,27 +921,21 @@ pub fn draw_log_viewer(
 
                     // Create separator text with batch info
                     let separator_text = format!(" Batch {} ({} logs) ", batch_num + 1, batch_size);
-                    let padding_needed = 80_usize.saturating_sub(separator_text.len());
-                    let left_padding = padding_needed / 2;
-                    let right_padding = padding_needed - left_padding;
-
-                    let separator_line = format!(
-                        "{}{}{}",
-                        "─".repeat(left_padding),
-                        separator_text,
-                        "─".repeat(right_padding)
-                    );
-
                     let separator = Line::from(Span::styled(
-                        separator_line,
-                        Style::default().fg(Color::DarkGray),
+                        separator_line(&separator_text, separator_width),
+                        Style::default().fg(app.theme.separator),
                     ));
                     log_lines.push(separator);
                 }
             }
         }
 
-        let process_name = log.source.process_name();
+        if unread_idx == Some(source_idx) {
+            log_lines.push(Line::from(Span::styled(
+                separator_line(" new ", separator_width),
+                Style::default().fg(app.theme.accent).add_modifier(Modifier::BOLD),
+            )));
+        }
 
         // Check if this line is the cursor (selected by ID)
         let is_cursor = app.navigation.selected_line_id == Some(log.id);
@@ -490,85 +944,53 @@ pub fn draw_log_viewer(
         let is_multi_selected = app.navigation.has_multi_select()
             && app.navigation.is_in_selection_ref(log.id, display_logs);
 
-        // Combined selection state (will be used by Step 6 for clearing on Escape)
-        let _is_selected = is_cursor || is_multi_selected;
-
-        // Format timestamp based on mode
-        let timestamp_part = match app.display.timestamp_mode {
-            TimestampMode::Seconds => format!("[{}] ", log.formatted_timestamp()),
-            TimestampMode::Milliseconds => format!("[{}] ", log.arrival_time.format("%H:%M:%S%.3f")),
-            TimestampMode::Off => String::new(),
-        };
-
-        // Get color ANSI codes for this process/log file name
-        let (color_start, color_reset) = app.process_colors.get_ansi(process_name);
-
-        // Process part with ANSI color codes for cached rendering paths
-        let process_part_colored = format!("{}{}{}: ", color_start, process_name, color_reset);
-        // Process part without color for width calculations
-        let process_part_plain = format!("{}: ", process_name);
-
-        // Apply condensing in compact mode (but not in batch view mode, which shows full content)
-        // Use cached condensed line
-        let (log_content, log_content_stripped): (&str, &str) = if app.display.is_compact() && current_batch_validated.is_none() {
-            (log.condensed_line(), log.condensed_stripped_line())
-        } else {
-            (&log.line, log.stripped_line())
-        };
-
-        // Build the full line with ANSI codes preserved (includes colored process name)
-        let full_line_with_ansi = format!("{}{}{}", timestamp_part, process_part_colored, log_content);
-
-        // For width calculations, use cached stripped content (no ANSI codes)
-        let full_line_clean = format!("{}{}{}", timestamp_part, process_part_plain, log_content_stripped);
-
-        // Determine if we need to truncate and render accordingly
-        let line = if current_batch_validated.is_some() || app.display.is_wrap() {
-            // In batch view mode or wrap mode: show full content with cached ANSI parsing
-            // Paragraph wrapping is applied at the widget level
-            let (bg_color, fg_override) =
-                line_selection_overrides(&app.theme, is_cursor, is_multi_selected);
-
-            // Use cache: batch/wrap view mode always uses non-compact content
-            let cache_key = AnsiCacheKey::new(log.id, false, app.display.timestamp_mode);
-            let cached = app.cache.ansi_cache.get_or_parse(cache_key, &full_line_with_ansi);
-            AnsiCache::to_line_with_overrides(cached, bg_color, fg_override)
-        } else if full_line_clean.width() > max_line_width {
-            // Truncate with ANSI color preservation
-            let suffix = "… ↵";
-            let suffix_width = suffix.width();
-            let target_width = max_line_width.saturating_sub(suffix_width);
-
-            let (bg_color, fg_override) =
-                line_selection_overrides(&app.theme, is_cursor, is_multi_selected);
-
-            let hint_style = truncation_hint_style(&app.theme, is_cursor, is_multi_selected);
-
-            // Use cached ANSI parsing and truncate the spans
-            let cache_key = AnsiCacheKey::new(log.id, app.display.is_compact(), app.display.timestamp_mode);
-            let cached = app.cache.ansi_cache.get_or_parse(cache_key, &full_line_with_ansi);
-            AnsiCache::to_truncated_line(cached, target_width, bg_color, fg_override, suffix, hint_style)
-        } else {
-            // Full line fits, parse ANSI codes with caching
-            let (bg_color, fg_override) =
-                line_selection_overrides(&app.theme, is_cursor, is_multi_selected);
-
-            // Use cache: key includes compact mode and timestamp mode since content may differ
-            let cache_key = AnsiCacheKey::new(log.id, app.display.is_compact(), app.display.timestamp_mode);
-            let cached = app.cache.ansi_cache.get_or_parse(cache_key, &full_line_with_ansi);
-            AnsiCache::to_line_with_overrides(cached, bg_color, fg_override)
+        let batch_view = current_batch_validated.is_some();
+        let line_style = LineStyle {
+            display: &app.display,
+            theme: &app.theme,
+            colors: &app.process_colors,
+            max_line_width,
+            name_width,
         };
+        let line = line_style.render(
+            &mut app.cache.ansi_cache,
+            log,
+            batch_view,
+            batch_view || app.display.is_wrap(),
+            is_cursor,
+            is_multi_selected,
+        );
 
         log_lines.push(line);
     }
 
+    // Restarts with no output since are shown after the last line
+    if display_start + display_logs.len() == total_logs {
+        for marker in markers_between(&restart_markers, marker_time_before(total_logs), None) {
+            log_lines.push(marker.line(separator_width));
+        }
+    }
+
+    let first_visible_time = display_logs.first().map(|log| log.arrival_time);
+
     // Build title with filters and search info (buffer/batch stats now in status bar)
     let mut title_parts = vec![];
 
-    if app.filters.filter_count() > 0 {
+    if let Some(pane_process) = pane {
+        title_parts.push(format!("[{}]", pane_process));
+    }
+
+    let disabled = app.filters.filter_count() - app.filters.active_filter_count();
+    if disabled > 0 {
+        title_parts.push(format!("({} filters, {} off)", app.filters.active_filter_count(), disabled));
+    } else if app.filters.filter_count() > 0 {
         title_parts.push(format!("({} filters)", app.filters.filter_count()));
     }
 
+    if app.filters.errors_only {
+        title_parts.push("[errors only]".to_string());
+    }
+
     if !active_search_pattern.is_empty() {
         if match_count == 0 {
             title_parts.push(format!("[Search: {}] no matches", active_search_pattern));
@@ -596,6 +1018,28 @@ pub fn draw_log_viewer(
     }
 
     f.render_widget(paragraph, area);
+
+    if let (Some(log), Some(preview_area)) = (preview_log, preview_area) {
+        let line_style = LineStyle {
+            display: &app.display,
+            theme: &app.theme,
+            colors: &app.process_colors,
+            max_line_width,
+            name_width,
+        };
+        let preview_lines = vec![
+            Line::from(Span::styled(separator_line(" Latest ", separator_width), Style::default().fg(app.theme.separator))),
+            line_style.render(&mut app.cache.ansi_cache, log, false, false, false, false),
+        ];
+        f.render_widget(Paragraph::new(preview_lines), preview_area);
+    }
+
+    // A tailing leader leaves the follower tailing too
+    if tailing && sync_to.is_none() {
+        None
+    } else {
+        first_visible_time
+    }
 }
 
 #[cfg(test)]
@@ -812,4 +1256,148 @@ mod tests {
         assert_eq!(z_cell.style().fg, Some(Color::Red));
         assert_eq!(z_cell.style().bg, Some(theme.selection_bg));
     }
+
+    #[test]
+    fn errors_only_hides_non_error_lines_and_shows_title() {
+        let mut app = App::new();
+        app.display.timestamp_mode = TimestampMode::Off;
+        app.filters.errors_only = true;
+
+        let mut manager = ProcessManager::new();
+        manager.add_test_log(LogLine::new(
+            LogSource::ProcessStdout("web".to_string()),
+            "request ok".to_string(),
+        ));
+        manager.add_test_log(LogLine::new(
+            LogSource::ProcessStdout("web".to_string()),
+            "Error: boom".to_string(),
+        ));
+
+        let terminal = render(&mut app, &manager, 60, 4);
+        let text: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+
+        assert!(text.contains("[errors only]"));
+        assert!(text.contains("Error: boom"));
+        assert!(!text.contains("request ok"));
+    }
+
+    fn rows(terminal: &Terminal<TestBackend>) -> Vec<String> {
+        let buffer = terminal.backend().buffer();
+        let width = buffer.area.width as usize;
+        let symbols: Vec<&str> = buffer.content().iter().map(|cell| cell.symbol()).collect();
+        symbols.chunks(width).map(|row| row.concat().trim_end().to_string()).collect()
+    }
+
+    fn day_crossing_manager() -> ProcessManager {
+        use chrono::TimeZone;
+        let mut manager = ProcessManager::new();
+        for (day, hour, text) in [(10, 23, "before midnight"), (11, 0, "after midnight")] {
+            let time = Local.with_ymd_and_hms(2024, 12, day, hour, 30, 0).unwrap();
+            manager.add_test_log(LogLine::new_with_time(
+                LogSource::ProcessStdout("web".to_string()),
+                text.to_string(),
+                time,
+            ));
+        }
+        manager
+    }
+
+    #[test]
+    fn date_divider_appears_between_lines_on_different_days() {
+        let mut app = App::new();
+        let manager = day_crossing_manager();
+
+        let rows = rows(&render(&mut app, &manager, 60, 6));
+        let before = rows.iter().position(|r| r.contains("before midnight")).unwrap();
+        assert!(rows[before + 1].contains(" 2024-12-11 "), "divider after the last line of the day: {:?}", rows);
+        assert!(rows[before + 1].starts_with('─'));
+        let after = rows.iter().position(|r| r.contains("after midnight")).unwrap();
+        assert!(after > before + 1, "divider sits between the two lines: {:?}", rows);
+    }
+
+    #[test]
+    fn date_dividers_can_be_turned_off() {
+        let mut app = App::new();
+        app.display.show_date_dividers = false;
+        let manager = day_crossing_manager();
+
+        let rows = rows(&render(&mut app, &manager, 60, 6));
+        assert!(!rows.iter().any(|r| r.contains("2024-12-11")));
+    }
+
+    #[test]
+    fn format_elapsed_scales_units() {
+        assert_eq!(format_elapsed(chrono::Duration::milliseconds(1234)), "+1.2s");
+        assert_eq!(format_elapsed(chrono::Duration::seconds(185)), "+3m05s");
+        assert_eq!(format_elapsed(chrono::Duration::seconds(7800)), "+2h10m");
+        assert_eq!(format_elapsed(chrono::Duration::seconds(-5)), "+0.0s");
+    }
+
+    #[test]
+    fn timestamp_prefix_relative_mode_uses_origin() {
+        let origin = chrono::Local::now();
+        let log = LogLine::new_with_time(
+            LogSource::ProcessStdout("web".to_string()),
+            "hi".to_string(),
+            origin + chrono::Duration::milliseconds(1500),
+        );
+        let mut display = DisplayState {
+            relative_timestamp_origin: Some(origin),
+            ..Default::default()
+        };
+
+        assert_eq!(timestamp_prefix(&log, &display), "[+1.5s] ");
+
+        display.timestamp_mode = TimestampMode::Off;
+        assert_eq!(timestamp_prefix(&log, &display), "");
+    }
+
+    #[test]
+    fn timestamp_prefix_hidden_for_empty_format() {
+        let mut log = LogLine::new(LogSource::ProcessStdout("web".to_string()), "hi".to_string());
+        let display = DisplayState::default();
+        assert_eq!(timestamp_prefix(&log, &display), format!("[{}] ", log.formatted_timestamp()));
+
+        log.set_timestamp_format("");
+        assert_eq!(timestamp_prefix(&log, &display), "");
+    }
+
+    #[test]
+    fn mark_stderr_draws_red_gutter_on_stderr_lines() {
+        let mut app = App::new();
+        app.display.timestamp_mode = TimestampMode::Off;
+        app.display.mark_stderr = true;
+
+        let mut manager = ProcessManager::new();
+        manager.add_test_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), "ok".to_string()));
+        manager.add_test_log(LogLine::new(LogSource::ProcessStderr("web".to_string()), "bad".to_string()));
+
+        let terminal = render(&mut app, &manager, 30, 3);
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| (0..30).map(|x| buffer[(x, y)].symbol()).collect::<String>();
+
+        assert!(row(1).starts_with("  web: ok"));
+        assert!(row(2).starts_with("! web: bad"));
+        assert_eq!(buffer[(0, 2)].style().fg, Some(Color::Red));
+    }
+
+    #[test]
+    fn stderr_lines_are_unmarked_by_default() {
+        let mut app = App::new();
+        app.display.timestamp_mode = TimestampMode::Off;
+
+        let mut manager = ProcessManager::new();
+        manager.add_test_log(LogLine::new(LogSource::ProcessStderr("web".to_string()), "bad".to_string()));
+
+        let terminal = render(&mut app, &manager, 30, 2);
+        let buffer = terminal.backend().buffer();
+        let row: String = (0..30).map(|x| buffer[(x, 1)].symbol()).collect();
+        assert!(row.starts_with("web: bad"));
+    }
 }
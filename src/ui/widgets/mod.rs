@@ -5,7 +5,7 @@ mod status_bar;
 mod command_input;
 
 pub use process_list::{draw_process_list, calculate_process_list_height};
-pub use process_tree::draw_process_tree;
-pub use log_viewer::draw_log_viewer;
+pub use process_tree::{draw_process_tree, status_color, status_label};
+pub use log_viewer::{draw_log_viewer, draw_split_log_viewer};
 pub use status_bar::draw_status_bar;
 pub use command_input::draw_command_input;
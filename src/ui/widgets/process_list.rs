@@ -8,7 +8,11 @@ use ratatui::{
 
 use crate::process::{ProcessManager, ProcessStatus};
 use crate::ui::app::App;
-use crate::ui::display_state::ProcessPanelViewMode;
+use crate::ui::display_state::{ProcessListPosition, ProcessPanelViewMode};
+use crate::ui::DisplayMode;
+
+/// Label slot width with `stable_process_layout`; longer labels are truncated
+const STABLE_LABEL_WIDTH: usize = 12;
 
 /// Represents a cell to be rendered in the process grid
 #[derive(Clone)]
@@ -17,8 +21,26 @@ struct Cell {
     name_color: Color,
     status_color: Color,
     custom_label: Option<String>,
+    /// Blank space reserved after the label so the cell keeps a fixed width
+    label_pad: usize,
     width: usize,
     is_noteworthy: bool,
+    /// Drawn with a leading `*` (`pinned_processes`)
+    pinned: bool,
+    /// Newest buffered line, plain text (`show_last_line`)
+    last_line: Option<String>,
+    /// Launch command, drawn dim beneath the name (`show_commands` in full mode)
+    command: Option<String>,
+}
+
+/// Commands are shown only in full mode, which already trades space for detail
+fn shows_commands(app: &App) -> bool {
+    app.display.show_commands && app.display.display_mode == DisplayMode::Full
+}
+
+/// One cell per row instead of a grid
+fn one_per_row(app: &App) -> bool {
+    app.display.show_last_line || shows_commands(app)
 }
 
 /// Calculate row layout: given cell widths and max width, returns padding for each cell.
@@ -72,25 +94,29 @@ fn calculate_row_count(cell_widths: &[usize], max_width: usize, separator_width:
 }
 
 /// Build cell widths for layout calculation based on view mode
+/// (cell widths, total process count, command rows under the shown cells)
 fn build_cell_widths(
     manager: &ProcessManager,
     app: &App,
     mode: ProcessPanelViewMode,
-) -> (Vec<usize>, usize) {
+) -> (Vec<usize>, usize, usize) {
     let all_cells = build_process_cells(manager, app);
     let total_count = all_cells.len();
     let mut cell_widths: Vec<usize> = Vec::new();
+    let mut command_rows = 0;
 
     match mode {
         ProcessPanelViewMode::Normal => {
             for cell in &all_cells {
                 cell_widths.push(cell.width);
+                command_rows += cell.command.is_some() as usize;
             }
         }
         ProcessPanelViewMode::Summary => {
             for cell in &all_cells {
                 if cell.is_noteworthy {
                     cell_widths.push(cell.width);
+                    command_rows += cell.command.is_some() as usize;
                 }
             }
         }
@@ -105,7 +131,7 @@ fn build_cell_widths(
         cell_widths.push(suffix.len());
     }
 
-    (cell_widths, total_count)
+    (cell_widths, total_count, command_rows)
 }
 
 /// Calculate the height needed for the process list
@@ -126,13 +152,17 @@ pub fn calculate_process_list_height(
         return 2;
     }
 
-    let (cell_widths, _total_count) = build_cell_widths(manager, app, mode);
+    let (cell_widths, _total_count, command_rows) = build_cell_widths(manager, app, mode);
 
     if cell_widths.is_empty() {
         return 2; // Empty or "all running" message + border
     }
 
-    let num_rows = calculate_row_count(&cell_widths, usable_width, 3);
+    let num_rows = if one_per_row(app) {
+        cell_widths.len() + command_rows
+    } else {
+        calculate_row_count(&cell_widths, usable_width, 3)
+    };
     (num_rows as u16) + 1 // +1 for border
 }
 
@@ -148,7 +178,12 @@ fn build_process_cells(manager: &ProcessManager, app: &App) -> Vec<Cell> {
 
     for name in names.iter() {
         let handle = &processes[*name];
-        let (status_color, custom_label) = get_process_status(handle);
+        let (status_color, mut custom_label) = get_process_status(handle);
+        if manager.is_ingest_paused(name) {
+            custom_label = Some("Paused".to_string());
+        } else if manager.is_waiting(name) {
+            custom_label = Some("Waiting".to_string());
+        }
         let is_hidden = app.filters.hidden_processes.contains(*name);
         let name_color = if is_hidden {
             Color::DarkGray
@@ -164,6 +199,7 @@ fn build_process_cells(manager: &ProcessManager, app: &App) -> Vec<Cell> {
             status_color,
             custom_label.as_deref(),
             is_noteworthy,
+            app.display.stable_process_layout,
         );
         all_cells.push(cell);
     }
@@ -176,10 +212,38 @@ fn build_process_cells(manager: &ProcessManager, app: &App) -> Vec<Cell> {
             app.process_colors.get(name)
         };
 
-        let cell = build_process_cell(name, name_color, Color::Cyan, None, is_hidden);
+        let paused = manager.is_ingest_paused(name);
+        let label = paused.then_some("Paused");
+        let cell = build_process_cell(name, name_color, Color::Cyan, label, is_hidden || paused, app.display.stable_process_layout);
         all_cells.push(cell);
     }
 
+    // Pinned entries go first, in pin order; the stable sort keeps the rest alphabetical
+    let pinned = &app.display.pinned_processes;
+    for cell in all_cells.iter_mut().filter(|cell| pinned.contains(&cell.name)) {
+        cell.pinned = true;
+        cell.width += 1;
+    }
+    all_cells.sort_by_key(|cell| pinned.iter().position(|name| *name == cell.name).unwrap_or(usize::MAX));
+
+    if shows_commands(app) {
+        for cell in all_cells.iter_mut() {
+            cell.command = processes.get(&cell.name).map(|handle| handle.command.clone());
+        }
+    }
+
+    if app.display.show_last_line {
+        let names: Vec<&str> = all_cells.iter().map(|cell| cell.name.as_str()).collect();
+        let last = manager.last_lines(&names);
+        let last_lines: Vec<Option<String>> = names
+            .iter()
+            .map(|name| last.get(name).map(|log| log.display_stripped_line().replace('\t', " ")))
+            .collect();
+        for (cell, last_line) in all_cells.iter_mut().zip(last_lines) {
+            cell.last_line = last_line;
+        }
+    }
+
     return all_cells;
 }
 
@@ -201,7 +265,7 @@ pub fn draw_process_list(f: &mut Frame, area: Rect, manager: &ProcessManager, ap
             "No processes",
             Style::default().fg(Color::DarkGray),
         )]);
-        let paragraph = Paragraph::new(vec![line]).block(Block::default().borders(Borders::BOTTOM));
+        let paragraph = Paragraph::new(vec![line]).block(Block::default().borders(separator(app)));
         f.render_widget(paragraph, area);
         return;
     }
@@ -210,9 +274,11 @@ pub fn draw_process_list(f: &mut Frame, area: Rect, manager: &ProcessManager, ap
     let total_count = all_cells.len();
     let usable_width = area.width.saturating_sub(2) as usize;
     let view_mode = app.display.process_panel_mode;
+    // Click regions are recorded inside the separator rule
+    let grid_area = Block::default().borders(separator(app)).inner(area);
 
     let lines = match view_mode {
-        ProcessPanelViewMode::Normal => render_grid(&all_cells, None, usable_width, area, app),
+        ProcessPanelViewMode::Normal => render_cells(&all_cells, None, usable_width, grid_area, app),
         ProcessPanelViewMode::Summary => {
             let noteworthy_cells: Vec<Cell> = all_cells
                 .into_iter()
@@ -230,7 +296,7 @@ pub fn draw_process_list(f: &mut Frame, area: Rect, manager: &ProcessManager, ap
                     noteworthy_cells.len(),
                     total_count
                 );
-                render_grid(&noteworthy_cells, Some(&suffix), usable_width, area, app)
+                render_cells(&noteworthy_cells, Some(&suffix), usable_width, grid_area, app)
             }
         }
         ProcessPanelViewMode::Minimal => {
@@ -241,31 +307,149 @@ pub fn draw_process_list(f: &mut Frame, area: Rect, manager: &ProcessManager, ap
         }
     };
 
-    let paragraph = Paragraph::new(lines).block(Block::default().borders(Borders::BOTTOM));
+    let paragraph = Paragraph::new(lines).block(Block::default().borders(separator(app)));
     f.render_widget(paragraph, area);
 }
 
-/// Build a cell for a process/log entry
+/// Build a cell for a process/log entry. With `stable` every cell reserves a
+/// `STABLE_LABEL_WIDTH` label slot, so labels appearing, changing or
+/// disappearing don't move the other cells.
 fn build_process_cell(
     name: &str,
     name_color: Color,
     status_color: Color,
     custom_label: Option<&str>,
     is_noteworthy: bool,
+    stable: bool,
 ) -> Cell {
-    let label = custom_label.map(|s| s.to_string());
-    let width = name.len() + 2 + label.as_ref().map(|l| l.len() + 3).unwrap_or(0);
+    let (label, label_pad) = if stable {
+        let label = custom_label.map(|l| truncate_label(l, STABLE_LABEL_WIDTH));
+        let pad = match &label {
+            Some(l) => STABLE_LABEL_WIDTH - l.chars().count(),
+            None => STABLE_LABEL_WIDTH + 3,
+        };
+        (label, pad)
+    } else {
+        (custom_label.map(|s| s.to_string()), 0)
+    };
+    let width = name.len() + 2 + label.as_ref().map(|l| l.chars().count() + 3).unwrap_or(0) + label_pad;
 
     Cell {
         name: name.to_string(),
         name_color,
         status_color,
         custom_label: label,
+        label_pad,
         width,
         is_noteworthy,
+        pinned: false,
+        last_line: None,
+        command: None,
+    }
+}
+
+/// Cut `label` to at most `max` characters, ending in `…` when shortened
+fn truncate_label(label: &str, max: usize) -> String {
+    if label.chars().count() <= max {
+        return label.to_string();
+    }
+    let mut truncated: String = label.chars().take(max.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+/// Lay cells out as a grid, or one per row with `show_last_line` or commands shown
+fn render_cells<'a>(
+    cells: &[Cell],
+    suffix: Option<&str>,
+    max_width: usize,
+    area: Rect,
+    app: &mut App,
+) -> Vec<Line<'a>> {
+    if one_per_row(app) {
+        render_rows(cells, suffix, max_width, area, app)
+    } else {
+        render_grid(cells, suffix, max_width, area, app)
     }
 }
 
+/// Render one cell per row followed by its newest line, cut to fit, and its
+/// command on a dim row beneath. Cells are padded to the widest one so the
+/// lines start in the same column.
+fn render_rows<'a>(
+    cells: &[Cell],
+    suffix: Option<&str>,
+    max_width: usize,
+    area: Rect,
+    app: &mut App,
+) -> Vec<Line<'a>> {
+    let slot = cells.iter().map(|c| c.width).max().unwrap_or(0);
+    let mut lines: Vec<Line> = Vec::new();
+
+    for cell in cells {
+        app.regions.process_regions.push((
+            cell.name.clone(),
+            Rect::new(area.x, area.y + lines.len() as u16, max_width as u16, 1),
+        ));
+
+        let mut spans = cell_spans(cell, slot - cell.width);
+        let room = max_width.saturating_sub(slot + 1);
+        if let Some(last_line) = cell.last_line.as_ref().filter(|_| room > 0) {
+            spans.push(Span::raw(" "));
+            spans.push(Span::styled(truncate_label(last_line, room), Style::default().fg(Color::DarkGray)));
+        }
+        lines.push(Line::from(spans));
+
+        if let Some(command) = &cell.command {
+            let command = truncate_label(command, max_width.saturating_sub(2));
+            lines.push(Line::from(Span::styled(format!("  {}", command), Style::default().fg(Color::DarkGray))));
+        }
+    }
+
+    if let Some(s) = suffix {
+        lines.push(Line::from(Span::styled(s.to_string(), Style::default().fg(Color::DarkGray))));
+    }
+
+    lines
+}
+
+/// Spans for one cell: pin marker, name, label, `padding` blanks and the status dot
+fn cell_spans<'a>(cell: &Cell, padding: usize) -> Vec<Span<'a>> {
+    let mut spans: Vec<Span> = Vec::new();
+
+    if cell.pinned {
+        spans.push(Span::styled("*", Style::default().fg(Color::DarkGray)));
+    }
+
+    // Name
+    spans.push(Span::styled(
+        cell.name.clone(),
+        Style::default()
+            .fg(cell.name_color)
+            .add_modifier(Modifier::BOLD),
+    ));
+
+    // Custom label if present
+    if let Some(ref label) = cell.custom_label {
+        spans.push(Span::raw(" ["));
+        spans.push(Span::styled(
+            label.clone(),
+            Style::default().fg(cell.status_color),
+        ));
+        spans.push(Span::raw("]"));
+    }
+
+    if padding + cell.label_pad > 0 {
+        spans.push(Span::raw(" ".repeat(padding + cell.label_pad)));
+    }
+
+    // Status dot
+    spans.push(Span::raw(" "));
+    spans.push(Span::styled("●", Style::default().fg(cell.status_color)));
+
+    spans
+}
+
 /// Render a grid of cells with optional suffix
 fn render_grid<'a>(
     cells: &[Cell],
@@ -323,35 +507,11 @@ fn render_grid<'a>(
                     Rect::new(x_pos, y_pos, (cell.width + padding) as u16, 1),
                 ));
 
-                // Name
-                spans.push(Span::styled(
-                    cell.name.clone(),
-                    Style::default()
-                        .fg(cell.name_color)
-                        .add_modifier(Modifier::BOLD),
-                ));
-
-                // Custom label if present
-                if let Some(ref label) = cell.custom_label {
-                    spans.push(Span::raw(" ["));
-                    spans.push(Span::styled(
-                        label.clone(),
-                        Style::default().fg(cell.status_color),
-                    ));
-                    spans.push(Span::raw("]"));
-                }
-
-                if padding > 0 {
-                    spans.push(Span::raw(" ".repeat(padding)));
-                }
-
-                // Status dot
-                spans.push(Span::raw(" "));
-                spans.push(Span::styled("●", Style::default().fg(cell.status_color)));
+                spans.extend(cell_spans(cell, padding));
 
                 // Separator if not last cell in row
                 if !is_last_col {
-                    spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+                    spans.push(Span::styled(" │ ", Style::default().fg(app.theme.separator)));
                 }
 
                 x_offset += padding + cell.width + if is_last_in_row { 0 } else { 3 };
@@ -382,8 +542,17 @@ fn get_process_status(handle: &crate::process::ProcessHandle) -> (Color, Option<
                     ProcessStatus::Restarting => (Color::Cyan, None),
                     ProcessStatus::Terminating => (Color::Magenta, None),
                     ProcessStatus::Failed(_) => (Color::Red, None),
+                    ProcessStatus::Done => (Color::Green, Some("Done".to_string())),
                 }
             }
         }
     }
 }
+
+/// The rule between the process list and the log viewer, on whichever side faces the logs
+fn separator(app: &App) -> Borders {
+    match app.display.process_list_position {
+        ProcessListPosition::Bottom => Borders::TOP,
+        _ => Borders::BOTTOM,
+    }
+}
@@ -11,24 +11,26 @@ use crate::process_tree::{ManagedRoot, TreeLineKind, build_tree_lines};
 use crate::ui::app::App;
 
 /// Human-readable status label for a managed process.
-fn status_label(status: &ProcessStatus) -> String {
+pub fn status_label(status: &ProcessStatus) -> String {
     match status {
         ProcessStatus::Running => "running".to_string(),
         ProcessStatus::Stopped => "stopped".to_string(),
         ProcessStatus::Terminating => "terminating".to_string(),
         ProcessStatus::Restarting => "restarting".to_string(),
         ProcessStatus::Failed(msg) => format!("failed ({})", msg),
+        ProcessStatus::Done => "done".to_string(),
     }
 }
 
 /// Color used for a managed process status (matches the process list panel).
-fn status_color(status: &ProcessStatus) -> Color {
+pub fn status_color(status: &ProcessStatus) -> Color {
     match status {
         ProcessStatus::Running => Color::Green,
         ProcessStatus::Stopped => Color::Red,
         ProcessStatus::Restarting => Color::Cyan,
         ProcessStatus::Terminating => Color::Magenta,
         ProcessStatus::Failed(_) => Color::Red,
+        ProcessStatus::Done => Color::Green,
     }
 }
 
@@ -10,6 +10,10 @@ use ratatui::{
 use crate::process::ProcessManager;
 use crate::ui::app::App;
 
+/// Status bar volume sparkline: 30 buckets of 10s (`volume_histogram = true`)
+const VOLUME_BUCKET_SECS: i64 = 10;
+const VOLUME_BUCKETS: usize = 30;
+
 /// Draw the status bar showing buffer stats and batch info
 ///
 /// Uses cached batch info from log_viewer to avoid duplicate O(n) batch detection.
@@ -32,6 +36,11 @@ pub fn draw_status_bar(
         )
     ];
 
+    if app.display.volume_histogram {
+        let histogram = manager.volume_histogram(VOLUME_BUCKET_SECS, VOLUME_BUCKETS, false);
+        status_parts.push(format!("vol {} peak {}/{}s", histogram.sparkline(), histogram.peak(), VOLUME_BUCKET_SECS));
+    }
+
     // Add batch info (using cached values from log_viewer)
     if app.batch.batch_view_mode {
         if let Some((batch_idx, total_batches, line_count)) = app.cache.cached_batch_info {
@@ -41,6 +50,15 @@ pub fn draw_status_bar(
         status_parts.push(format!("{} batches", app.cache.cached_batch_count));
     }
 
+    // Log flood still draining from the channel
+    if manager.is_catching_up() {
+        status_parts.push(format!("catching up ({} queued)", manager.ingest_backlog()));
+    }
+    let lagging = manager.lagging_processes();
+    if !lagging.is_empty() {
+        status_parts.push(format!("{}: output lagging", lagging.join(", ")));
+    }
+
     let status_text = status_parts.join(" | ");
     let footer_fg = app.theme.footer_fg;
 
@@ -96,7 +114,7 @@ pub fn draw_status_bar(
     };
 
     let paragraph = Paragraph::new(line)
-        .style(Style::default().bg(app.theme.footer_bg).fg(footer_fg));
+        .style(Style::default().bg(app.theme.footer_bg).fg(footer_fg).add_modifier(app.theme.footer_modifier));
 
     f.render_widget(paragraph, area);
 }
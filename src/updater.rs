@@ -106,6 +106,29 @@ fn download_and_update(tag: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// Whether `latest` is a later release than `current`. Versions compare
+/// numerically part by part; anything unparseable counts as newer when it differs.
+fn is_newer(latest: &str, current: &str) -> bool {
+    let parse = |v: &str| v.split('.').map(str::parse::<u64>).collect::<Result<Vec<_>, _>>();
+    match (parse(latest), parse(current)) {
+        (Ok(latest), Ok(current)) => latest > current,
+        _ => latest != current,
+    }
+}
+
+/// Status bar notice for a newer release
+pub fn update_available_message(current_version: &str, latest_version: &str) -> Option<String> {
+    let latest = latest_version.trim_start_matches('v');
+    is_newer(latest, current_version).then(|| format!("Update available: v{} (run oit --update)", latest))
+}
+
+/// Look up the latest release without installing it (`background_update_check`).
+/// Returns the notice to show, or None when up to date or the check failed.
+pub fn check_for_update(current_version: &str) -> Option<String> {
+    let latest_version = get_latest_version().ok()?;
+    update_available_message(current_version, &latest_version)
+}
+
 /// Check for updates and apply if available.
 /// If an update is applied, this function re-execs and never returns.
 /// Returns Ok(()) if no update needed, Err if something went wrong.
@@ -139,6 +162,25 @@ pub fn check_and_update(current_version: &str) -> anyhow::Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_update_available_message_for_newer_version() {
+        assert_eq!(
+            update_available_message("1.0.6", "1.2.0"),
+            Some("Update available: v1.2.0 (run oit --update)".to_string())
+        );
+        assert_eq!(
+            update_available_message("1.0.6", "v1.0.10"),
+            Some("Update available: v1.0.10 (run oit --update)".to_string())
+        );
+    }
+
+    #[test]
+    fn test_update_available_message_none_when_current_or_older() {
+        assert_eq!(update_available_message("1.0.6", "1.0.6"), None);
+        assert_eq!(update_available_message("1.0.6", "1.0.5"), None);
+        assert!(update_available_message("1.0.6", "1.1.0-beta").is_some());
+    }
+
     #[test]
     fn test_asset_name_for_macos_arm64() {
         let result = asset_name_for_platform("macos", "aarch64");
@@ -1,7 +1,8 @@
 mod common;
 
-use common::{create_manager_with_logs, create_test_app, create_test_process_manager, render_app_to_string};
+use common::{create_manager_with_logs, create_manager_with_mixed_states, create_test_app, create_test_process_manager, render_app_to_string};
 use insta::assert_snapshot;
+use overitall::log::{LogLine, LogSource};
 
 #[test]
 fn test_basic_ui_rendering() {
@@ -116,3 +117,156 @@ fn test_empty_search_pattern() {
 
     assert!(!output.is_empty());
 }
+
+#[test]
+fn test_snapshot_help_overlay_scrolled_at_short_height() {
+    let mut app = create_test_app();
+    let manager = create_test_process_manager();
+
+    app.display.toggle_help();
+    for _ in 0..12 {
+        app.display.scroll_help_down();
+    }
+
+    let output = render_app_to_string(&mut app, &manager, 100, 20);
+    assert_snapshot!(output);
+    assert_eq!(app.display.help_scroll_offset, 12);
+}
+
+#[test]
+fn test_snapshot_help_overlay_jumps_to_keyword() {
+    let mut app = create_test_app();
+    let manager = create_test_process_manager();
+
+    app.display.toggle_help();
+    app.display.start_help_query();
+    app.display.set_help_query("kill-all".to_string());
+
+    let output = render_app_to_string(&mut app, &manager, 100, 20);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_help_overlay_scroll_clamps_to_content() {
+    let mut app = create_test_app();
+    let manager = create_test_process_manager();
+
+    app.display.toggle_help();
+    app.display.help_scroll_offset = u16::MAX;
+    render_app_to_string(&mut app, &manager, 100, 20);
+    let clamped = app.display.help_scroll_offset;
+    assert!(clamped < u16::MAX);
+
+    // Scrolling past the end stays on the last page
+    app.display.scroll_help_down();
+    render_app_to_string(&mut app, &manager, 100, 20);
+    assert_eq!(app.display.help_scroll_offset, clamped);
+}
+
+#[test]
+fn test_snapshot_command_palette_filtered_to_batch() {
+    let mut app = create_test_app();
+    let manager = create_test_process_manager();
+
+    app.palette.open();
+    for c in "batch".chars() {
+        app.palette.push_char(c);
+    }
+    app.palette.select_next();
+
+    let output = render_app_to_string(&mut app, &manager, 120, 40);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_command_palette_prefills_commands_that_need_an_argument() {
+    use overitall::ui::PaletteInvocation;
+
+    let mut app = create_test_app();
+    app.palette.open();
+    for c in "hide".chars() {
+        app.palette.push_char(c);
+    }
+
+    assert_eq!(app.palette.selected_invocation(), Some(PaletteInvocation::Prefill("hide ".to_string())));
+}
+
+#[test]
+fn test_snapshot_process_picker_lists_processes_with_status() {
+    let mut app = create_test_app();
+    let manager = create_manager_with_mixed_states();
+
+    app.process_picker.open();
+    app.process_picker.select_next(manager.get_processes().len());
+
+    let output = render_app_to_string(&mut app, &manager, 100, 30);
+    assert!(output.contains("r restart | k kill | s start"));
+    assert_snapshot!(output);
+}
+
+/// The status bar and command input rows, each as runs of text with their style,
+/// so snapshots catch theme color and modifier changes
+fn render_footer_styles(app: &mut overitall::ui::App, height: u16) -> String {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let width = 60;
+    let manager = create_test_process_manager();
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| overitall::ui::draw(f, app, &manager)).unwrap();
+
+    let buffer = terminal.backend().buffer();
+    let mut result = String::new();
+    for y in height - 2..height {
+        let mut runs: Vec<(String, ratatui::style::Style)> = Vec::new();
+        for x in 0..width {
+            let cell = buffer.cell((x, y)).unwrap();
+            match runs.last_mut() {
+                Some((text, style)) if *style == cell.style() => text.push_str(cell.symbol()),
+                _ => runs.push((cell.symbol().to_string(), cell.style())),
+            }
+        }
+        for (text, style) in runs {
+            result.push_str(&format!(
+                "{:?} fg={:?} bg={:?} mods={:?}\n",
+                text,
+                style.fg.unwrap_or_default(),
+                style.bg.unwrap_or_default(),
+                style.add_modifier
+            ));
+        }
+        result.push('\n');
+    }
+    result
+}
+
+#[test]
+fn test_snapshot_status_bar_theme_presets() {
+    for name in overitall::ui::theme::THEMES {
+        let mut app = create_test_app();
+        app.set_theme(overitall::ui::Theme::from_config(Some(name)));
+        app.display.set_status_error("Failed to start: web".to_string());
+
+        let output = render_footer_styles(&mut app, 12);
+        insta::with_settings!({ snapshot_suffix => *name }, {
+            assert_snapshot!(output);
+        });
+    }
+}
+
+#[test]
+fn test_status_bar_volume_histogram_is_opt_in() {
+    let mut app = create_test_app();
+    let mut manager = create_test_process_manager();
+    manager.add_process("web".to_string(), "ruby web.rb".to_string(), None, None, None);
+    for i in 0..3 {
+        manager.add_test_log(LogLine::new(LogSource::ProcessStdout("web".to_string()), format!("request {}", i)));
+    }
+
+    let output = render_app_to_string(&mut app, &manager, 160, 30);
+    assert!(!output.contains("vol "), "{}", output);
+
+    app.display.volume_histogram = true;
+    let output = render_app_to_string(&mut app, &manager, 160, 30);
+    // Every line arrived just now, so the newest bucket holds the peak
+    assert!(output.contains("█ peak 3/10s"), "{}", output);
+}
@@ -27,6 +27,36 @@ fn test_snapshot_batch_separators_visible() {
     assert_snapshot!(output);
 }
 
+/// Every batch divider row is exactly `width` columns of `─` around its label
+fn assert_dividers_fill(output: &str, width: usize) {
+    let dividers: Vec<&str> = output.lines().filter(|l| l.contains(" Batch ")).collect();
+    assert!(!dividers.is_empty(), "expected batch dividers");
+    for line in dividers {
+        assert_eq!(line.chars().count(), width, "divider fills the line: {:?}", line);
+        assert!(line.starts_with('─') && line.ends_with('─'), "divider reaches both edges: {:?}", line);
+    }
+}
+
+#[test]
+fn test_snapshot_batch_separators_narrow_terminal() {
+    let mut app = create_test_app();
+    let manager = create_manager_with_batched_logs();
+
+    let output = render_app_to_string(&mut app, &manager, 60, 30);
+    assert_dividers_fill(&output, 60);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_snapshot_batch_separators_wide_terminal() {
+    let mut app = create_test_app();
+    let manager = create_manager_with_batched_logs();
+
+    let output = render_app_to_string(&mut app, &manager, 200, 30);
+    assert_dividers_fill(&output, 200);
+    assert_snapshot!(output);
+}
+
 #[test]
 fn test_snapshot_batch_navigation_second_batch() {
     let mut app = create_test_app();
@@ -63,3 +93,38 @@ fn test_snapshot_single_batch_no_separators() {
     let output = render_app_to_string(&mut app, &manager, 120, 40);
     assert_snapshot!(output);
 }
+
+#[test]
+fn test_snapshot_restart_marker_between_batches() {
+    let mut app = create_test_app();
+    let mut manager = create_manager_with_batched_logs();
+
+    // Restart initiated between batch 1 (12:00:00.000) and batch 2 (12:00:00.500)
+    let restart_time = Local.with_ymd_and_hms(2024, 12, 10, 12, 0, 0).unwrap() + chrono::Duration::milliseconds(300);
+    manager.record_restart("worker", restart_time);
+
+    let output = render_app_to_string(&mut app, &manager, 120, 40);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_snapshot_sticky_header_names_batch_of_top_line() {
+    let mut app = create_test_app();
+    app.display.sticky_header = true;
+    let manager = create_manager_with_n_logs_separate_batches(20);
+
+    // Tailing: no sticky row
+    let output = render_app_to_string(&mut app, &manager, 80, 14);
+    assert!(!output.contains("Batch 20 · 2024-12-10"), "{}", output);
+
+    // Scrolled so "Log line number 9" (batch 9) is the top line
+    app.navigation.auto_scroll = false;
+    app.navigation.scroll_offset = 8;
+    let output = render_app_to_string(&mut app, &manager, 80, 14);
+    assert!(output.contains(" Batch 9 · 2024-12-10 · web "), "{}", output);
+    assert_snapshot!(output);
+
+    app.navigation.scroll_offset = 10;
+    let output = render_app_to_string(&mut app, &manager, 80, 14);
+    assert!(output.contains(" Batch 11 · 2024-12-10 · web "), "{}", output);
+}
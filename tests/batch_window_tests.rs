@@ -6,6 +6,7 @@ use chrono::{Local, TimeZone};
 use overitall::{
     log::{LogLine, LogSource},
     process::ProcessManager,
+    ui::BatchStrategy,
 };
 
 #[test]
@@ -46,11 +47,11 @@ fn test_batch_window_affects_batch_detection() {
 
     // With 100ms window, should be 2 batches
     let logs = manager.get_all_logs();
-    let batches_100ms = overitall::ui::detect_batches_from_logs(&logs, 100);
+    let batches_100ms = overitall::ui::detect_batches_from_logs(&logs, 100, BatchStrategy::TimeWindow);
     assert_eq!(batches_100ms.len(), 2, "With 100ms window, should have 2 batches");
 
     // With 300ms window, should be 1 batch
-    let batches_300ms = overitall::ui::detect_batches_from_logs(&logs, 300);
+    let batches_300ms = overitall::ui::detect_batches_from_logs(&logs, 300, BatchStrategy::TimeWindow);
     assert_eq!(batches_300ms.len(), 1, "With 300ms window, should have 1 batch");
 }
 
@@ -138,7 +139,7 @@ fn test_batch_window_prevents_chaining() {
     // Expected: 2 batches
     // - Batch 1: indices 0-1 (logs 1-2)
     // - Batch 2: indices 2-3 (logs 3-4)
-    let batches = overitall::ui::detect_batches_from_logs(&logs, 3000);
+    let batches = overitall::ui::detect_batches_from_logs(&logs, 3000, BatchStrategy::TimeWindow);
 
     assert_eq!(batches.len(), 2, "Should have 2 batches with 3s window");
     assert_eq!(batches[0], (0, 1), "First batch should contain logs 0-1");
@@ -148,3 +149,54 @@ fn test_batch_window_prevents_chaining() {
     // All logs would be in one batch because each consecutive pair is 2s < 3s
     // This test ensures we're comparing to the batch START, not previous log
 }
+
+/// web and worker alternate every 100ms for 1s, then each logs once more after a pause
+fn interleaved_logs() -> Vec<LogLine> {
+    let base_time = Local.with_ymd_and_hms(2024, 12, 10, 12, 0, 0).unwrap();
+    let mut times: Vec<i64> = (0..10).map(|i| i * 100).collect();
+    times.extend([1500, 1600]);
+    times
+        .iter()
+        .enumerate()
+        .map(|(i, ms)| {
+            let process = if i % 2 == 0 { "web" } else { "worker" };
+            let when = base_time + chrono::Duration::milliseconds(*ms);
+            LogLine::new_with_time(LogSource::ProcessStdout(process.to_string()), format!("Log {}", i), when)
+        })
+        .collect()
+}
+
+#[test]
+fn test_batch_strategies_on_interleaved_processes() {
+    let logs = interleaved_logs();
+    let refs: Vec<&LogLine> = logs.iter().collect();
+    let detect = |strategy| overitall::ui::detect_batches_from_logs(&refs, 1000, strategy);
+
+    // One global window lumps both processes together
+    assert_eq!(detect(BatchStrategy::TimeWindow), vec![(0, 9), (10, 11)]);
+
+    // Every process change breaks the batch
+    assert_eq!(detect(BatchStrategy::Hybrid).len(), 12);
+}
+
+#[test]
+fn test_batch_strategies_agree_for_a_single_process() {
+    let base_time = Local.with_ymd_and_hms(2024, 12, 10, 12, 0, 0).unwrap();
+    let logs: Vec<LogLine> = [0, 50, 400, 450, 2000]
+        .iter()
+        .map(|ms| {
+            LogLine::new_with_time(
+                LogSource::ProcessStdout("web".to_string()),
+                "line".to_string(),
+                base_time + chrono::Duration::milliseconds(*ms),
+            )
+        })
+        .collect();
+    let refs: Vec<&LogLine> = logs.iter().collect();
+
+    let expected = overitall::ui::detect_batches_from_logs(&refs, 100, BatchStrategy::TimeWindow);
+    assert_eq!(expected.len(), 3);
+    for strategy in BatchStrategy::ALL {
+        assert_eq!(overitall::ui::detect_batches_from_logs(&refs, 100, strategy), expected);
+    }
+}
@@ -148,11 +148,13 @@ pub fn create_manager_with_custom_status() -> ProcessManager {
     let status_config = StatusConfig {
         default: Some("Starting".to_string()),
         color: None,
+        ready: None,
         transitions: vec![
             StatusTransition {
                 pattern: "Ready".to_string(),
                 label: "Ready".to_string(),
                 color: Some("green".to_string()),
+                marks_failed: false,
             },
         ],
     };
@@ -201,11 +203,13 @@ pub fn create_manager_with_mixed_states() -> ProcessManager {
     let db_config = StatusConfig {
         default: Some("Syncing".to_string()),
         color: None,
+        ready: None,
         transitions: vec![
             StatusTransition {
                 pattern: "Ready".to_string(),
                 label: "Ready".to_string(),
                 color: Some("green".to_string()),
+                marks_failed: false,
             },
         ],
     };
@@ -234,11 +238,13 @@ pub fn create_manager_with_many_processes() -> ProcessManager {
     let web_config = StatusConfig {
         default: Some("Booting".to_string()),
         color: None,
+        ready: None,
         transitions: vec![
             StatusTransition {
                 pattern: "Ready".to_string(),
                 label: "Ready".to_string(),
                 color: Some("green".to_string()),
+                marks_failed: false,
             },
         ],
     };
@@ -249,6 +255,7 @@ pub fn create_manager_with_many_processes() -> ProcessManager {
     let api_config = StatusConfig {
         default: Some("Initializing".to_string()),
         color: None,
+        ready: None,
         transitions: vec![],
     };
     manager.add_process("api".to_string(), "echo hi".to_string(), None, Some(&api_config), None);
@@ -3,7 +3,7 @@ mod common;
 use common::*;
 use insta::assert_snapshot;
 use overitall::ui::DisplayMode;
-use overitall::ui::display_state::TimestampMode;
+use overitall::ui::display_state::{ProcessListPosition, TimestampMode};
 
 // ============================================================================
 // Display Mode Tests
@@ -39,6 +39,184 @@ fn test_snapshot_display_mode_wrap() {
     assert_snapshot!(output);
 }
 
+// ============================================================================
+// Truncation Width Tests
+// ============================================================================
+
+/// Log rows that were cut (they carry the truncation suffix)
+fn truncated_rows<'a>(output: &'a str, suffix: &str) -> Vec<&'a str> {
+    output.lines().filter(|row| row.trim_end().ends_with(suffix)).collect()
+}
+
+#[test]
+fn test_snapshot_truncation_fills_width() {
+    for width in [60u16, 80, 100] {
+        let mut app = create_test_app();
+        app.display.display_mode = DisplayMode::Full;
+        let manager = create_manager_with_long_logs();
+
+        let output = render_app_to_string(&mut app, &manager, width, 12);
+        let rows = truncated_rows(&output, "↵");
+        assert!(!rows.is_empty(), "no truncated rows at width {}", width);
+        for row in rows {
+            // The suffix lands in the last column: no wasted columns, no overflow
+            assert_eq!(row.chars().count(), width as usize, "row at width {}: {:?}", width, row);
+            assert!(row.ends_with("… ↵"), "row at width {}: {:?}", width, row);
+        }
+        assert_snapshot!(format!("truncation_fills_width_{}", width), output);
+    }
+}
+
+#[test]
+fn test_snapshot_truncation_suffix_and_margin_are_configurable() {
+    let mut app = create_test_app();
+    app.display.display_mode = DisplayMode::Full;
+    app.display.truncation_suffix = "...".to_string();
+    app.display.line_margin = 4;
+    let manager = create_manager_with_long_logs();
+
+    let output = render_app_to_string(&mut app, &manager, 80, 12);
+    let rows = truncated_rows(&output, "...");
+    assert_eq!(rows.len(), 3);
+    for row in rows {
+        assert!(row.ends_with("...    "), "{:?}", row);
+    }
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_snapshot_truncation_wide_chars_stay_inside_viewer() {
+    let mut manager = create_test_process_manager();
+    manager.add_process("web".to_string(), "ruby web.rb".to_string(), None, None, None);
+    // Prefix "[12:00:00] web: " is 16 columns, so the double-width characters
+    // alternate between fitting exactly and straddling the cut column
+    manager.add_test_log(create_test_log_line("web", "日本語のログメッセージが画面の端で切られます。さらに続くテキスト"));
+    manager.add_test_log(create_test_log_line("web", "x日本語のログメッセージが画面の端で切られます。さらに続くテキスト"));
+
+    for width in [40u16, 41] {
+        let mut app = create_test_app();
+        app.display.display_mode = DisplayMode::Full;
+
+        let backend = ratatui::backend::TestBackend::new(width, 8);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal.draw(|f| overitall::ui::draw(f, &mut app, &manager)).unwrap();
+        let buffer = terminal.backend().buffer();
+
+        let mut cut_rows = 0;
+        for y in 0..buffer.area.height {
+            let last = buffer.cell((width - 1, y)).unwrap().symbol();
+            let before = buffer.cell((width - 2, y)).unwrap().symbol();
+            if last == "↵" || before == "↵" {
+                cut_rows += 1;
+                // A wide character that doesn't fit leaves at most one blank column
+                assert!(last == "↵" || last == " ", "row {} at width {} overflows: {:?}", y, width, last);
+            }
+        }
+        assert_eq!(cut_rows, 2, "width {}", width);
+        assert_snapshot!(format!("truncation_wide_chars_{}", width), render_app_to_string(&mut app, &manager, width, 8));
+    }
+}
+
+#[test]
+fn test_snapshot_horizontal_scroll_shows_later_columns() {
+    let mut manager = create_test_process_manager();
+    manager.add_process("db".to_string(), "psql".to_string(), None, None, None);
+    manager.add_test_log(create_test_log_line(
+        "db",
+        "| id | name    | email             | created_at          | plan       | last_login          |",
+    ));
+    manager.add_test_log(create_test_log_line("db", "(1 row)"));
+
+    let mut app = create_test_app();
+    app.display.display_mode = DisplayMode::Full;
+    for _ in 0..6 {
+        app.display.scroll_right(overitall::ui::display_state::HORIZONTAL_SCROLL_STEP);
+    }
+
+    let output = render_app_to_string(&mut app, &manager, 60, 8);
+    let table_row = output.lines().find(|row| row.contains("created_at")).expect("scrolled table row");
+    assert!(table_row.contains("« ") && table_row.trim_end().ends_with("»"), "{:?}", table_row);
+    assert!(table_row.contains("last_login") && !table_row.contains("email"), "{:?}", table_row);
+    assert_snapshot!(output);
+
+    // Switching display mode starts scrolling over
+    app.display.cycle_display_mode();
+    assert_eq!(app.display.horizontal_offset, 0);
+}
+
+#[test]
+fn test_snapshot_condensed_display_collapses_metadata() {
+    let mut manager = create_test_process_manager();
+    manager.add_process("web".to_string(), "ruby web.rb".to_string(), None, None, None);
+    manager.add_test_log(create_test_log_line(
+        "web",
+        "[service:api] [env:prod] [request_id:a1b2c3] [user:42] Started GET /users",
+    ));
+
+    for (name, condensed) in [("full", false), ("condensed", true)] {
+        let mut app = create_test_app();
+        app.display.display_mode = DisplayMode::Full;
+        app.display.condensed = condensed;
+
+        let output = render_app_to_string(&mut app, &manager, 80, 8);
+        assert_eq!(output.contains("[+4] Started GET /users"), condensed, "{}", output);
+        insta::with_settings!({ snapshot_suffix => name }, {
+            assert_snapshot!(output);
+        });
+
+        // The expanded line view keeps the raw line
+        overitall::operations::navigation::select_next_line(&mut app, &manager);
+        app.display.expanded_line_view = true;
+        let expanded = render_app_to_string(&mut app, &manager, 120, 30);
+        assert!(expanded.contains("[request_id:a1b2c3]"), "{}", expanded);
+    }
+}
+
+/// Runs of same-styled text on the first row containing `needle`, with their colors
+fn styled_row(app: &mut overitall::ui::App, manager: &overitall::process::ProcessManager, needle: &str) -> String {
+    let (width, height) = (80u16, 8u16);
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(width, height)).unwrap();
+    terminal.draw(|f| overitall::ui::draw(f, app, manager)).unwrap();
+    let buffer = terminal.backend().buffer();
+
+    let row = (0..height)
+        .find(|&y| (0..width).map(|x| buffer.cell((x, y)).unwrap().symbol()).collect::<String>().contains(needle))
+        .expect("row not rendered");
+    let mut runs: Vec<(String, ratatui::style::Style)> = Vec::new();
+    for x in 0..width {
+        let cell = buffer.cell((x, row)).unwrap();
+        match runs.last_mut() {
+            Some((text, style)) if *style == cell.style() => text.push_str(cell.symbol()),
+            _ => runs.push((cell.symbol().to_string(), cell.style())),
+        }
+    }
+    runs.iter()
+        .map(|(text, style)| format!("{:?} fg={:?}\n", text, style.fg.unwrap_or_default()))
+        .collect()
+}
+
+#[test]
+fn test_snapshot_highlight_syntax_colors_status_code_by_class() {
+    let mut manager = create_test_process_manager();
+    manager.add_process("web".to_string(), "ruby web.rb".to_string(), None, None, None);
+    manager.add_test_log(create_test_log_line("web", "Completed 200 OK in 12ms (user=\"ana\")"));
+    manager.add_test_log(create_test_log_line("web", "Completed 500 Internal Server Error in 3ms"));
+    // Lines with their own colors are left alone
+    manager.add_test_log(create_test_log_line("web", "\x1b[35mCompleted 404 Not Found\x1b[0m"));
+
+    let mut app = create_test_app();
+    app.display.timestamp_mode = TimestampMode::Off;
+    app.display.highlight_syntax = true;
+
+    let rows = ["200 OK", "500 Internal", "404 Not"]
+        .map(|needle| styled_row(&mut app, &manager, needle))
+        .join("\n");
+    assert!(rows.contains("\"200\" fg=Green"), "{}", rows);
+    assert!(rows.contains("\"500\" fg=Red"), "{}", rows);
+    assert!(!rows.contains("\"404\""), "{}", rows);
+    assert_snapshot!(rows);
+}
+
 // ============================================================================
 // Expanded Line View Tests
 // ============================================================================
@@ -126,3 +304,103 @@ fn test_snapshot_timestamp_mode_off() {
     let output = render_app_to_string(&mut app, &manager, 120, 20);
     assert_snapshot!(output);
 }
+
+// ============================================================================
+// Aligned Messages Tests
+// ============================================================================
+
+fn create_manager_with_mixed_name_lengths() -> overitall::process::ProcessManager {
+    let mut manager = create_manager_with_logs();
+    manager.add_process("elasticsearch".to_string(), "bin/elasticsearch".to_string(), None, None, None);
+    manager.add_test_log(create_test_log_line("elasticsearch", "Cluster health status changed to GREEN"));
+    manager
+}
+
+#[test]
+fn test_snapshot_align_messages() {
+    let mut app = create_test_app();
+    app.display.align_messages = true;
+    app.display.timestamp_mode = TimestampMode::Off;
+    let manager = create_manager_with_mixed_name_lengths();
+
+    let output = render_app_to_string(&mut app, &manager, 120, 20);
+    assert!(output.contains("web:           Server ready to accept connections"));
+    assert!(output.contains("elasticsearch: Cluster health status changed to GREEN"));
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_align_messages_ignores_hidden_processes() {
+    let mut app = create_test_app();
+    app.display.align_messages = true;
+    app.display.timestamp_mode = TimestampMode::Off;
+    app.filters.hidden_processes.insert("elasticsearch".to_string());
+    let manager = create_manager_with_mixed_name_lengths();
+
+    let output = render_app_to_string(&mut app, &manager, 120, 20);
+    assert!(output.contains("web:    Server ready to accept connections"));
+}
+
+// ============================================================================
+// Split View Tests
+// ============================================================================
+
+#[test]
+fn test_snapshot_split_view_two_panes() {
+    let mut app = create_test_app();
+    let manager = create_manager_with_logs();
+    app.display.split_processes = Some(("web".to_string(), "worker".to_string()));
+
+    let output = render_app_to_string(&mut app, &manager, 140, 20);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_split_view_navigation_skips_other_processes() {
+    let mut app = create_test_app();
+    let mut manager = create_manager_with_logs();
+    manager.add_process("api".to_string(), "ruby api.rb".to_string(), None, None, None);
+    manager.add_test_log(create_test_log_line("api", "api line"));
+    app.display.split_processes = Some(("web".to_string(), "worker".to_string()));
+
+    // select_prev_line from the tail starts at the last navigable line
+    overitall::operations::navigation::select_prev_line(&mut app, &manager);
+    let selected = app.navigation.selected_line_id.unwrap();
+    let log = manager.get_all_logs().into_iter().find(|l| l.id == selected).unwrap();
+    assert_eq!(log.line, "Server ready to accept connections");
+}
+
+// ============================================================================
+// Process List Position Tests
+// ============================================================================
+
+#[test]
+fn test_snapshot_process_list_top() {
+    let mut app = create_test_app();
+    app.display.process_list_position = ProcessListPosition::Top;
+    let manager = create_manager_with_logs();
+
+    let output = render_app_to_string(&mut app, &manager, 120, 20);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_snapshot_process_list_bottom() {
+    let mut app = create_test_app();
+    app.display.process_list_position = ProcessListPosition::Bottom;
+    let manager = create_manager_with_logs();
+
+    let output = render_app_to_string(&mut app, &manager, 120, 20);
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_snapshot_process_list_hidden() {
+    let mut app = create_test_app();
+    app.display.process_list_position = ProcessListPosition::Hidden;
+    let manager = create_manager_with_logs();
+
+    let output = render_app_to_string(&mut app, &manager, 120, 20);
+    assert_snapshot!(output);
+    assert!(app.regions.process_list_area.is_none());
+}
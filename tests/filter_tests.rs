@@ -61,6 +61,28 @@ fn test_snapshot_filter_list_display() {
     assert_snapshot!(output);
 }
 
+#[test]
+fn test_snapshot_disabled_filter_is_listed_but_not_applied() {
+    let mut app = create_test_app();
+    let manager = create_manager_with_logs();
+
+    app.filters.add_include_filter("ERROR".to_string());
+    app.filters.add_exclude_filter("job".to_string());
+    app.filters.toggle_filter("ERROR");
+
+    // Only the exclude filter applies: non-ERROR lines stay, job lines go
+    let output = render_app_to_string(&mut app, &manager, 120, 40);
+    assert!(output.contains("Starting web server on port 3000"));
+    assert!(output.contains("ERROR: Database connection failed"));
+    assert!(!output.contains("Processing job #1234"));
+    assert!(output.contains("(1 filters, 1 off)"));
+
+    let listed = overitall::operations::filter::list_filters(&app).unwrap();
+    assert_eq!(listed, "Filters: 1) include: ERROR [off], 2) exclude: job");
+
+    assert_snapshot!(output);
+}
+
 #[test]
 fn test_snapshot_empty_results_after_filtering() {
     let mut app = create_test_app();
@@ -72,3 +94,79 @@ fn test_snapshot_empty_results_after_filtering() {
     let output = render_app_to_string(&mut app, &manager, 120, 40);
     assert_snapshot!(output);
 }
+
+/// web has `quiet_until = "Listening"`; its boot output arrives before the readiness line
+fn create_manager_with_quiet_startup() -> overitall::process::ProcessManager {
+    let mut manager = create_test_process_manager();
+    manager.add_process("web".to_string(), "rails s".to_string(), None, None, None);
+    manager.add_process("worker".to_string(), "sidekiq".to_string(), None, None, None);
+    manager.set_quiet_until("web", Some(regex::Regex::new("Listening").unwrap()));
+    manager.reset_process_status("web");
+
+    for line in [
+        "=> Booting Puma",
+        "=> Rails 7.1 application starting in development",
+        "Puma starting in single mode...",
+        "WARN: config.cache_store not set, using memory store",
+        "* Min threads: 5, max threads: 5",
+        "Listening on http://127.0.0.1:3000",
+        "GET /health 200 OK",
+    ] {
+        manager.process_single_log(create_test_log_line("web", line));
+    }
+    manager.process_single_log(create_test_log_line("worker", "Processing job #1"));
+    manager
+}
+
+#[test]
+fn test_snapshot_quiet_startup_collapses_boot_output() {
+    let mut app = create_test_app();
+    let manager = create_manager_with_quiet_startup();
+
+    let output = render_app_to_string(&mut app, &manager, 100, 20);
+    assert!(!output.contains("Booting Puma"), "boot boilerplate collapsed");
+    assert!(output.contains("WARN: config.cache_store"), "warnings during boot still show");
+    assert!(output.contains("Listening on http"), "readiness line shows");
+    assert!(output.contains("GET /health"), "post-ready lines show");
+    assert_snapshot!(output);
+
+    // :quiet off shows everything
+    app.filters.toggle_quiet_startup();
+    let output = render_app_to_string(&mut app, &manager, 100, 20);
+    assert!(output.contains("=> Booting Puma"));
+    assert!(!output.contains("booting…"));
+}
+
+#[test]
+fn test_muted_lines_hidden_until_pattern_then_shown() {
+    let mut app = create_test_app();
+    let mut manager = create_test_process_manager();
+    manager.add_process("web".to_string(), "rails s".to_string(), None, None, None);
+    manager.add_process("worker".to_string(), "sidekiq".to_string(), None, None, None);
+    manager.add_test_log(create_test_log_line("worker", "before mute"));
+
+    overitall::operations::visibility::mute_process(&mut app, &manager, "worker", "ready").unwrap();
+
+    // Same path as the main loop: ingest, then check the lines buffered since the last pass
+    let ingest = |app: &mut overitall::ui::App, manager: &mut overitall::process::ProcessManager, process: &str, line: &str| {
+        manager.ingest_from_channel(create_test_log_line(process, line));
+        let new_lines = manager.take_new_line_count();
+        overitall::operations::visibility::check_unmutes(app, &manager.get_recent_logs(new_lines));
+    };
+    ingest(&mut app, &mut manager, "worker", "polling queue 1");
+    ingest(&mut app, &mut manager, "web", "GET /health 200");
+
+    let output = render_app_to_string(&mut app, &manager, 100, 16);
+    assert!(output.contains("before mute"), "lines from before the mute stay visible");
+    assert!(!output.contains("polling queue"), "muted lines are hidden");
+    assert!(output.contains("GET /health"), "other processes are unaffected");
+
+    ingest(&mut app, &mut manager, "worker", "worker ready");
+    ingest(&mut app, &mut manager, "worker", "processing job 7");
+
+    let output = render_app_to_string(&mut app, &manager, 100, 16);
+    assert!(output.contains("worker ready") && output.contains("processing job 7"), "un-muted from the matching line on");
+    assert!(!output.contains("polling queue"), "lines logged while muted stay hidden");
+    assert!(output.contains("worker un-muted (matched 'ready')"));
+    assert_eq!(manager.get_all_logs().len(), 5, "muting never drops lines from the buffer");
+}
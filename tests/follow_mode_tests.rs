@@ -83,3 +83,61 @@ fn test_follow_mode_with_batch_separators() {
 
     assert_snapshot!(output);
 }
+
+// ============================================================================
+// Tail Preview Tests
+// ============================================================================
+
+fn scrolled_to_top_with_tail_preview() -> overitall::ui::App {
+    let mut app = create_test_app();
+    app.display.tail_preview = true;
+    app.navigation.auto_scroll = false;
+    app.navigation.scroll_offset = 0;
+    app
+}
+
+#[test]
+fn test_tail_preview_pins_newest_line_while_scrolled_up() {
+    let mut app = scrolled_to_top_with_tail_preview();
+    let mut manager = create_manager_with_n_logs_same_batch(50);
+
+    let output = render_app_to_string(&mut app, &manager, 100, 24);
+    assert!(output.contains("Log line number 1 "));
+    assert!(output.contains("Latest"));
+    assert!(output.contains("Log line number 50"));
+
+    // A line that arrives while scrolled up replaces the preview
+    manager.add_test_log(create_test_log_line("web", "Fresh error from the tail"));
+    let output = render_app_to_string(&mut app, &manager, 100, 24);
+    assert!(output.contains("Fresh error from the tail"));
+    assert!(!output.contains("Log line number 50"));
+
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_tail_preview_respects_filters() {
+    let mut app = scrolled_to_top_with_tail_preview();
+    app.filters.add_exclude_filter("number 50".to_string());
+    let manager = create_manager_with_n_logs_same_batch(50);
+
+    let output = render_app_to_string(&mut app, &manager, 100, 24);
+    assert!(output.contains("Log line number 49"));
+    assert!(!output.contains("Log line number 50"));
+}
+
+#[test]
+fn test_tail_preview_hidden_when_tailing_or_disabled() {
+    let manager = create_manager_with_n_logs_same_batch(50);
+
+    let mut app = create_test_app();
+    app.display.tail_preview = true;
+    let output = render_app_to_string(&mut app, &manager, 100, 24);
+    assert!(!output.contains("Latest"));
+
+    let mut app = scrolled_to_top_with_tail_preview();
+    app.display.tail_preview = false;
+    let output = render_app_to_string(&mut app, &manager, 100, 24);
+    assert!(!output.contains("Latest"));
+    assert!(!output.contains("Log line number 50"));
+}
@@ -113,7 +113,7 @@ fn test_batch_navigation_increments() {
     let logs = manager.get_all_logs();
     let filtered_logs = overitall::ui::apply_filters(logs, &app.filters.filters);
     let filtered_refs: Vec<&overitall::log::LogLine> = filtered_logs.iter().collect();
-    let batches = overitall::ui::detect_batches_from_logs(&filtered_refs, app.batch.batch_window_ms);
+    let batches = overitall::ui::detect_batches_from_logs(&filtered_refs, app.batch.batch_window_ms, app.batch.batch_strategy);
     let num_batches = batches.len();
 
     app.navigation.create_snapshot(filtered_logs);
@@ -214,7 +214,7 @@ fn test_focus_batch_finds_correct_batch() {
     let logs = manager.get_all_logs();
     let filtered_logs = overitall::ui::apply_filters(logs, &app.filters.filters);
     let filtered_refs: Vec<&overitall::log::LogLine> = filtered_logs.iter().collect();
-    let batches = overitall::ui::detect_batches_from_logs(&filtered_refs, app.batch.batch_window_ms);
+    let batches = overitall::ui::detect_batches_from_logs(&filtered_refs, app.batch.batch_window_ms, app.batch.batch_strategy);
 
     // Select a line in the second batch (if there are multiple batches)
     if batches.len() >= 2 {
@@ -509,9 +509,9 @@ fn test_parse_process_commands() {
 fn test_parse_commands_missing_args() {
     use overitall::command::parse_command;
 
-    // Missing process name
+    // :s without arg starts the most recently killed process
     let cmd = parse_command("s");
-    assert!(matches!(cmd, overitall::command::Command::Unknown(_)));
+    assert!(matches!(cmd, overitall::command::Command::StartLastKilled));
 
     // :r without arg now means "restart all" - it's valid
     let cmd = parse_command("r");
@@ -686,3 +686,45 @@ fn test_manual_trace_time_window_filtering() {
     // Should contain "1 logs" since only one log was within the time window
     assert!(msg.contains("1 logs") || msg.contains("Captured 1"));
 }
+
+#[test]
+fn test_manual_trace_labeled_recordings_get_numbered_ids() {
+    use overitall::operations::manual_trace;
+
+    let mut app = create_test_app();
+
+    let first = manual_trace::start_labeled_recording(&mut app, Some("checkout".to_string()));
+    assert_eq!(first, "rec-1");
+    assert_eq!(app.trace.manual_trace_label.as_deref(), Some("checkout"));
+
+    manual_trace::cancel_recording(&mut app);
+    assert!(app.trace.manual_trace_id.is_none());
+
+    let second = manual_trace::start_labeled_recording(&mut app, None);
+    assert_eq!(second, "rec-2");
+    assert_eq!(app.trace.manual_trace_id.as_deref(), Some("rec-2"));
+}
+
+#[test]
+fn test_manual_trace_annotate_adds_note_to_captured_window() {
+    use overitall::operations::manual_trace;
+
+    let mut app = create_test_app();
+    let mut manager = ProcessManager::new();
+
+    assert!(manual_trace::annotate(&app, &mut manager, "too early").is_err());
+
+    manual_trace::start_recording(&mut app);
+    manual_trace::annotate(&app, &mut manager, "clicked pay").unwrap();
+
+    let msg = manual_trace::stop_recording(&mut app, &manager).unwrap();
+    assert!(msg.contains("Captured 1"));
+    let captured = app.navigation.snapshot.as_ref().unwrap();
+    assert_eq!(captured[0].line, "[oit] note: clicked pay");
+    assert_eq!(captured[0].source.source_type(), "annotation");
+
+    // The note isn't a process's output: hiding processes keeps it and counts skip it
+    app.filters.hidden_processes.insert("oit".to_string());
+    assert!(!app.filters.hides_process_of(&captured[0]));
+    assert!(manager.line_counts().is_empty());
+}
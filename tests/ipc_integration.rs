@@ -488,6 +488,7 @@ fn test_snapshot_with_logs(logs: Vec<LogLineInfo>) -> StateSnapshot {
                 error: None,
                 custom_label: None,
                 custom_color: None,
+                color: None,
             },
             ProcessInfo {
                 name: "worker".to_string(),
@@ -495,6 +496,7 @@ fn test_snapshot_with_logs(logs: Vec<LogLineInfo>) -> StateSnapshot {
                 error: None,
                 custom_label: None,
                 custom_color: None,
+                color: None,
             },
         ],
         log_files: vec![],
@@ -508,9 +510,18 @@ fn test_snapshot_with_logs(logs: Vec<LogLineInfo>) -> StateSnapshot {
         buffer_stats: BufferStats::default(),
         trace_recording: false,
         active_trace_id: None,
+        recording_trace_id: None,
+        uptime_secs: 0,
+        tick: 0,
+        presets: Vec::new(),
+        pinned_processes: Vec::new(),
+        search_match_ids: Vec::new(),
         total_log_lines: logs.len(),
         hidden_processes: vec![],
         recent_logs: logs,
+        selected_line_id: None,
+        process_colors: std::collections::HashMap::new(),
+        process_details: std::collections::HashMap::new(),
     }
 }
 
@@ -533,6 +544,11 @@ async fn test_errors_command_returns_error_logs() {
             content: "Server started on port 3000".to_string(),
             timestamp: "2025-12-17T10:00:00Z".to_string(),
             batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
         LogLineInfo {
             id: 2,
@@ -540,6 +556,11 @@ async fn test_errors_command_returns_error_logs() {
             content: "Error: connection refused".to_string(),
             timestamp: "2025-12-17T10:00:01Z".to_string(),
             batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
         LogLineInfo {
             id: 3,
@@ -547,6 +568,11 @@ async fn test_errors_command_returns_error_logs() {
             content: "Warning: slow query detected".to_string(),
             timestamp: "2025-12-17T10:00:02Z".to_string(),
             batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
         LogLineInfo {
             id: 4,
@@ -554,6 +580,11 @@ async fn test_errors_command_returns_error_logs() {
             content: "Job failed with exit code 1".to_string(),
             timestamp: "2025-12-17T10:00:03Z".to_string(),
             batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
     ]);
 
@@ -603,6 +634,11 @@ async fn test_errors_command_with_level_filter() {
             content: "Error: database connection lost".to_string(),
             timestamp: "2025-12-17T10:00:00Z".to_string(),
             batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
         LogLineInfo {
             id: 2,
@@ -610,6 +646,11 @@ async fn test_errors_command_with_level_filter() {
             content: "Warning: memory usage high".to_string(),
             timestamp: "2025-12-17T10:00:01Z".to_string(),
             batch_id: None,
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
     ]);
 
@@ -654,6 +695,7 @@ async fn test_summary_command_returns_comprehensive_state() {
                 error: None,
                 custom_label: None,
                 custom_color: None,
+                color: None,
             },
             ProcessInfo {
                 name: "worker".to_string(),
@@ -661,6 +703,7 @@ async fn test_summary_command_returns_comprehensive_state() {
                 error: Some("Exit code: 1".to_string()),
                 custom_label: None,
                 custom_color: None,
+                color: None,
             },
             ProcessInfo {
                 name: "scheduler".to_string(),
@@ -668,6 +711,7 @@ async fn test_summary_command_returns_comprehensive_state() {
                 error: None,
                 custom_label: None,
                 custom_color: None,
+                color: None,
             },
         ],
         log_files: vec![],
@@ -677,10 +721,12 @@ async fn test_summary_command_returns_comprehensive_state() {
             FilterInfo {
                 pattern: "debug".to_string(),
                 filter_type: "exclude".to_string(),
+                enabled: true,
             },
             FilterInfo {
                 pattern: "error".to_string(),
                 filter_type: "include".to_string(),
+                enabled: true,
             },
         ],
         search_pattern: None,
@@ -690,6 +736,9 @@ async fn test_summary_command_returns_comprehensive_state() {
             trace_filter: false,
             trace_selection: false,
             display_mode: "compact".to_string(),
+            errors_only: false,
+            batch_window_ms: 100,
+            batch_strategy: "time_window".to_string(),
         },
         auto_scroll: false,
         log_count: 500,
@@ -697,9 +746,16 @@ async fn test_summary_command_returns_comprehensive_state() {
             buffer_bytes: 10000000,
             max_buffer_bytes: 52428800,
             usage_percent: 19.07,
+            ingest_backlog: 0,
         },
         trace_recording: true,
         active_trace_id: Some("trace123".to_string()),
+        recording_trace_id: None,
+        uptime_secs: 0,
+        tick: 0,
+        presets: Vec::new(),
+        pinned_processes: Vec::new(),
+        search_match_ids: Vec::new(),
         recent_logs: vec![
             LogLineInfo {
                 id: 1,
@@ -707,10 +763,18 @@ async fn test_summary_command_returns_comprehensive_state() {
                 content: "Error: connection timeout".to_string(),
                 timestamp: "2025-12-17T10:00:00Z".to_string(),
                 batch_id: None,
+                stderr: false,
+                run: None,
+                source_type: "stdout".to_string(),
+                source_path: None,
+                arrival_time: None,
             },
         ],
         total_log_lines: 500,
         hidden_processes: vec!["scheduler".to_string()],
+        selected_line_id: None,
+        process_colors: std::collections::HashMap::new(),
+        process_details: std::collections::HashMap::new(),
     };
 
     // Send summary command
@@ -780,6 +844,11 @@ async fn test_batch_command_returns_batch_lines() {
             content: "Request started".to_string(),
             timestamp: "2025-12-17T10:00:00Z".to_string(),
             batch_id: Some(10),
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
         LogLineInfo {
             id: 2,
@@ -787,6 +856,11 @@ async fn test_batch_command_returns_batch_lines() {
             content: "Processing request".to_string(),
             timestamp: "2025-12-17T10:00:01Z".to_string(),
             batch_id: Some(10),
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
         LogLineInfo {
             id: 3,
@@ -794,6 +868,11 @@ async fn test_batch_command_returns_batch_lines() {
             content: "Request completed".to_string(),
             timestamp: "2025-12-17T10:00:02Z".to_string(),
             batch_id: Some(10),
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
         LogLineInfo {
             id: 4,
@@ -801,6 +880,11 @@ async fn test_batch_command_returns_batch_lines() {
             content: "Different batch".to_string(),
             timestamp: "2025-12-17T10:00:03Z".to_string(),
             batch_id: Some(11),
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
     ]);
 
@@ -854,6 +938,11 @@ async fn test_batch_command_with_scroll_flag() {
             content: "Batch line 1".to_string(),
             timestamp: "2025-12-17T10:00:00Z".to_string(),
             batch_id: Some(7),
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
         LogLineInfo {
             id: 43,
@@ -861,6 +950,11 @@ async fn test_batch_command_with_scroll_flag() {
             content: "Batch line 2".to_string(),
             timestamp: "2025-12-17T10:00:01Z".to_string(),
             batch_id: Some(7),
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
     ]);
 
@@ -905,6 +999,11 @@ async fn test_batch_command_nonexistent_batch_returns_error() {
             content: "Some log".to_string(),
             timestamp: "2025-12-17T10:00:00Z".to_string(),
             batch_id: Some(5),
+            stderr: false,
+            run: None,
+            source_type: "stdout".to_string(),
+            source_path: None,
+            arrival_time: None,
         },
     ]);
 
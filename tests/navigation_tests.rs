@@ -71,3 +71,28 @@ fn test_wraparound_with_filters() {
     let output = render_app_to_string(&mut app, &manager, 120, 40);
     assert_snapshot!(output);
 }
+
+#[test]
+fn test_snapshot_new_marker_before_lines_that_arrived_under_an_overlay() {
+    let mut app = create_test_app();
+    let mut manager = create_manager_with_logs();
+    render_app_to_string(&mut app, &manager, 100, 20);
+
+    // Lines arrive while the help overlay covers the logs
+    app.display.show_help = true;
+    render_app_to_string(&mut app, &manager, 100, 20);
+    manager.add_test_log(create_test_log_line("web", "GET /api/orders 200"));
+    manager.add_test_log(create_test_log_line("worker", "Processed order 17"));
+    app.display.show_help = false;
+
+    let output = render_app_to_string(&mut app, &manager, 100, 20);
+    let lines: Vec<&str> = output.lines().collect();
+    let marker = lines.iter().position(|line| line.contains("─ new ─")).expect(&output);
+    assert!(lines[marker + 1].contains("GET /api/orders 200"), "{}", output);
+    assert_snapshot!(output);
+
+    // Jumping to the latest clears it
+    app.navigation.scroll_to_bottom();
+    let output = render_app_to_string(&mut app, &manager, 100, 20);
+    assert!(!output.contains("─ new ─"), "{}", output);
+}
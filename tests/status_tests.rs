@@ -33,11 +33,13 @@ fn test_custom_status_after_transition() {
     let status_config = StatusConfig {
         default: Some("Starting".to_string()),
         color: None,
+        ready: None,
         transitions: vec![
             StatusTransition {
                 pattern: "Server ready".to_string(),
                 label: "Ready".to_string(),
                 color: Some("green".to_string()),
+                marks_failed: false,
             },
         ],
     };
@@ -81,11 +83,13 @@ fn test_custom_status_with_multiple_processes() {
     let web_config = StatusConfig {
         default: Some("Booting".to_string()),
         color: None,
+        ready: None,
         transitions: vec![
             StatusTransition {
                 pattern: "Listening".to_string(),
                 label: "Listening".to_string(),
                 color: Some("yellow".to_string()),
+                marks_failed: false,
             },
         ],
     };
@@ -93,11 +97,13 @@ fn test_custom_status_with_multiple_processes() {
     let worker_config = StatusConfig {
         default: Some("Idle".to_string()),
         color: None,
+        ready: None,
         transitions: vec![
             StatusTransition {
                 pattern: "Processing".to_string(),
                 label: "Working".to_string(),
                 color: Some("cyan".to_string()),
+                marks_failed: false,
             },
         ],
     };
@@ -251,3 +257,134 @@ fn test_snapshot_process_panel_summary_with_hidden() {
     let output = render_app_to_string(&mut app, &manager, 120, 20);
     assert_snapshot!(output);
 }
+
+// ============================================================================
+// Stable Process Layout Tests
+// ============================================================================
+
+fn manager_with_web_label(label: &str) -> overitall::process::ProcessManager {
+    let status_config = StatusConfig {
+        default: Some(label.to_string()),
+        color: None,
+        ready: None,
+        transitions: vec![],
+    };
+    let mut manager = overitall::process::ProcessManager::new();
+    manager.add_process("web".to_string(), "echo hi".to_string(), None, Some(&status_config), None);
+    manager.add_process("worker".to_string(), "echo hi".to_string(), None, None, None);
+    manager.reset_process_status("web");
+    manager
+}
+
+/// Character column of `needle` in `line`
+fn column_of(line: &str, needle: &str) -> Option<usize> {
+    line.find(needle).map(|idx| line[..idx].chars().count())
+}
+
+fn process_row(app: &mut overitall::ui::App, manager: &overitall::process::ProcessManager) -> String {
+    let output = render_app_to_string(app, manager, 80, 8);
+    output.lines().next().unwrap().to_string()
+}
+
+#[test]
+fn test_snapshot_stable_process_layout_when_label_grows() {
+    let mut app = create_test_app();
+    app.display.stable_process_layout = true;
+
+    let before = process_row(&mut app, &manager_with_web_label("Ready"));
+    let after = process_row(&mut app, &manager_with_web_label("Compiling assets for production"));
+
+    // worker stays in place and the long label is cut to fit its slot
+    assert_eq!(column_of(&before, "worker"), column_of(&after, "worker"));
+    assert!(after.contains("[Compiling a…]"));
+
+    assert_snapshot!(format!("{}\n{}", before, after));
+}
+
+#[test]
+fn test_process_layout_reflows_without_stable_layout() {
+    let mut app = create_test_app();
+
+    let before = process_row(&mut app, &manager_with_web_label("Ready"));
+    let after = process_row(&mut app, &manager_with_web_label("Compiling assets for production"));
+
+    assert_ne!(column_of(&before, "worker"), column_of(&after, "worker"));
+    assert!(after.contains("[Compiling assets for production]"));
+}
+
+#[test]
+fn test_snapshot_pinned_processes_come_first() {
+    let mut app = create_test_app();
+    app.display.pinned_processes = vec!["worker".to_string(), "db".to_string()];
+    let manager = create_manager_with_mixed_states();
+
+    let row = process_row(&mut app, &manager);
+
+    // Pin order first, then the rest alphabetically
+    let order: Vec<usize> = ["*worker", "*db", "api", "mailer", "web"]
+        .iter()
+        .map(|name| column_of(&row, name).unwrap_or_else(|| panic!("{} missing from {:?}", name, row)))
+        .collect();
+    assert!(order.windows(2).all(|pair| pair[0] < pair[1]), "{:?}", row);
+
+    assert_snapshot!(row);
+}
+
+#[test]
+fn test_paused_ingest_shows_paused_label() {
+    let mut app = create_test_app();
+    let mut manager = create_manager_with_mixed_states();
+    manager.pause_ingest("api");
+
+    let row = process_row(&mut app, &manager);
+    assert!(row.contains("api [Paused]"), "{:?}", row);
+}
+
+// ============================================================================
+// Last Line Header Tests
+// ============================================================================
+
+#[test]
+fn test_snapshot_show_last_line_appends_newest_line_per_process() {
+    let mut app = create_test_app();
+    app.display.show_last_line = true;
+    let mut manager = manager_with_web_label("Running");
+    manager.add_process("db".to_string(), "echo hi".to_string(), None, None, None);
+    manager.add_test_log(create_test_log_line("web", "Listening on :3000"));
+    manager.add_test_log(create_test_log_line("worker", "\x1b[32mProcessed job 41\x1b[0m"));
+    manager.add_test_log(create_test_log_line("web", "GET /api/users 200 in 12ms with a very long trailing message"));
+
+    let output = render_app_to_string(&mut app, &manager, 60, 10);
+    let header: Vec<&str> = output.lines().take(3).collect();
+
+    // One row per process, each with its newest line cut to fit
+    assert!(header[1].contains("web [Running]"), "{:?}", header);
+    assert!(header[1].contains("GET /api/users 200") && header[1].trim_end().ends_with('…'), "{:?}", header);
+    assert!(header[2].contains("worker") && header[2].contains("Processed job 41"), "{:?}", header);
+    assert_eq!(column_of(header[1], "GET"), column_of(header[2], "Processed"));
+
+    assert_snapshot!(output);
+}
+
+#[test]
+fn test_snapshot_show_commands_in_full_mode_lists_command_under_name() {
+    let mut app = create_test_app();
+    app.display.show_commands = true;
+    let mut manager = overitall::process::ProcessManager::new();
+    manager.add_process("web".to_string(), "bundle exec rails server -p 3000".to_string(), None, None, None);
+    manager.add_process("worker".to_string(), "bundle exec sidekiq -C config/sidekiq.yml".to_string(), None, None, None);
+
+    // Compact mode keeps the grid
+    let output = render_app_to_string(&mut app, &manager, 60, 10);
+    assert!(!output.contains("rails server"), "{}", output);
+
+    app.display.display_mode = overitall::ui::DisplayMode::Full;
+    let output = render_app_to_string(&mut app, &manager, 60, 10);
+    let header: Vec<&str> = output.lines().take(4).collect();
+    assert!(header[0].starts_with("web"), "{:?}", header);
+    assert!(header[1].contains("bundle exec rails server -p 3000"), "{:?}", header);
+    assert!(header[2].starts_with("worker"), "{:?}", header);
+    assert!(header[3].contains("bundle exec sidekiq"), "{:?}", header);
+
+    assert_snapshot!(output);
+}